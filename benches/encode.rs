@@ -134,6 +134,113 @@ benchmark_impl!(
     encode_style_quoted_text_to_writer
 );
 
+benchmark_impl!(comment, encode_comment, encode_comment_to_string, encode_comment_to_writer);
+
+benchmark_impl!(cdata, encode_cdata, encode_cdata_to_string, encode_cdata_to_writer);
+
+benchmark_impl!(rcdata, encode_rcdata, encode_rcdata_to_string, encode_rcdata_to_writer);
+
+benchmark_impl!(xml_text, encode_xml_text, encode_xml_text_to_string, encode_xml_text_to_writer);
+
+benchmark_impl!(
+    xml_attribute,
+    encode_xml_attribute,
+    encode_xml_attribute_to_string,
+    encode_xml_attribute_to_writer
+);
+
+benchmark_impl!(
+    processing_instruction,
+    encode_processing_instruction,
+    encode_processing_instruction_to_string,
+    encode_processing_instruction_to_writer
+);
+
+benchmark_impl!(
+    json_for_script,
+    encode_json_for_script,
+    encode_json_for_script_to_string,
+    encode_json_for_script_to_writer
+);
+
+benchmark_impl!(
+    js_in_attribute,
+    encode_js_in_attribute,
+    encode_js_in_attribute_to_string,
+    encode_js_in_attribute_to_writer
+);
+
+benchmark_impl!(
+    css_in_attribute,
+    encode_css_in_attribute,
+    encode_css_in_attribute_to_string,
+    encode_css_in_attribute_to_writer
+);
+
+benchmark_impl!(srcset, encode_srcset, encode_srcset_to_string, encode_srcset_to_writer);
+
+benchmark_impl!(href, encode_href, encode_href_to_string, encode_href_to_writer);
+
+benchmark_impl!(
+    text_ascii_only,
+    encode_text_ascii_only,
+    encode_text_ascii_only_to_string,
+    encode_text_ascii_only_to_writer
+);
+
+benchmark_impl!(
+    text_named_entities,
+    encode_text_named_entities,
+    encode_text_named_entities_to_string,
+    encode_text_named_entities_to_writer
+);
+
+benchmark_impl!(
+    text_preserving_entities,
+    encode_text_preserving_entities,
+    encode_text_preserving_entities_to_string,
+    encode_text_preserving_entities_to_writer
+);
+
+benchmark_impl!(
+    safe_idempotent,
+    encode_safe_idempotent,
+    encode_safe_idempotent_to_string,
+    encode_safe_idempotent_to_writer
+);
+
+benchmark_impl!(
+    unquoted_attribute_minimal,
+    encode_unquoted_attribute_minimal,
+    encode_unquoted_attribute_minimal_to_string,
+    encode_unquoted_attribute_minimal_to_writer
+);
+
+benchmark_impl!(
+    script_backquoted_text,
+    encode_script_backquoted_text,
+    encode_script_backquoted_text_to_string,
+    encode_script_backquoted_text_to_writer
+);
+
+benchmark_impl!(srcdoc, encode_srcdoc, encode_srcdoc_to_string, encode_srcdoc_to_writer);
+
+benchmark_impl!(
+    text_email,
+    encode_text_email,
+    encode_text_email_to_string,
+    encode_text_email_to_writer
+);
+
+benchmark_impl!(css_url, encode_css_url, encode_css_url_to_string, encode_css_url_to_writer);
+
+benchmark_impl!(
+    plain_text_to_html,
+    text_to_html,
+    text_to_html_to_string,
+    text_to_html_to_writer
+);
+
 benchmark_main!(
     text_minimal,
     text,
@@ -150,4 +257,25 @@ benchmark_main!(
     style_single_quoted_text,
     style_double_quoted_text,
     style_quoted_text,
+    comment,
+    cdata,
+    rcdata,
+    xml_text,
+    xml_attribute,
+    processing_instruction,
+    json_for_script,
+    js_in_attribute,
+    css_in_attribute,
+    srcset,
+    href,
+    text_ascii_only,
+    text_named_entities,
+    text_preserving_entities,
+    safe_idempotent,
+    unquoted_attribute_minimal,
+    script_backquoted_text,
+    srcdoc,
+    text_email,
+    css_url,
+    plain_text_to_html,
 );
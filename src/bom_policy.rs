@@ -0,0 +1,29 @@
+/// How the `*_with_bom_policy` encoders and decoders handle U+FEFF BYTE ORDER MARK.
+///
+/// Content read from files frequently starts with a BOM, which ends up as an invisible character in the rendered page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BomPolicy {
+    /// Keep the BOMs as they are, like the functions without a policy do.
+    Keep,
+    /// Remove a leading BOM.
+    Strip,
+    /// Remove a leading BOM. When encoding, also escape the other BOMs to `&#xFEFF;`. When decoding, the other BOMs are kept.
+    StripAndEscape,
+}
+
+impl BomPolicy {
+    /// Remove a leading BOM from `text` unless the policy is `Keep`.
+    #[inline]
+    pub(crate) fn strip(self, text: &str) -> &str {
+        match self {
+            BomPolicy::Keep => text,
+            _ => text.strip_prefix('\u{FEFF}').unwrap_or(text),
+        }
+    }
+
+    /// Whether `c` is a BOM which should be escaped.
+    #[inline]
+    pub(crate) fn escapes(self, c: char) -> bool {
+        c == '\u{FEFF}' && self == BomPolicy::StripAndEscape
+    }
+}
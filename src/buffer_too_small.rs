@@ -0,0 +1,133 @@
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// The error of the `*_to_slice` functions when the given buffer cannot hold the whole output. The output of the consumed input is written to the beginning of the buffer, so the rest of the input can be continued with another buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferTooSmall {
+    consumed: usize,
+    written: usize,
+}
+
+impl BufferTooSmall {
+    /// The number of input bytes whose output is completely written. This is always on a character boundary.
+    #[inline]
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// The number of bytes written to the buffer.
+    #[inline]
+    pub fn written(&self) -> usize {
+        self.written
+    }
+}
+
+impl Display for BufferTooSmall {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "buffer too small after {} input bytes are consumed and {} bytes are written",
+            self.consumed, self.written
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BufferTooSmall {}
+
+/// A caller-provided buffer which the `*_to_slice` functions write to.
+pub(crate) struct SliceWriter<'a> {
+    output: &'a mut [u8],
+    written: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    #[inline]
+    pub(crate) fn new(output: &'a mut [u8]) -> SliceWriter<'a> {
+        SliceWriter {
+            output,
+            written: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Copy `text[start..end]`. If it does not fit, copy as many whole characters of it as fit and fail.
+    #[inline]
+    pub(crate) fn write_text(
+        &mut self,
+        text: &str,
+        start: usize,
+        end: usize,
+    ) -> Result<(), BufferTooSmall> {
+        let available = self.output.len() - self.written;
+
+        let mut length = end - start;
+
+        let result = if length > available {
+            length = available;
+
+            while !text.is_char_boundary(start + length) {
+                length -= 1;
+            }
+
+            Err(BufferTooSmall {
+                consumed: start + length,
+                written: self.written + length,
+            })
+        } else {
+            Ok(())
+        };
+
+        self.output[self.written..(self.written + length)]
+            .copy_from_slice(&text.as_bytes()[start..(start + length)]);
+        self.written += length;
+
+        result
+    }
+
+    /// Copy the escape sequence of a character after the first `consumed` input bytes, or fail if it does not fit.
+    #[inline]
+    pub(crate) fn write_escape(
+        &mut self,
+        bytes: &[u8],
+        consumed: usize,
+    ) -> Result<(), BufferTooSmall> {
+        if bytes.len() > self.output.len() - self.written {
+            return Err(BufferTooSmall {
+                consumed,
+                written: self.written,
+            });
+        }
+
+        self.output[self.written..(self.written + bytes.len())].copy_from_slice(bytes);
+        self.written += bytes.len();
+
+        Ok(())
+    }
+
+    /// Write the escape sequence of a character after the first `consumed` input bytes with `f`, which may write it in several pieces through `EncodeSink`, or fail without writing any of it if it does not fit.
+    #[inline]
+    pub(crate) fn write_escape_with<F: FnOnce(&mut Self) -> Result<(), BufferTooSmall>>(
+        &mut self,
+        consumed: usize,
+        f: F,
+    ) -> Result<(), BufferTooSmall> {
+        let written = self.written;
+
+        f(self).map_err(|_| {
+            self.written = written;
+
+            BufferTooSmall {
+                consumed,
+                written,
+            }
+        })
+    }
+}
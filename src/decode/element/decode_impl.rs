@@ -1,10 +1,10 @@
 macro_rules! decode_impl {
-    ($l:expr; $(#[$attr: meta])* $parse_macro:ident; $(#[$decode_attr: meta])* $decode_name: ident; $(#[$decode_to_string_attr: meta])* $decode_to_string_name: ident; $(#[$decode_to_vec_attr: meta])* $decode_to_vec_name: ident; $(#[$decode_to_writer_attr: meta])* $decode_to_writer_name: ident $(;)*) => {
+    ($l:expr; $(#[$attr: meta])* $parse_macro:ident; $(#[$decode_attr: meta])* $decode_name: ident; $(#[$decode_to_string_attr: meta])* $decode_to_string_name: ident; $(#[$decode_to_vec_attr: meta])* $decode_to_vec_name: ident; $(#[$decode_to_writer_attr: meta])* $decode_to_writer_name: ident; $(#[$decode_to_fmt_writer_attr: meta])* $decode_to_fmt_writer_name: ident $(;)*) => {
         $(#[$decode_attr])*
         ///
         $(#[$attr])*
         #[inline]
-        pub fn $decode_name<S: ?Sized + AsRef<str>>(text: &S) -> Cow<str> {
+        pub fn $decode_name<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
             let text = text.as_ref();
             let text_bytes = text.as_bytes();
             let text_length = text_bytes.len();
@@ -16,6 +16,16 @@ macro_rules! decode_impl {
 
             let (mut v, mut start) = loop {
                 if p == text_length {
+                    // the encoders also escape the tag at the end of the text
+                    if step == $l + 2 {
+                        let mut v = Vec::with_capacity(text_length);
+
+                        v.extend_from_slice(&text_bytes[..(p - ($l + 1))]);
+                        v.extend_from_slice(&text_bytes[(p - $l)..]);
+
+                        return Cow::from(unsafe { String::from_utf8_unchecked(v) });
+                    }
+
                     return Cow::from(text);
                 }
 
@@ -58,6 +68,11 @@ macro_rules! decode_impl {
                 p += 1;
             }
 
+            if step == $l + 2 {
+                v.extend_from_slice(&text_bytes[start..(p - ($l + 1))]);
+                start = p - $l;
+            }
+
             v.extend_from_slice(&text_bytes[start..p]);
 
             Cow::from(unsafe { String::from_utf8_unchecked(v) })
@@ -104,6 +119,11 @@ macro_rules! decode_impl {
                 end += 1;
             }
 
+            if step == $l + 2 {
+                output.extend_from_slice(&text_bytes[start..(end - ($l + 1))]);
+                start = end - $l;
+            }
+
             output.extend_from_slice(&text_bytes[start..end]);
 
             &output[current_length..]
@@ -138,7 +158,20 @@ macro_rules! decode_impl {
                 end += 1;
             }
 
+            if step == $l + 2 {
+                output.write_all(&text_bytes[start..(end - ($l + 1))])?;
+                start = end - $l;
+            }
+
             output.write_all(&text_bytes[start..end])
         }
+
+        $(#[$decode_to_fmt_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $decode_to_fmt_writer_name<S: AsRef<str>, W: fmt::Write>(text: S, output: &mut W) -> Result<(), fmt::Error> {
+            output.write_str(&$decode_name(text.as_ref()))
+        }
     };
 }
@@ -1,3 +1,4 @@
+use core::fmt;
 use core::str::from_utf8_unchecked;
 
 use alloc::borrow::Cow;
@@ -26,6 +27,10 @@ macro_rules! parse_script {
             2 => {
                 match $e {
                     b'/' => $step = 3,
+                    b'!' => {
+                        $step = 0;
+                        $bq
+                    }
                     $($(| $addi)+ => {
                         $step = 0;
                         $bq
@@ -77,7 +82,7 @@ macro_rules! parse_script {
             }
             9 => {
                 match $e {
-                    b'>' | 9..=13 | 28..=32 => {
+                    b'>' | b'/' | b'\t' | b'\n' | b'\x0C' | b'\r' | b' ' => {
                         $step = 0;
                         $b
                     },
@@ -112,6 +117,12 @@ macro_rules! parse_script_double_quoted_text {
     };
 }
 
+macro_rules! parse_script_backquoted_text {
+    ($e:expr, $step:ident, $b:block, $bq:block) => {
+        parse_script!($e, $step, $b, $bq, b'`', b'$');
+    };
+}
+
 macro_rules! parse_script_quoted_text {
     ($e:expr, $step:ident, $b:block, $bq:block) => {
         parse_script!($e, $step, $b, $bq, b'\'', b'"');
@@ -120,68 +131,101 @@ macro_rules! parse_script_quoted_text {
 
 decode_impl! {
     7;
-    /// The following substring is unescaped:
+    /// The following substrings are unescaped:
     ///
-    /// * `<\/script>` => `</script>`
+    /// * `<\/script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `</script`
+    /// * `<\!` => `<!`
     parse_script;
     /// Decode text from the `<script>` element.
     decode_script;
-    /// Write text from the `<script>` element to a mutable `String` reference and return the encoded string slice.
+    /// Write text from the `<script>` element to a mutable `String` reference and return the decoded string slice.
     decode_script_to_string;
-    /// Write text from the `<script>` element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    /// Write text from the `<script>` element to a mutable `Vec<u8>` reference and return the decoded data slice.
     decode_script_to_vec;
     /// Write text from the `<script>` element to a writer.
     decode_script_to_writer;
+    /// Write text from the `<script>` element to a `fmt::Write`.
+    decode_script_to_fmt_writer;
 }
 
 decode_impl! {
     7;
-    /// The following substring and character is unescaped:
+    /// The following substrings and character are unescaped:
     ///
-    /// * `<\/script>` => `</script>`
+    /// * `<\/script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `</script`
+    /// * `<\!` => `<!`
     /// * `\'` => `'`
     parse_script_single_quoted_text;
     /// Decode text from a single quoted text in the `<script>` element.
     decode_script_single_quoted_text;
-    /// Write text from a single quoted text in the `<script>` element to a mutable `String` reference and return the encoded string slice.
+    /// Write text from a single quoted text in the `<script>` element to a mutable `String` reference and return the decoded string slice.
     decode_script_single_quoted_text_to_string;
-    /// Write text from a single quoted text in the `<script>` element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    /// Write text from a single quoted text in the `<script>` element to a mutable `Vec<u8>` reference and return the decoded data slice.
     decode_script_single_quoted_text_to_vec;
     /// Write text from a single quoted text in the `<script>` element to a writer.
     decode_script_single_quoted_text_to_writer;
+    /// Write text from a single quoted text in the `<script>` element to a `fmt::Write`.
+    decode_script_single_quoted_text_to_fmt_writer;
 }
 
 decode_impl! {
     7;
-    /// The following substring and character are unescaped:
+    /// The following substrings and character are unescaped:
     ///
-    /// * `<\/script>` => `</script>`
+    /// * `<\/script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `</script`
+    /// * `<\!` => `<!`
     /// * `\"` => `"`
     parse_script_double_quoted_text;
     /// Decode text from a double quoted text in the `<script>` element.
     decode_script_double_quoted_text;
-    /// Write text from a double quoted text in the `<script>` element to a mutable `String` reference and return the encoded string slice.
+    /// Write text from a double quoted text in the `<script>` element to a mutable `String` reference and return the decoded string slice.
     decode_script_double_quoted_text_to_string;
-    /// Write text from a double quoted text in the `<script>` element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    /// Write text from a double quoted text in the `<script>` element to a mutable `Vec<u8>` reference and return the decoded data slice.
     decode_script_double_quoted_text_to_vec;
     /// Write text from a double quoted text in the `<script>` element to a writer.
     decode_script_double_quoted_text_to_writer;
+    /// Write text from a double quoted text in the `<script>` element to a `fmt::Write`.
+    decode_script_double_quoted_text_to_fmt_writer;
 }
 
 decode_impl! {
     7;
-    /// The following substring and characters are unescaped:
+    /// The following substrings and characters are unescaped:
     ///
-    /// * `<\/script>` => `</script>`
+    /// * `<\/script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `</script`
+    /// * `<\!` => `<!`
     /// * `\"` => `"`
     /// * `\'` => `'`
     parse_script_quoted_text;
     /// Decode text from a quoted text in the `<script>` element.
     decode_script_quoted_text;
-    /// Write text from a quoted text in the `<script>` element to a mutable `String` reference and return the encoded string slice.
+    /// Write text from a quoted text in the `<script>` element to a mutable `String` reference and return the decoded string slice.
     decode_script_quoted_text_to_string;
-    /// Write text from a quoted text in the `<script>` element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    /// Write text from a quoted text in the `<script>` element to a mutable `Vec<u8>` reference and return the decoded data slice.
     decode_script_quoted_text_to_vec;
     /// Write text from a quoted text in the `<script>` element to a writer.
     decode_script_quoted_text_to_writer;
+    /// Write text from a quoted text in the `<script>` element to a `fmt::Write`.
+    decode_script_quoted_text_to_fmt_writer;
+}
+
+decode_impl! {
+    7;
+    /// The following substrings and characters are unescaped:
+    ///
+    /// * `<\/script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `</script`
+    /// * `<\!` => `<!`
+    /// * `` \` `` => `` ` ``
+    /// * `\$` => `$`
+    parse_script_backquoted_text;
+    /// Decode text from a template literal in the `<script>` element.
+    decode_script_backquoted_text;
+    /// Write text from a template literal in the `<script>` element to a mutable `String` reference and return the decoded string slice.
+    decode_script_backquoted_text_to_string;
+    /// Write text from a template literal in the `<script>` element to a mutable `Vec<u8>` reference and return the decoded data slice.
+    decode_script_backquoted_text_to_vec;
+    /// Write text from a template literal in the `<script>` element to a writer.
+    decode_script_backquoted_text_to_writer;
+    /// Write text from a template literal in the `<script>` element to a `fmt::Write`.
+    decode_script_backquoted_text_to_fmt_writer;
 }
@@ -1,3 +1,4 @@
+use core::fmt;
 use core::str::from_utf8_unchecked;
 
 use alloc::borrow::Cow;
@@ -70,7 +71,7 @@ macro_rules! parse_style {
             }
             8 => {
                 match $e {
-                    b'>' | 9..=13 | 28..=32 => {
+                    b'>' | b'/' | b'\t' | b'\n' | b'\x0C' | b'\r' | b' ' => {
                         $step = 0;
                         $b
                     },
@@ -115,66 +116,74 @@ decode_impl! {
     6;
     /// The following substring is unescaped:
     ///
-    /// * `<\/style>` => `</style>`
+    /// * `<\/style` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `</style`
     parse_style;
     /// Decode text from the `<style>` element.
     decode_style;
-    /// Write text from the `<style>` element to a mutable `String` reference and return the encoded string slice.
+    /// Write text from the `<style>` element to a mutable `String` reference and return the decoded string slice.
     decode_style_to_string;
-    /// Write text from the `<style>` element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    /// Write text from the `<style>` element to a mutable `Vec<u8>` reference and return the decoded data slice.
     decode_style_to_vec;
     /// Write text from the `<style>` element to a writer.
     decode_style_to_writer;
+    /// Write text from the `<style>` element to a `fmt::Write`.
+    decode_style_to_fmt_writer;
 }
 
 decode_impl! {
     6;
-    /// The following substring and character is unescaped:
+    /// The following substring and character are unescaped:
     ///
-    /// * `<\/style>` => `</style>`
+    /// * `<\/style` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `</style`
     /// * `\'` => `'`
     parse_style_single_quoted_text;
     /// Decode text from a single quoted text in the `<style>` element.
     decode_style_single_quoted_text;
-    /// Write text from a single quoted text in the `<style>` element to a mutable `String` reference and return the encoded string slice.
+    /// Write text from a single quoted text in the `<style>` element to a mutable `String` reference and return the decoded string slice.
     decode_style_single_quoted_text_to_string;
-    /// Write text from a single quoted text in the `<style>` element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    /// Write text from a single quoted text in the `<style>` element to a mutable `Vec<u8>` reference and return the decoded data slice.
     decode_style_single_quoted_text_to_vec;
     /// Write text from a single quoted text in the `<style>` element to a writer.
     decode_style_single_quoted_text_to_writer;
+    /// Write text from a single quoted text in the `<style>` element to a `fmt::Write`.
+    decode_style_single_quoted_text_to_fmt_writer;
 }
 
 decode_impl! {
     6;
     /// The following substring and character are unescaped:
     ///
-    /// * `<\/style>` => `</style>`
+    /// * `<\/style` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `</style`
     /// * `\"` => `"`
     parse_style_double_quoted_text;
     /// Decode text from a double quoted text in the `<style>` element.
     decode_style_double_quoted_text;
-    /// Write text from a double quoted text in the `<style>` element to a mutable `String` reference and return the encoded string slice.
+    /// Write text from a double quoted text in the `<style>` element to a mutable `String` reference and return the decoded string slice.
     decode_style_double_quoted_text_to_string;
-    /// Write text from a double quoted text in the `<style>` element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    /// Write text from a double quoted text in the `<style>` element to a mutable `Vec<u8>` reference and return the decoded data slice.
     decode_style_double_quoted_text_to_vec;
     /// Write text from a double quoted text in the `<style>` element to a writer.
     decode_style_double_quoted_text_to_writer;
+    /// Write text from a double quoted text in the `<style>` element to a `fmt::Write`.
+    decode_style_double_quoted_text_to_fmt_writer;
 }
 
 decode_impl! {
     6;
     /// The following substring and characters are unescaped:
     ///
-    /// * `<\/style>` => `</style>`
+    /// * `<\/style` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `</style`
     /// * `\"` => `"`
     /// * `\'` => `'`
     parse_style_quoted_text;
     /// Decode text from a quoted text in the `<style>` element.
     decode_style_quoted_text;
-    /// Write text from a quoted text in the `<style>` element to a mutable `String` reference and return the encoded string slice.
+    /// Write text from a quoted text in the `<style>` element to a mutable `String` reference and return the decoded string slice.
     decode_style_quoted_text_to_string;
-    /// Write text from a quoted text in the `<style>` element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    /// Write text from a quoted text in the `<style>` element to a mutable `Vec<u8>` reference and return the decoded data slice.
     decode_style_quoted_text_to_vec;
     /// Write text from a quoted text in the `<style>` element to a writer.
     decode_style_quoted_text_to_writer;
+    /// Write text from a quoted text in the `<style>` element to a `fmt::Write`.
+    decode_style_quoted_text_to_fmt_writer;
 }
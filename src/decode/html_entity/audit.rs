@@ -0,0 +1,228 @@
+use core::convert::Infallible;
+use core::fmt;
+use core::ops::Range;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::reference::*;
+use super::DecodeOptions;
+
+/// A character reference which is decoded, passed to the callback of the `decode_html_entities_with_audit*` functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AuditedReference<'a> {
+    source: &'a str,
+    start: usize,
+    replacement: &'a str,
+}
+
+impl<'a> AuditedReference<'a> {
+    /// The reference as it is in the given string, such as `&lt;` or `&#x26;`.
+    #[inline]
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    /// The byte range of the reference in the given string.
+    #[inline]
+    pub fn span(&self) -> Range<usize> {
+        self.start..(self.start + self.source.len())
+    }
+
+    /// The name of a named reference without the `&` and the trailing semicolon, such as `lt`, or `None` for a numeric reference.
+    #[inline]
+    pub fn name(&self) -> Option<&'a str> {
+        if self.source.as_bytes()[1] == b'#' {
+            None
+        } else {
+            Some(self.source[1..].trim_end_matches(';'))
+        }
+    }
+
+    /// The number of a numeric reference, such as `38` for `&#x26;`, or `None` for a named reference. Numbers beyond the Unicode range are reported as `0x110000`.
+    #[inline]
+    pub fn number(&self) -> Option<u32> {
+        if self.source.as_bytes()[1] == b'#' {
+            Some(scan_numeric(&self.source.as_bytes()[1..], usize::MAX).0)
+        } else {
+            None
+        }
+    }
+
+    /// What the reference is decoded to, which is empty if it is stripped by `ReplacementPolicy::Strip`.
+    #[inline]
+    pub fn replacement(&self) -> &'a str {
+        self.replacement
+    }
+}
+
+fn decode_with_audit_impl<E, A: FnMut(AuditedReference<'_>), W: FnMut(&[u8]) -> Result<(), E>>(
+    text: &str,
+    mode: ReferenceMode,
+    mut audit: A,
+    mut write: W,
+) -> Result<usize, E> {
+    let text_bytes = text.as_bytes();
+
+    let mut references = 0;
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        match parse_reference(text_bytes, p, mode) {
+            Some((reference, end)) => {
+                let mut buffer = [0u8; 4];
+                let bytes = reference.to_bytes(&mut buffer);
+
+                audit(AuditedReference {
+                    source: &text[p..end],
+                    start: p,
+                    replacement: unsafe { from_utf8_unchecked(bytes) },
+                });
+
+                write(&text_bytes[start..p])?;
+                write(bytes)?;
+
+                references += 1;
+
+                p = end;
+                start = end;
+            }
+            None => p += 1,
+        }
+    }
+
+    write(&text_bytes[start..])?;
+
+    Ok(references)
+}
+
+/// Decode html entities in a given string according to the given options, calling `audit` for every decoded reference in the order they appear, so the entities used in untrusted input can be logged. The strictness and the output limit are not checked.
+///
+/// ```
+/// let mut audited = Vec::new();
+///
+/// let decoded = html_escape::decode_html_entities_with_audit(
+///     "a &lt; b &#x26; &foo;",
+///     &html_escape::DecodeOptions::new(),
+///     |reference| audited.push((reference.source().to_string(), reference.span())),
+/// );
+///
+/// assert_eq!("a < b & &foo;", decoded);
+/// assert_eq!(vec![("&lt;".to_string(), 2..6), ("&#x26;".to_string(), 9..15)], audited);
+/// ```
+pub fn decode_html_entities_with_audit<
+    'a,
+    S: ?Sized + AsRef<str>,
+    A: FnMut(AuditedReference<'_>),
+>(
+    text: &'a S,
+    options: &DecodeOptions,
+    audit: A,
+) -> Cow<'a, str> {
+    let text = text.as_ref();
+
+    if !text.as_bytes().contains(&b'&') {
+        return Cow::from(text);
+    }
+
+    let mut v = Vec::with_capacity(text.len());
+
+    let references = decode_with_audit_impl(text, options.mode, audit, |bytes| {
+        v.extend_from_slice(bytes);
+
+        Ok::<(), Infallible>(())
+    })
+    .unwrap();
+
+    if references == 0 {
+        Cow::from(text)
+    } else {
+        Cow::from(unsafe { String::from_utf8_unchecked(v) })
+    }
+}
+
+/// Decode html entities in a given string to a mutable `String` reference and return the decoded string slice according to the given options, calling `audit` for every decoded reference in the order they appear. The strictness and the output limit are not checked.
+#[inline]
+pub fn decode_html_entities_with_audit_to_string<
+    'a,
+    S: AsRef<str>,
+    A: FnMut(AuditedReference<'_>),
+>(
+    text: S,
+    options: &DecodeOptions,
+    audit: A,
+    output: &'a mut String,
+) -> &'a str {
+    unsafe {
+        from_utf8_unchecked(decode_html_entities_with_audit_to_vec(
+            text,
+            options,
+            audit,
+            output.as_mut_vec(),
+        ))
+    }
+}
+
+/// Decode html entities in a given string to a mutable `Vec<u8>` reference and return the decoded data slice according to the given options, calling `audit` for every decoded reference in the order they appear. The strictness and the output limit are not checked.
+pub fn decode_html_entities_with_audit_to_vec<'a, S: AsRef<str>, A: FnMut(AuditedReference<'_>)>(
+    text: S,
+    options: &DecodeOptions,
+    audit: A,
+    output: &'a mut Vec<u8>,
+) -> &'a [u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    decode_with_audit_impl(text, options.mode, audit, |bytes| {
+        output.extend_from_slice(bytes);
+
+        Ok::<(), Infallible>(())
+    })
+    .unwrap();
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in a given string to a writer according to the given options, calling `audit` for every decoded reference in the order they appear. A reference is reported before what it is decoded to is written. The strictness and the output limit are not checked.
+#[inline]
+pub fn decode_html_entities_with_audit_to_writer<
+    S: AsRef<str>,
+    W: Write,
+    A: FnMut(AuditedReference<'_>),
+>(
+    text: S,
+    options: &DecodeOptions,
+    audit: A,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_with_audit_impl(text.as_ref(), options.mode, audit, |bytes| output.write_all(bytes))
+        .map(|_| ())
+}
+
+/// Decode html entities in a given string to a `fmt::Write` according to the given options, calling `audit` for every decoded reference in the order they appear. A reference is reported before what it is decoded to is written. The strictness and the output limit are not checked.
+#[inline]
+pub fn decode_html_entities_with_audit_to_fmt_writer<
+    S: AsRef<str>,
+    W: fmt::Write,
+    A: FnMut(AuditedReference<'_>),
+>(
+    text: S,
+    options: &DecodeOptions,
+    audit: A,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    output.write_str(&decode_html_entities_with_audit(text.as_ref(), options, audit))
+}
@@ -0,0 +1,85 @@
+use core::convert::Infallible;
+use core::str::from_utf8;
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::reference::*;
+use super::*;
+use crate::functions::for_each_utf8_segment;
+
+/// How `decode_html_entities_bytes` and `encode_os_str` handle data which is not valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidUtf8Policy {
+    /// Keep the invalid bytes as they are, without validating the data.
+    Keep,
+    /// Replace each invalid sequence with U+FFFD REPLACEMENT CHARACTER, like `String::from_utf8_lossy` does, so the result is valid UTF-8.
+    Replace,
+}
+
+/// Decode html entities in given bytes, such as a body of an HTTP response, handling invalid UTF-8 according to the given policy.
+pub fn decode_html_entities_bytes(text_bytes: &[u8], policy: InvalidUtf8Policy) -> Cow<'_, [u8]> {
+    if policy == InvalidUtf8Policy::Replace && from_utf8(text_bytes).is_err() {
+        let mut v = Vec::with_capacity(text_bytes.len());
+
+        decode_html_entities_bytes_to_vec(text_bytes, policy, &mut v);
+
+        Cow::from(v)
+    } else {
+        decode_bytes_with_mode(text_bytes, ReferenceMode::STRICT)
+    }
+}
+
+/// Decode html entities in given bytes to a mutable `Vec<u8>` reference and return the decoded data slice, handling invalid UTF-8 according to the given policy.
+pub fn decode_html_entities_bytes_to_vec<'a>(
+    text_bytes: &[u8],
+    policy: InvalidUtf8Policy,
+    output: &'a mut Vec<u8>,
+) -> &'a [u8] {
+    match policy {
+        InvalidUtf8Policy::Keep => {
+            decode_to_vec_with_mode(text_bytes, ReferenceMode::STRICT, output)
+        }
+        InvalidUtf8Policy::Replace => {
+            let current_length = output.len();
+
+            let _ = for_each_utf8_segment(text_bytes, |segment, invalid| {
+                decode_to_vec_with_mode(segment, ReferenceMode::STRICT, output);
+
+                if invalid {
+                    output.extend_from_slice("\u{FFFD}".as_bytes());
+                }
+
+                Ok::<(), Infallible>(())
+            });
+
+            &output[current_length..]
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in given bytes to a writer, handling invalid UTF-8 according to the given policy.
+pub fn decode_html_entities_bytes_to_writer<W: Write>(
+    text_bytes: &[u8],
+    policy: InvalidUtf8Policy,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    match policy {
+        InvalidUtf8Policy::Keep => {
+            decode_to_writer_with_mode(text_bytes, ReferenceMode::STRICT, output)
+        }
+        InvalidUtf8Policy::Replace => for_each_utf8_segment(text_bytes, |segment, invalid| {
+            decode_to_writer_with_mode(segment, ReferenceMode::STRICT, output)?;
+
+            if invalid {
+                output.write_all("\u{FFFD}".as_bytes())?;
+            }
+
+            Ok(())
+        }),
+    }
+}
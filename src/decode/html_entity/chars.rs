@@ -0,0 +1,102 @@
+use core::iter::FusedIterator;
+use core::str::Chars;
+
+use super::reference::*;
+use super::DecodeOptions;
+
+/// A lazy iterator over the characters of a decoded string, created by the `decode_html_entities_chars*` functions.
+#[derive(Debug, Clone)]
+pub struct DecodedChars<'a> {
+    text: &'a str,
+    mode: ReferenceMode,
+    /// The characters to yield before decoding further, which are either text before a reference or a decoded named reference.
+    chars: Chars<'a>,
+    /// The reference after `chars`.
+    reference: Option<Reference>,
+    p: usize,
+}
+
+impl<'a> Iterator for DecodedChars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.chars.next() {
+                return Some(c);
+            }
+
+            match self.reference.take() {
+                Some(Reference::Named(s)) => {
+                    self.chars = s.chars();
+
+                    continue;
+                }
+                Some(Reference::Numeric(c)) => return Some(c),
+                Some(Reference::Removed) => continue,
+                None => (),
+            }
+
+            if self.p == self.text.len() {
+                return None;
+            }
+
+            let text_bytes = self.text.as_bytes();
+
+            let mut p = self.p;
+
+            while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+                p += offset;
+
+                if let Some((reference, end)) = parse_reference(text_bytes, p, self.mode) {
+                    self.chars = self.text[self.p..p].chars();
+                    self.reference = Some(reference);
+                    self.p = end;
+
+                    break;
+                }
+
+                p += 1;
+            }
+
+            if self.reference.is_none() {
+                self.chars = self.text[self.p..].chars();
+                self.p = text_bytes.len();
+            }
+        }
+    }
+}
+
+impl<'a> FusedIterator for DecodedChars<'a> {}
+
+/// Decode html entities in a given string lazily, yielding the characters of the decoded text without allocating.
+///
+/// ```
+/// let decoded = html_escape::decode_html_entities_chars("a &lt; b &#x26; &copy;");
+///
+/// assert_eq!("a < b & \u{A9}", decoded.collect::<String>());
+/// assert_eq!(9, html_escape::decode_html_entities_chars("a &lt; b &#x26; &copy;").count());
+/// ```
+#[inline]
+pub fn decode_html_entities_chars<S: ?Sized + AsRef<str>>(text: &S) -> DecodedChars<'_> {
+    decode_chars_with_mode(text.as_ref(), ReferenceMode::STRICT)
+}
+
+/// Decode html entities in a given string lazily according to the given options, yielding the characters of the decoded text without allocating. The strictness and the output limit are not checked.
+#[inline]
+pub fn decode_html_entities_chars_with<'a, S: ?Sized + AsRef<str>>(
+    text: &'a S,
+    options: &DecodeOptions,
+) -> DecodedChars<'a> {
+    decode_chars_with_mode(text.as_ref(), options.mode)
+}
+
+#[inline]
+fn decode_chars_with_mode(text: &str, mode: ReferenceMode) -> DecodedChars<'_> {
+    DecodedChars {
+        text,
+        mode,
+        chars: "".chars(),
+        reference: None,
+        p: 0,
+    }
+}
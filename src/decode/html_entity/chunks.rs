@@ -0,0 +1,99 @@
+use core::iter::FusedIterator;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use super::reference::*;
+use super::DecodeOptions;
+
+/// An iterator over the pieces of a decoded string, created by the `decode_html_entities_chunks*` functions. The text between character references is borrowed from the input, named references are borrowed from the entity table, and only numeric references are allocated as small strings.
+#[derive(Debug, Clone)]
+pub struct DecodeChunks<'a> {
+    text: &'a str,
+    mode: ReferenceMode,
+    /// The reference before `p`, which is yielded next.
+    reference: Option<Reference>,
+    p: usize,
+}
+
+impl<'a> Iterator for DecodeChunks<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Cow<'a, str>> {
+        loop {
+            match self.reference.take() {
+                Some(Reference::Named(s)) => return Some(Cow::from(s)),
+                Some(Reference::Numeric(c)) => return Some(Cow::from(String::from(c))),
+                Some(Reference::Removed) | None => (),
+            }
+
+            let text_bytes = self.text.as_bytes();
+
+            if self.p == text_bytes.len() {
+                return None;
+            }
+
+            let start = self.p;
+            let mut p = start;
+
+            while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+                p += offset;
+
+                if let Some((reference, end)) = parse_reference(text_bytes, p, self.mode) {
+                    self.reference = Some(reference);
+                    self.p = end;
+
+                    break;
+                }
+
+                p += 1;
+            }
+
+            if self.reference.is_none() {
+                self.p = text_bytes.len();
+
+                return Some(Cow::from(&self.text[start..]));
+            }
+
+            if p > start {
+                return Some(Cow::from(&self.text[start..p]));
+            }
+        }
+    }
+}
+
+impl<'a> FusedIterator for DecodeChunks<'a> {}
+
+/// Decode html entities in a given string lazily, yielding the decoded text in pieces instead of allocating it as a whole. The text between character references is borrowed from the given string, so the pieces can be streamed into a sink with little copying.
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// let chunks: Vec<Cow<str>> = html_escape::decode_html_entities_chunks("a &lt; b&#x26;").collect();
+///
+/// assert_eq!(vec!["a ", "<", " b", "&"], chunks);
+/// assert!(matches!(chunks[0], Cow::Borrowed(_)));
+/// ```
+#[inline]
+pub fn decode_html_entities_chunks<S: ?Sized + AsRef<str>>(text: &S) -> DecodeChunks<'_> {
+    decode_chunks_with_mode(text.as_ref(), ReferenceMode::STRICT)
+}
+
+/// Decode html entities in a given string lazily according to the given options, yielding the decoded text in pieces instead of allocating it as a whole. The strictness and the output limit are not checked.
+#[inline]
+pub fn decode_html_entities_chunks_with<'a, S: ?Sized + AsRef<str>>(
+    text: &'a S,
+    options: &DecodeOptions,
+) -> DecodeChunks<'a> {
+    decode_chunks_with_mode(text.as_ref(), options.mode)
+}
+
+#[inline]
+fn decode_chunks_with_mode(text: &str, mode: ReferenceMode) -> DecodeChunks<'_> {
+    DecodeChunks {
+        text,
+        mode,
+        reference: None,
+        p: 0,
+    }
+}
@@ -0,0 +1,164 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use super::reference::*;
+use super::*;
+
+/// The kind of content a chunk of an html document belongs to, which determines whether character references are decoded in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentKind {
+    /// Text between tags, where character references are decoded.
+    Data,
+    /// The content of `<title>` and `<textarea>`, where character references are decoded but no tags are recognized.
+    RcData,
+    /// The content of `<script>`, `<style>`, `<xmp>`, `<iframe>`, `<noembed>`, `<noframes>` and `<plaintext>`, or a CDATA section, where character references are not decoded.
+    RawText,
+    /// An attribute value, where character references are decoded like `decode_html_entities_in_attribute` does.
+    Attribute,
+}
+
+/// An incremental decoder for documents which are tokenized by the caller, who feeds the text in chunks tagged with the kind of content they belong to. Character references are only decoded where they exist according to the HTML specification, so the content of `<script>` and `<style>` is kept as it is.
+///
+/// A character reference split across chunks of the same kind is held back until it is complete, like `Decoder` does. A change of the kind ends the held back reference, so call `finish` between two chunks of the same kind which are not adjacent, such as two attribute values.
+///
+/// ```rust
+/// extern crate html_escape;
+///
+/// use html_escape::{ContentDecoder, ContentKind};
+///
+/// let mut decoder = ContentDecoder::new();
+/// let mut s = String::new();
+///
+/// s.extend(decoder.feed(ContentKind::Data, "a &am"));
+/// s.extend(decoder.feed(ContentKind::Data, "p; b"));
+/// s.extend(decoder.feed(ContentKind::RawText, "if (a &amp;& b) {}"));
+/// s.extend(decoder.feed(ContentKind::Attribute, "?a=1&copy=2"));
+///
+/// s.push_str(&decoder.finish());
+///
+/// assert_eq!("a & bif (a &amp;& b) {}?a=1&copy=2", s);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContentDecoder {
+    data_options: DecodeOptions,
+    attribute_options: DecodeOptions,
+    kind: ContentKind,
+    decoder: Decoder,
+}
+
+impl ContentDecoder {
+    /// Create a decoder which works like browsers do, that is, like `decode_html_entities_legacy` for data and RCDATA, and like `decode_html_entities_in_attribute` for attribute values.
+    #[inline]
+    pub fn new() -> ContentDecoder {
+        let mut data_options = DecodeOptions::new();
+        let mut attribute_options = DecodeOptions::new();
+
+        data_options.mode = ReferenceMode::LEGACY;
+        attribute_options.mode = ReferenceMode::ATTRIBUTE;
+
+        ContentDecoder {
+            data_options,
+            attribute_options,
+            kind: ContentKind::Data,
+            decoder: Decoder::with_options(&data_options),
+        }
+    }
+
+    /// Create a decoder which works like `decode_html_entities_with`, setting `DecodeOptions::attribute` according to the kind of content. The strictness and the output limit, which applies to all the chunks, are only checked by `try_feed` and `try_finish`.
+    #[inline]
+    pub fn with_options(options: &DecodeOptions) -> ContentDecoder {
+        let data_options = options.attribute(false);
+
+        ContentDecoder {
+            data_options,
+            attribute_options: options.attribute(true),
+            kind: ContentKind::Data,
+            decoder: Decoder::with_options(&data_options),
+        }
+    }
+
+    /// Decode the next chunk, which is of the content `kind`. The decoded text is returned in pieces, which may borrow from the chunk.
+    #[inline]
+    pub fn feed<'a>(
+        &mut self,
+        kind: ContentKind,
+        chunk: &'a str,
+    ) -> impl Iterator<Item = Cow<'a, str>> {
+        match self.feed_impl(kind, chunk, false) {
+            Ok(pieces) => pieces,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Decode the next chunk like `feed` does, failing on the first malformed character reference in strict mode or once the output limit, which also counts the chunks of raw text, is exceeded. The offset of the error is in all the chunks fed so far. The decoder should not be used after a failure.
+    #[inline]
+    pub fn try_feed<'a>(
+        &mut self,
+        kind: ContentKind,
+        chunk: &'a str,
+    ) -> Result<impl Iterator<Item = Cow<'a, str>>, DecodeError> {
+        self.feed_impl(kind, chunk, true)
+    }
+
+    fn feed_impl<'a>(
+        &mut self,
+        kind: ContentKind,
+        chunk: &'a str,
+        checked: bool,
+    ) -> Result<impl Iterator<Item = Cow<'a, str>>, DecodeError> {
+        let first = if kind != self.kind {
+            let held = self.decoder.flush(checked)?;
+
+            if (kind == ContentKind::Attribute) != (self.kind == ContentKind::Attribute) {
+                self.decoder.set_options(if kind == ContentKind::Attribute {
+                    &self.attribute_options
+                } else {
+                    &self.data_options
+                });
+            }
+
+            self.kind = kind;
+
+            if held.is_empty() {
+                None
+            } else {
+                Some(Cow::from(held))
+            }
+        } else {
+            None
+        };
+
+        let (decoded, raw) = match kind {
+            ContentKind::RawText => {
+                self.decoder.skip(chunk, checked)?;
+
+                (None, Some(Cow::from(chunk)))
+            }
+            _ => (Some(self.decoder.feed_impl(chunk, checked)?), None),
+        };
+
+        Ok(first.into_iter().chain(decoded.into_iter().flatten()).chain(raw))
+    }
+
+    /// Decode the text which has been held back. The decoder can be reused afterwards, and the offsets and the output limit keep covering all the chunks fed to it.
+    #[inline]
+    pub fn finish(&mut self) -> String {
+        match self.decoder.flush(false) {
+            Ok(decoded) => decoded,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Decode the text which has been held back like `finish` does, failing on a malformed character reference in strict mode or if the output limit is exceeded.
+    #[inline]
+    pub fn try_finish(&mut self) -> Result<String, DecodeError> {
+        self.decoder.flush(true)
+    }
+}
+
+impl Default for ContentDecoder {
+    #[inline]
+    fn default() -> Self {
+        ContentDecoder::new()
+    }
+}
@@ -0,0 +1,210 @@
+use core::fmt::{self, Display, Formatter};
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::reference::*;
+use super::*;
+
+/// The ways a character reference can be malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodeErrorKind {
+    /// A name followed by `;` which is not a named entity, such as `&foo;`.
+    UnknownNamedReference,
+    /// A numeric reference without digits, such as `&#;` or `&#x`.
+    MissingDigits,
+    /// A reference without the trailing semicolon, such as `&#x2` or `&amp`.
+    MissingSemicolon,
+    /// A numeric reference to zero, a surrogate or a number beyond the Unicode range, such as `&#xD800;`.
+    InvalidCodePoint,
+    /// A numeric reference with more significant digits than `DecodeOptions::max_numeric_length` allows, which is kept as it is.
+    TooManyDigits,
+    /// The decoded text is longer than the limit set by `DecodeOptions::max_output_length`. This is not about a reference, so the offset is where the limit is reached.
+    OutputLimitExceeded,
+}
+
+impl Display for DecodeErrorKind {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str(match self {
+            DecodeErrorKind::UnknownNamedReference => "unknown named reference",
+            DecodeErrorKind::MissingDigits => "numeric reference without digits",
+            DecodeErrorKind::MissingSemicolon => "reference without the trailing semicolon",
+            DecodeErrorKind::InvalidCodePoint => "numeric reference to an invalid code point",
+            DecodeErrorKind::TooManyDigits => "numeric reference with too many digits",
+            DecodeErrorKind::OutputLimitExceeded => "decoded text longer than the limit",
+        })
+    }
+}
+
+/// A malformed character reference found by the `try_decode_html_entities*` functions, or the `decode_html_entities_with*` functions in strict mode, or an exceeded output limit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DecodeError {
+    kind: DecodeErrorKind,
+    offset: usize,
+    reference: String,
+}
+
+impl DecodeError {
+    /// How the reference is malformed.
+    #[inline]
+    pub fn kind(&self) -> DecodeErrorKind {
+        self.kind
+    }
+
+    /// The byte offset of the `&` of the reference in the decoded text.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The malformed reference, such as `&foo;`. This is empty if the output limit is exceeded.
+    #[inline]
+    pub fn reference(&self) -> &str {
+        self.reference.as_str()
+    }
+}
+
+impl DecodeError {
+    #[inline]
+    pub(crate) fn output_limit_exceeded(offset: usize) -> DecodeError {
+        DecodeError {
+            kind: DecodeErrorKind::OutputLimitExceeded,
+            offset,
+            reference: String::new(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn shift(mut self, offset: usize) -> DecodeError {
+        self.offset += offset;
+
+        self
+    }
+}
+
+impl Display for DecodeError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        if self.reference.is_empty() {
+            write!(f, "{} at byte {}", self.kind, self.offset)
+        } else {
+            write!(f, "{} {:?} at byte {}", self.kind, self.reference, self.offset)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DecodeError {}
+
+/// Check the `&` at `text_bytes[p]`.
+#[inline]
+pub(crate) fn check_reference_at(
+    text: &str,
+    p: usize,
+    mode: ReferenceMode,
+) -> Result<(), DecodeError> {
+    match find_malformed_reference(text.as_bytes(), p, mode) {
+        Some((kind, end)) => Err(DecodeError {
+            kind,
+            offset: p,
+            reference: text[p..end].to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Check the `&` at `text_bytes[p]` for a reference which the replacement policy applies to.
+#[inline]
+pub(crate) fn check_invalid_reference_at(
+    text: &str,
+    p: usize,
+    mode: ReferenceMode,
+) -> Result<(), DecodeError> {
+    match find_invalid_reference(text.as_bytes(), p, mode) {
+        Some((kind, end)) => Err(DecodeError {
+            kind,
+            offset: p,
+            reference: text[p..end].to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+const STRICT: DecodeOptions = DecodeOptions::new().strict(true);
+
+/// Decode html entities in a given string, failing on the first malformed character reference instead of keeping it as it is.
+///
+/// A reference is malformed if it is an unknown named reference followed by `;`, a numeric reference without digits or to an invalid code point, or a reference without the trailing semicolon. An `&` which does not start a reference, such as the one in `AT&T`, is fine.
+///
+/// The given string is borrowed if it has no references at all, so validating text which usually needs no decoding does not allocate.
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(html_escape::try_decode_html_entities("AT&T"), Ok(Cow::Borrowed("AT&T"))));
+/// assert!(matches!(html_escape::try_decode_html_entities("&lt;"), Ok(Cow::Owned(_))));
+/// assert_eq!(2, html_escape::try_decode_html_entities("a &foo;").unwrap_err().offset());
+/// ```
+#[inline]
+pub fn try_decode_html_entities<S: ?Sized + AsRef<str>>(
+    text: &S,
+) -> Result<Cow<'_, str>, DecodeError> {
+    decode_html_entities_with(text, &STRICT)
+}
+
+/// Decode html entities in a given string to a mutable `String` reference and return the decoded string slice, failing on the first malformed character reference instead of keeping it as it is. Nothing is written on failure.
+///
+/// A reference is malformed if it is an unknown named reference followed by `;`, a numeric reference without digits or to an invalid code point, or a reference without the trailing semicolon. An `&` which does not start a reference, such as the one in `AT&T`, is fine.
+#[inline]
+pub fn try_decode_html_entities_to_string<S: AsRef<str>>(
+    text: S,
+    output: &mut String,
+) -> Result<&str, DecodeError> {
+    unsafe {
+        try_decode_html_entities_to_vec(text, output.as_mut_vec()).map(|v| from_utf8_unchecked(v))
+    }
+}
+
+/// Decode html entities in a given string to a mutable `Vec<u8>` reference and return the decoded data slice, failing on the first malformed character reference instead of keeping it as it is. Nothing is written on failure.
+///
+/// A reference is malformed if it is an unknown named reference followed by `;`, a numeric reference without digits or to an invalid code point, or a reference without the trailing semicolon. An `&` which does not start a reference, such as the one in `AT&T`, is fine.
+#[inline]
+pub fn try_decode_html_entities_to_vec<S: AsRef<str>>(
+    text: S,
+    output: &mut Vec<u8>,
+) -> Result<&[u8], DecodeError> {
+    decode_html_entities_with_to_vec(text, &STRICT, output)
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in a given string to a writer, failing on the first malformed character reference instead of keeping it as it is. The `DecodeError` is wrapped in an `io::Error` of the kind `InvalidData`, and the text before the malformed reference has already been written on failure.
+///
+/// A reference is malformed if it is an unknown named reference followed by `;`, a numeric reference without digits or to an invalid code point, or a reference without the trailing semicolon. An `&` which does not start a reference, such as the one in `AT&T`, is fine.
+#[inline]
+pub fn try_decode_html_entities_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_html_entities_with_to_writer(text, &STRICT, output)
+}
+
+/// Decode html entities in a given string to a `fmt::Write`, failing on the first malformed character reference instead of keeping it as it is. The text is decoded before it is written, so nothing is written if the `DecodeError` is returned, and the result of writing is returned otherwise.
+///
+/// A reference is malformed if it is an unknown named reference followed by `;`, a numeric reference without digits or to an invalid code point, or a reference without the trailing semicolon. An `&` which does not start a reference, such as the one in `AT&T`, is fine.
+#[inline]
+pub fn try_decode_html_entities_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<Result<(), fmt::Error>, DecodeError> {
+    let decoded = try_decode_html_entities(text.as_ref())?;
+
+    Ok(output.write_str(&decoded))
+}
@@ -0,0 +1,629 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::UnicodeNormalization;
+
+use super::reference::*;
+use super::*;
+
+/// The named entities recognized by the `decode_html_entities_with*` functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamedEntitySet {
+    /// All the named entities of HTML5, such as `&nbsp;` and `&copy;`. With the `html4-entities` feature, only those of HTML 4.01 are recognized instead.
+    Html5,
+    /// Only the 252 named entities of HTML 4.01, such as `&nbsp;` and `&hearts;`.
+    Html4,
+    /// Only the five predefined entities of XML, `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&apos;`.
+    Xml,
+    /// No named entities, so only numeric references are decoded.
+    None,
+}
+
+/// How the `decode_html_entities_with*` functions handle numeric references to invalid code points. Use `DecodeOptions::strict` to fail on them instead.
+///
+/// Any number of leading zeros and up to `DecodeOptions::max_numeric_length` significant digits are accepted. The number saturates beyond the Unicode range instead of overflowing, so `&#99999999999;` is just another reference to an invalid code point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumericErrorPolicy {
+    /// Like browsers do, decode references to zero, surrogates and numbers beyond the Unicode range to U+FFFD REPLACEMENT CHARACTER, and remap those to U+0080 to U+009F through Windows-1252.
+    Replace,
+    /// Keep references to zero, surrogates and numbers beyond the Unicode range as they are, and decode those to U+0080 to U+009F to the C1 controls they refer to.
+    Keep,
+}
+
+/// A preset of how numeric references are decoded, set by `DecodeOptions::numeric_policy`. It is a more complete alternative to `NumericErrorPolicy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumericPolicy {
+    /// Like browsers do according to the WHATWG HTML Living Standard, decode references to zero, surrogates and numbers beyond the Unicode range to U+FFFD REPLACEMENT CHARACTER, and remap those to U+0080 to U+009F through Windows-1252. This is the same as `NumericErrorPolicy::Replace`.
+    Html,
+    /// Like XML 1.0 requires, only decode references to the characters allowed in XML documents, which excludes zero, surrogates, U+FFFE, U+FFFF and the C0 controls other than tab, line feed and carriage return. The other references are kept as they are, or fail in strict mode. Those to U+0080 to U+009F are decoded to the C1 controls they refer to.
+    XmlStrict,
+    /// Decode references to any Unicode scalar value, including zero and the C1 controls, as they are. Only references to surrogates and numbers beyond the Unicode range are kept as they are.
+    Passthrough,
+}
+
+/// How unknown named references followed by `;`, such as `&foo;`, and numeric references to invalid code points, such as `&#xD800;`, are handled, set by `DecodeOptions::replacement_policy`. By default, the former are kept as they are and the latter are handled according to the `NumericErrorPolicy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReplacementPolicy {
+    /// Replace them with U+FFFD REPLACEMENT CHARACTER.
+    ReplaceWithFFFD,
+    /// Keep them as they are.
+    KeepRaw,
+    /// Remove them.
+    Strip,
+    /// Fail on the first one with a `DecodeError`. The functions which cannot fail, such as those of `Decoder::feed`, keep them as they are.
+    Error,
+}
+
+/// The options of the `decode_html_entities_with*` functions.
+///
+/// The default options are what `decode_html_entities` uses: all the HTML5 named entities are recognized, the trailing semicolon is required, numeric references to invalid code points are replaced like browsers do and malformed references are kept as they are.
+///
+/// ```
+/// use html_escape::{DecodeOptions, NamedEntitySet};
+///
+/// let options = DecodeOptions::new().require_semicolon(false).named_entities(NamedEntitySet::Xml);
+///
+/// assert_eq!("<a> &nbsp;", html_escape::decode_html_entities_with("&lta&gt &nbsp;", &options).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecodeOptions {
+    pub(crate) mode: ReferenceMode,
+    pub(crate) strict: bool,
+    pub(crate) max_output_length: Option<usize>,
+    #[cfg(feature = "unicode-normalization")]
+    pub(crate) nfc: bool,
+}
+
+impl DecodeOptions {
+    /// The options for XML: only the five predefined entities and numeric references are recognized, the trailing semicolon is required and numeric references to the characters which are not allowed in XML are kept as they are. Use `strict(true)` to fail on HTML-only names such as `&nbsp;` instead of keeping them.
+    pub const XML: DecodeOptions = DecodeOptions::new()
+        .named_entities(NamedEntitySet::Xml)
+        .numeric_policy(NumericPolicy::XmlStrict);
+
+    /// Create the default options.
+    #[inline]
+    pub const fn new() -> DecodeOptions {
+        DecodeOptions {
+            mode: ReferenceMode::STRICT,
+            strict: false,
+            max_output_length: None,
+            #[cfg(feature = "unicode-normalization")]
+            nfc: false,
+        }
+    }
+
+    /// Whether the trailing semicolon is required. If not, the legacy named entities such as `&amp` and `&copy` and numeric references are also decoded without it, like browsers do.
+    #[inline]
+    pub const fn require_semicolon(mut self, require_semicolon: bool) -> DecodeOptions {
+        self.mode.legacy = !require_semicolon;
+
+        self
+    }
+
+    /// Which named entities are recognized.
+    #[inline]
+    pub const fn named_entities(mut self, named_entities: NamedEntitySet) -> DecodeOptions {
+        self.mode.named = named_entities;
+
+        self
+    }
+
+    /// How numeric references to invalid code points are handled.
+    #[inline]
+    pub const fn numeric_error_policy(mut self, policy: NumericErrorPolicy) -> DecodeOptions {
+        self.mode.numeric = match policy {
+            NumericErrorPolicy::Replace => NumericHandling::Html,
+            NumericErrorPolicy::Keep => NumericHandling::Keep,
+        };
+
+        self
+    }
+
+    /// How numeric references are decoded, overriding the `NumericErrorPolicy`.
+    #[inline]
+    pub const fn numeric_policy(mut self, policy: NumericPolicy) -> DecodeOptions {
+        self.mode.numeric = match policy {
+            NumericPolicy::Html => NumericHandling::Html,
+            NumericPolicy::XmlStrict => NumericHandling::Xml,
+            NumericPolicy::Passthrough => NumericHandling::Passthrough,
+        };
+
+        self
+    }
+
+    /// Whether numeric references such as `&#x26;` are decoded. If not, they are kept as they are.
+    #[inline]
+    pub const fn numeric_references(mut self, numeric_references: bool) -> DecodeOptions {
+        self.mode.numeric_references = numeric_references;
+
+        self
+    }
+
+    /// Whether a named reference followed by `;` which is not found falls back to a case-insensitive match, so wrong-case spellings such as `&NBSP;` and `&Amp;` are decoded. The all-lowercase spelling is preferred if the name is ambiguous, so `&DAGGER;` is decoded like `&dagger;`.
+    #[inline]
+    pub const fn case_insensitive(mut self, case_insensitive: bool) -> DecodeOptions {
+        self.mode.case_insensitive = case_insensitive;
+
+        self
+    }
+
+    /// Whether the text is an attribute value, where a legacy named entity without the trailing semicolon is kept if it is followed by `=` or an alphanumeric character. This has no effect if the trailing semicolon is required.
+    #[inline]
+    pub const fn attribute(mut self, attribute: bool) -> DecodeOptions {
+        self.mode.attribute = attribute;
+
+        self
+    }
+
+    /// How unknown named references followed by `;` and numeric references to invalid code points are handled, overriding the `NumericErrorPolicy` for the latter. Named references are not affected if no named entities are recognized.
+    #[inline]
+    pub const fn replacement_policy(mut self, policy: ReplacementPolicy) -> DecodeOptions {
+        self.mode.replacement = Some(policy);
+
+        self
+    }
+
+    /// The maximum length of the name of a named reference, which is 32 by default and covers all the names of the HTML5 named entities. The name after `&` is not scanned beyond this length, so a long run of letters such as `&aaaa…` costs no more than a short one, and a longer name is kept as it is without being reported as malformed. Raise it if longer unknown names have to be handled by a `ReplacementPolicy` or by the `decode_html_entities_with_fallback*` functions.
+    #[inline]
+    pub const fn max_entity_name_length(mut self, max_entity_name_length: usize) -> DecodeOptions {
+        self.mode.max_name_length = max_entity_name_length;
+
+        self
+    }
+
+    /// The maximum number of significant digits of a numeric reference, which is 32 by default. Leading zeros are skipped without being counted, and the digits after them are not scanned beyond this length, so a long run of digits costs no more than a short one. A reference with more significant digits is kept as it is, or reported as `DecodeErrorKind::TooManyDigits` in strict mode. A `Decoder` holds back at most this many digits, including leading zeros, so a reference split across chunks after a longer run of zeros is kept as it is.
+    #[inline]
+    pub const fn max_numeric_length(mut self, max_numeric_length: usize) -> DecodeOptions {
+        self.mode.max_numeric_length = max_numeric_length;
+
+        self
+    }
+
+    /// Whether to fail on the first malformed character reference instead of keeping it as it is. A reference is malformed if it is an unknown named reference followed by `;`, a numeric reference without digits or to an invalid code point, or a reference without the trailing semicolon, even if it is decoded.
+    #[inline]
+    pub const fn strict(mut self, strict: bool) -> DecodeOptions {
+        self.strict = strict;
+
+        self
+    }
+
+    /// Fail once the decoded text would be longer than `max_output_length` bytes, with a `DecodeError` of the kind `OutputLimitExceeded`.
+    #[inline]
+    pub const fn max_output_length(mut self, max_output_length: usize) -> DecodeOptions {
+        self.max_output_length = Some(max_output_length);
+
+        self
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    /// Whether to normalize the decoded text to NFC, since numeric references often produce decomposed sequences such as `e&#x301;`, which break string comparisons. The output limit applies to the text before the normalization. A `Decoder` holds back the decoded text from the last character which may still be composed with the next chunk, such as `e` before `&#x301;`, until the next `feed` or `finish`, so the result does not depend on the chunk boundaries.
+    #[inline]
+    pub const fn normalize_nfc(mut self, nfc: bool) -> DecodeOptions {
+        self.nfc = nfc;
+
+        self
+    }
+
+    /// Whether the trailing semicolon is required.
+    #[inline]
+    pub const fn is_semicolon_required(&self) -> bool {
+        !self.mode.legacy
+    }
+
+    /// Which named entities are recognized.
+    #[inline]
+    pub const fn get_named_entities(&self) -> NamedEntitySet {
+        self.mode.named
+    }
+
+    /// How numeric references to invalid code points are handled.
+    #[inline]
+    pub const fn get_numeric_error_policy(&self) -> NumericErrorPolicy {
+        match self.mode.numeric {
+            NumericHandling::Html => NumericErrorPolicy::Replace,
+            _ => NumericErrorPolicy::Keep,
+        }
+    }
+
+    /// How numeric references are decoded, or `None` if it is set by `NumericErrorPolicy::Keep`.
+    #[inline]
+    pub const fn get_numeric_policy(&self) -> Option<NumericPolicy> {
+        match self.mode.numeric {
+            NumericHandling::Html => Some(NumericPolicy::Html),
+            NumericHandling::Keep => None,
+            NumericHandling::Xml => Some(NumericPolicy::XmlStrict),
+            NumericHandling::Passthrough => Some(NumericPolicy::Passthrough),
+        }
+    }
+
+    /// How unknown named references followed by `;` and numeric references to invalid code points are handled, if set.
+    #[inline]
+    pub const fn get_replacement_policy(&self) -> Option<ReplacementPolicy> {
+        self.mode.replacement
+    }
+
+    /// Whether numeric references are decoded.
+    #[inline]
+    pub const fn is_numeric_references_enabled(&self) -> bool {
+        self.mode.numeric_references
+    }
+
+    /// Whether named references fall back to a case-insensitive match.
+    #[inline]
+    pub const fn is_case_insensitive(&self) -> bool {
+        self.mode.case_insensitive
+    }
+
+    /// Whether the text is an attribute value.
+    #[inline]
+    pub const fn is_attribute(&self) -> bool {
+        self.mode.attribute
+    }
+
+    /// Whether to fail on the first malformed character reference.
+    #[inline]
+    pub const fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// The maximum length of the name of a named reference.
+    #[inline]
+    pub const fn get_max_entity_name_length(&self) -> usize {
+        self.mode.max_name_length
+    }
+
+    /// The maximum number of digits of a numeric reference.
+    #[inline]
+    pub const fn get_max_numeric_length(&self) -> usize {
+        self.mode.max_numeric_length
+    }
+
+    /// The maximum length of the decoded text in bytes, if any.
+    #[inline]
+    pub const fn get_max_output_length(&self) -> Option<usize> {
+        self.max_output_length
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    /// Whether the decoded text is normalized to NFC.
+    #[inline]
+    pub const fn is_nfc_normalized(&self) -> bool {
+        self.nfc
+    }
+}
+
+impl Default for DecodeOptions {
+    #[inline]
+    fn default() -> Self {
+        DecodeOptions::new()
+    }
+}
+
+pub(crate) type NoFallback = fn(&str) -> Option<&'static str>;
+
+/// Check whether `length` more bytes fit in the output after `written` bytes. `offset` is where the bytes come from in the text, and `split` is whether the limit is reached right in them.
+#[inline]
+fn check_output_length(
+    written: usize,
+    length: usize,
+    limit: usize,
+    offset: usize,
+    split: bool,
+) -> Result<(), DecodeError> {
+    if length > limit - written {
+        Err(DecodeError::output_limit_exceeded(if split {
+            offset + (limit - written)
+        } else {
+            offset
+        }))
+    } else {
+        Ok(())
+    }
+}
+
+/// Check the `&` at `text_bytes[p]` in strict mode or with `ReplacementPolicy::Error`.
+#[inline]
+fn check_options_at(text: &str, p: usize, options: &DecodeOptions) -> Result<(), DecodeError> {
+    if options.strict {
+        check_reference_at(text, p, options.mode)
+    } else if options.mode.replacement == Some(ReplacementPolicy::Error) {
+        check_invalid_reference_at(text, p, options.mode)
+    } else {
+        Ok(())
+    }
+}
+
+/// Normalize `output[origin..]` to NFC.
+#[cfg(feature = "unicode-normalization")]
+#[inline]
+fn normalize_nfc_in_vec(output: &mut Vec<u8>, origin: usize) {
+    let decoded = unsafe { from_utf8_unchecked(&output[origin..]) };
+
+    if !unicode_normalization::is_nfc(decoded) {
+        let normalized: String = decoded.nfc().collect();
+
+        output.truncate(origin);
+        output.extend_from_slice(normalized.as_bytes());
+    }
+}
+
+/// Decode `text[from..]` to `output`, which ends with `text[..from]`.
+pub(crate) fn decode_to_vec_with_options<F: FnMut(&str) -> Option<R>, R: AsRef<str>>(
+    text: &str,
+    from: usize,
+    options: &DecodeOptions,
+    mut fallback: Option<&mut F>,
+    output: &mut Vec<u8>,
+) -> Result<(), DecodeError> {
+    let text_bytes = text.as_bytes();
+    let limit = options.max_output_length.unwrap_or(usize::MAX);
+
+    check_output_length(0, from, limit, 0, true)?;
+
+    let origin = output.len() - from;
+
+    output.reserve(text_bytes.len() - from);
+
+    let mut p = from;
+    let mut start = from;
+
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        if let Some(fallback) = fallback.as_mut() {
+            if let Some((name, end)) = find_unknown_named_reference(text_bytes, p, options.mode) {
+                if let Some(replacement) = fallback(name) {
+                    let replacement = replacement.as_ref().as_bytes();
+
+                    check_output_length(output.len() - origin, p - start, limit, start, true)?;
+                    output.extend_from_slice(&text_bytes[start..p]);
+                    check_output_length(output.len() - origin, replacement.len(), limit, p, false)?;
+                    output.extend_from_slice(replacement);
+
+                    p = end;
+                    start = end;
+
+                    continue;
+                }
+            }
+        }
+
+        check_options_at(text, p, options)?;
+
+        match parse_reference(text_bytes, p, options.mode) {
+            Some((reference, end)) => {
+                let mut buffer = [0u8; 4];
+                let bytes = reference.to_bytes(&mut buffer);
+
+                check_output_length(output.len() - origin, p - start, limit, start, true)?;
+                output.extend_from_slice(&text_bytes[start..p]);
+                check_output_length(output.len() - origin, bytes.len(), limit, p, false)?;
+                output.extend_from_slice(bytes);
+
+                p = end;
+                start = end;
+            }
+            None => p += 1,
+        }
+    }
+
+    check_output_length(output.len() - origin, text_bytes.len() - start, limit, start, true)?;
+    output.extend_from_slice(&text_bytes[start..]);
+
+    #[cfg(feature = "unicode-normalization")]
+    {
+        if options.nfc {
+            normalize_nfc_in_vec(output, origin);
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn decode_with_options<'a, F: FnMut(&str) -> Option<R>, R: AsRef<str>>(
+    text: &'a str,
+    options: &DecodeOptions,
+    fallback: Option<&mut F>,
+) -> Result<Cow<'a, str>, DecodeError> {
+    let text_bytes = text.as_bytes();
+
+    let mut p = 0;
+
+    loop {
+        match text_bytes[p..].iter().position(|&e| e == b'&') {
+            Some(offset) => p += offset,
+            None => {
+                let limit = options.max_output_length.unwrap_or(usize::MAX);
+
+                check_output_length(0, text_bytes.len(), limit, 0, true)?;
+
+                #[cfg(feature = "unicode-normalization")]
+                {
+                    if options.nfc && !unicode_normalization::is_nfc(text) {
+                        return Ok(Cow::from(text.nfc().collect::<String>()));
+                    }
+                }
+
+                return Ok(Cow::from(text));
+            }
+        }
+
+        if (fallback.is_some()
+            && find_unknown_named_reference(text_bytes, p, options.mode).is_some())
+            || check_options_at(text, p, options).is_err()
+            || parse_reference(text_bytes, p, options.mode).is_some()
+        {
+            break;
+        }
+
+        p += 1;
+    }
+
+    let mut v = Vec::with_capacity(text_bytes.len());
+
+    v.extend_from_slice(&text_bytes[..p]);
+
+    decode_to_vec_with_options(text, p, options, fallback, &mut v)?;
+
+    Ok(Cow::from(unsafe { String::from_utf8_unchecked(v) }))
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn decode_to_writer_with_options<
+    W: Write,
+    F: FnMut(&str) -> Option<R>,
+    R: AsRef<str>,
+>(
+    text: &str,
+    options: &DecodeOptions,
+    mut fallback: Option<&mut F>,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    let text_bytes = text.as_bytes();
+    let limit = options.max_output_length.unwrap_or(usize::MAX);
+
+    let to_io_error = |error| io::Error::new(io::ErrorKind::InvalidData, error);
+
+    // the text has to be normalized as a whole
+    #[cfg(feature = "unicode-normalization")]
+    {
+        if options.nfc {
+            let mut v = Vec::new();
+
+            decode_to_vec_with_options(text, 0, options, fallback, &mut v).map_err(to_io_error)?;
+
+            return output.write_all(&v);
+        }
+    }
+
+    let mut p = 0;
+    let mut start = 0;
+    let mut written = 0;
+
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        if let Some(fallback) = fallback.as_mut() {
+            if let Some((name, end)) = find_unknown_named_reference(text_bytes, p, options.mode) {
+                if let Some(replacement) = fallback(name) {
+                    let replacement = replacement.as_ref().as_bytes();
+
+                    check_output_length(written, p - start, limit, start, true)
+                        .map_err(to_io_error)?;
+                    output.write_all(&text_bytes[start..p])?;
+                    written += p - start;
+                    check_output_length(written, replacement.len(), limit, p, false)
+                        .map_err(to_io_error)?;
+                    output.write_all(replacement)?;
+                    written += replacement.len();
+
+                    p = end;
+                    start = end;
+
+                    continue;
+                }
+            }
+        }
+
+        if let Err(error) = check_options_at(text, p, options) {
+            check_output_length(written, p - start, limit, start, true).map_err(to_io_error)?;
+            output.write_all(&text_bytes[start..p])?;
+
+            return Err(to_io_error(error));
+        }
+
+        match parse_reference(text_bytes, p, options.mode) {
+            Some((reference, end)) => {
+                let mut buffer = [0u8; 4];
+                let bytes = reference.to_bytes(&mut buffer);
+
+                check_output_length(written, p - start, limit, start, true).map_err(to_io_error)?;
+                output.write_all(&text_bytes[start..p])?;
+                written += p - start;
+                check_output_length(written, bytes.len(), limit, p, false).map_err(to_io_error)?;
+                output.write_all(bytes)?;
+                written += bytes.len();
+
+                p = end;
+                start = end;
+            }
+            None => p += 1,
+        }
+    }
+
+    check_output_length(written, text_bytes.len() - start, limit, start, true)
+        .map_err(to_io_error)?;
+    output.write_all(&text_bytes[start..])
+}
+
+/// Decode html entities in a given string according to the given options. An error is only returned in strict mode, with `ReplacementPolicy::Error` or if the output limit is exceeded.
+#[inline]
+pub fn decode_html_entities_with<'a, S: ?Sized + AsRef<str>>(
+    text: &'a S,
+    options: &DecodeOptions,
+) -> Result<Cow<'a, str>, DecodeError> {
+    decode_with_options(text.as_ref(), options, None::<&mut NoFallback>)
+}
+
+/// Decode html entities in a given string to a mutable `String` reference and return the decoded string slice according to the given options. An error is only returned in strict mode, with `ReplacementPolicy::Error` or if the output limit is exceeded, in which case nothing is written.
+#[inline]
+pub fn decode_html_entities_with_to_string<'a, S: AsRef<str>>(
+    text: S,
+    options: &DecodeOptions,
+    output: &'a mut String,
+) -> Result<&'a str, DecodeError> {
+    unsafe {
+        decode_html_entities_with_to_vec(text, options, output.as_mut_vec())
+            .map(|v| from_utf8_unchecked(v))
+    }
+}
+
+/// Decode html entities in a given string to a mutable `Vec<u8>` reference and return the decoded data slice according to the given options. An error is only returned in strict mode, with `ReplacementPolicy::Error` or if the output limit is exceeded, in which case nothing is written.
+#[inline]
+pub fn decode_html_entities_with_to_vec<'a, S: AsRef<str>>(
+    text: S,
+    options: &DecodeOptions,
+    output: &'a mut Vec<u8>,
+) -> Result<&'a [u8], DecodeError> {
+    let current_length = output.len();
+
+    match decode_to_vec_with_options(text.as_ref(), 0, options, None::<&mut NoFallback>, output) {
+        Ok(()) => Ok(&output[current_length..]),
+        Err(error) => {
+            output.truncate(current_length);
+
+            Err(error)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in a given string to a writer according to the given options. In strict mode, with `ReplacementPolicy::Error` or if the output limit is exceeded, the `DecodeError` is wrapped in an `io::Error` of the kind `InvalidData`, and the text before the malformed reference or the limit has already been written on failure.
+#[inline]
+pub fn decode_html_entities_with_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    options: &DecodeOptions,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_to_writer_with_options(text.as_ref(), options, None::<&mut NoFallback>, output)
+}
+
+/// Decode html entities in a given string to a `fmt::Write` according to the given options. A `DecodeError` is only returned in strict mode, with `ReplacementPolicy::Error` or if the output limit is exceeded, in which case nothing is written, and the result of writing is returned otherwise.
+#[inline]
+pub fn decode_html_entities_with_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    options: &DecodeOptions,
+    output: &mut W,
+) -> Result<Result<(), fmt::Error>, DecodeError> {
+    let decoded = decode_html_entities_with(text.as_ref(), options)?;
+
+    Ok(output.write_str(&decoded))
+}
@@ -0,0 +1,228 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use alloc::string::String;
+
+use futures_core::Stream;
+
+use super::*;
+
+/// A stream which yields the chunks of the inner stream with html entities decoded, like `Decoder` does, so a character reference split across chunks is decoded as if the whole text were decoded at once. The chunks can be of any type which can be borrowed as a `str`, such as `String`.
+///
+/// A chunk which is entirely held back is not yielded, and the held back text is yielded as the last chunk once the inner stream ends.
+///
+/// ```
+/// # use core::pin::Pin;
+/// # use core::task::{Context, Poll};
+/// use futures_core::Stream;
+///
+/// use html_escape::DecodeStream;
+///
+/// # struct Chunks(Vec<&'static str>);
+/// #
+/// # impl Stream for Chunks {
+/// #     type Item = &'static str;
+/// #
+/// #     fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<&'static str>> {
+/// #         Poll::Ready(if self.0.is_empty() { None } else { Some(self.0.remove(0)) })
+/// #     }
+/// # }
+/// #
+/// # let mut cx = Context::from_waker(core::task::Waker::noop());
+/// #
+/// let mut stream = DecodeStream::new(Chunks(vec!["a &am", "p; b &amp"]));
+///
+/// assert_eq!(Poll::Ready(Some(String::from("a "))), Pin::new(&mut stream).poll_next(&mut cx));
+/// assert_eq!(Poll::Ready(Some(String::from("& b "))), Pin::new(&mut stream).poll_next(&mut cx));
+/// assert_eq!(Poll::Ready(Some(String::from("&amp"))), Pin::new(&mut stream).poll_next(&mut cx));
+/// assert_eq!(Poll::Ready(None), Pin::new(&mut stream).poll_next(&mut cx));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DecodeStream<St> {
+    stream: St,
+    decoder: Decoder,
+    finished: bool,
+}
+
+impl<St> DecodeStream<St> {
+    /// Create a stream which decodes the chunks of `stream` like `decode_html_entities`.
+    #[inline]
+    pub fn new(stream: St) -> DecodeStream<St> {
+        DecodeStream::with_decoder(stream, Decoder::new())
+    }
+
+    /// Create a stream which decodes the chunks of `stream` like `decode_html_entities_with`. The strictness and the output limit are not checked, use `TryDecodeStream` to check them.
+    #[inline]
+    pub fn with_options(stream: St, options: &DecodeOptions) -> DecodeStream<St> {
+        DecodeStream::with_decoder(stream, Decoder::with_options(options))
+    }
+
+    #[inline]
+    fn with_decoder(stream: St, decoder: Decoder) -> DecodeStream<St> {
+        DecodeStream {
+            stream,
+            decoder,
+            finished: false,
+        }
+    }
+
+    /// A reference to the inner stream.
+    #[inline]
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// A mutable reference to the inner stream.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Return the inner stream. A held back character reference is dropped.
+    #[inline]
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<T: AsRef<str>, St: Stream<Item = T> + Unpin> Stream for DecodeStream<St> {
+    type Item = String;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<String>> {
+        let this = self.get_mut();
+
+        if this.finished {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(chunk)) => {
+                    let decoded: String = this.decoder.feed(chunk.as_ref()).collect();
+
+                    if !decoded.is_empty() {
+                        return Poll::Ready(Some(decoded));
+                    }
+                }
+                Poll::Ready(None) => {
+                    this.finished = true;
+
+                    let held = this.decoder.finish();
+
+                    return Poll::Ready(if held.is_empty() { None } else { Some(held) });
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// A stream which decodes the chunks of the inner stream like `DecodeStream` does, but checks the strictness and the output limit of its `DecodeOptions` like `Decoder::try_feed` does. The first error is yielded as the last item.
+///
+/// ```
+/// # use core::pin::Pin;
+/// # use core::task::{Context, Poll};
+/// use futures_core::Stream;
+///
+/// use html_escape::{DecodeOptions, TryDecodeStream};
+///
+/// # struct Chunks(Vec<&'static str>);
+/// #
+/// # impl Stream for Chunks {
+/// #     type Item = &'static str;
+/// #
+/// #     fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<&'static str>> {
+/// #         Poll::Ready(if self.0.is_empty() { None } else { Some(self.0.remove(0)) })
+/// #     }
+/// # }
+/// #
+/// # let mut cx = Context::from_waker(core::task::Waker::noop());
+/// #
+/// let options = DecodeOptions::new().strict(true);
+///
+/// let mut stream = TryDecodeStream::new(Chunks(vec!["a &am", "p; b &amp c"]), &options);
+///
+/// assert_eq!(Poll::Ready(Some(Ok(String::from("a ")))), Pin::new(&mut stream).poll_next(&mut cx));
+/// assert!(matches!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(Some(Err(_)))));
+/// assert_eq!(Poll::Ready(None), Pin::new(&mut stream).poll_next(&mut cx));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TryDecodeStream<St> {
+    stream: St,
+    decoder: Decoder,
+    finished: bool,
+}
+
+impl<St> TryDecodeStream<St> {
+    /// Create a stream which decodes the chunks of `stream` like `decode_html_entities_with`.
+    #[inline]
+    pub fn new(stream: St, options: &DecodeOptions) -> TryDecodeStream<St> {
+        TryDecodeStream {
+            stream,
+            decoder: Decoder::with_options(options),
+            finished: false,
+        }
+    }
+
+    /// A reference to the inner stream.
+    #[inline]
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// A mutable reference to the inner stream.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Return the inner stream. A held back character reference is dropped.
+    #[inline]
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<T: AsRef<str>, St: Stream<Item = T> + Unpin> Stream for TryDecodeStream<St> {
+    type Item = Result<String, DecodeError>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<String, DecodeError>>> {
+        let this = self.get_mut();
+
+        if this.finished {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(chunk)) => match this.decoder.try_feed(chunk.as_ref()) {
+                    Ok(pieces) => {
+                        let decoded: String = pieces.collect();
+
+                        if !decoded.is_empty() {
+                            return Poll::Ready(Some(Ok(decoded)));
+                        }
+                    }
+                    Err(error) => {
+                        // the decoder should not be used after a failure
+                        this.finished = true;
+
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                },
+                Poll::Ready(None) => {
+                    this.finished = true;
+
+                    return Poll::Ready(match this.decoder.try_finish() {
+                        Ok(held) if held.is_empty() => None,
+                        result => Some(result),
+                    });
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::{char::canonical_combining_class, IsNormalized, UnicodeNormalization};
+
+use super::reference::*;
+use super::*;
+
+/// Whether `tail`, which starts with `&`, can still become a longer character reference with more input. A name is not held back beyond the maximum name length of `mode`, and digits are not held back beyond its maximum numeric length.
+#[inline]
+pub(crate) fn is_reference_prefix(tail: &[u8], mode: &ReferenceMode) -> bool {
+    match tail {
+        [b'&'] | [b'&', b'#'] => true,
+        [b'&', b'#', b'x', hex @ ..] | [b'&', b'#', b'X', hex @ ..] => {
+            hex.len() <= mode.max_numeric_length && hex.iter().all(u8::is_ascii_hexdigit)
+        }
+        [b'&', b'#', digits @ ..] => {
+            digits.len() <= mode.max_numeric_length && digits.iter().all(u8::is_ascii_digit)
+        }
+        [b'&', name @ ..] => {
+            name.len() <= mode.max_name_length && name.iter().all(u8::is_ascii_alphanumeric)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `prefix`, which is a possibly incomplete character reference, is still one after `e` is appended. Only the beginning of `prefix` is checked, so a long run of digits is not scanned again for every byte.
+#[inline]
+fn extends_reference_prefix(prefix: &[u8], e: u8, mode: &ReferenceMode) -> bool {
+    match prefix {
+        [b'&'] => e == b'#' || e.is_ascii_alphanumeric(),
+        [b'&', b'#'] => e == b'x' || e == b'X' || e.is_ascii_digit(),
+        [b'&', b'#', b'x', hex @ ..] | [b'&', b'#', b'X', hex @ ..] => {
+            hex.len() < mode.max_numeric_length && e.is_ascii_hexdigit()
+        }
+        [b'&', b'#', digits @ ..] => digits.len() < mode.max_numeric_length && e.is_ascii_digit(),
+        _ => prefix.len() <= mode.max_name_length && e.is_ascii_alphanumeric(),
+    }
+}
+
+/// The index from which `text` has to be held back because it may be an incomplete character reference.
+#[inline]
+fn hold_index(text: &str, mode: &ReferenceMode) -> usize {
+    let text_bytes = text.as_bytes();
+
+    match text_bytes.iter().rposition(|&e| e == b'&') {
+        Some(p) if is_reference_prefix(&text_bytes[p..], mode) => p,
+        _ => text_bytes.len(),
+    }
+}
+
+/// An incremental decoder of html entities for text which arrives in chunks, such as a network stream.
+///
+/// A character reference split across chunks, such as `&am` in one chunk and `p;` in the next, is held back until it is complete, so the decoded result is the same as decoding the whole text at once.
+///
+/// ```rust
+/// extern crate html_escape;
+///
+/// let mut decoder = html_escape::Decoder::new();
+/// let mut s = String::new();
+///
+/// for chunk in ["a &am", "p; b &l", "t;"].iter() {
+///     s.extend(decoder.feed(chunk));
+/// }
+///
+/// s.push_str(&decoder.finish());
+///
+/// assert_eq!("a & b <", s);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    options: DecodeOptions,
+    pending: String,
+    /// The offset of the held back text in the whole input.
+    offset: usize,
+    /// The length of the decoded text so far.
+    written: usize,
+    /// The decoded text from the last character on which the text after it may still be composed with, held back for the normalization to NFC.
+    #[cfg(feature = "unicode-normalization")]
+    unnormalized: String,
+}
+
+/// The index of the last character of `text` which the text after it can neither be composed with nor reordered before, so `text[..index]` can be normalized on its own.
+#[cfg(feature = "unicode-normalization")]
+#[inline]
+fn stable_index(text: &str) -> usize {
+    text.char_indices()
+        .rev()
+        .find(|&(_, c)| {
+            canonical_combining_class(c) == 0
+                && unicode_normalization::is_nfc_quick(core::iter::once(c)) == IsNormalized::Yes
+        })
+        .map_or(0, |(index, _)| index)
+}
+
+/// Decode a piece of the input at `offset`, checking the options and the output length if `checked`.
+#[inline]
+fn decode_piece<'a>(
+    options: &DecodeOptions,
+    checked: bool,
+    offset: &mut usize,
+    written: &mut usize,
+    piece: &'a str,
+) -> Result<Cow<'a, str>, DecodeError> {
+    let decoded = if checked {
+        let mut options = *options;
+
+        // the decoder normalizes the pieces itself, across their boundaries
+        #[cfg(feature = "unicode-normalization")]
+        {
+            options.nfc = false;
+        }
+
+        if let Some(limit) = options.max_output_length {
+            options.max_output_length = Some(limit - *written);
+        }
+
+        decode_with_options(piece, &options, None::<&mut NoFallback>)
+            .map_err(|error| error.shift(*offset))?
+    } else {
+        decode_with_mode(piece, options.mode)
+    };
+
+    *offset += piece.len();
+    *written += decoded.len();
+
+    Ok(decoded)
+}
+
+impl Decoder {
+    /// Create a decoder which works like `decode_html_entities`.
+    #[inline]
+    pub fn new() -> Decoder {
+        Decoder::with_mode(ReferenceMode::STRICT)
+    }
+
+    /// Create a decoder which works like `decode_html_entities_legacy`.
+    #[inline]
+    pub fn new_legacy() -> Decoder {
+        Decoder::with_mode(ReferenceMode::LEGACY)
+    }
+
+    /// Create a decoder which works like `decode_html_entities_in_attribute`.
+    #[inline]
+    pub fn new_in_attribute() -> Decoder {
+        Decoder::with_mode(ReferenceMode::ATTRIBUTE)
+    }
+
+    /// Create a decoder which works like `decode_html_entities_with`. The strictness and the output limit, which applies to the whole input, are only checked by `try_feed` and `try_finish`.
+    #[inline]
+    pub fn with_options(options: &DecodeOptions) -> Decoder {
+        Decoder {
+            options: *options,
+            pending: String::new(),
+            offset: 0,
+            written: 0,
+            #[cfg(feature = "unicode-normalization")]
+            unnormalized: String::new(),
+        }
+    }
+
+    #[inline]
+    fn with_mode(mode: ReferenceMode) -> Decoder {
+        let mut options = DecodeOptions::new();
+
+        options.mode = mode;
+
+        Decoder::with_options(&options)
+    }
+
+    /// Decode the next chunk. The decoded text is returned in pieces, which may borrow from the chunk. A possibly incomplete character reference at the end of the chunk is held back until the next `feed` or `finish`.
+    #[inline]
+    pub fn feed<'a>(&mut self, chunk: &'a str) -> impl Iterator<Item = Cow<'a, str>> {
+        match self.feed_impl(chunk, false) {
+            Ok(pieces) => pieces,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Decode the next chunk like `feed` does, failing on the first malformed character reference in strict mode or once the output limit is exceeded. The offset of the error is in the whole input. The decoder should not be used after a failure.
+    #[inline]
+    pub fn try_feed<'a>(
+        &mut self,
+        chunk: &'a str,
+    ) -> Result<impl Iterator<Item = Cow<'a, str>>, DecodeError> {
+        self.feed_impl(chunk, true)
+    }
+
+    pub(crate) fn feed_impl<'a>(
+        &mut self,
+        chunk: &'a str,
+        checked: bool,
+    ) -> Result<impl Iterator<Item = Cow<'a, str>>, DecodeError> {
+        let chunk_bytes = chunk.as_bytes();
+
+        let mut first = None;
+        let mut p = 0;
+
+        if !self.pending.is_empty() {
+            loop {
+                if p == chunk_bytes.len() {
+                    // the reference is still incomplete
+                    return Ok(first.into_iter().chain(None));
+                }
+
+                let e = chunk_bytes[p];
+
+                if e == b'&' || !e.is_ascii() {
+                    break;
+                }
+
+                let extends =
+                    extends_reference_prefix(self.pending.as_bytes(), e, &self.options.mode);
+
+                self.pending.push(e as char);
+                p += 1;
+
+                if !extends {
+                    break;
+                }
+            }
+
+            let decoded = decode_piece(
+                &self.options,
+                checked,
+                &mut self.offset,
+                &mut self.written,
+                &self.pending,
+            )?;
+
+            first = Some(Cow::from(decoded.into_owned()));
+
+            self.pending.clear();
+        }
+
+        let rest = &chunk[p..];
+        let hold = hold_index(rest, &self.options.mode);
+
+        let second = if hold > 0 {
+            Some(decode_piece(
+                &self.options,
+                checked,
+                &mut self.offset,
+                &mut self.written,
+                &rest[..hold],
+            )?)
+        } else {
+            None
+        };
+
+        self.pending.push_str(&rest[hold..]);
+
+        #[cfg(feature = "unicode-normalization")]
+        {
+            if self.options.nfc {
+                for piece in first.iter().chain(second.iter()) {
+                    self.unnormalized.push_str(piece);
+                }
+
+                let stable = stable_index(&self.unnormalized);
+                let normalized: String = self.unnormalized[..stable].nfc().collect();
+
+                self.unnormalized.drain(..stable);
+
+                let normalized = Some(Cow::from(normalized)).filter(|s| !s.is_empty());
+
+                return Ok(normalized.into_iter().chain(None));
+            }
+        }
+
+        Ok(first.into_iter().chain(second))
+    }
+
+    /// Decode the text which has been held back. The decoder can be reused afterwards.
+    #[inline]
+    pub fn finish(&mut self) -> String {
+        match self.finish_impl(false) {
+            Ok(decoded) => decoded,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Decode the text which has been held back like `finish` does, failing on a malformed character reference in strict mode or if the output limit is exceeded. The decoder can be reused afterwards.
+    #[inline]
+    pub fn try_finish(&mut self) -> Result<String, DecodeError> {
+        self.finish_impl(true)
+    }
+
+    fn finish_impl(&mut self, checked: bool) -> Result<String, DecodeError> {
+        let decoded = self.flush(checked);
+
+        self.offset = 0;
+        self.written = 0;
+
+        decoded
+    }
+
+    /// Decode the text which has been held back, keeping the position in the whole input.
+    pub(crate) fn flush(&mut self, checked: bool) -> Result<String, DecodeError> {
+        let decoded = decode_piece(
+            &self.options,
+            checked,
+            &mut self.offset,
+            &mut self.written,
+            &self.pending,
+        )
+        .map(Cow::into_owned);
+
+        self.pending.clear();
+
+        #[cfg(feature = "unicode-normalization")]
+        {
+            if self.options.nfc {
+                let decoded = decoded.map(|decoded| {
+                    self.unnormalized.push_str(&decoded);
+
+                    self.unnormalized.nfc().collect()
+                });
+
+                self.unnormalized.clear();
+
+                return decoded;
+            }
+        }
+
+        decoded
+    }
+
+    /// Count a chunk which is not decoded, such as the content of `<script>`, in the position in the whole input and the output length. Nothing should be held back.
+    pub(crate) fn skip(&mut self, chunk: &str, checked: bool) -> Result<(), DecodeError> {
+        if checked {
+            if let Some(limit) = self.options.max_output_length {
+                let remaining = limit.saturating_sub(self.written);
+
+                if chunk.len() > remaining {
+                    return Err(DecodeError::output_limit_exceeded(self.offset + remaining));
+                }
+            }
+        }
+
+        self.offset += chunk.len();
+        self.written += chunk.len();
+
+        Ok(())
+    }
+
+    /// Use other options, keeping the position in the whole input. Nothing should be held back.
+    #[inline]
+    pub(crate) fn set_options(&mut self, options: &DecodeOptions) {
+        self.options = *options;
+    }
+}
+
+impl Default for Decoder {
+    #[inline]
+    fn default() -> Self {
+        Decoder::new()
+    }
+}
@@ -0,0 +1,169 @@
+use alloc::vec::Vec;
+
+use std::io::{self, Read};
+
+use super::decoding_writer::decode_error_to_io;
+use super::*;
+use crate::functions::{invalid_utf8, PendingChar};
+
+const CHUNK_SIZE: usize = 4096;
+
+/// A reader which decodes html entities in the text read from the inner reader, like `Decoder` does for chunks, so a large file can be decoded while it is streamed.
+///
+/// The inner reader has to yield UTF-8, but a character or a character reference may be split across its reads, so the decoded result is the same as decoding the whole text at once.
+///
+/// ```
+/// use std::io::Read;
+///
+/// use html_escape::DecodingReader;
+///
+/// let mut reader = DecodingReader::new("&lt;b&gt;caf&eacute; &amp; co".as_bytes());
+/// let mut s = String::new();
+///
+/// reader.read_to_string(&mut s).unwrap();
+///
+/// assert_eq!("<b>caf\u{E9} & co", s);
+/// ```
+#[derive(Debug)]
+pub struct DecodingReader<R: Read> {
+    inner: R,
+    decoder: Decoder,
+    pending: PendingChar,
+    /// The decoded text which has not been read yet, from `position`.
+    output: Vec<u8>,
+    position: usize,
+    /// Whether the inner reader has yielded invalid UTF-8, which is reported once the text before it is read.
+    invalid: bool,
+    /// Whether the inner reader has ended and the held back text is decoded.
+    finished: bool,
+}
+
+impl<R: Read> DecodingReader<R> {
+    /// Create a reader which reads from `inner` and decodes like `decode_html_entities`.
+    #[inline]
+    pub fn new(inner: R) -> DecodingReader<R> {
+        DecodingReader::with_decoder(inner, Decoder::new())
+    }
+
+    /// Create a reader which reads from `inner` and decodes like `decode_html_entities_with`. A malformed character reference in strict mode or exceeding the output limit fails with an `io::Error` of the kind `InvalidData` which wraps the `DecodeError`.
+    #[inline]
+    pub fn with_options(inner: R, options: &DecodeOptions) -> DecodingReader<R> {
+        DecodingReader::with_decoder(inner, Decoder::with_options(options))
+    }
+
+    #[inline]
+    fn with_decoder(inner: R, decoder: Decoder) -> DecodingReader<R> {
+        DecodingReader {
+            inner,
+            decoder,
+            pending: PendingChar::default(),
+            output: Vec::new(),
+            position: 0,
+            invalid: false,
+            finished: false,
+        }
+    }
+
+    /// A reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner reader. Reading from it directly skips the text it yields.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Return the inner reader. The decoded text which has not been read yet and a held back character reference are dropped.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read from the inner reader until there is decoded text to return or it ends.
+    fn fill(&mut self) -> Result<(), io::Error> {
+        if self.invalid {
+            return Err(invalid_utf8());
+        }
+
+        self.output.clear();
+        self.position = 0;
+
+        let mut chunk = [0u8; CHUNK_SIZE];
+
+        while self.output.is_empty() && !self.finished {
+            let length = self.inner.read(&mut chunk)?;
+
+            if length == 0 {
+                if !self.pending.is_empty() {
+                    return Err(invalid_utf8());
+                }
+
+                let held = self.decoder.try_finish().map_err(decode_error_to_io)?;
+
+                self.output.extend_from_slice(held.as_bytes());
+                self.finished = true;
+
+                break;
+            }
+
+            let decoder = &mut self.decoder;
+            let output = &mut self.output;
+            let mut decode_error = None;
+
+            let result = self.pending.feed(&chunk[..length], |text| match decoder.try_feed(text) {
+                Ok(pieces) => {
+                    for piece in pieces {
+                        output.extend_from_slice(piece.as_bytes());
+                    }
+
+                    Ok(())
+                }
+                Err(error) => {
+                    decode_error = Some(error);
+
+                    Err(invalid_utf8())
+                }
+            });
+
+            if let Some(error) = decode_error {
+                return Err(decode_error_to_io(error));
+            }
+
+            if result.is_err() {
+                self.invalid = true;
+
+                break;
+            }
+        }
+
+        if self.invalid && self.output.is_empty() {
+            return Err(invalid_utf8());
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for DecodingReader<R> {
+    /// Read the decoded text. Fail with an `io::Error` of the kind `InvalidData` if the inner reader does not yield UTF-8.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.position == self.output.len() {
+            self.fill()?;
+        }
+
+        let rest = &self.output[self.position..];
+        let length = rest.len().min(buf.len());
+
+        buf[..length].copy_from_slice(&rest[..length]);
+        self.position += length;
+
+        Ok(length)
+    }
+}
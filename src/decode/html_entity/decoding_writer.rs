@@ -0,0 +1,152 @@
+use std::io::{self, BufRead, Write};
+
+use super::*;
+use crate::functions::{copy_buffered, invalid_utf8, PendingChar};
+
+/// A writer which decodes html entities in everything written through it before passing it to the inner writer, like `Decoder` does for chunks.
+///
+/// The written bytes have to be UTF-8, but a character or a character reference may be split across `write` calls, so the decoded result is the same as decoding the whole text at once. Call `finish` at the end to write a character reference which is held back.
+///
+/// ```
+/// use std::io::Write;
+///
+/// use html_escape::DecodingWriter;
+///
+/// let mut writer = DecodingWriter::new(Vec::new());
+///
+/// writer.write_all(b"&lt;b&g").unwrap();
+/// writer.write_all(b"t;caf\xC3").unwrap();
+/// writer.write_all(b"\xA9 &amp; co").unwrap();
+///
+/// assert_eq!("<b>caf\u{E9} & co".as_bytes(), writer.finish().unwrap().as_slice());
+/// ```
+#[derive(Debug)]
+pub struct DecodingWriter<W: Write> {
+    inner: W,
+    decoder: Decoder,
+    pending: PendingChar,
+}
+
+#[inline]
+pub(super) fn decode_error_to_io(error: DecodeError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+impl<W: Write> DecodingWriter<W> {
+    /// Create a writer which decodes like `decode_html_entities` and writes to `inner`.
+    #[inline]
+    pub fn new(inner: W) -> DecodingWriter<W> {
+        DecodingWriter::with_decoder(inner, Decoder::new())
+    }
+
+    /// Create a writer which decodes like `decode_html_entities_with` and writes to `inner`. A malformed character reference in strict mode or exceeding the output limit fails with an `io::Error` of the kind `InvalidData` which wraps the `DecodeError`.
+    #[inline]
+    pub fn with_options(inner: W, options: &DecodeOptions) -> DecodingWriter<W> {
+        DecodingWriter::with_decoder(inner, Decoder::with_options(options))
+    }
+
+    #[inline]
+    fn with_decoder(inner: W, decoder: Decoder) -> DecodingWriter<W> {
+        DecodingWriter {
+            inner,
+            decoder,
+            pending: PendingChar::default(),
+        }
+    }
+
+    /// A reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner writer. Writing to it directly bypasses the decoding and may come before a held back character reference.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Write the held back text, flush the inner writer and return it. Fail with an `io::Error` of the kind `InvalidData` if the written bytes end with an incomplete character.
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        if !self.pending.is_empty() {
+            return Err(invalid_utf8());
+        }
+
+        let held = self.decoder.try_finish().map_err(decode_error_to_io)?;
+
+        self.inner.write_all(held.as_bytes())?;
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    fn decode(&mut self, text: &str) -> Result<(), io::Error> {
+        for piece in self.decoder.try_feed(text).map_err(decode_error_to_io)? {
+            self.inner.write_all(piece.as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for DecodingWriter<W> {
+    /// Decode and write the whole `buf`, except for an incomplete character or character reference at its end, which is held back until the next call. Fail with an `io::Error` of the kind `InvalidData` if `buf` is not UTF-8, in which case the text before the invalid bytes has already been decoded.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        let mut pending = self.pending;
+
+        let result = pending.feed(buf, |text| self.decode(text));
+
+        self.pending = pending;
+
+        result.map(|_| buf.len())
+    }
+
+    /// Flush the inner writer. A held back character reference is not written, since more input may still extend it.
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.inner.flush()
+    }
+}
+
+/// Decode html entities in everything read from `reader` like `decode_html_entities` and write it to `writer`, returning the number of bytes read, like `io::copy` does. No more than the buffer of `reader` and a held back character reference are held in memory, and a character or a character reference split across refills of the buffer is decoded as if the whole text were decoded at once, so large files can be decoded by wrapping them in a `BufReader`.
+///
+/// Fail with an `io::Error` of the kind `InvalidData` if the read bytes are not UTF-8, in which case the text before the invalid bytes has already been decoded.
+///
+/// ```
+/// let mut output = Vec::new();
+///
+/// let length =
+///     html_escape::decode_html_entities_from_reader("&lt;b&gt; &amp; co".as_bytes(), &mut output)
+///         .unwrap();
+///
+/// assert_eq!(18, length);
+/// assert_eq!(b"<b> & co", output.as_slice());
+/// ```
+#[inline]
+pub fn decode_html_entities_from_reader<R: BufRead, W: Write>(
+    reader: R,
+    writer: W,
+) -> Result<u64, io::Error> {
+    decode_from_reader(reader, DecodingWriter::new(writer))
+}
+
+/// Decode html entities in everything read from `reader` like `decode_html_entities_with` and write it to `writer`, returning the number of bytes read. A malformed character reference in strict mode or exceeding the output limit fails with an `io::Error` of the kind `InvalidData` which wraps the `DecodeError`.
+#[inline]
+pub fn decode_html_entities_from_reader_with<R: BufRead, W: Write>(
+    reader: R,
+    writer: W,
+    options: &DecodeOptions,
+) -> Result<u64, io::Error> {
+    decode_from_reader(reader, DecodingWriter::with_options(writer, options))
+}
+
+fn decode_from_reader<R: BufRead, W: Write>(
+    reader: R,
+    mut writer: DecodingWriter<W>,
+) -> Result<u64, io::Error> {
+    let length = copy_buffered(reader, &mut writer)?;
+
+    writer.finish()?;
+
+    Ok(length)
+}
@@ -0,0 +1,229 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use super::reference::*;
+use super::DecodeOptions;
+
+/// A character reference found by the `find_html_entities*` functions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EntityMatch<'a> {
+    range: Range<usize>,
+    raw: &'a str,
+    reference: Reference,
+    buffer: [u8; 4],
+}
+
+impl<'a> EntityMatch<'a> {
+    /// The byte range of the reference in the text.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The reference as it is in the text, such as `&amp;`.
+    #[inline]
+    pub fn raw(&self) -> &'a str {
+        self.raw
+    }
+
+    /// What the reference is decoded to, such as `&`.
+    #[inline]
+    pub fn decoded(&self) -> &str {
+        match self.reference {
+            Reference::Named(s) => s,
+            Reference::Numeric(c) => unsafe { from_utf8_unchecked(&self.buffer[..c.len_utf8()]) },
+            Reference::Removed => "",
+        }
+    }
+}
+
+/// An iterator over the character references in a string, created by the `find_html_entities*` functions.
+#[derive(Debug, Clone)]
+pub struct HtmlEntities<'a> {
+    text: &'a str,
+    mode: ReferenceMode,
+    p: usize,
+}
+
+impl<'a> Iterator for HtmlEntities<'a> {
+    type Item = EntityMatch<'a>;
+
+    fn next(&mut self) -> Option<EntityMatch<'a>> {
+        let text_bytes = self.text.as_bytes();
+
+        while let Some(offset) = text_bytes[self.p..].iter().position(|&e| e == b'&') {
+            let p = self.p + offset;
+
+            match parse_reference(text_bytes, p, self.mode) {
+                Some((reference, end)) => {
+                    self.p = end;
+
+                    let mut buffer = [0u8; 4];
+
+                    if let Reference::Numeric(c) = reference {
+                        c.encode_utf8(&mut buffer);
+                    }
+
+                    return Some(EntityMatch {
+                        range: p..end,
+                        raw: &self.text[p..end],
+                        reference,
+                        buffer,
+                    });
+                }
+                None => self.p = p + 1,
+            }
+        }
+
+        self.p = text_bytes.len();
+
+        None
+    }
+}
+
+impl<'a> FusedIterator for HtmlEntities<'a> {}
+
+/// Find the character references in a given string which `decode_html_entities` decodes, with their byte ranges and decoded values, without decoding the whole string.
+///
+/// ```
+/// let entities: Vec<_> = html_escape::find_html_entities("a &lt; b &#x26; c").collect();
+///
+/// assert_eq!(2..6, entities[0].range());
+/// assert_eq!("&lt;", entities[0].raw());
+/// assert_eq!("&", entities[1].decoded());
+/// ```
+#[inline]
+pub fn find_html_entities<S: ?Sized + AsRef<str>>(text: &S) -> HtmlEntities<'_> {
+    HtmlEntities {
+        text: text.as_ref(),
+        mode: ReferenceMode::STRICT,
+        p: 0,
+    }
+}
+
+/// Find the character references in a given string which `decode_html_entities_with` decodes according to the given options, with their byte ranges and decoded values, without decoding the whole string. Malformed references are skipped even in strict mode.
+#[inline]
+pub fn find_html_entities_with<'a, S: ?Sized + AsRef<str>>(
+    text: &'a S,
+    options: &DecodeOptions,
+) -> HtmlEntities<'a> {
+    HtmlEntities {
+        text: text.as_ref(),
+        mode: options.mode,
+        p: 0,
+    }
+}
+
+/// Decode html entities in a given string according to the given options into a list of edits instead of a new string. Each edit is the byte range of a character reference in the text and what it is decoded to, in the order they appear, so applying them from the last one to the first one on the text gives the decoded text. Malformed references are skipped even in strict mode.
+///
+/// ```
+/// let mut text = String::from("a &lt; b &#x26; c");
+///
+/// let edits = html_escape::decode_html_entities_to_edits(&text, &html_escape::DecodeOptions::new());
+///
+/// assert_eq!(2, edits.len());
+///
+/// for (range, replacement) in edits.into_iter().rev() {
+///     text.replace_range(range, &replacement);
+/// }
+///
+/// assert_eq!("a < b & c", text);
+/// ```
+pub fn decode_html_entities_to_edits<S: ?Sized + AsRef<str>>(
+    text: &S,
+    options: &DecodeOptions,
+) -> Vec<(Range<usize>, Cow<'static, str>)> {
+    find_html_entities_with(text, options)
+        .map(|entity| {
+            let replacement = match entity.reference {
+                Reference::Named(s) => Cow::from(s),
+                Reference::Numeric(c) => Cow::from(c.to_string()),
+                Reference::Removed => Cow::from(""),
+            };
+
+            (entity.range, replacement)
+        })
+        .collect()
+}
+
+/// An iterator over the byte ranges of double encoded character references in a string, created by the `find_double_encoded_entities` function.
+#[derive(Debug, Clone)]
+pub struct DoubleEncodedEntities<'a> {
+    text: &'a str,
+    p: usize,
+}
+
+impl<'a> DoubleEncodedEntities<'a> {
+    /// Find the end of the reference whose `&` is itself encoded as the reference which ends with the `;` at `text_bytes[p]`, such as `lt;` in `&amp;lt;`.
+    #[inline]
+    fn find_inner_end(text_bytes: &[u8], p: usize) -> Option<(Reference, usize)> {
+        if text_bytes[p] == b';' {
+            parse_reference(text_bytes, p, ReferenceMode::STRICT)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Iterator for DoubleEncodedEntities<'a> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        let text_bytes = self.text.as_bytes();
+
+        while let Some(offset) = text_bytes[self.p..].iter().position(|&e| e == b'&') {
+            let p = self.p + offset;
+
+            self.p = p + 1;
+
+            let mut end = match parse_reference(text_bytes, p, ReferenceMode::STRICT) {
+                Some((reference, end)) if reference.to_bytes(&mut [0u8; 4]) == b"&" => end,
+                _ => continue,
+            };
+
+            let mut found = false;
+
+            // follow `&amp;amp;lt;` up to the innermost reference
+            while let Some((reference, inner_end)) = Self::find_inner_end(text_bytes, end - 1) {
+                found = true;
+                end = inner_end;
+
+                if reference.to_bytes(&mut [0u8; 4]) != b"&" {
+                    break;
+                }
+            }
+
+            if found {
+                self.p = end;
+
+                return Some(p..end);
+            }
+        }
+
+        self.p = text_bytes.len();
+
+        None
+    }
+}
+
+impl<'a> FusedIterator for DoubleEncodedEntities<'a> {}
+
+/// Find the character references in a given string which seem to be encoded more than once, such as `&amp;lt;` and `&amp;#x27;`, whose `&` is encoded as a reference which is immediately followed by the rest of another reference. Return an iterator over their byte ranges, so suspicious text can be reported before it is decoded again.
+///
+/// ```
+/// let ranges: Vec<_> = html_escape::find_double_encoded_entities("a &amp; b &amp;lt; c &#38;amp;#x27;").collect();
+///
+/// assert_eq!(vec![10..18, 21..35], ranges);
+/// ```
+#[inline]
+pub fn find_double_encoded_entities<S: ?Sized + AsRef<str>>(text: &S) -> DoubleEncodedEntities<'_> {
+    DoubleEncodedEntities {
+        text: text.as_ref(),
+        p: 0,
+    }
+}
@@ -0,0 +1,114 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::*;
+
+/// Decode html entities in a given string according to the given options, calling `fallback` with the name of every unrecognized named reference followed by `;`, such as `myapp-icon` for `&myapp-icon;`. The name may also contain `-`, `.` and `_`. If `fallback` returns `Some`, the reference is replaced with the returned text, otherwise it is handled as usual. An error is only returned in strict mode, with `ReplacementPolicy::Error` or if the output limit is exceeded.
+///
+/// ```
+/// let decoded = html_escape::decode_html_entities_with_fallback(
+///     "&lt;&myapp-icon;&gt; &foo;",
+///     &html_escape::DecodeOptions::new(),
+///     |name| if name == "myapp-icon" { Some("★") } else { None },
+/// );
+///
+/// assert_eq!("<★> &foo;", decoded.unwrap());
+/// ```
+#[inline]
+pub fn decode_html_entities_with_fallback<
+    'a,
+    S: ?Sized + AsRef<str>,
+    F: FnMut(&str) -> Option<R>,
+    R: AsRef<str>,
+>(
+    text: &'a S,
+    options: &DecodeOptions,
+    mut fallback: F,
+) -> Result<Cow<'a, str>, DecodeError> {
+    decode_with_options(text.as_ref(), options, Some(&mut fallback))
+}
+
+/// Decode html entities in a given string to a mutable `String` reference and return the decoded string slice according to the given options, calling `fallback` with the name of every unrecognized named reference followed by `;`, such as `myapp-icon` for `&myapp-icon;`. The name may also contain `-`, `.` and `_`. If `fallback` returns `Some`, the reference is replaced with the returned text, otherwise it is handled as usual. An error is only returned in strict mode, with `ReplacementPolicy::Error` or if the output limit is exceeded, in which case nothing is written.
+#[inline]
+pub fn decode_html_entities_with_fallback_to_string<
+    'a,
+    S: AsRef<str>,
+    F: FnMut(&str) -> Option<R>,
+    R: AsRef<str>,
+>(
+    text: S,
+    options: &DecodeOptions,
+    fallback: F,
+    output: &'a mut String,
+) -> Result<&'a str, DecodeError> {
+    unsafe {
+        decode_html_entities_with_fallback_to_vec(text, options, fallback, output.as_mut_vec())
+            .map(|v| from_utf8_unchecked(v))
+    }
+}
+
+/// Decode html entities in a given string to a mutable `Vec<u8>` reference and return the decoded data slice according to the given options, calling `fallback` with the name of every unrecognized named reference followed by `;`, such as `myapp-icon` for `&myapp-icon;`. The name may also contain `-`, `.` and `_`. If `fallback` returns `Some`, the reference is replaced with the returned text, otherwise it is handled as usual. An error is only returned in strict mode, with `ReplacementPolicy::Error` or if the output limit is exceeded, in which case nothing is written.
+pub fn decode_html_entities_with_fallback_to_vec<
+    'a,
+    S: AsRef<str>,
+    F: FnMut(&str) -> Option<R>,
+    R: AsRef<str>,
+>(
+    text: S,
+    options: &DecodeOptions,
+    mut fallback: F,
+    output: &'a mut Vec<u8>,
+) -> Result<&'a [u8], DecodeError> {
+    let current_length = output.len();
+
+    match decode_to_vec_with_options(text.as_ref(), 0, options, Some(&mut fallback), output) {
+        Ok(()) => Ok(&output[current_length..]),
+        Err(error) => {
+            output.truncate(current_length);
+
+            Err(error)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in a given string to a writer according to the given options, calling `fallback` with the name of every unrecognized named reference followed by `;`, such as `myapp-icon` for `&myapp-icon;`. The name may also contain `-`, `.` and `_`. If `fallback` returns `Some`, the reference is replaced with the returned text, otherwise it is handled as usual. In strict mode, with `ReplacementPolicy::Error` or if the output limit is exceeded, the `DecodeError` is wrapped in an `io::Error` of the kind `InvalidData`, and the text before the malformed reference or the limit has already been written on failure.
+#[inline]
+pub fn decode_html_entities_with_fallback_to_writer<
+    S: AsRef<str>,
+    W: Write,
+    F: FnMut(&str) -> Option<R>,
+    R: AsRef<str>,
+>(
+    text: S,
+    options: &DecodeOptions,
+    mut fallback: F,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_to_writer_with_options(text.as_ref(), options, Some(&mut fallback), output)
+}
+
+/// Decode html entities in a given string to a `fmt::Write` according to the given options, calling `fallback` with the name of every unrecognized named reference followed by `;`, such as `myapp-icon` for `&myapp-icon;`. The name may also contain `-`, `.` and `_`. If `fallback` returns `Some`, the reference is replaced with the returned text, otherwise it is handled as usual. A `DecodeError` is only returned in strict mode, with `ReplacementPolicy::Error` or if the output limit is exceeded, in which case nothing is written, and the result of writing is returned otherwise.
+#[inline]
+pub fn decode_html_entities_with_fallback_to_fmt_writer<
+    S: AsRef<str>,
+    W: fmt::Write,
+    F: FnMut(&str) -> Option<R>,
+    R: AsRef<str>,
+>(
+    text: S,
+    options: &DecodeOptions,
+    fallback: F,
+    output: &mut W,
+) -> Result<Result<(), fmt::Error>, DecodeError> {
+    let decoded = decode_html_entities_with_fallback(text.as_ref(), options, fallback)?;
+
+    Ok(output.write_str(&decoded))
+}
@@ -0,0 +1,298 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::reference::*;
+use super::NamedEntitySet;
+
+/// Find the next reference from `p` which `decode` accepts. `decode` is given the name of a named reference, or `None` for a numeric one, and the decoded text.
+#[inline]
+fn find_reference<F: FnMut(Option<&[u8]>, &[u8]) -> bool>(
+    text_bytes: &[u8],
+    mut p: usize,
+    decode: &mut F,
+) -> Option<(usize, Reference, usize)> {
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        if let Some((reference, end)) = parse_reference(text_bytes, p, ReferenceMode::STRICT) {
+            let name = match reference {
+                Reference::Named(_) => Some(&text_bytes[(p + 1)..(end - 1)]),
+                Reference::Numeric(_) | Reference::Removed => None,
+            };
+
+            if decode(name, reference.to_bytes(&mut [0u8; 4])) {
+                return Some((p, reference, end));
+            }
+        }
+
+        p += 1;
+    }
+
+    None
+}
+
+fn decode_filtered_to_vec<'a, F: FnMut(Option<&[u8]>, &[u8]) -> bool>(
+    text: &str,
+    mut decode: F,
+    output: &'a mut Vec<u8>,
+) -> &'a [u8] {
+    let text_bytes = text.as_bytes();
+
+    output.reserve(text_bytes.len());
+
+    let current_length = output.len();
+
+    let mut start = 0;
+
+    while let Some((p, reference, end)) = find_reference(text_bytes, start, &mut decode) {
+        output.extend_from_slice(&text_bytes[start..p]);
+        reference.write_to_vec(output);
+
+        start = end;
+    }
+
+    output.extend_from_slice(&text_bytes[start..]);
+
+    &output[current_length..]
+}
+
+fn decode_filtered<F: FnMut(Option<&[u8]>, &[u8]) -> bool>(
+    text: &str,
+    mut decode: F,
+) -> Cow<'_, str> {
+    let text_bytes = text.as_bytes();
+
+    match find_reference(text_bytes, 0, &mut decode) {
+        Some((p, ..)) => {
+            let mut v = Vec::with_capacity(text_bytes.len());
+
+            v.extend_from_slice(&text_bytes[..p]);
+
+            decode_filtered_to_vec(
+                unsafe { from_utf8_unchecked(&text_bytes[p..]) },
+                decode,
+                &mut v,
+            );
+
+            Cow::from(unsafe { String::from_utf8_unchecked(v) })
+        }
+        None => Cow::from(text),
+    }
+}
+
+#[cfg(feature = "std")]
+fn decode_filtered_to_writer<F: FnMut(Option<&[u8]>, &[u8]) -> bool, W: Write>(
+    text: &str,
+    mut decode: F,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    let text_bytes = text.as_bytes();
+
+    let mut start = 0;
+
+    while let Some((p, reference, end)) = find_reference(text_bytes, start, &mut decode) {
+        output.write_all(&text_bytes[start..p])?;
+        reference.write_to_writer(output)?;
+
+        start = end;
+    }
+
+    output.write_all(&text_bytes[start..])
+}
+
+#[inline]
+fn is_in(names: &[&str], name: Option<&[u8]>) -> bool {
+    match name {
+        Some(name) => names.iter().any(|n| n.as_bytes() == name),
+        None => false,
+    }
+}
+
+#[inline]
+fn is_excluded(names: &[&str], name: Option<&[u8]>, decoded: &[u8]) -> bool {
+    is_in(names, name)
+        || names.iter().any(|n| {
+            // `&apos;` is not in the HTML 4.01 table which the `html4-entities` feature uses
+            find_named_entity(n.as_bytes(), NamedEntitySet::Html5)
+                .or_else(|| find_named_entity(n.as_bytes(), NamedEntitySet::Xml))
+                .map(|s| s.as_bytes() == decoded)
+                .unwrap_or(false)
+        })
+}
+
+/// Decode only the named entities in `names`, such as `["amp", "quot"]` for `&amp;` and `&quot;`, in a given string, keeping the other references, including numeric ones, as they are.
+///
+/// ```
+/// assert_eq!("&lt;a & b&gt;", html_escape::decode_html_entities_only("&lt;a &amp; b&gt;", &["amp"]));
+/// ```
+#[inline]
+pub fn decode_html_entities_only<'a, S: ?Sized + AsRef<str>>(
+    text: &'a S,
+    names: &[&str],
+) -> Cow<'a, str> {
+    decode_filtered(text.as_ref(), |name, _| is_in(names, name))
+}
+
+/// Decode only the named entities in `names`, such as `["amp", "quot"]` for `&amp;` and `&quot;`, in a given string to a mutable `String` reference and return the decoded string slice, keeping the other references, including numeric ones, as they are.
+#[inline]
+pub fn decode_html_entities_only_to_string<'a, S: AsRef<str>>(
+    text: S,
+    names: &[&str],
+    output: &'a mut String,
+) -> &'a str {
+    unsafe {
+        from_utf8_unchecked(decode_html_entities_only_to_vec(text, names, output.as_mut_vec()))
+    }
+}
+
+/// Decode only the named entities in `names`, such as `["amp", "quot"]` for `&amp;` and `&quot;`, in a given string to a mutable `Vec<u8>` reference and return the decoded data slice, keeping the other references, including numeric ones, as they are.
+#[inline]
+pub fn decode_html_entities_only_to_vec<'a, S: AsRef<str>>(
+    text: S,
+    names: &[&str],
+    output: &'a mut Vec<u8>,
+) -> &'a [u8] {
+    decode_filtered_to_vec(text.as_ref(), |name, _| is_in(names, name), output)
+}
+
+#[cfg(feature = "std")]
+/// Decode only the named entities in `names`, such as `["amp", "quot"]` for `&amp;` and `&quot;`, in a given string to a writer, keeping the other references, including numeric ones, as they are.
+#[inline]
+pub fn decode_html_entities_only_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    names: &[&str],
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_filtered_to_writer(text.as_ref(), |name, _| is_in(names, name), output)
+}
+
+/// Decode only the named entities in `names`, such as `["amp", "quot"]` for `&amp;` and `&quot;`, in a given string to a `fmt::Write`, keeping the other references, including numeric ones, as they are.
+#[inline]
+pub fn decode_html_entities_only_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    names: &[&str],
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    output.write_str(&decode_html_entities_only(text.as_ref(), names))
+}
+
+/// Decode html entities in a given string, keeping the named entities in `names`, such as `["lt", "gt"]` for `&lt;` and `&gt;`, as they are. Any other reference which is decoded to the same text as one of them, such as `&#60;` or `&LT;` for `lt`, is kept as well.
+///
+/// ```
+/// assert_eq!("&lt;a & b&#62;", html_escape::decode_html_entities_except("&lt;a &amp; b&#62;", &["lt", "gt"]));
+/// ```
+#[inline]
+pub fn decode_html_entities_except<'a, S: ?Sized + AsRef<str>>(
+    text: &'a S,
+    names: &[&str],
+) -> Cow<'a, str> {
+    decode_filtered(text.as_ref(), |name, decoded| !is_excluded(names, name, decoded))
+}
+
+/// Decode html entities in a given string to a mutable `String` reference and return the decoded string slice, keeping the named entities in `names`, such as `["lt", "gt"]` for `&lt;` and `&gt;`, as they are. Any other reference which is decoded to the same text as one of them, such as `&#60;` or `&LT;` for `lt`, is kept as well.
+#[inline]
+pub fn decode_html_entities_except_to_string<'a, S: AsRef<str>>(
+    text: S,
+    names: &[&str],
+    output: &'a mut String,
+) -> &'a str {
+    unsafe {
+        from_utf8_unchecked(decode_html_entities_except_to_vec(text, names, output.as_mut_vec()))
+    }
+}
+
+/// Decode html entities in a given string to a mutable `Vec<u8>` reference and return the decoded data slice, keeping the named entities in `names`, such as `["lt", "gt"]` for `&lt;` and `&gt;`, as they are. Any other reference which is decoded to the same text as one of them, such as `&#60;` or `&LT;` for `lt`, is kept as well.
+#[inline]
+pub fn decode_html_entities_except_to_vec<'a, S: AsRef<str>>(
+    text: S,
+    names: &[&str],
+    output: &'a mut Vec<u8>,
+) -> &'a [u8] {
+    decode_filtered_to_vec(
+        text.as_ref(),
+        |name, decoded| !is_excluded(names, name, decoded),
+        output,
+    )
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in a given string to a writer, keeping the named entities in `names`, such as `["lt", "gt"]` for `&lt;` and `&gt;`, as they are. Any other reference which is decoded to the same text as one of them, such as `&#60;` or `&LT;` for `lt`, is kept as well.
+#[inline]
+pub fn decode_html_entities_except_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    names: &[&str],
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_filtered_to_writer(
+        text.as_ref(),
+        |name, decoded| !is_excluded(names, name, decoded),
+        output,
+    )
+}
+
+/// Decode html entities in a given string to a `fmt::Write`, keeping the named entities in `names`, such as `["lt", "gt"]` for `&lt;` and `&gt;`, as they are. Any other reference which is decoded to the same text as one of them, such as `&#60;` or `&LT;` for `lt`, is kept as well.
+#[inline]
+pub fn decode_html_entities_except_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    names: &[&str],
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    output.write_str(&decode_html_entities_except(text.as_ref(), names))
+}
+
+/// The named entities kept by the `decode_html_entities_except_markup*` functions.
+const MARKUP_NAMES: [&str; 5] = ["lt", "gt", "amp", "quot", "apos"];
+
+/// Decode html entities in a given string except those of `<`, `>`, `&`, `"` and `'`, such as `&lt;` and `&#38;`, so the result is readable but still safe to be embedded in html as text or in a quoted attribute value.
+///
+/// ```
+/// assert_eq!("&lt;b&gt; \u{A9} &amp; &#39;", html_escape::decode_html_entities_except_markup("&lt;b&gt; &copy; &amp; &#39;"));
+/// ```
+#[inline]
+pub fn decode_html_entities_except_markup<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    decode_html_entities_except(text, &MARKUP_NAMES)
+}
+
+/// Decode html entities in a given string except those of `<`, `>`, `&`, `"` and `'`, such as `&lt;` and `&#38;`, to a mutable `String` reference and return the decoded string slice, so the result is readable but still safe to be embedded in html as text or in a quoted attribute value.
+#[inline]
+pub fn decode_html_entities_except_markup_to_string<S: AsRef<str>>(
+    text: S,
+    output: &mut String,
+) -> &str {
+    decode_html_entities_except_to_string(text, &MARKUP_NAMES, output)
+}
+
+/// Decode html entities in a given string except those of `<`, `>`, `&`, `"` and `'`, such as `&lt;` and `&#38;`, to a mutable `Vec<u8>` reference and return the decoded data slice, so the result is readable but still safe to be embedded in html as text or in a quoted attribute value.
+#[inline]
+pub fn decode_html_entities_except_markup_to_vec<S: AsRef<str>>(
+    text: S,
+    output: &mut Vec<u8>,
+) -> &[u8] {
+    decode_html_entities_except_to_vec(text, &MARKUP_NAMES, output)
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in a given string except those of `<`, `>`, `&`, `"` and `'`, such as `&lt;` and `&#38;`, to a writer, so the result is readable but still safe to be embedded in html as text or in a quoted attribute value.
+#[inline]
+pub fn decode_html_entities_except_markup_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_html_entities_except_to_writer(text, &MARKUP_NAMES, output)
+}
+
+/// Decode html entities in a given string except those of `<`, `>`, `&`, `"` and `'`, such as `&lt;` and `&#38;`, to a `fmt::Write`, so the result is readable but still safe to be embedded in html as text or in a quoted attribute value.
+#[inline]
+pub fn decode_html_entities_except_markup_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    output.write_str(&decode_html_entities_except_markup(text.as_ref()))
+}
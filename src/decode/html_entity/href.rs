@@ -0,0 +1,53 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::reference::*;
+use super::*;
+
+/// Decode a URL copied from a `href` or `src` attribute, such as `?a=1&amp;b=2`, so it can be used as it is. Character references are decoded like browsers do in an attribute value, so `?a=1&copy=2` stays as it is. Percent-escapes such as `%20` and `%26` are kept, since decoding them is up to the URL parser.
+///
+/// ```
+/// assert_eq!("/search?q=a%20b&lang=en&copy=1", html_escape::decode_href("/search?q=a%20b&amp;lang=en&copy=1"));
+/// ```
+#[inline]
+pub fn decode_href<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    decode_with_mode(text.as_ref(), ReferenceMode::ATTRIBUTE)
+}
+
+/// Decode a URL copied from a `href` or `src` attribute, such as `?a=1&amp;b=2`, to a mutable `String` reference and return the decoded string slice. Character references are decoded like browsers do in an attribute value, so `?a=1&copy=2` stays as it is. Percent-escapes such as `%20` and `%26` are kept, since decoding them is up to the URL parser.
+#[inline]
+pub fn decode_href_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(decode_href_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Decode a URL copied from a `href` or `src` attribute, such as `?a=1&amp;b=2`, to a mutable `Vec<u8>` reference and return the decoded data slice. Character references are decoded like browsers do in an attribute value, so `?a=1&copy=2` stays as it is. Percent-escapes such as `%20` and `%26` are kept, since decoding them is up to the URL parser.
+#[inline]
+pub fn decode_href_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    decode_to_vec_with_mode(text.as_ref().as_bytes(), ReferenceMode::ATTRIBUTE, output)
+}
+
+#[cfg(feature = "std")]
+/// Decode a URL copied from a `href` or `src` attribute, such as `?a=1&amp;b=2`, to a writer. Character references are decoded like browsers do in an attribute value, so `?a=1&copy=2` stays as it is. Percent-escapes such as `%20` and `%26` are kept, since decoding them is up to the URL parser.
+#[inline]
+pub fn decode_href_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_to_writer_with_mode(text.as_ref().as_bytes(), ReferenceMode::ATTRIBUTE, output)
+}
+
+/// Decode a URL copied from a `href` or `src` attribute, such as `?a=1&amp;b=2`, to a `fmt::Write`. Character references are decoded like browsers do in an attribute value, so `?a=1&copy=2` stays as it is. Percent-escapes such as `%20` and `%26` are kept, since decoding them is up to the URL parser.
+#[inline]
+pub fn decode_href_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    output.write_str(&decode_href(text.as_ref()))
+}
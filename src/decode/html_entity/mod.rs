@@ -1,8 +1,61 @@
+mod audit;
+mod bytes;
+mod chars;
+mod chunks;
+mod content_decoder;
+mod decode_error;
+mod decode_options;
+#[cfg(feature = "futures-core")]
+mod decode_stream;
+mod decoder;
+#[cfg(feature = "std")]
+mod decoding_reader;
+#[cfg(feature = "std")]
+mod decoding_writer;
+mod entities;
+mod fallback;
+mod filter;
+mod href;
+mod offsets;
+mod ranges;
+mod reference;
+mod repeated;
+mod splice;
+mod stats;
 mod tables;
-
+mod utf16;
+mod visitor;
+mod xml;
+
+pub use audit::*;
+pub use bytes::*;
+pub use chars::*;
+pub use chunks::*;
+pub use content_decoder::*;
+pub use decode_error::*;
+pub use decode_options::*;
+#[cfg(feature = "futures-core")]
+pub use decode_stream::*;
+pub use decoder::*;
+#[cfg(feature = "std")]
+pub use decoding_reader::*;
+#[cfg(feature = "std")]
+pub use decoding_writer::*;
+pub use entities::*;
+pub use fallback::*;
+pub use filter::*;
+pub use href::*;
+pub use offsets::*;
+pub use ranges::*;
+pub use repeated::*;
+pub use splice::*;
+pub use stats::*;
 pub use tables::*;
+pub use utf16::*;
+pub use visitor::*;
+pub use xml::*;
 
-use core::convert::TryFrom;
+use core::fmt;
 use core::str::from_utf8_unchecked;
 
 use alloc::borrow::Cow;
@@ -12,479 +65,541 @@ use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::io::{self, Write};
 
-use crate::functions::*;
+use crate::buffer_too_small::SliceWriter;
+use crate::{BomPolicy, BufferTooSmall, EncodeSink, FmtSink};
 
-/// Decode html entities in a given string.
-pub fn decode_html_entities<S: ?Sized + AsRef<str>>(text: &S) -> Cow<str> {
-    let text = text.as_ref();
-    let text_bytes = text.as_bytes();
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+use reference::*;
+
+fn decode_bytes_with_mode(text_bytes: &[u8], mode: ReferenceMode) -> Cow<'_, [u8]> {
     let text_length = text_bytes.len();
 
     let mut p = 0;
-    let mut ep = 0;
-    let mut e;
 
-    let mut step = 0;
-
-    let (mut v, mut start) = loop {
-        if p == text_length {
-            return Cow::from(text);
+    let (reference, end) = loop {
+        match text_bytes[p..].iter().position(|&e| e == b'&') {
+            Some(offset) => p += offset,
+            None => return Cow::from(text_bytes),
         }
 
-        e = text_bytes[p];
-
-        match step {
-            0 => {
-                if e == b'&' {
-                    step = 1;
-                    ep = p;
-                }
-            }
-            1 => {
-                match e {
-                    b'#' => {
-                        step = 3;
-                    }
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => {
-                        step = 2;
-                    }
-                }
-            }
-            2 => {
-                if e == b';' {
-                    // named
-                    let mut v = Vec::with_capacity(text_length);
-
-                    v.extend_from_slice(&text_bytes[..ep]);
-
-                    let name = &text_bytes[(ep + 1)..p];
-
-                    match NAMED_ENTITIES.binary_search_by(|(t_name, _)| t_name.cmp(&name)) {
-                        Ok(index) => {
-                            v.extend_from_slice(NAMED_ENTITIES[index].1.as_bytes());
-                            break (v, p + 1);
-                        }
-                        Err(_) => break (v, ep),
-                    }
-                }
-            }
-            3 => {
-                match e {
-                    b'x' | b'X' => {
-                        step = 5;
-                    }
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => step = 4,
-                }
-            }
-            4 => {
-                if e == b';' {
-                    // numeric
-                    let mut v = Vec::with_capacity(text_length);
-
-                    v.extend_from_slice(&text_bytes[..ep]);
-
-                    let number = unsafe { text.get_unchecked((ep + 2)..p) };
-
-                    match number.parse::<u32>() {
-                        Ok(number) => {
-                            match char::try_from(number) {
-                                Ok(c) => {
-                                    write_char_to_vec(c, &mut v);
-                                    break (v, p + 1);
-                                }
-                                Err(_) => break (v, ep),
-                            }
-                        }
-                        Err(_) => break (v, ep),
-                    }
-                }
-            }
-            5 => {
-                match e {
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => step = 6,
-                }
-            }
-            6 => {
-                if e == b';' {
-                    // hex
-                    let mut v = Vec::with_capacity(text_length);
-
-                    v.extend_from_slice(&text_bytes[..ep]);
-
-                    let hex = unsafe { text.get_unchecked((ep + 3)..p) };
-
-                    match u32::from_str_radix(hex, 16) {
-                        Ok(number) => {
-                            match char::try_from(number) {
-                                Ok(c) => {
-                                    write_char_to_vec(c, &mut v);
-                                    break (v, p + 1);
-                                }
-                                Err(_) => break (v, ep),
-                            }
-                        }
-                        Err(_) => break (v, ep),
-                    }
-                }
-            }
-            _ => unreachable!(),
+        if let Some(parsed) = parse_reference(text_bytes, p, mode) {
+            break parsed;
         }
 
         p += 1;
     };
 
-    p += 1;
+    let mut v = Vec::with_capacity(text_length);
 
-    step = 0;
+    v.extend_from_slice(&text_bytes[..p]);
 
-    for e in text_bytes[p..].iter().copied() {
-        match step {
-            0 => {
-                if e == b'&' {
-                    step = 1;
-                    ep = p;
-                }
-            }
-            1 => {
-                match e {
-                    b'#' => {
-                        step = 3;
-                    }
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => {
-                        step = 2;
-                    }
-                }
-            }
-            2 => {
-                if e == b';' {
-                    // named
-                    step = 0;
-
-                    let name = &text_bytes[(ep + 1)..p];
-
-                    if let Ok(index) =
-                        NAMED_ENTITIES.binary_search_by(|(t_name, _)| t_name.cmp(&name))
-                    {
-                        v.extend_from_slice(&text_bytes[start..ep]);
-                        start = p + 1;
-                        v.extend_from_slice(NAMED_ENTITIES[index].1.as_bytes());
-                    }
-                }
-            }
-            3 => {
-                match e {
-                    b'x' | b'X' => {
-                        step = 5;
-                    }
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => step = 4,
-                }
-            }
-            4 => {
-                if e == b';' {
-                    // numeric
-                    step = 0;
-
-                    let number = unsafe { text.get_unchecked((ep + 2)..p) };
-
-                    if let Ok(number) = number.parse::<u32>() {
-                        if let Ok(c) = char::try_from(number) {
-                            v.extend_from_slice(&text_bytes[start..ep]);
-                            start = p + 1;
-                            write_char_to_vec(c, &mut v);
-                        }
-                    }
-                }
+    reference.write_to_vec(&mut v);
+
+    decode_to_vec_with_mode(&text_bytes[end..], mode, &mut v);
+
+    Cow::from(v)
+}
+
+#[inline]
+fn decode_with_mode(text: &str, mode: ReferenceMode) -> Cow<'_, str> {
+    match decode_bytes_with_mode(text.as_bytes(), mode) {
+        Cow::Borrowed(_) => Cow::from(text),
+        Cow::Owned(v) => Cow::from(unsafe { String::from_utf8_unchecked(v) }),
+    }
+}
+
+fn decode_to_vec_with_mode<'a>(
+    text_bytes: &[u8],
+    mode: ReferenceMode,
+    output: &'a mut Vec<u8>,
+) -> &'a [u8] {
+    output.reserve(text_bytes.len());
+
+    let current_length = output.len();
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        match parse_reference(text_bytes, p, mode) {
+            Some((reference, end)) => {
+                output.extend_from_slice(&text_bytes[start..p]);
+                reference.write_to_vec(output);
+
+                p = end;
+                start = end;
             }
-            5 => {
-                match e {
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => step = 6,
-                }
+            None => p += 1,
+        }
+    }
+
+    output.extend_from_slice(&text_bytes[start..]);
+
+    &output[current_length..]
+}
+
+fn decode_to_slice_with_mode(
+    text: &str,
+    mode: ReferenceMode,
+    output: &mut [u8],
+) -> Result<usize, BufferTooSmall> {
+    let text_bytes = text.as_bytes();
+
+    let mut output = SliceWriter::new(output);
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        match parse_reference(text_bytes, p, mode) {
+            Some((reference, end)) => {
+                output.write_text(text, start, p)?;
+                output.write_escape(reference.to_bytes(&mut [0u8; 4]), p)?;
+
+                p = end;
+                start = end;
             }
-            6 => {
-                if e == b';' {
-                    // hex
-                    step = 0;
-
-                    let hex = unsafe { text.get_unchecked((ep + 3)..p) };
-
-                    if let Ok(number) = u32::from_str_radix(hex, 16) {
-                        if let Ok(c) = char::try_from(number) {
-                            v.extend_from_slice(&text_bytes[start..ep]);
-                            start = p + 1;
-                            write_char_to_vec(c, &mut v);
-                        }
-                    }
-                }
+            None => p += 1,
+        }
+    }
+
+    output.write_text(text, start, text_bytes.len())?;
+
+    Ok(output.written())
+}
+
+fn decode_to_sink_with_mode<W: EncodeSink>(
+    text: &str,
+    mode: ReferenceMode,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text_bytes = text.as_bytes();
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        match parse_reference(text_bytes, p, mode) {
+            Some((reference, end)) => {
+                let mut buffer = [0u8; 4];
+                let decoded = unsafe { from_utf8_unchecked(reference.to_bytes(&mut buffer)) };
+
+                output.write_str(unsafe { text.get_unchecked(start..p) })?;
+                output.write_str(decoded)?;
+
+                p = end;
+                start = end;
             }
-            _ => unreachable!(),
+            None => p += 1,
         }
+    }
 
-        p += 1;
+    output.write_str(unsafe { text.get_unchecked(start..) })
+}
+
+#[cfg(feature = "std")]
+fn decode_to_writer_with_mode<W: Write>(
+    text_bytes: &[u8],
+    mode: ReferenceMode,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    let mut p = 0;
+    let mut start = 0;
+
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        match parse_reference(text_bytes, p, mode) {
+            Some((reference, end)) => {
+                output.write_all(&text_bytes[start..p])?;
+                reference.write_to_writer(output)?;
+
+                p = end;
+                start = end;
+            }
+            None => p += 1,
+        }
     }
 
-    v.extend_from_slice(&text_bytes[start..p]);
+    output.write_all(&text_bytes[start..])
+}
 
-    Cow::from(unsafe { String::from_utf8_unchecked(v) })
+/// Decode html entities in a given string.
+///
+/// Like browsers do, numeric references to zero, surrogates and numbers beyond the Unicode range are decoded to U+FFFD REPLACEMENT CHARACTER, and those to U+0080 to U+009F are remapped through Windows-1252.
+#[inline]
+pub fn decode_html_entities<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    decode_with_mode(text.as_ref(), ReferenceMode::STRICT)
 }
 
 /// Decode html entities in a given string to a mutable `String` reference and return the decoded string slice.
+#[inline]
 pub fn decode_html_entities_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
     unsafe { from_utf8_unchecked(decode_html_entities_to_vec(text, output.as_mut_vec())) }
 }
 
 /// Decode html entities in a given string to a mutable `Vec<u8>` reference and return the decoded data slice.
+#[inline]
 pub fn decode_html_entities_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
-    let text = text.as_ref();
-    let text_bytes = text.as_bytes();
-    let text_length = text_bytes.len();
+    decode_to_vec_with_mode(text.as_ref().as_bytes(), ReferenceMode::STRICT, output)
+}
 
-    output.reserve(text_length);
+#[cfg(feature = "std")]
+/// Decode html entities in a given string to a writer.
+#[inline]
+pub fn decode_html_entities_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::STRICT, &mut IoSink(output))
+}
 
-    let current_length = output.len();
+/// Decode html entities in a given string to a `fmt::Write`.
+#[inline]
+pub fn decode_html_entities_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::STRICT, &mut FmtSink(output))
+}
 
+/// Decode html entities in a given string to a byte slice and return the length of the decoded data. A character reference is never split, so the decoding can be continued with the consumed length which `BufferTooSmall` reports.
+#[inline]
+pub fn decode_html_entities_to_slice<S: AsRef<str>>(
+    text: S,
+    output: &mut [u8],
+) -> Result<usize, BufferTooSmall> {
+    decode_to_slice_with_mode(text.as_ref(), ReferenceMode::STRICT, output)
+}
+
+/// Decode html entities in a given string to an `EncodeSink`.
+#[inline]
+pub fn decode_html_entities_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::STRICT, output)
+}
+
+/// Decode html entities in a given `String` in place, without allocating another buffer.
+///
+/// The decoded text is never longer than the original one, except that `&nGt;` and `&nLt;` are one byte shorter than what they are decoded to, in which case the text from the first of them on is decoded to another buffer.
+pub fn decode_html_entities_in_place(text: &mut String) {
+    let v = unsafe { text.as_mut_vec() };
+
+    let mut p = 0;
     let mut start = 0;
-    let mut end = 0;
-    let mut ep = 0;
+    let mut length = 0;
 
-    let mut step = 0;
+    while let Some(offset) = v[p..].iter().position(|&e| e == b'&') {
+        p += offset;
 
-    for e in text_bytes.iter().copied() {
-        match step {
-            0 => {
-                if e == b'&' {
-                    step = 1;
-                    ep = end;
-                }
-            }
-            1 => {
-                match e {
-                    b'#' => {
-                        step = 3;
-                    }
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => {
-                        step = 2;
-                    }
-                }
-            }
-            2 => {
-                if e == b';' {
-                    // named
-                    step = 0;
-
-                    let name = &text_bytes[(ep + 1)..end];
-
-                    if let Ok(index) =
-                        NAMED_ENTITIES.binary_search_by(|(t_name, _)| t_name.cmp(&name))
-                    {
-                        output.extend_from_slice(&text_bytes[start..ep]);
-                        start = end + 1;
-                        output.extend_from_slice(NAMED_ENTITIES[index].1.as_bytes());
-                    }
-                }
-            }
-            3 => {
-                match e {
-                    b'x' | b'X' => {
-                        step = 5;
-                    }
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => step = 4,
-                }
-            }
-            4 => {
-                if e == b';' {
-                    // numeric
-                    step = 0;
-
-                    let number = unsafe { text.get_unchecked((ep + 2)..end) };
-
-                    if let Ok(number) = number.parse::<u32>() {
-                        if let Ok(c) = char::try_from(number) {
-                            output.extend_from_slice(&text_bytes[start..ep]);
-                            start = end + 1;
-                            write_char_to_vec(c, output);
-                        }
-                    }
-                }
-            }
-            5 => {
-                match e {
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => step = 6,
-                }
-            }
-            6 => {
-                if e == b';' {
-                    // hex
-                    step = 0;
-
-                    let hex = unsafe { text.get_unchecked((ep + 3)..end) };
-
-                    if let Ok(number) = u32::from_str_radix(hex, 16) {
-                        if let Ok(c) = char::try_from(number) {
-                            output.extend_from_slice(&text_bytes[start..ep]);
-                            start = end + 1;
-                            write_char_to_vec(c, output);
-                        }
-                    }
+        match parse_reference(v, p, ReferenceMode::STRICT) {
+            Some((reference, end)) => {
+                v.copy_within(start..p, length);
+                length += p - start;
+
+                let mut buffer = [0u8; 4];
+                let bytes = reference.to_bytes(&mut buffer);
+
+                // only `&nGt;` and `&nLt;` are shorter than what they are decoded to
+                if length + bytes.len() > end {
+                    // decode the rest at once instead of shifting it for every one of them
+                    let rest = decode_html_entities(unsafe { from_utf8_unchecked(&v[p..]) });
+                    let rest = rest.into_owned();
+
+                    v.truncate(length);
+                    v.extend_from_slice(rest.as_bytes());
+
+                    return;
                 }
+
+                v[length..(length + bytes.len())].copy_from_slice(bytes);
+                length += bytes.len();
+
+                p = end;
+                start = end;
             }
-            _ => unreachable!(),
+            None => p += 1,
         }
-
-        end += 1;
     }
 
-    output.extend_from_slice(&text_bytes[start..end]);
+    let text_length = v.len();
 
-    &output[current_length..]
+    v.copy_within(start..text_length, length);
+    v.truncate(length + (text_length - start));
+}
+
+/// Decode html entities in a given string like browsers do in text content, also decoding the legacy named entities such as `&amp`, `&lt` and `&copy` and numeric references without the trailing semicolon.
+///
+/// The longest name which matches is used, so `&notin;` is decoded as a whole while only the `&not` of `&notit;` is decoded.
+///
+/// ```
+/// assert_eq!("\u{2209} \u{AC}it;", html_escape::decode_html_entities_legacy("&notin; &notit;"));
+/// ```
+#[inline]
+pub fn decode_html_entities_legacy<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    decode_with_mode(text.as_ref(), ReferenceMode::LEGACY)
+}
+
+/// Decode html entities in a given string like browsers do in text content to a mutable `String` reference and return the decoded string slice, also decoding the legacy named entities such as `&amp`, `&lt` and `&copy` and numeric references without the trailing semicolon.
+#[inline]
+pub fn decode_html_entities_legacy_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(decode_html_entities_legacy_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Decode html entities in a given string like browsers do in text content to a mutable `Vec<u8>` reference and return the decoded data slice, also decoding the legacy named entities such as `&amp`, `&lt` and `&copy` and numeric references without the trailing semicolon.
+#[inline]
+pub fn decode_html_entities_legacy_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    decode_to_vec_with_mode(text.as_ref().as_bytes(), ReferenceMode::LEGACY, output)
 }
 
 #[cfg(feature = "std")]
-/// Decode html entities in a given string to a writer.
-pub fn decode_html_entities_to_writer<S: AsRef<str>, W: Write>(
+/// Decode html entities in a given string like browsers do in text content to a writer, also decoding the legacy named entities such as `&amp`, `&lt` and `&copy` and numeric references without the trailing semicolon.
+#[inline]
+pub fn decode_html_entities_legacy_to_writer<S: AsRef<str>, W: Write>(
     text: S,
     output: &mut W,
 ) -> Result<(), io::Error> {
-    let text = text.as_ref();
-    let text_bytes = text.as_bytes();
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::LEGACY, &mut IoSink(output))
+}
 
-    let mut start = 0;
-    let mut end = 0;
-    let mut ep = 0;
+/// Decode html entities in a given string like browsers do in text content to a `fmt::Write`, also decoding the legacy named entities such as `&amp`, `&lt` and `&copy` and numeric references without the trailing semicolon.
+#[inline]
+pub fn decode_html_entities_legacy_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::LEGACY, &mut FmtSink(output))
+}
 
-    let mut step = 0;
+/// Decode html entities in a given string like browsers do in text content to a byte slice and return the length of the decoded data, also decoding the legacy named entities such as `&amp`, `&lt` and `&copy` and numeric references without the trailing semicolon. A character reference is never split, so the decoding can be continued with the consumed length which `BufferTooSmall` reports.
+#[inline]
+pub fn decode_html_entities_legacy_to_slice<S: AsRef<str>>(
+    text: S,
+    output: &mut [u8],
+) -> Result<usize, BufferTooSmall> {
+    decode_to_slice_with_mode(text.as_ref(), ReferenceMode::LEGACY, output)
+}
 
-    for e in text_bytes.iter().copied() {
-        match step {
-            0 => {
-                if e == b'&' {
-                    step = 1;
-                    ep = end;
-                }
-            }
-            1 => {
-                match e {
-                    b'#' => {
-                        step = 3;
-                    }
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => {
-                        step = 2;
-                    }
-                }
-            }
-            2 => {
-                if e == b';' {
-                    // named
-                    step = 0;
-
-                    let name = &text_bytes[(ep + 1)..end];
-
-                    if let Ok(index) =
-                        NAMED_ENTITIES.binary_search_by(|(t_name, _)| t_name.cmp(&name))
-                    {
-                        output.write_all(&text_bytes[start..ep])?;
-                        start = end + 1;
-                        output.write_all(NAMED_ENTITIES[index].1.as_bytes())?;
-                    }
-                }
-            }
-            3 => {
-                match e {
-                    b'x' | b'X' => {
-                        step = 5;
-                    }
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => step = 4,
-                }
-            }
-            4 => {
-                if e == b';' {
-                    // numeric
-                    step = 0;
-
-                    let number = unsafe { text.get_unchecked((ep + 2)..end) };
-
-                    if let Ok(number) = number.parse::<u32>() {
-                        if let Ok(c) = char::try_from(number) {
-                            output.write_all(&text_bytes[start..ep])?;
-                            start = end + 1;
-                            write_char_to_writer(c, output)?;
-                        }
-                    }
-                }
-            }
-            5 => {
-                match e {
-                    b';' => {
-                        // incorrect
-                        step = 0;
-                    }
-                    _ => step = 6,
-                }
-            }
-            6 => {
-                if e == b';' {
-                    // hex
-                    step = 0;
-
-                    let hex = unsafe { text.get_unchecked((ep + 3)..end) };
-
-                    if let Ok(number) = u32::from_str_radix(hex, 16) {
-                        if let Ok(c) = char::try_from(number) {
-                            output.write_all(&text_bytes[start..ep])?;
-                            start = end + 1;
-                            write_char_to_writer(c, output)?;
-                        }
-                    }
-                }
-            }
-            _ => unreachable!(),
-        }
+/// Decode html entities in a given string like browsers do in text content to an `EncodeSink`, also decoding the legacy named entities such as `&amp`, `&lt` and `&copy` and numeric references without the trailing semicolon.
+#[inline]
+pub fn decode_html_entities_legacy_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::LEGACY, output)
+}
+
+/// Decode html entities in a given string like browsers do in an attribute value, also decoding the legacy named entities such as `&amp` and numeric references without the trailing semicolon. A legacy named entity without the semicolon is kept if it is followed by `=` or an alphanumeric character, so `?a=1&copy=2` stays as it is.
+#[inline]
+pub fn decode_html_entities_in_attribute<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    decode_with_mode(text.as_ref(), ReferenceMode::ATTRIBUTE)
+}
 
-        end += 1;
+/// Decode html entities in a given string to a mutable `String` reference and return the decoded string slice like browsers do in an attribute value, also decoding the legacy named entities such as `&amp` and numeric references without the trailing semicolon. A legacy named entity without the semicolon is kept if it is followed by `=` or an alphanumeric character, so `?a=1&copy=2` stays as it is.
+#[inline]
+pub fn decode_html_entities_in_attribute_to_string<S: AsRef<str>>(
+    text: S,
+    output: &mut String,
+) -> &str {
+    unsafe {
+        from_utf8_unchecked(decode_html_entities_in_attribute_to_vec(text, output.as_mut_vec()))
     }
+}
+
+/// Decode html entities in a given string to a mutable `Vec<u8>` reference and return the decoded data slice like browsers do in an attribute value, also decoding the legacy named entities such as `&amp` and numeric references without the trailing semicolon. A legacy named entity without the semicolon is kept if it is followed by `=` or an alphanumeric character, so `?a=1&copy=2` stays as it is.
+#[inline]
+pub fn decode_html_entities_in_attribute_to_vec<S: AsRef<str>>(
+    text: S,
+    output: &mut Vec<u8>,
+) -> &[u8] {
+    decode_to_vec_with_mode(text.as_ref().as_bytes(), ReferenceMode::ATTRIBUTE, output)
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in a given string to a writer like browsers do in an attribute value, also decoding the legacy named entities such as `&amp` and numeric references without the trailing semicolon. A legacy named entity without the semicolon is kept if it is followed by `=` or an alphanumeric character, so `?a=1&copy=2` stays as it is.
+#[inline]
+pub fn decode_html_entities_in_attribute_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::ATTRIBUTE, &mut IoSink(output))
+}
+
+/// Decode html entities in a given string to a `fmt::Write` like browsers do in an attribute value, also decoding the legacy named entities such as `&amp` and numeric references without the trailing semicolon. A legacy named entity without the semicolon is kept if it is followed by `=` or an alphanumeric character, so `?a=1&copy=2` stays as it is.
+#[inline]
+pub fn decode_html_entities_in_attribute_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::ATTRIBUTE, &mut FmtSink(output))
+}
 
-    output.write_all(&text_bytes[start..end])
+/// Decode html entities in a given string to a byte slice and return the length of the decoded data like browsers do in an attribute value, also decoding the legacy named entities such as `&amp` and numeric references without the trailing semicolon. A legacy named entity without the semicolon is kept if it is followed by `=` or an alphanumeric character, so `?a=1&copy=2` stays as it is. A character reference is never split, so the decoding can be continued with the consumed length which `BufferTooSmall` reports.
+#[inline]
+pub fn decode_html_entities_in_attribute_to_slice<S: AsRef<str>>(
+    text: S,
+    output: &mut [u8],
+) -> Result<usize, BufferTooSmall> {
+    decode_to_slice_with_mode(text.as_ref(), ReferenceMode::ATTRIBUTE, output)
+}
+
+/// Decode html entities in a given string to an `EncodeSink` like browsers do in an attribute value, also decoding the legacy named entities such as `&amp` and numeric references without the trailing semicolon. A legacy named entity without the semicolon is kept if it is followed by `=` or an alphanumeric character, so `?a=1&copy=2` stays as it is.
+#[inline]
+pub fn decode_html_entities_in_attribute_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::ATTRIBUTE, output)
+}
+
+/// Decode only the numeric references in a given string, keeping named references such as `&amp;` as they are.
+#[inline]
+pub fn decode_numeric_entities<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    decode_with_mode(text.as_ref(), ReferenceMode::NUMERIC_ONLY)
+}
+
+/// Decode only the numeric references in a given string to a mutable `String` reference and return the decoded string slice, keeping named references such as `&amp;` as they are.
+#[inline]
+pub fn decode_numeric_entities_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(decode_numeric_entities_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Decode only the numeric references in a given string to a mutable `Vec<u8>` reference and return the decoded data slice, keeping named references such as `&amp;` as they are.
+#[inline]
+pub fn decode_numeric_entities_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    decode_to_vec_with_mode(text.as_ref().as_bytes(), ReferenceMode::NUMERIC_ONLY, output)
+}
+
+#[cfg(feature = "std")]
+/// Decode only the numeric references in a given string to a writer, keeping named references such as `&amp;` as they are.
+#[inline]
+pub fn decode_numeric_entities_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::NUMERIC_ONLY, &mut IoSink(output))
+}
+
+/// Decode only the numeric references in a given string to a `fmt::Write`, keeping named references such as `&amp;` as they are.
+#[inline]
+pub fn decode_numeric_entities_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::NUMERIC_ONLY, &mut FmtSink(output))
+}
+
+/// Decode only the numeric references in a given string to an `EncodeSink`, keeping named references such as `&amp;` as they are.
+#[inline]
+pub fn decode_numeric_entities_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::NUMERIC_ONLY, output)
+}
+
+/// Decode only the named references in a given string, keeping numeric references such as `&#x26;` as they are.
+#[inline]
+pub fn decode_named_entities<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    decode_with_mode(text.as_ref(), ReferenceMode::NAMED_ONLY)
+}
+
+/// Decode only the named references in a given string to a mutable `String` reference and return the decoded string slice, keeping numeric references such as `&#x26;` as they are.
+#[inline]
+pub fn decode_named_entities_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(decode_named_entities_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Decode only the named references in a given string to a mutable `Vec<u8>` reference and return the decoded data slice, keeping numeric references such as `&#x26;` as they are.
+#[inline]
+pub fn decode_named_entities_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    decode_to_vec_with_mode(text.as_ref().as_bytes(), ReferenceMode::NAMED_ONLY, output)
+}
+
+#[cfg(feature = "std")]
+/// Decode only the named references in a given string to a writer, keeping numeric references such as `&#x26;` as they are.
+#[inline]
+pub fn decode_named_entities_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::NAMED_ONLY, &mut IoSink(output))
+}
+
+/// Decode only the named references in a given string to a `fmt::Write`, keeping numeric references such as `&#x26;` as they are.
+#[inline]
+pub fn decode_named_entities_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::NAMED_ONLY, &mut FmtSink(output))
+}
+
+/// Decode only the named references in a given string to an `EncodeSink`, keeping numeric references such as `&#x26;` as they are.
+#[inline]
+pub fn decode_named_entities_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    decode_to_sink_with_mode(text.as_ref(), ReferenceMode::NAMED_ONLY, output)
+}
+
+/// Decode html entities in a given string, removing a leading BOM according to the given policy.
+#[inline]
+pub fn decode_html_entities_with_bom_policy<S: ?Sized + AsRef<str>>(
+    text: &S,
+    policy: BomPolicy,
+) -> Cow<'_, str> {
+    decode_html_entities(policy.strip(text.as_ref()))
+}
+
+/// Decode html entities in a given string to a mutable `String` reference and return the decoded string slice, removing a leading BOM according to the given policy.
+#[inline]
+pub fn decode_html_entities_with_bom_policy_to_string<S: AsRef<str>>(
+    text: S,
+    policy: BomPolicy,
+    output: &mut String,
+) -> &str {
+    decode_html_entities_to_string(policy.strip(text.as_ref()), output)
+}
+
+/// Decode html entities in a given string to a mutable `Vec<u8>` reference and return the decoded data slice, removing a leading BOM according to the given policy.
+#[inline]
+pub fn decode_html_entities_with_bom_policy_to_vec<S: AsRef<str>>(
+    text: S,
+    policy: BomPolicy,
+    output: &mut Vec<u8>,
+) -> &[u8] {
+    decode_html_entities_to_vec(policy.strip(text.as_ref()), output)
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in a given string to a writer, removing a leading BOM according to the given policy.
+#[inline]
+pub fn decode_html_entities_with_bom_policy_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    policy: BomPolicy,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_html_entities_to_writer(policy.strip(text.as_ref()), output)
+}
+
+/// Decode html entities in a given string to a `fmt::Write`, removing a leading BOM according to the given policy.
+#[inline]
+pub fn decode_html_entities_with_bom_policy_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    policy: BomPolicy,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    output.write_str(&decode_html_entities_with_bom_policy(text.as_ref(), policy))
 }
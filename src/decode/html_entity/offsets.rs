@@ -0,0 +1,201 @@
+use core::convert::Infallible;
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::reference::*;
+use super::DecodeOptions;
+
+/// A decoded character reference in an `OffsetMap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct DecodedReference {
+    output_start: usize,
+    output_length: usize,
+    input_start: usize,
+    input_end: usize,
+}
+
+/// A mapping from byte offsets in a decoded text to byte offsets in the text it is decoded from, returned by the `decode_html_entities_with_offsets*` functions. Only the decoded character references are stored, so the mapping is compact.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OffsetMap {
+    references: Vec<DecodedReference>,
+}
+
+impl OffsetMap {
+    /// The byte offset in the original text of the byte at `output_offset` in the decoded text. An offset inside what a character reference is decoded to is mapped to the `&` of the reference, and the length of the decoded text is mapped to the length of the original one. A reference removed by `ReplacementPolicy::Strip` is decoded to nothing, so the offset where it was is mapped past it, to what follows it in the original text.
+    pub fn input_offset(&self, output_offset: usize) -> usize {
+        // the last reference starting at or before the offset, which is after the removed ones starting at the same offset
+        let index =
+            self.references.partition_point(|reference| reference.output_start <= output_offset);
+
+        match index.checked_sub(1).map(|index| self.references[index]) {
+            Some(reference) => {
+                let output_end = reference.output_start + reference.output_length;
+
+                if output_offset < output_end {
+                    reference.input_start
+                } else {
+                    reference.input_end + (output_offset - output_end)
+                }
+            }
+            None => output_offset,
+        }
+    }
+
+    /// The number of decoded character references.
+    #[inline]
+    pub fn references(&self) -> usize {
+        self.references.len()
+    }
+}
+
+fn decode_with_offsets_impl<E, W: FnMut(&[u8]) -> Result<(), E>>(
+    text: &str,
+    mode: ReferenceMode,
+    mut write: W,
+) -> Result<OffsetMap, E> {
+    let text_bytes = text.as_bytes();
+
+    let mut map = OffsetMap::default();
+
+    let mut p = 0;
+    let mut start = 0;
+    let mut written = 0;
+
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        match parse_reference(text_bytes, p, mode) {
+            Some((reference, end)) => {
+                write(&text_bytes[start..p])?;
+                written += p - start;
+
+                let mut buffer = [0u8; 4];
+                let bytes = reference.to_bytes(&mut buffer);
+
+                write(bytes)?;
+
+                map.references.push(DecodedReference {
+                    output_start: written,
+                    output_length: bytes.len(),
+                    input_start: p,
+                    input_end: end,
+                });
+
+                written += bytes.len();
+
+                p = end;
+                start = end;
+            }
+            None => p += 1,
+        }
+    }
+
+    write(&text_bytes[start..])?;
+
+    Ok(map)
+}
+
+/// Decode html entities in a given string according to the given options, also returning a mapping from byte offsets in the decoded text to those in the given string, so positions found in the decoded text can be reported in the original one. The strictness and the output limit are not checked.
+///
+/// ```
+/// let (decoded, map) = html_escape::decode_html_entities_with_offsets(
+///     "&lt;a&gt; b",
+///     &html_escape::DecodeOptions::new(),
+/// );
+///
+/// assert_eq!("<a> b", decoded);
+/// assert_eq!(4, map.input_offset(1));
+/// assert_eq!(10, map.input_offset(4));
+/// ```
+pub fn decode_html_entities_with_offsets<'a, S: ?Sized + AsRef<str>>(
+    text: &'a S,
+    options: &DecodeOptions,
+) -> (Cow<'a, str>, OffsetMap) {
+    let text = text.as_ref();
+
+    if !text.as_bytes().contains(&b'&') {
+        return (Cow::from(text), OffsetMap::default());
+    }
+
+    let mut v = Vec::with_capacity(text.len());
+
+    let map = decode_with_offsets_impl(text, options.mode, |bytes| {
+        v.extend_from_slice(bytes);
+
+        Ok::<(), Infallible>(())
+    })
+    .unwrap();
+
+    if map.references.is_empty() {
+        (Cow::from(text), map)
+    } else {
+        (Cow::from(unsafe { String::from_utf8_unchecked(v) }), map)
+    }
+}
+
+/// Decode html entities in a given string to a mutable `String` reference according to the given options, returning the decoded string slice and a mapping from byte offsets in it to those in the given string. The strictness and the output limit are not checked.
+#[inline]
+pub fn decode_html_entities_with_offsets_to_string<'a, S: AsRef<str>>(
+    text: S,
+    options: &DecodeOptions,
+    output: &'a mut String,
+) -> (&'a str, OffsetMap) {
+    let (decoded, map) =
+        decode_html_entities_with_offsets_to_vec(text, options, unsafe { output.as_mut_vec() });
+
+    (unsafe { from_utf8_unchecked(decoded) }, map)
+}
+
+/// Decode html entities in a given string to a mutable `Vec<u8>` reference according to the given options, returning the decoded data slice and a mapping from byte offsets in it to those in the given string. The strictness and the output limit are not checked.
+pub fn decode_html_entities_with_offsets_to_vec<'a, S: AsRef<str>>(
+    text: S,
+    options: &DecodeOptions,
+    output: &'a mut Vec<u8>,
+) -> (&'a [u8], OffsetMap) {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    let map = decode_with_offsets_impl(text, options.mode, |bytes| {
+        output.extend_from_slice(bytes);
+
+        Ok::<(), Infallible>(())
+    })
+    .unwrap();
+
+    (&output[current_length..], map)
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in a given string to a writer according to the given options, returning a mapping from byte offsets in the written text to those in the given string. The strictness and the output limit are not checked.
+#[inline]
+pub fn decode_html_entities_with_offsets_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    options: &DecodeOptions,
+    output: &mut W,
+) -> Result<OffsetMap, io::Error> {
+    decode_with_offsets_impl(text.as_ref(), options.mode, |bytes| output.write_all(bytes))
+}
+
+/// Decode html entities in a given string to a `fmt::Write` according to the given options, returning a mapping from byte offsets in the written text to those in the given string. The strictness and the output limit are not checked.
+#[inline]
+pub fn decode_html_entities_with_offsets_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    options: &DecodeOptions,
+    output: &mut W,
+) -> Result<OffsetMap, fmt::Error> {
+    let (decoded, map) = decode_html_entities_with_offsets(text.as_ref(), options);
+
+    output.write_str(&decoded)?;
+
+    Ok(map)
+}
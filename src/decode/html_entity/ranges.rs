@@ -0,0 +1,75 @@
+use core::ops::Range;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::*;
+
+/// Decode html entities only within the given byte ranges of a given string according to the given options, such as the attribute values found by a tokenizer, appending each decoded range to a mutable `String` reference. Return the byte ranges of the decoded texts in `output`, in the order of `ranges`. The strictness and the output limit are not checked.
+///
+/// ```
+/// let text = r#"<a href="?a=1&amp;b=2" title="&lt;x&gt;">"#;
+///
+/// let mut output = String::new();
+///
+/// let ranges = html_escape::decode_html_entities_in_ranges_to_string(
+///     text,
+///     vec![9..21, 30..39],
+///     &html_escape::DecodeOptions::new().attribute(true),
+///     &mut output,
+/// );
+///
+/// assert_eq!("?a=1&b=2", &output[ranges[0].clone()]);
+/// assert_eq!("<x>", &output[ranges[1].clone()]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if a range is out of bounds or does not lie on character boundaries.
+#[inline]
+pub fn decode_html_entities_in_ranges_to_string<
+    S: AsRef<str>,
+    I: IntoIterator<Item = Range<usize>>,
+>(
+    text: S,
+    ranges: I,
+    options: &DecodeOptions,
+    output: &mut String,
+) -> Vec<Range<usize>> {
+    let text = text.as_ref();
+
+    // check the boundaries, which the decoding of the bytes does not
+    let ranges = ranges.into_iter().inspect(|range| {
+        let _ = &text[range.clone()];
+    });
+
+    decode_html_entities_in_ranges_to_vec(text, ranges, options, unsafe { output.as_mut_vec() })
+}
+
+/// Decode html entities only within the given byte ranges of a given string according to the given options, such as the attribute values found by a tokenizer, appending each decoded range to a mutable `Vec<u8>` reference. Return the byte ranges of the decoded data in `output`, in the order of `ranges`. The strictness and the output limit are not checked.
+///
+/// # Panics
+///
+/// Panics if a range is out of bounds.
+pub fn decode_html_entities_in_ranges_to_vec<
+    S: AsRef<str>,
+    I: IntoIterator<Item = Range<usize>>,
+>(
+    text: S,
+    ranges: I,
+    options: &DecodeOptions,
+    output: &mut Vec<u8>,
+) -> Vec<Range<usize>> {
+    let text_bytes = text.as_ref().as_bytes();
+
+    ranges
+        .into_iter()
+        .map(|range| {
+            let start = output.len();
+
+            decode_to_vec_with_mode(&text_bytes[range], options.mode, output);
+
+            start..output.len()
+        })
+        .collect()
+}
@@ -0,0 +1,472 @@
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use alloc::vec::Vec;
+
+use super::tables::*;
+use super::{DecodeErrorKind, NamedEntitySet, ReplacementPolicy};
+use crate::functions::*;
+
+/// A decoded character reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Reference {
+    Named(&'static str),
+    Numeric(char),
+    /// An invalid reference which is stripped.
+    Removed,
+}
+
+impl Reference {
+    /// The UTF-8 bytes of the decoded reference. Numeric references are encoded into `buffer`.
+    #[inline]
+    pub(crate) fn to_bytes(self, buffer: &mut [u8; 4]) -> &[u8] {
+        match self {
+            Reference::Named(s) => s.as_bytes(),
+            Reference::Numeric(c) => c.encode_utf8(buffer).as_bytes(),
+            Reference::Removed => b"",
+        }
+    }
+
+    #[inline]
+    pub(crate) fn write_to_vec(self, output: &mut Vec<u8>) {
+        match self {
+            Reference::Named(s) => output.extend_from_slice(s.as_bytes()),
+            Reference::Numeric(c) => write_char_to_vec(c, output),
+            Reference::Removed => (),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    pub(crate) fn write_to_writer<W: Write>(self, output: &mut W) -> Result<(), io::Error> {
+        match self {
+            Reference::Named(s) => output.write_all(s.as_bytes()),
+            Reference::Numeric(c) => write_char_to_writer(c, output),
+            Reference::Removed => Ok(()),
+        }
+    }
+}
+
+/// How numeric references are decoded, which is set by a `NumericErrorPolicy` or a `NumericPolicy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum NumericHandling {
+    /// `NumericErrorPolicy::Replace` and `NumericPolicy::Html`.
+    Html,
+    /// `NumericErrorPolicy::Keep`.
+    Keep,
+    /// `NumericPolicy::XmlStrict`.
+    Xml,
+    /// `NumericPolicy::Passthrough`.
+    Passthrough,
+}
+
+/// How character references are recognized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct ReferenceMode {
+    /// Whether the legacy named entities and numeric references are recognized without the trailing semicolon.
+    pub(crate) legacy: bool,
+    /// Whether the references are in an attribute value, where a legacy named entity without the trailing semicolon is not recognized if it is followed by `=` or an alphanumeric character.
+    pub(crate) attribute: bool,
+    /// Which named entities are recognized.
+    pub(crate) named: NamedEntitySet,
+    /// How numeric references are decoded.
+    pub(crate) numeric: NumericHandling,
+    /// Whether numeric references are recognized.
+    pub(crate) numeric_references: bool,
+    /// Whether a named reference followed by `;` falls back to a case-insensitive match, such as `&NBSP;`.
+    pub(crate) case_insensitive: bool,
+    /// How unknown named references followed by `;` and numeric references to invalid code points are handled instead of the default way, if set.
+    pub(crate) replacement: Option<ReplacementPolicy>,
+    /// The maximum length of a name which is scanned after `&`.
+    pub(crate) max_name_length: usize,
+    /// The maximum number of digits which are scanned in a numeric reference.
+    pub(crate) max_numeric_length: usize,
+}
+
+/// The default maximum length of the name of a named reference. The longest name, `CounterClockwiseContourIntegral`, has 31 characters.
+pub(crate) const MAX_NAME_LENGTH: usize = 32;
+
+/// The default maximum number of digits of a numeric reference, which leaves plenty of room for leading zeros, since the largest code point has 7 decimal digits.
+pub(crate) const MAX_NUMERIC_LENGTH: usize = 32;
+
+impl ReferenceMode {
+    pub(crate) const STRICT: ReferenceMode = ReferenceMode {
+        legacy: false,
+        attribute: false,
+        named: NamedEntitySet::Html5,
+        numeric: NumericHandling::Html,
+        numeric_references: true,
+        case_insensitive: false,
+        replacement: None,
+        max_name_length: MAX_NAME_LENGTH,
+        max_numeric_length: MAX_NUMERIC_LENGTH,
+    };
+    pub(crate) const LEGACY: ReferenceMode = ReferenceMode {
+        legacy: true,
+        attribute: false,
+        named: NamedEntitySet::Html5,
+        numeric: NumericHandling::Html,
+        numeric_references: true,
+        case_insensitive: false,
+        replacement: None,
+        max_name_length: MAX_NAME_LENGTH,
+        max_numeric_length: MAX_NUMERIC_LENGTH,
+    };
+    pub(crate) const ATTRIBUTE: ReferenceMode = ReferenceMode {
+        legacy: true,
+        attribute: true,
+        named: NamedEntitySet::Html5,
+        numeric: NumericHandling::Html,
+        numeric_references: true,
+        case_insensitive: false,
+        replacement: None,
+        max_name_length: MAX_NAME_LENGTH,
+        max_numeric_length: MAX_NUMERIC_LENGTH,
+    };
+    pub(crate) const NAMED_ONLY: ReferenceMode = ReferenceMode {
+        numeric_references: false,
+        ..ReferenceMode::STRICT
+    };
+    pub(crate) const NUMERIC_ONLY: ReferenceMode = ReferenceMode {
+        named: NamedEntitySet::None,
+        ..ReferenceMode::STRICT
+    };
+}
+
+/// The table of a set of named entities. With the `html4-entities` feature, the HTML 4.01 one is also used for `NamedEntitySet::Html5`, so the HTML5 one is left out of the binary.
+#[inline]
+fn named_entity_table(set: NamedEntitySet) -> Option<&'static [(&'static [u8], &'static str)]> {
+    match set {
+        #[cfg(not(feature = "html4-entities"))]
+        NamedEntitySet::Html5 => Some(&NAMED_ENTITIES),
+        #[cfg(feature = "html4-entities")]
+        NamedEntitySet::Html5 => Some(&HTML4_NAMED_ENTITIES),
+        NamedEntitySet::Html4 => Some(&HTML4_NAMED_ENTITIES),
+        _ => None,
+    }
+}
+
+/// The table of the named entities of a set which are only found by a case-insensitive match, keyed by their lowercase spellings.
+#[inline]
+fn case_folded_named_entity_table(
+    set: NamedEntitySet,
+) -> Option<&'static [(&'static [u8], &'static str)]> {
+    match set {
+        #[cfg(not(feature = "html4-entities"))]
+        NamedEntitySet::Html5 => Some(&CASE_FOLDED_NAMED_ENTITIES),
+        _ => None,
+    }
+}
+
+#[inline]
+pub(crate) fn find_named_entity(name: &[u8], set: NamedEntitySet) -> Option<&'static str> {
+    match set {
+        NamedEntitySet::Html5 | NamedEntitySet::Html4 => {
+            let table = named_entity_table(set)?;
+
+            table.binary_search_by(|(t_name, _)| t_name.cmp(&name)).ok().map(|index| table[index].1)
+        }
+        NamedEntitySet::Xml => match name {
+            b"amp" => Some("&"),
+            b"lt" => Some("<"),
+            b"gt" => Some(">"),
+            b"quot" => Some("\""),
+            b"apos" => Some("'"),
+            _ => None,
+        },
+        NamedEntitySet::None => None,
+    }
+}
+
+/// Find a named entity followed by `;`, falling back to a case-insensitive match if the mode allows it. The all-lowercase spelling is preferred, so `&DAGGER;` is `&dagger;`.
+#[inline]
+fn find_terminated_named_entity(name: &[u8], mode: ReferenceMode) -> Option<&'static str> {
+    let found = find_named_entity(name, mode.named);
+
+    if found.is_some() || !mode.case_insensitive || name.len() > 31 {
+        return found;
+    }
+
+    let mut buffer = [0u8; 31];
+    let lowercase = &mut buffer[..name.len()];
+
+    lowercase.copy_from_slice(name);
+    lowercase.make_ascii_lowercase();
+
+    let lowercase = &*lowercase;
+
+    find_named_entity(lowercase, mode.named).or_else(|| {
+        let table = case_folded_named_entity_table(mode.named)?;
+
+        table
+            .binary_search_by(|(t_name, _)| t_name.cmp(&lowercase))
+            .ok()
+            .map(|index| table[index].1)
+    })
+}
+
+/// Parse the character reference which starts with the `&` at `text_bytes[p]`. Return the decoded reference and the index right after it.
+pub(crate) fn parse_reference(
+    text_bytes: &[u8],
+    p: usize,
+    mode: ReferenceMode,
+) -> Option<(Reference, usize)> {
+    let body = &text_bytes[(p + 1)..];
+
+    match body.first() {
+        Some(b'#') if mode.numeric_references => {
+            parse_numeric(body, mode).map(|(reference, length)| (reference, p + 1 + length))
+        }
+        Some(e) if e.is_ascii_alphanumeric() => match parse_named(body, mode) {
+            Some((s, length)) => Some((Reference::Named(s), p + 1 + length)),
+            None => parse_unknown_named(body, mode)
+                .map(|(reference, length)| (reference, p + 1 + length)),
+        },
+        _ => None,
+    }
+}
+
+/// What an invalid reference is replaced with according to `policy`, or `None` if it is kept.
+#[inline]
+fn replace_invalid(policy: Option<ReplacementPolicy>) -> Option<Reference> {
+    match policy {
+        Some(ReplacementPolicy::ReplaceWithFFFD) => Some(Reference::Numeric('\u{FFFD}')),
+        Some(ReplacementPolicy::Strip) => Some(Reference::Removed),
+        _ => None,
+    }
+}
+
+/// Parse an unknown named reference followed by `;` without the leading `&` according to the replacement policy. Return the replacement and the length of the reference.
+#[inline]
+fn parse_unknown_named(body: &[u8], mode: ReferenceMode) -> Option<(Reference, usize)> {
+    if mode.named == NamedEntitySet::None {
+        return None;
+    }
+
+    let length = scan_name(body, mode.max_name_length)?;
+
+    if body.get(length) == Some(&b';') {
+        replace_invalid(mode.replacement).map(|reference| (reference, length + 1))
+    } else {
+        None
+    }
+}
+
+/// Check whether the `&` at `text_bytes[p]` starts a reference which the replacement policy applies to. Return how it is invalid and the index right after it.
+pub(crate) fn find_invalid_reference(
+    text_bytes: &[u8],
+    p: usize,
+    mode: ReferenceMode,
+) -> Option<(DecodeErrorKind, usize)> {
+    let mode = ReferenceMode {
+        replacement: Some(ReplacementPolicy::Strip),
+        ..mode
+    };
+
+    match parse_reference(text_bytes, p, mode) {
+        Some((Reference::Removed, end)) => {
+            if text_bytes[p + 1] == b'#' {
+                Some((DecodeErrorKind::InvalidCodePoint, end))
+            } else {
+                Some((DecodeErrorKind::UnknownNamedReference, end))
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Scan the alphanumeric name at the beginning of `body`, but not beyond `max_length` bytes, so a long run of letters after `&` costs no more than a short one. Return the length of the name, or `None` if it is longer than `max_length`.
+#[inline]
+fn scan_name(body: &[u8], max_length: usize) -> Option<usize> {
+    let length = body
+        .iter()
+        .take(max_length.saturating_add(1))
+        .take_while(|e| e.is_ascii_alphanumeric())
+        .count();
+
+    if length > max_length {
+        None
+    } else {
+        Some(length)
+    }
+}
+
+/// Parse a named reference without the leading `&`. Return the decoded string and the length of the reference.
+fn parse_named(body: &[u8], mode: ReferenceMode) -> Option<(&'static str, usize)> {
+    let (length, terminated) = match scan_name(body, mode.max_name_length) {
+        Some(length) => (length, body.get(length) == Some(&b';')),
+        None => (mode.max_name_length, false),
+    };
+
+    let name = &body[..length];
+
+    if terminated {
+        if let Some(s) = find_terminated_named_entity(name, mode) {
+            return Some((s, length + 1));
+        }
+    }
+
+    if mode.legacy {
+        // the longest legacy names, such as `frac12`, have 6 characters
+        for length in (2..=length.min(6)).rev() {
+            let name = &name[..length];
+
+            if LEGACY_NAMED_ENTITIES.binary_search(&name).is_ok() {
+                if mode.attribute {
+                    if let Some(&e) = body.get(length) {
+                        if e == b'=' || e.is_ascii_alphanumeric() {
+                            return None;
+                        }
+                    }
+                }
+
+                return find_named_entity(name, mode.named).map(|s| (s, length));
+            }
+        }
+    }
+
+    None
+}
+
+/// Check whether the `&` at `text_bytes[p]` starts a name followed by `;` which is not a recognized named entity, such as `&myapp-icon;`. The name may also contain `-`, `.` and `_`. Return the name and the index right after the `;`.
+pub(crate) fn find_unknown_named_reference(
+    text_bytes: &[u8],
+    p: usize,
+    mode: ReferenceMode,
+) -> Option<(&str, usize)> {
+    let body = &text_bytes[(p + 1)..];
+
+    let length = body
+        .iter()
+        .take(mode.max_name_length.saturating_add(1))
+        .take_while(|&&e| e.is_ascii_alphanumeric() || e == b'-' || e == b'.' || e == b'_')
+        .count();
+
+    if length == 0 || length > mode.max_name_length || body.get(length) != Some(&b';') {
+        return None;
+    }
+
+    let name = &body[..length];
+
+    if find_terminated_named_entity(name, mode).is_some() {
+        None
+    } else {
+        Some((unsafe { core::str::from_utf8_unchecked(name) }, p + 2 + length))
+    }
+}
+
+/// Scan a numeric reference without the leading `&`. Leading zeros are skipped without being counted, and the significant digits are scanned up to one more than `max_length`. Return the number, which saturates beyond the Unicode range, the length of the `#` or `#x` prefix, the length of the reference without the trailing semicolon and the number of significant digits.
+#[inline]
+pub(crate) fn scan_numeric(body: &[u8], max_length: usize) -> (u32, usize, usize, usize) {
+    let (radix, offset) = match body.get(1) {
+        Some(b'x') | Some(b'X') => (16, 2),
+        _ => (10, 1),
+    };
+
+    let mut length = offset + body[offset..].iter().take_while(|&&e| e == b'0').count();
+
+    let mut number: u32 = 0;
+    let start = length;
+    let end = max_length.saturating_add(start + 1);
+
+    while let Some(d) =
+        body.get(length).filter(|_| length < end).and_then(|&e| (e as char).to_digit(radix))
+    {
+        // saturate beyond the Unicode range so that long digit sequences cannot overflow
+        number = (number * radix + d).min(0x11_0000);
+        length += 1;
+    }
+
+    (number, offset, length, length - start)
+}
+
+/// Parse a numeric reference without the leading `&`. Return the decoded reference and the length of the reference.
+fn parse_numeric(body: &[u8], mode: ReferenceMode) -> Option<(Reference, usize)> {
+    let (number, offset, mut length, digits) = scan_numeric(body, mode.max_numeric_length);
+
+    if length == offset || digits > mode.max_numeric_length {
+        return None;
+    }
+
+    if body.get(length) == Some(&b';') {
+        length += 1;
+    } else if !mode.legacy {
+        return None;
+    }
+
+    match number_to_char(number, mode.numeric) {
+        Some(c) => Some((Reference::Numeric(c), length)),
+        None if mode.replacement.is_none() && mode.numeric == NumericHandling::Html => {
+            Some((Reference::Numeric('\u{FFFD}'), length))
+        }
+        None => replace_invalid(mode.replacement).map(|reference| (reference, length)),
+    }
+}
+
+/// Check whether the `&` at `text_bytes[p]` starts a malformed character reference. Return how it is malformed and the index right after it.
+pub(crate) fn find_malformed_reference(
+    text_bytes: &[u8],
+    p: usize,
+    mode: ReferenceMode,
+) -> Option<(DecodeErrorKind, usize)> {
+    let body = &text_bytes[(p + 1)..];
+
+    match body.first() {
+        Some(b'#') if mode.numeric_references => {
+            let (number, offset, length, digits) = scan_numeric(body, mode.max_numeric_length);
+
+            if length == offset {
+                Some((DecodeErrorKind::MissingDigits, p + 1 + offset))
+            } else if digits > mode.max_numeric_length {
+                Some((DecodeErrorKind::TooManyDigits, p + 1 + length))
+            } else if body.get(length) != Some(&b';') {
+                Some((DecodeErrorKind::MissingSemicolon, p + 1 + length))
+            } else if number_to_char(number, mode.numeric).is_none() {
+                Some((DecodeErrorKind::InvalidCodePoint, p + 2 + length))
+            } else {
+                None
+            }
+        }
+        Some(e) if e.is_ascii_alphanumeric() => {
+            let terminated = scan_name(body, mode.max_name_length);
+
+            if let Some(length) = terminated.filter(|&length| body.get(length) == Some(&b';')) {
+                if find_terminated_named_entity(&body[..length], mode).is_none() {
+                    Some((DecodeErrorKind::UnknownNamedReference, p + 2 + length))
+                } else {
+                    None
+                }
+            } else {
+                let mode = ReferenceMode {
+                    legacy: true,
+                    attribute: false,
+                    ..mode
+                };
+
+                parse_named(body, mode)
+                    .map(|(_, length)| (DecodeErrorKind::MissingSemicolon, p + 1 + length))
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Map the number of a numeric reference to a character, or `None` if it refers to an invalid code point. Like browsers do, the C1 controls which have a Windows-1252 counterpart are remapped with `NumericHandling::Html`.
+#[inline]
+fn number_to_char(number: u32, numeric: NumericHandling) -> Option<char> {
+    match numeric {
+        NumericHandling::Html => match number {
+            0x80..=0x9F => match WINDOWS_1252_C1[(number - 0x80) as usize] {
+                '\0' => Some(char::from(number as u8)),
+                c => Some(c),
+            },
+            _ => core::char::from_u32(number).filter(|&c| c != '\0'),
+        },
+        NumericHandling::Keep => core::char::from_u32(number).filter(|&c| c != '\0'),
+        // the `Char` production of XML 1.0
+        NumericHandling::Xml => core::char::from_u32(number).filter(|&c| {
+            matches!(c, '\t' | '\n' | '\r' | '\u{20}'..='\u{D7FF}' | '\u{E000}'..='\u{FFFD}' | '\u{10000}'..='\u{10FFFF}')
+        }),
+        NumericHandling::Passthrough => core::char::from_u32(number),
+    }
+}
@@ -0,0 +1,89 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::reference::*;
+use super::*;
+
+/// Decode html entities in a given string again and again until nothing more is decoded, or at most `max_iterations` times, to repair text which is encoded more than once, such as `&amp;amp;eacute;`.
+///
+/// ```
+/// assert_eq!("caf\u{E9}", html_escape::decode_html_entities_repeatedly("caf&amp;amp;eacute;", 8));
+/// assert_eq!("caf&amp;eacute;", html_escape::decode_html_entities_repeatedly("caf&amp;amp;eacute;", 1));
+/// ```
+pub fn decode_html_entities_repeatedly<S: ?Sized + AsRef<str>>(
+    text: &S,
+    max_iterations: usize,
+) -> Cow<'_, str> {
+    let mut decoded = Cow::from(text.as_ref());
+
+    for _ in 0..max_iterations {
+        let next = match decode_with_mode(&decoded, ReferenceMode::STRICT) {
+            Cow::Borrowed(_) => break,
+            Cow::Owned(s) => s,
+        };
+
+        decoded = Cow::from(next);
+    }
+
+    decoded
+}
+
+/// Decode html entities in a given string again and again until nothing more is decoded, or at most `max_iterations` times, to a mutable `String` reference and return the decoded string slice.
+#[inline]
+pub fn decode_html_entities_repeatedly_to_string<S: AsRef<str>>(
+    text: S,
+    max_iterations: usize,
+    output: &mut String,
+) -> &str {
+    unsafe {
+        from_utf8_unchecked(decode_html_entities_repeatedly_to_vec(
+            text,
+            max_iterations,
+            output.as_mut_vec(),
+        ))
+    }
+}
+
+/// Decode html entities in a given string again and again until nothing more is decoded, or at most `max_iterations` times, to a mutable `Vec<u8>` reference and return the decoded data slice.
+#[inline]
+pub fn decode_html_entities_repeatedly_to_vec<S: AsRef<str>>(
+    text: S,
+    max_iterations: usize,
+    output: &mut Vec<u8>,
+) -> &[u8] {
+    let current_length = output.len();
+
+    output.extend_from_slice(
+        decode_html_entities_repeatedly(text.as_ref(), max_iterations).as_bytes(),
+    );
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in a given string again and again until nothing more is decoded, or at most `max_iterations` times, to a writer.
+#[inline]
+pub fn decode_html_entities_repeatedly_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    max_iterations: usize,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    output.write_all(decode_html_entities_repeatedly(text.as_ref(), max_iterations).as_bytes())
+}
+
+/// Decode html entities in a given string again and again until nothing more is decoded, or at most `max_iterations` times, to a `fmt::Write`.
+#[inline]
+pub fn decode_html_entities_repeatedly_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    max_iterations: usize,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    output.write_str(&decode_html_entities_repeatedly(text.as_ref(), max_iterations))
+}
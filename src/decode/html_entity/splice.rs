@@ -0,0 +1,208 @@
+use core::fmt;
+use core::ops::Range;
+use core::str::from_utf8_unchecked;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::decoder::is_reference_prefix;
+use super::reference::*;
+use super::*;
+use crate::functions::*;
+
+/// The start and the end of the character reference which contains `text_bytes[p - 1]` and `text_bytes[p]`, if any.
+#[inline]
+fn find_reference_across(
+    text_bytes: &[u8],
+    p: usize,
+    mode: ReferenceMode,
+) -> Option<(usize, usize)> {
+    let amp = text_bytes[..p].iter().rposition(|&e| e == b'&')?;
+
+    match parse_reference(text_bytes, amp, mode) {
+        Some((_, end)) if end > p => Some((amp, end)),
+        _ => None,
+    }
+}
+
+/// Whether `text_bytes` ends with an incomplete character reference which the following text could complete, such as `&am`.
+#[inline]
+fn ends_with_reference_prefix(text_bytes: &[u8], mode: ReferenceMode) -> bool {
+    match text_bytes.iter().rposition(|&e| e == b'&') {
+        Some(amp) => is_reference_prefix(&text_bytes[amp..], &mode),
+        None => false,
+    }
+}
+
+/// Decode the region `range` of a given html text according to the given options, pass the decoded text to `transform`, and return the text with the region replaced by what `transform` returns, encoded with `encode_quoted_attribute`, so it is safe in text and in quoted attribute values. The rest of the text is kept as it is. The strictness and the output limit are not checked.
+///
+/// A character reference which crosses a boundary of the region is included in the region, and if the text before the region ends with something like `&am`, the first character after it is encoded as a numeric reference, so no reference is created or broken at the boundaries.
+///
+/// ```
+/// let text = "<p title=\"Tom &amp; Jerry\">Tom &amp; Jerry</p>";
+///
+/// assert_eq!(
+///     "<p title=\"Tom &amp; Jerry\">Tom &amp; Spike</p>",
+///     html_escape::splice_decoded_region(
+///         text,
+///         27..42,
+///         &html_escape::DecodeOptions::new(),
+///         |decoded| decoded.replace("Jerry", "Spike"),
+///     )
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds or does not lie on character boundaries.
+#[inline]
+pub fn splice_decoded_region<S: AsRef<str>, F: FnOnce(&str) -> R, R: AsRef<str>>(
+    text: S,
+    range: Range<usize>,
+    options: &DecodeOptions,
+    transform: F,
+) -> String {
+    let mut s = String::new();
+
+    splice_decoded_region_to_string(text, range, options, transform, &mut s);
+
+    s
+}
+
+/// Decode the region `range` of a given html text according to the given options, pass the decoded text to `transform`, and write the text with the region replaced by what `transform` returns, encoded with `encode_quoted_attribute`, to a mutable `String` reference. Return the written string slice. The strictness and the output limit are not checked.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds or does not lie on character boundaries.
+#[inline]
+pub fn splice_decoded_region_to_string<'a, S: AsRef<str>, F: FnOnce(&str) -> R, R: AsRef<str>>(
+    text: S,
+    range: Range<usize>,
+    options: &DecodeOptions,
+    transform: F,
+    output: &'a mut String,
+) -> &'a str {
+    unsafe {
+        from_utf8_unchecked(splice_decoded_region_to_vec(
+            text,
+            range,
+            options,
+            transform,
+            output.as_mut_vec(),
+        ))
+    }
+}
+
+/// Decode the region `range` of a given html text according to the given options, pass the decoded text to `transform`, and write the text with the region replaced by what `transform` returns, encoded with `encode_quoted_attribute`, to a mutable `Vec<u8>` reference. Return the written data slice. The strictness and the output limit are not checked.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds or does not lie on character boundaries.
+pub fn splice_decoded_region_to_vec<'a, S: AsRef<str>, F: FnOnce(&str) -> R, R: AsRef<str>>(
+    text: S,
+    range: Range<usize>,
+    options: &DecodeOptions,
+    transform: F,
+    output: &'a mut Vec<u8>,
+) -> &'a [u8] {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let mode = options.mode;
+
+    let _ = &text[range.clone()];
+
+    let Range {
+        mut start,
+        mut end,
+    } = range;
+
+    if let Some((reference_start, reference_end)) = find_reference_across(text_bytes, start, mode) {
+        start = reference_start;
+        end = end.max(reference_end);
+    }
+
+    if let Some((reference_start, reference_end)) = find_reference_across(text_bytes, end, mode) {
+        start = start.min(reference_start);
+        end = reference_end;
+    }
+
+    let mut decoded = Vec::with_capacity(end - start);
+
+    decode_to_vec_with_mode(&text_bytes[start..end], mode, &mut decoded);
+
+    let transformed = transform(unsafe { from_utf8_unchecked(&decoded) });
+
+    let current_length = output.len();
+
+    output.reserve(text_bytes.len());
+    output.extend_from_slice(&text_bytes[..start]);
+
+    let middle = output.len();
+
+    crate::encode_quoted_attribute_to_vec(transformed.as_ref(), output);
+    output.extend_from_slice(&text_bytes[end..]);
+
+    if let Some(&e) = output.get(middle) {
+        if (e.is_ascii_alphanumeric() || e == b'#' || e == b';')
+            && ends_with_reference_prefix(&text_bytes[..start], mode)
+        {
+            let mut escaped = Vec::with_capacity(6);
+
+            write_hex_to_vec(e, &mut escaped);
+
+            output.splice(middle..=middle, escaped);
+        }
+    }
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Decode the region `range` of a given html text according to the given options, pass the decoded text to `transform`, and write the text with the region replaced by what `transform` returns, encoded with `encode_quoted_attribute`, to a writer. The strictness and the output limit are not checked.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds or does not lie on character boundaries.
+#[inline]
+pub fn splice_decoded_region_to_writer<
+    S: AsRef<str>,
+    W: Write,
+    F: FnOnce(&str) -> R,
+    R: AsRef<str>,
+>(
+    text: S,
+    range: Range<usize>,
+    options: &DecodeOptions,
+    transform: F,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    let mut v = Vec::new();
+
+    splice_decoded_region_to_vec(text, range, options, transform, &mut v);
+
+    output.write_all(&v)
+}
+
+/// Decode the region `range` of a given html text according to the given options, pass the decoded text to `transform`, and write the text with the region replaced by what `transform` returns, encoded with `encode_quoted_attribute`, to a `fmt::Write`. The strictness and the output limit are not checked.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds or does not lie on character boundaries.
+#[inline]
+pub fn splice_decoded_region_to_fmt_writer<
+    S: AsRef<str>,
+    W: fmt::Write,
+    F: FnOnce(&str) -> R,
+    R: AsRef<str>,
+>(
+    text: S,
+    range: Range<usize>,
+    options: &DecodeOptions,
+    transform: F,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    output.write_str(&splice_decoded_region(text, range, options, transform))
+}
@@ -0,0 +1,198 @@
+use core::convert::Infallible;
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::reference::*;
+use super::*;
+
+/// Statistics about the character references in a decoded text, returned by the `decode_html_entities_with_stats*` functions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DecodeStats {
+    named: usize,
+    numeric: usize,
+    replaced: usize,
+    removed: usize,
+    malformed: Vec<DecodeError>,
+}
+
+impl DecodeStats {
+    /// The number of named references which are decoded.
+    #[inline]
+    pub fn named_references(&self) -> usize {
+        self.named
+    }
+
+    /// The number of numeric references which are decoded.
+    #[inline]
+    pub fn numeric_references(&self) -> usize {
+        self.numeric
+    }
+
+    /// The number of unknown named references, such as `&foo;`, which are replaced with U+FFFD REPLACEMENT CHARACTER by `ReplacementPolicy::ReplaceWithFFFD`.
+    #[inline]
+    pub fn replaced_references(&self) -> usize {
+        self.replaced
+    }
+
+    /// The number of invalid references, named or numeric, which are removed by `ReplacementPolicy::Strip`.
+    #[inline]
+    pub fn removed_references(&self) -> usize {
+        self.removed
+    }
+
+    /// The malformed references in the order they appear, with their positions. A malformed reference may still be decoded, such as `&amp` without the semicolon if it is not required.
+    #[inline]
+    pub fn malformed_references(&self) -> &[DecodeError] {
+        self.malformed.as_slice()
+    }
+}
+
+fn decode_with_stats_impl<E, W: FnMut(&[u8]) -> Result<(), E>>(
+    text: &str,
+    mode: ReferenceMode,
+    mut write: W,
+) -> Result<DecodeStats, E> {
+    let text_bytes = text.as_bytes();
+
+    let mut stats = DecodeStats::default();
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        if let Err(error) = check_reference_at(text, p, mode) {
+            stats.malformed.push(error);
+        }
+
+        match parse_reference(text_bytes, p, mode) {
+            Some((reference, end)) => {
+                match reference {
+                    Reference::Named(_) => stats.named += 1,
+                    Reference::Numeric(_) if text_bytes[p + 1] == b'#' => stats.numeric += 1,
+                    // an unknown name replaced with U+FFFD
+                    Reference::Numeric(_) => stats.replaced += 1,
+                    Reference::Removed => stats.removed += 1,
+                }
+
+                write(&text_bytes[start..p])?;
+                write(reference.to_bytes(&mut [0u8; 4]))?;
+
+                p = end;
+                start = end;
+            }
+            None => p += 1,
+        }
+    }
+
+    write(&text_bytes[start..])?;
+
+    Ok(stats)
+}
+
+/// Decode html entities in a given string according to the given options, also returning how many named and numeric references are decoded and where the malformed ones are. Malformed references are reported instead of failing even in strict mode, and the output limit is not checked.
+///
+/// ```
+/// let (decoded, stats) = html_escape::decode_html_entities_with_stats(
+///     "&lt;a&gt; &#x26; &foo;",
+///     &html_escape::DecodeOptions::new(),
+/// );
+///
+/// assert_eq!("<a> & &foo;", decoded);
+/// assert_eq!(2, stats.named_references());
+/// assert_eq!(1, stats.numeric_references());
+/// assert_eq!(17, stats.malformed_references()[0].offset());
+/// ```
+pub fn decode_html_entities_with_stats<'a, S: ?Sized + AsRef<str>>(
+    text: &'a S,
+    options: &DecodeOptions,
+) -> (Cow<'a, str>, DecodeStats) {
+    let text = text.as_ref();
+
+    if !text.as_bytes().contains(&b'&') {
+        return (Cow::from(text), DecodeStats::default());
+    }
+
+    let mut v = Vec::with_capacity(text.len());
+
+    let stats = decode_with_stats_impl(text, options.mode, |bytes| {
+        v.extend_from_slice(bytes);
+
+        Ok::<(), Infallible>(())
+    })
+    .unwrap();
+
+    if stats.named == 0 && stats.numeric == 0 && stats.replaced == 0 && stats.removed == 0 {
+        (Cow::from(text), stats)
+    } else {
+        (Cow::from(unsafe { String::from_utf8_unchecked(v) }), stats)
+    }
+}
+
+/// Decode html entities in a given string to a mutable `String` reference according to the given options, returning the decoded string slice and how many named and numeric references are decoded and where the malformed ones are. Malformed references are reported instead of failing even in strict mode, and the output limit is not checked.
+#[inline]
+pub fn decode_html_entities_with_stats_to_string<'a, S: AsRef<str>>(
+    text: S,
+    options: &DecodeOptions,
+    output: &'a mut String,
+) -> (&'a str, DecodeStats) {
+    let (decoded, stats) =
+        decode_html_entities_with_stats_to_vec(text, options, unsafe { output.as_mut_vec() });
+
+    (unsafe { from_utf8_unchecked(decoded) }, stats)
+}
+
+/// Decode html entities in a given string to a mutable `Vec<u8>` reference according to the given options, returning the decoded data slice and how many named and numeric references are decoded and where the malformed ones are. Malformed references are reported instead of failing even in strict mode, and the output limit is not checked.
+pub fn decode_html_entities_with_stats_to_vec<'a, S: AsRef<str>>(
+    text: S,
+    options: &DecodeOptions,
+    output: &'a mut Vec<u8>,
+) -> (&'a [u8], DecodeStats) {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    let stats = decode_with_stats_impl(text, options.mode, |bytes| {
+        output.extend_from_slice(bytes);
+
+        Ok::<(), Infallible>(())
+    })
+    .unwrap();
+
+    (&output[current_length..], stats)
+}
+
+#[cfg(feature = "std")]
+/// Decode html entities in a given string to a writer according to the given options, returning how many named and numeric references are decoded and where the malformed ones are. Malformed references are reported instead of failing even in strict mode, and the output limit is not checked.
+#[inline]
+pub fn decode_html_entities_with_stats_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    options: &DecodeOptions,
+    output: &mut W,
+) -> Result<DecodeStats, io::Error> {
+    decode_with_stats_impl(text.as_ref(), options.mode, |bytes| output.write_all(bytes))
+}
+
+/// Decode html entities in a given string to a `fmt::Write` according to the given options, returning how many named and numeric references are decoded and where the malformed ones are. Malformed references are reported instead of failing even in strict mode, and the output limit is not checked.
+#[inline]
+pub fn decode_html_entities_with_stats_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    options: &DecodeOptions,
+    output: &mut W,
+) -> Result<DecodeStats, fmt::Error> {
+    let (decoded, stats) = decode_html_entities_with_stats(text.as_ref(), options);
+
+    output.write_str(&decoded)?;
+
+    Ok(stats)
+}
@@ -0,0 +1,56 @@
+use core::char::decode_utf16;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::*;
+use crate::LoneSurrogatePolicy;
+
+/// Decode html entities in text given as UTF-16, such as a string from a Windows API or JavaScript, like `decode_html_entities` does, handling lone surrogates according to the given policy. Surrogate pairs are kept as they are.
+///
+/// ```
+/// use html_escape::LoneSurrogatePolicy;
+///
+/// let text: Vec<u16> = "&lt;&#x1F600;&gt;".encode_utf16().chain([0xD800]).collect();
+///
+/// assert_eq!(
+///     "<\u{1F600}>\u{FFFD}".encode_utf16().collect::<Vec<u16>>(),
+///     html_escape::decode_html_entities_utf16(&text, LoneSurrogatePolicy::Replace)
+/// );
+/// ```
+#[inline]
+pub fn decode_html_entities_utf16(text: &[u16], policy: LoneSurrogatePolicy) -> Vec<u16> {
+    let mut v = Vec::with_capacity(text.len());
+
+    decode_html_entities_utf16_to_vec(text, policy, &mut v);
+
+    v
+}
+
+/// Decode html entities in text given as UTF-16 to a mutable `Vec<u16>` reference and return the decoded data slice, handling lone surrogates according to the given policy.
+pub fn decode_html_entities_utf16_to_vec<'a>(
+    text: &[u16],
+    policy: LoneSurrogatePolicy,
+    output: &'a mut Vec<u16>,
+) -> &'a [u16] {
+    let current_length = output.len();
+
+    // a character reference is ASCII, so it never spans a lone surrogate
+    let mut segment = String::with_capacity(text.len());
+
+    for c in decode_utf16(text.iter().copied()) {
+        match c {
+            Ok(c) => segment.push(c),
+            Err(error) => {
+                output.extend(decode_html_entities(&segment).encode_utf16());
+                output.push(policy.handle(error.unpaired_surrogate()));
+
+                segment.clear();
+            }
+        }
+    }
+
+    output.extend(decode_html_entities(&segment).encode_utf16());
+
+    &output[current_length..]
+}
@@ -0,0 +1,97 @@
+use alloc::string::String;
+
+use super::{
+    find_html_entities, find_html_entities_with, DecodeOptions, EntityMatch, HtmlEntities,
+};
+
+/// The callbacks of the `visit_html_entities*` functions, which are called in the order of the text.
+pub trait DecodeVisitor {
+    /// Called with a piece of the text which contains no character references. The pieces are borrowed from the text, and empty pieces are skipped.
+    fn on_text(&mut self, text: &str);
+
+    /// Called with a character reference. By default, what it is decoded to is passed to `on_text`.
+    #[inline]
+    fn on_entity(&mut self, entity: EntityMatch<'_>) {
+        self.on_text(entity.decoded());
+    }
+}
+
+impl DecodeVisitor for String {
+    #[inline]
+    fn on_text(&mut self, text: &str) {
+        self.push_str(text);
+    }
+}
+
+fn visit_entities<V: ?Sized + DecodeVisitor>(
+    text: &str,
+    entities: HtmlEntities<'_>,
+    visitor: &mut V,
+) {
+    let mut start = 0;
+
+    for entity in entities {
+        let range = entity.range();
+
+        if range.start > start {
+            visitor.on_text(&text[start..range.start]);
+        }
+
+        start = range.end;
+
+        visitor.on_entity(entity);
+    }
+
+    if start < text.len() {
+        visitor.on_text(&text[start..]);
+    }
+}
+
+/// Scan a given string for the character references which `decode_html_entities` decodes, calling `visitor` with the text between them and with the references, without allocating.
+///
+/// ```
+/// use html_escape::{DecodeVisitor, EntityMatch};
+///
+/// struct Counter {
+///     text: usize,
+///     entities: usize,
+/// }
+///
+/// impl DecodeVisitor for Counter {
+///     fn on_text(&mut self, text: &str) {
+///         self.text += text.len();
+///     }
+///
+///     fn on_entity(&mut self, _entity: EntityMatch<'_>) {
+///         self.entities += 1;
+///     }
+/// }
+///
+/// let mut counter = Counter { text: 0, entities: 0 };
+///
+/// html_escape::visit_html_entities("a &lt; b &#x26; c", &mut counter);
+///
+/// assert_eq!(7, counter.text);
+/// assert_eq!(2, counter.entities);
+/// ```
+#[inline]
+pub fn visit_html_entities<S: ?Sized + AsRef<str>, V: ?Sized + DecodeVisitor>(
+    text: &S,
+    visitor: &mut V,
+) {
+    let text = text.as_ref();
+
+    visit_entities(text, find_html_entities(text), visitor)
+}
+
+/// Scan a given string for the character references which `decode_html_entities_with` decodes according to the given options, calling `visitor` with the text between them and with the references, without allocating. Malformed references are passed to `on_text` as they are even in strict mode.
+#[inline]
+pub fn visit_html_entities_with<S: ?Sized + AsRef<str>, V: ?Sized + DecodeVisitor>(
+    text: &S,
+    options: &DecodeOptions,
+    visitor: &mut V,
+) {
+    let text = text.as_ref();
+
+    visit_entities(text, find_html_entities_with(text, options), visitor)
+}
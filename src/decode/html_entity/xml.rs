@@ -0,0 +1,57 @@
+use core::fmt;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::*;
+
+const XML_STRICT: DecodeOptions = DecodeOptions::XML.strict(true);
+
+/// Decode XML character data or an attribute value, which may only contain the five predefined entities `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&apos;` and numeric references, failing on the first malformed reference or HTML-only name such as `&nbsp;`. Use `decode_html_entities_with` with `DecodeOptions::XML` to keep them instead.
+#[inline]
+pub fn decode_xml_entities<S: ?Sized + AsRef<str>>(text: &S) -> Result<Cow<'_, str>, DecodeError> {
+    decode_html_entities_with(text, &XML_STRICT)
+}
+
+/// Decode XML character data or an attribute value to a mutable `String` reference and return the decoded string slice, failing on the first malformed reference or HTML-only name such as `&nbsp;`. Nothing is written on failure.
+#[inline]
+pub fn decode_xml_entities_to_string<S: AsRef<str>>(
+    text: S,
+    output: &mut String,
+) -> Result<&str, DecodeError> {
+    decode_html_entities_with_to_string(text, &XML_STRICT, output)
+}
+
+/// Decode XML character data or an attribute value to a mutable `Vec<u8>` reference and return the decoded data slice, failing on the first malformed reference or HTML-only name such as `&nbsp;`. Nothing is written on failure.
+#[inline]
+pub fn decode_xml_entities_to_vec<S: AsRef<str>>(
+    text: S,
+    output: &mut Vec<u8>,
+) -> Result<&[u8], DecodeError> {
+    decode_html_entities_with_to_vec(text, &XML_STRICT, output)
+}
+
+#[cfg(feature = "std")]
+/// Decode XML character data or an attribute value to a writer, failing on the first malformed reference or HTML-only name such as `&nbsp;`. The `DecodeError` is wrapped in an `io::Error` of the kind `InvalidData`, and the text before the malformed reference has already been written on failure.
+#[inline]
+pub fn decode_xml_entities_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    decode_html_entities_with_to_writer(text, &XML_STRICT, output)
+}
+
+/// Decode XML character data or an attribute value to a `fmt::Write`, failing on the first malformed reference or HTML-only name such as `&nbsp;`. The text is decoded before it is written, so nothing is written if the `DecodeError` is returned, and the result of writing is returned otherwise.
+#[inline]
+pub fn decode_xml_entities_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<Result<(), fmt::Error>, DecodeError> {
+    let decoded = decode_xml_entities(text.as_ref())?;
+
+    Ok(output.write_str(&decoded))
+}
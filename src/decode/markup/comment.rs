@@ -0,0 +1,152 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+#[inline]
+fn unescape_comment(text_bytes: &[u8]) -> Option<(&'static [u8], usize)> {
+    if text_bytes.starts_with(b"&amp;") {
+        Some((b"&", 5))
+    } else if text_bytes.starts_with(b"&gt;") {
+        Some((b">", 4))
+    } else if text_bytes.starts_with(b"&#x2D;") {
+        Some((b"-", 6))
+    } else {
+        None
+    }
+}
+
+/// Decode text which is encoded by `encode_comment`.
+///
+/// The following substrings are unescaped:
+///
+/// * `&amp;` => `&`
+/// * `&gt;` => `>`
+/// * `&#x2D;` => `-`
+pub fn decode_comment<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+
+    match text_bytes
+        .iter()
+        .enumerate()
+        .find(|&(p, &e)| e == b'&' && unescape_comment(&text_bytes[p..]).is_some())
+    {
+        Some((p, _)) => {
+            let mut v = Vec::with_capacity(text_bytes.len());
+
+            v.extend_from_slice(&text_bytes[..p]);
+
+            decode_comment_to_vec(unsafe { from_utf8_unchecked(&text_bytes[p..]) }, &mut v);
+
+            Cow::from(unsafe { String::from_utf8_unchecked(v) })
+        }
+        None => Cow::from(text),
+    }
+}
+
+/// Decode text which is encoded by `encode_comment` to a mutable `String` reference and return the decoded string slice.
+///
+/// The following substrings are unescaped:
+///
+/// * `&amp;` => `&`
+/// * `&gt;` => `>`
+/// * `&#x2D;` => `-`
+#[inline]
+pub fn decode_comment_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(decode_comment_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Decode text which is encoded by `encode_comment` to a mutable `Vec<u8>` reference and return the decoded data slice.
+///
+/// The following substrings are unescaped:
+///
+/// * `&amp;` => `&`
+/// * `&gt;` => `>`
+/// * `&#x2D;` => `-`
+pub fn decode_comment_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+
+    output.reserve(text_bytes.len());
+
+    let current_length = output.len();
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        match unescape_comment(&text_bytes[p..]) {
+            Some((unescaped, length)) => {
+                output.extend_from_slice(&text_bytes[start..p]);
+                output.extend_from_slice(unescaped);
+
+                p += length;
+                start = p;
+            }
+            None => p += 1,
+        }
+    }
+
+    output.extend_from_slice(&text_bytes[start..]);
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Decode text which is encoded by `encode_comment` to a writer.
+///
+/// The following substrings are unescaped:
+///
+/// * `&amp;` => `&`
+/// * `&gt;` => `>`
+/// * `&#x2D;` => `-`
+pub fn decode_comment_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while let Some(offset) = text_bytes[p..].iter().position(|&e| e == b'&') {
+        p += offset;
+
+        match unescape_comment(&text_bytes[p..]) {
+            Some((unescaped, length)) => {
+                output.write_all(&text_bytes[start..p])?;
+                output.write_all(unescaped)?;
+
+                p += length;
+                start = p;
+            }
+            None => p += 1,
+        }
+    }
+
+    output.write_all(&text_bytes[start..])
+}
+
+/// Decode text which is encoded by `encode_comment` to a `fmt::Write`.
+///
+/// The following substrings are unescaped:
+///
+/// * `&amp;` => `&`
+/// * `&gt;` => `>`
+/// * `&#x2D;` => `-`
+#[inline]
+pub fn decode_comment_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    output.write_str(&decode_comment(text.as_ref()))
+}
@@ -1,5 +1,7 @@
 mod element;
 mod html_entity;
+mod markup;
 
 pub use element::*;
 pub use html_entity::*;
+pub use markup::*;
@@ -0,0 +1,122 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+#[inline]
+fn is_forbidden_in_attribute_name(c: char) -> bool {
+    match c {
+        '\u{0}'..='\u{20}' | '"' | '\'' | '/' | '<' | '=' | '>' | '\u{7F}'..='\u{9F}' => true,
+        '\u{FDD0}'..='\u{FDEF}' => true,
+        _ => (c as u32) & 0xFFFE == 0xFFFE,
+    }
+}
+
+/// Check whether a string can be used as an attribute name.
+///
+/// An attribute name must consist of one or more characters other than controls, whitespace, `"`, `'`, `/`, `<`, `=`, `>` and noncharacters.
+#[inline]
+pub fn is_valid_attribute_name<S: AsRef<str>>(name: S) -> bool {
+    let name = name.as_ref();
+
+    !name.is_empty() && !name.chars().any(is_forbidden_in_attribute_name)
+}
+
+/// Sanitize a string to be used as an attribute name, such as the key of a `data-*` attribute.
+///
+/// Controls, whitespace, `"`, `'`, `/`, `<`, `=`, `>` and noncharacters are replaced with `_`. The result is a valid attribute name unless it is empty.
+pub fn sanitize_attribute_name<S: ?Sized + AsRef<str>>(name: &S) -> Cow<'_, str> {
+    let name = name.as_ref();
+
+    match name.char_indices().find(|&(_, c)| is_forbidden_in_attribute_name(c)) {
+        Some((p, _)) => {
+            let mut v = Vec::with_capacity(name.len());
+
+            v.extend_from_slice(&name.as_bytes()[..p]);
+
+            sanitize_attribute_name_to_vec(unsafe { name.get_unchecked(p..) }, &mut v);
+
+            Cow::from(unsafe { String::from_utf8_unchecked(v) })
+        }
+        None => Cow::from(name),
+    }
+}
+
+/// Write a string sanitized to be used as an attribute name to a mutable `String` reference and return the sanitized string slice.
+///
+/// Controls, whitespace, `"`, `'`, `/`, `<`, `=`, `>` and noncharacters are replaced with `_`. The result is a valid attribute name unless it is empty.
+#[inline]
+pub fn sanitize_attribute_name_to_string<S: AsRef<str>>(name: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(sanitize_attribute_name_to_vec(name, output.as_mut_vec())) }
+}
+
+/// Write a string sanitized to be used as an attribute name to a mutable `Vec<u8>` reference and return the sanitized data slice.
+///
+/// Controls, whitespace, `"`, `'`, `/`, `<`, `=`, `>` and noncharacters are replaced with `_`. The result is a valid attribute name unless it is empty.
+#[inline]
+pub fn sanitize_attribute_name_to_vec<S: AsRef<str>>(name: S, output: &mut Vec<u8>) -> &[u8] {
+    let name = name.as_ref();
+
+    output.reserve(name.len());
+
+    let current_length = output.len();
+
+    sanitize_attribute_name_to_sink(name, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write a string sanitized to be used as an attribute name to a writer.
+///
+/// Controls, whitespace, `"`, `'`, `/`, `<`, `=`, `>` and noncharacters are replaced with `_`. The result is a valid attribute name unless it is empty.
+#[inline]
+pub fn sanitize_attribute_name_to_writer<S: AsRef<str>, W: Write>(
+    name: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    sanitize_attribute_name_to_sink(name, &mut IoSink(output))
+}
+
+/// Write a string sanitized to be used as an attribute name to a `fmt::Write`.
+///
+/// Controls, whitespace, `"`, `'`, `/`, `<`, `=`, `>` and noncharacters are replaced with `_`. The result is a valid attribute name unless it is empty.
+#[inline]
+pub fn sanitize_attribute_name_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    name: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    sanitize_attribute_name_to_sink(name, &mut FmtSink(output))
+}
+
+/// Write a string sanitized to be used as an attribute name to an `EncodeSink`.
+///
+/// Controls, whitespace, `"`, `'`, `/`, `<`, `=`, `>` and noncharacters are replaced with `_`. The result is a valid attribute name unless it is empty.
+pub fn sanitize_attribute_name_to_sink<S: AsRef<str>, W: EncodeSink>(
+    name: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let name = name.as_ref();
+
+    let mut start = 0;
+
+    for (p, c) in name.char_indices() {
+        if is_forbidden_in_attribute_name(c) {
+            output.write_str(unsafe { name.get_unchecked(start..p) })?;
+            start = p + c.len_utf8();
+            output.write_str("_")?;
+        }
+    }
+
+    output.write_str(unsafe { name.get_unchecked(start..) })
+}
@@ -1,10 +1,10 @@
 macro_rules! encode_impl {
-    ($l:expr; $(#[$attr: meta])* $parse_macro:ident; $(#[$encode_attr: meta])* $encode_name: ident; $(#[$encode_to_string_attr: meta])* $encode_to_string_name: ident; $(#[$encode_to_vec_attr: meta])* $encode_to_vec_name: ident; $(#[$encode_to_writer_attr: meta])* $encode_to_writer_name: ident $(;)*) => {
+    (@impl $l:expr, $escape_line_separators:expr; $(#[$attr: meta])* $parse_macro:ident; $(#[$encode_attr: meta])* $encode_name: ident; $(#[$encode_to_string_attr: meta])* $encode_to_string_name: ident; $(#[$encode_to_vec_attr: meta])* $encode_to_vec_name: ident; $(#[$encode_to_writer_attr: meta])* $encode_to_writer_name: ident; $(#[$encode_to_fmt_writer_attr: meta])* $encode_to_fmt_writer_name: ident; $(#[$encode_to_sink_attr: meta])* $encode_to_sink_name: ident $(;)*) => {
         $(#[$encode_attr])*
         ///
         $(#[$attr])*
         #[inline]
-        pub fn $encode_name<S: ?Sized + AsRef<str>>(text: &S) -> Cow<str> {
+        pub fn $encode_name<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
             let text = text.as_ref();
             let text_bytes = text.as_bytes();
             let text_length = text_bytes.len();
@@ -16,9 +16,34 @@ macro_rules! encode_impl {
 
             let (mut v, mut start) = loop {
                 if p == text_length {
+                    // the text may be followed by `>`, `/` or whitespace, which would end the tag at its end
+                    if step == $l + 1 {
+                        let mut v = Vec::with_capacity(text_length + 1);
+
+                        v.extend_from_slice(&text_bytes[..(p - $l)]);
+                        v.push(b'\\');
+                        v.extend_from_slice(&text_bytes[(p - $l)..]);
+
+                        return Cow::from(unsafe { String::from_utf8_unchecked(v) });
+                    }
+
                     return Cow::from(text);
                 }
 
+                if $escape_line_separators {
+                    if let Some(escaped) = crate::functions::escape_line_separator(text_bytes, p) {
+                        let mut v = Vec::with_capacity(text_length + 3);
+
+                        v.extend_from_slice(&text_bytes[..p]);
+                        v.extend_from_slice(escaped);
+
+                        p += 2;
+                        step = 0;
+
+                        break (v, p + 1);
+                    }
+                }
+
                 e = text_bytes[p];
 
                 $parse_macro!(
@@ -28,6 +53,7 @@ macro_rules! encode_impl {
                         let mut v = Vec::with_capacity(text_length + 1);
 
                         v.extend_from_slice(&text_bytes[..(p - $l)]);
+                        v.push(b'\\');
 
                         break (v, p - $l);
                     },
@@ -35,6 +61,7 @@ macro_rules! encode_impl {
                         let mut v = Vec::with_capacity(text_length + 1);
 
                         v.extend_from_slice(&text_bytes[..p]);
+                        v.push(b'\\');
 
                         break (v, p);
                     }
@@ -43,11 +70,24 @@ macro_rules! encode_impl {
                 p += 1;
             };
 
-            v.push(b'\\');
-
             p += 1;
 
-            for e in text_bytes[p..].iter().copied() {
+            while p < text_length {
+                if $escape_line_separators {
+                    if let Some(escaped) = crate::functions::escape_line_separator(text_bytes, p) {
+                        v.extend_from_slice(&text_bytes[start..p]);
+                        v.extend_from_slice(escaped);
+
+                        p += 3;
+                        start = p;
+                        step = 0;
+
+                        continue;
+                    }
+                }
+
+                e = text_bytes[p];
+
                 $parse_macro!(
                     e,
                     step,
@@ -66,6 +106,12 @@ macro_rules! encode_impl {
                 p += 1;
             }
 
+            if step == $l + 1 {
+                v.extend_from_slice(&text_bytes[start..(p - $l)]);
+                start = p - $l;
+                v.push(b'\\');
+            }
+
             v.extend_from_slice(&text_bytes[start..p]);
 
             Cow::from(unsafe { String::from_utf8_unchecked(v) })
@@ -92,31 +138,7 @@ macro_rules! encode_impl {
 
             let current_length = output.len();
 
-            let mut start = 0;
-            let mut end = 0;
-
-            let mut step = 0;
-
-            for e in text_bytes.iter().copied() {
-                $parse_macro!(
-                    e,
-                    step,
-                    {
-                        output.extend_from_slice(&text_bytes[start..(end - $l)]);
-                        start = end - $l;
-                        output.push(b'\\');
-                    },
-                    {
-                        output.extend_from_slice(&text_bytes[start..end]);
-                        start = end;
-                        output.push(b'\\');
-                    }
-                );
-
-                end += 1;
-            }
-
-            output.extend_from_slice(&text_bytes[start..end]);
+            $encode_to_sink_name(text, output).unwrap_or_else(|error| match error {});
 
             &output[current_length..]
         }
@@ -127,34 +149,84 @@ macro_rules! encode_impl {
         $(#[$attr])*
         #[inline]
         pub fn $encode_to_writer_name<S: AsRef<str>, W: Write>(text: S, output: &mut W) -> Result<(), io::Error> {
+            $encode_to_sink_name(text, &mut IoSink(output))
+        }
+
+        $(#[$encode_to_fmt_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_fmt_writer_name<S: AsRef<str>, W: fmt::Write>(text: S, output: &mut W) -> Result<(), fmt::Error> {
+            $encode_to_sink_name(text, &mut FmtSink(output))
+        }
+
+        $(#[$encode_to_sink_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_sink_name<S: AsRef<str>, W: EncodeSink>(text: S, output: &mut W) -> Result<(), W::Error> {
             let text = text.as_ref();
             let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
 
             let mut start = 0;
             let mut end = 0;
+            let mut e;
 
             let mut step = 0;
 
-            for e in text_bytes.iter().copied() {
+            while end < text_length {
+                if $escape_line_separators {
+                    if let Some(escaped) = crate::functions::escape_line_separator(text_bytes, end) {
+                        output.write_str(unsafe { text.get_unchecked(start..end) })?;
+                        output.write_str(unsafe { from_utf8_unchecked(escaped) })?;
+
+                        end += 3;
+                        start = end;
+                        step = 0;
+
+                        continue;
+                    }
+                }
+
+                e = text_bytes[end];
+
                 $parse_macro!(
                     e,
                     step,
                     {
-                        output.write_all(&text_bytes[start..(end - $l)])?;
+                        output.write_str(unsafe { text.get_unchecked(start..(end - $l)) })?;
                         start = end - $l;
-                        output.write_all(b"\\")?;
+                        output.write_str("\\")?;
                     },
                     {
-                        output.write_all(&text_bytes[start..end])?;
+                        output.write_str(unsafe { text.get_unchecked(start..end) })?;
                         start = end;
-                        output.write_all(b"\\")?;
+                        output.write_str("\\")?;
                     }
                 );
 
                 end += 1;
             }
 
-            output.write_all(&text_bytes[start..end])
+            if step == $l + 1 {
+                output.write_str(unsafe { text.get_unchecked(start..(end - $l)) })?;
+                start = end - $l;
+                output.write_str("\\")?;
+            }
+
+            output.write_str(unsafe { text.get_unchecked(start..end) })
+        }
+    };
+    ($l:expr, escape_line_separators; $($rest:tt)*) => {
+        encode_impl! {
+            @impl $l, true;
+            $($rest)*
+        }
+    };
+    ($l:expr; $($rest:tt)*) => {
+        encode_impl! {
+            @impl $l, false;
+            $($rest)*
         }
     };
 }
@@ -0,0 +1,170 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+use crate::functions::*;
+
+#[inline]
+fn escape_json(text_bytes: &[u8], p: usize) -> Option<(&'static [u8], usize)> {
+    match text_bytes[p] {
+        b'<' if is_tag_name_at(&text_bytes[(p + 1)..], b"/script")
+            || text_bytes[(p + 1)..].starts_with(b"!--") =>
+        {
+            Some((b"\\u003C", 1))
+        }
+        0xE2 if text_bytes[(p + 1)..].starts_with(b"\x80\xA8") => Some((b"\\u2028", 3)),
+        0xE2 if text_bytes[(p + 1)..].starts_with(b"\x80\xA9") => Some((b"\\u2029", 3)),
+        _ => None,
+    }
+}
+
+/// Encode JSON text used in the `<script>` element, such as `<script type="application/json">` and JSON-LD blocks. The JSON text itself must be valid.
+///
+/// The following substrings and characters are escaped:
+///
+/// * `</script` => `\u003C/script` (case-insensitive)
+/// * `<!--` => `\u003C!--`
+/// * `U+2028` => `\u2028`
+/// * `U+2029` => `\u2029`
+pub fn encode_json_for_script<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+
+    let (first, width) = loop {
+        if p == text_length {
+            return Cow::from(text);
+        }
+
+        if let Some(escaped) = escape_json(text_bytes, p) {
+            break escaped;
+        }
+
+        p += 1;
+    };
+
+    let mut v = Vec::with_capacity(text_length + 5);
+
+    v.extend_from_slice(&text_bytes[..p]);
+    v.extend_from_slice(first);
+
+    encode_json_for_script_to_vec(
+        unsafe { from_utf8_unchecked(&text_bytes[(p + width)..]) },
+        &mut v,
+    );
+
+    Cow::from(unsafe { String::from_utf8_unchecked(v) })
+}
+
+/// Write JSON text used in the `<script>` element to a mutable `String` reference and return the encoded string slice. The JSON text itself must be valid.
+///
+/// The following substrings and characters are escaped:
+///
+/// * `</script` => `\u003C/script` (case-insensitive)
+/// * `<!--` => `\u003C!--`
+/// * `U+2028` => `\u2028`
+/// * `U+2029` => `\u2029`
+#[inline]
+pub fn encode_json_for_script_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(encode_json_for_script_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Write JSON text used in the `<script>` element to a mutable `Vec<u8>` reference and return the encoded data slice. The JSON text itself must be valid.
+///
+/// The following substrings and characters are escaped:
+///
+/// * `</script` => `\u003C/script` (case-insensitive)
+/// * `<!--` => `\u003C!--`
+/// * `U+2028` => `\u2028`
+/// * `U+2029` => `\u2029`
+#[inline]
+pub fn encode_json_for_script_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    encode_json_for_script_to_sink(text, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write JSON text used in the `<script>` element to a writer. The JSON text itself must be valid.
+///
+/// The following substrings and characters are escaped:
+///
+/// * `</script` => `\u003C/script` (case-insensitive)
+/// * `<!--` => `\u003C!--`
+/// * `U+2028` => `\u2028`
+/// * `U+2029` => `\u2029`
+#[inline]
+pub fn encode_json_for_script_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_json_for_script_to_sink(text, &mut IoSink(output))
+}
+
+/// Write JSON text used in the `<script>` element to a `fmt::Write`. The JSON text itself must be valid.
+///
+/// The following substrings and characters are escaped:
+///
+/// * `</script` => `\u003C/script` (case-insensitive)
+/// * `<!--` => `\u003C!--`
+/// * `U+2028` => `\u2028`
+/// * `U+2029` => `\u2029`
+#[inline]
+pub fn encode_json_for_script_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_json_for_script_to_sink(text, &mut FmtSink(output))
+}
+
+/// Write JSON text used in the `<script>` element to an `EncodeSink`. The JSON text itself must be valid.
+///
+/// The following substrings and characters are escaped:
+///
+/// * `</script` => `\u003C/script` (case-insensitive)
+/// * `<!--` => `\u003C!--`
+/// * `U+2028` => `\u2028`
+/// * `U+2029` => `\u2029`
+pub fn encode_json_for_script_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while p < text_length {
+        if let Some((escaped, width)) = escape_json(text_bytes, p) {
+            output.write_str(unsafe { text.get_unchecked(start..p) })?;
+            p += width;
+            start = p;
+            output.write_str(unsafe { from_utf8_unchecked(escaped) })?;
+        } else {
+            p += 1;
+        }
+    }
+
+    output.write_str(unsafe { text.get_unchecked(start..p) })
+}
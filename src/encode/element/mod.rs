@@ -1,8 +1,14 @@
 #[macro_use]
 mod encode_impl;
 
+mod json;
+mod rcdata;
 mod script;
+mod script_cdata;
 mod style;
 
+pub use json::*;
+pub use rcdata::*;
 pub use script::*;
+pub use script_cdata::*;
 pub use style::*;
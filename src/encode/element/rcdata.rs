@@ -0,0 +1,320 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::functions::*;
+use crate::{EncodeSink, FmtSink, LineEndingPolicy};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+#[inline]
+fn escape_rcdata(text_bytes: &[u8], p: usize) -> Option<&'static [u8]> {
+    match text_bytes[p] {
+        b'&' => Some(b"&amp;"),
+        b'<' if text_bytes.get(p + 1) == Some(&b'/')
+            && (is_tag_name_at(&text_bytes[(p + 2)..], b"title")
+                || is_tag_name_at(&text_bytes[(p + 2)..], b"textarea")) =>
+        {
+            Some(b"&lt;")
+        }
+        _ => None,
+    }
+}
+
+/// Encode text used in an RCDATA element such as `<title>` and `<textarea>`.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+pub fn encode_rcdata<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+
+    let first = loop {
+        if p == text_length {
+            return Cow::from(text);
+        }
+
+        if let Some(escaped) = escape_rcdata(text_bytes, p) {
+            break escaped;
+        }
+
+        p += 1;
+    };
+
+    let mut v = Vec::with_capacity(text_length + 4);
+
+    v.extend_from_slice(&text_bytes[..p]);
+    v.extend_from_slice(first);
+
+    encode_rcdata_to_vec(unsafe { from_utf8_unchecked(&text_bytes[(p + 1)..]) }, &mut v);
+
+    Cow::from(unsafe { String::from_utf8_unchecked(v) })
+}
+
+/// Write text used in an RCDATA element such as `<title>` and `<textarea>` to a mutable `String` reference and return the encoded string slice.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+#[inline]
+pub fn encode_rcdata_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(encode_rcdata_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Write text used in an RCDATA element such as `<title>` and `<textarea>` to a mutable `Vec<u8>` reference and return the encoded data slice.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+#[inline]
+pub fn encode_rcdata_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    encode_rcdata_to_sink(text, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write text used in an RCDATA element such as `<title>` and `<textarea>` to a writer.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+#[inline]
+pub fn encode_rcdata_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_rcdata_to_sink(text, &mut IoSink(output))
+}
+
+/// Write text used in an RCDATA element such as `<title>` and `<textarea>` to a `fmt::Write`.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+#[inline]
+pub fn encode_rcdata_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_rcdata_to_sink(text, &mut FmtSink(output))
+}
+
+/// Write text used in an RCDATA element such as `<title>` and `<textarea>` to an `EncodeSink`.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+pub fn encode_rcdata_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while p < text_length {
+        if let Some(escaped) = escape_rcdata(text_bytes, p) {
+            output.write_str(unsafe { text.get_unchecked(start..p) })?;
+            start = p + 1;
+            output.write_str(unsafe { from_utf8_unchecked(escaped) })?;
+        }
+
+        p += 1;
+    }
+
+    output.write_str(unsafe { text.get_unchecked(start..p) })
+}
+
+/// Encode text used in an RCDATA element such as `<title>` and `<textarea>`, handling line endings according to the given policy.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+///
+/// Carriage returns are kept, normalized to `\n` or escaped to `&#x0D;` according to the given policy, so that a `<textarea>` default value can round-trip byte-identically.
+pub fn encode_rcdata_with_line_endings<S: ?Sized + AsRef<str>>(
+    text: &S,
+    policy: LineEndingPolicy,
+) -> Cow<'_, str> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let p = match (0..text_length).find(|&p| {
+        policy.escape(text_bytes, p).is_some() || escape_rcdata(text_bytes, p).is_some()
+    }) {
+        Some(p) => p,
+        None => return Cow::from(text),
+    };
+
+    let mut v = Vec::with_capacity(text_length + 4);
+
+    v.extend_from_slice(&text_bytes[..p]);
+
+    encode_rcdata_with_line_endings_to_vec(
+        unsafe { from_utf8_unchecked(&text_bytes[p..]) },
+        policy,
+        &mut v,
+    );
+
+    Cow::from(unsafe { String::from_utf8_unchecked(v) })
+}
+
+/// Write text used in an RCDATA element such as `<title>` and `<textarea>` to a mutable `String` reference and return the encoded string slice, handling line endings according to the given policy.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+///
+/// Carriage returns are kept, normalized to `\n` or escaped to `&#x0D;` according to the given policy, so that a `<textarea>` default value can round-trip byte-identically.
+#[inline]
+pub fn encode_rcdata_with_line_endings_to_string<S: AsRef<str>>(
+    text: S,
+    policy: LineEndingPolicy,
+    output: &mut String,
+) -> &str {
+    unsafe {
+        from_utf8_unchecked(encode_rcdata_with_line_endings_to_vec(
+            text,
+            policy,
+            output.as_mut_vec(),
+        ))
+    }
+}
+
+/// Write text used in an RCDATA element such as `<title>` and `<textarea>` to a mutable `Vec<u8>` reference and return the encoded data slice, handling line endings according to the given policy.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+///
+/// Carriage returns are kept, normalized to `\n` or escaped to `&#x0D;` according to the given policy, so that a `<textarea>` default value can round-trip byte-identically.
+#[inline]
+pub fn encode_rcdata_with_line_endings_to_vec<S: AsRef<str>>(
+    text: S,
+    policy: LineEndingPolicy,
+    output: &mut Vec<u8>,
+) -> &[u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    encode_rcdata_with_line_endings_to_sink(text, policy, output)
+        .unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write text used in an RCDATA element such as `<title>` and `<textarea>` to a writer, handling line endings according to the given policy.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+///
+/// Carriage returns are kept, normalized to `\n` or escaped to `&#x0D;` according to the given policy, so that a `<textarea>` default value can round-trip byte-identically.
+#[inline]
+pub fn encode_rcdata_with_line_endings_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    policy: LineEndingPolicy,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_rcdata_with_line_endings_to_sink(text, policy, &mut IoSink(output))
+}
+
+/// Write text used in an RCDATA element such as `<title>` and `<textarea>` to a `fmt::Write`, handling line endings according to the given policy.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+///
+/// Carriage returns are kept, normalized to `\n` or escaped to `&#x0D;` according to the given policy, so that a `<textarea>` default value can round-trip byte-identically.
+#[inline]
+pub fn encode_rcdata_with_line_endings_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    policy: LineEndingPolicy,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_rcdata_with_line_endings_to_sink(text, policy, &mut FmtSink(output))
+}
+
+/// Write text used in an RCDATA element such as `<title>` and `<textarea>` to an `EncodeSink`, handling line endings according to the given policy.
+///
+/// The following character and substrings are escaped:
+///
+/// * `&` => `&amp;`
+/// * `</title` => `&lt;/title` (case-insensitive)
+/// * `</textarea` => `&lt;/textarea` (case-insensitive)
+///
+/// Carriage returns are kept, normalized to `\n` or escaped to `&#x0D;` according to the given policy, so that a `<textarea>` default value can round-trip byte-identically.
+pub fn encode_rcdata_with_line_endings_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    policy: LineEndingPolicy,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while p < text_length {
+        if let Some(escaped) =
+            policy.escape(text_bytes, p).or_else(|| escape_rcdata(text_bytes, p))
+        {
+            output.write_str(unsafe { text.get_unchecked(start..p) })?;
+            start = p + 1;
+            output.write_str(unsafe { from_utf8_unchecked(escaped) })?;
+        }
+
+        p += 1;
+    }
+
+    output.write_str(unsafe { text.get_unchecked(start..p) })
+}
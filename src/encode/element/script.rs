@@ -1,3 +1,4 @@
+use core::fmt;
 use core::str::from_utf8_unchecked;
 
 use alloc::borrow::Cow;
@@ -7,6 +8,11 @@ use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::io::{self, Write};
 
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
 macro_rules! parse_script {
     ($e:expr, $step:ident, $b:block, $bq:block $(, $($addi:expr),+)?) => {
         match $step {
@@ -20,7 +26,12 @@ macro_rules! parse_script {
             }
             1 => {
                 match $e {
+                    b'<' => $step = 1,
                     b'/' => $step = 2,
+                    b'!' => {
+                        $step = 0;
+                        $bq
+                    }
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
                         $step = 0;
@@ -31,6 +42,7 @@ macro_rules! parse_script {
             }
             2 => {
                 match $e {
+                    b'<' => $step = 1,
                     b's' | b'S' => $step = 3,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -42,6 +54,7 @@ macro_rules! parse_script {
             }
             3 => {
                 match $e {
+                    b'<' => $step = 1,
                     b'c' | b'C' => $step = 4,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -53,6 +66,7 @@ macro_rules! parse_script {
             }
             4 => {
                 match $e {
+                    b'<' => $step = 1,
                     b'r' | b'R' => $step = 5,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -64,6 +78,7 @@ macro_rules! parse_script {
             }
             5 => {
                 match $e {
+                    b'<' => $step = 1,
                     b'i' | b'I' => $step = 6,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -75,6 +90,7 @@ macro_rules! parse_script {
             }
             6 => {
                 match $e {
+                    b'<' => $step = 1,
                     b'p' | b'P' => $step = 7,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -86,6 +102,7 @@ macro_rules! parse_script {
             }
             7 => {
                 match $e {
+                    b'<' => $step = 1,
                     b't' | b'T' => $step = 8,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -97,7 +114,8 @@ macro_rules! parse_script {
             }
             8 => {
                 match $e {
-                    b'>' | 9..=13 | 28..=32 => {
+                    b'<' => $step = 1,
+                    b'>' | b'/' | b'\t' | b'\n' | b'\x0C' | b'\r' | b' ' => {
                         $step = 0;
                         $b
                     },
@@ -132,6 +150,12 @@ macro_rules! parse_script_double_quoted_text {
     };
 }
 
+macro_rules! parse_script_backquoted_text {
+    ($e:expr, $step:ident, $b:block, $bq:block) => {
+        parse_script!($e, $step, $b, $bq, b'`', b'$');
+    };
+}
+
 macro_rules! parse_script_quoted_text {
     ($e:expr, $step:ident, $b:block, $bq:block) => {
         parse_script!($e, $step, $b, $bq, b'\'', b'"');
@@ -140,9 +164,10 @@ macro_rules! parse_script_quoted_text {
 
 encode_impl! {
     7;
-    /// The following substring is escaped:
+    /// The following substrings are escaped:
     ///
-    /// * `</script>` => `<\/script>`
+    /// * `</script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive, as the HTML tokenizer recognizes the end tag) => `<\/script`
+    /// * `<!` => `<\!`, so that `<!--` cannot make the tokenizer enter the escaped script data state
     parse_script;
     /// Encode text used in the `<script>` element.
     encode_script;
@@ -152,14 +177,20 @@ encode_impl! {
     encode_script_to_vec;
     /// Write text used in the `<script>` element to a writer.
     encode_script_to_writer;
+    /// Write text used in the `<script>` element to a `fmt::Write`.
+    encode_script_to_fmt_writer;
+    /// Write text used in the `<script>` element to an `EncodeSink`.
+    encode_script_to_sink;
 }
 
 encode_impl! {
-    7;
-    /// The following substring and character are escaped:
+    7, escape_line_separators;
+    /// The following substrings and character are escaped:
     ///
-    /// * `</script>` => `<\/script>`
+    /// * `</script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive, as the HTML tokenizer recognizes the end tag) => `<\/script`
+    /// * `<!` => `<\!`, so that `<!--` cannot make the tokenizer enter the escaped script data state
     /// * `'` => `\'`
+    /// * U+2028 and U+2029 => `\u2028` and `\u2029`, which are line terminators in older JavaScript engines
     parse_script_single_quoted_text;
     /// Encode text used in a single quoted text in the `<script>` element.
     encode_script_single_quoted_text;
@@ -169,14 +200,20 @@ encode_impl! {
     encode_script_single_quoted_text_to_vec;
     /// Write text used in a single quoted text in the `<script>` element to a writer.
     encode_script_single_quoted_text_to_writer;
+    /// Write text used in a single quoted text in the `<script>` element to a `fmt::Write`.
+    encode_script_single_quoted_text_to_fmt_writer;
+    /// Write text used in a single quoted text in the `<script>` element to an `EncodeSink`.
+    encode_script_single_quoted_text_to_sink;
 }
 
 encode_impl! {
-    7;
-    /// The following substring and character are escaped:
+    7, escape_line_separators;
+    /// The following substrings and character are escaped:
     ///
-    /// * `</script>` => `<\/script>`
+    /// * `</script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive, as the HTML tokenizer recognizes the end tag) => `<\/script`
+    /// * `<!` => `<\!`, so that `<!--` cannot make the tokenizer enter the escaped script data state
     /// * `"` => `\"`
+    /// * U+2028 and U+2029 => `\u2028` and `\u2029`, which are line terminators in older JavaScript engines
     parse_script_double_quoted_text;
     /// Encode text used in a double quoted text in the `<script>` element.
     encode_script_double_quoted_text;
@@ -186,15 +223,21 @@ encode_impl! {
     encode_script_double_quoted_text_to_vec;
     /// Write text used in a double quoted text in the `<script>` element to a writer.
     encode_script_double_quoted_text_to_writer;
+    /// Write text used in a double quoted text in the `<script>` element to a `fmt::Write`.
+    encode_script_double_quoted_text_to_fmt_writer;
+    /// Write text used in a double quoted text in the `<script>` element to an `EncodeSink`.
+    encode_script_double_quoted_text_to_sink;
 }
 
 encode_impl! {
-    7;
-    /// The following substring and characters are escaped:
+    7, escape_line_separators;
+    /// The following substrings and characters are escaped:
     ///
-    /// * `</script>` => `<\/script>`
+    /// * `</script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive, as the HTML tokenizer recognizes the end tag) => `<\/script`
+    /// * `<!` => `<\!`, so that `<!--` cannot make the tokenizer enter the escaped script data state
     /// * `"` => `\"`
     /// * `'` => `\'`
+    /// * U+2028 and U+2029 => `\u2028` and `\u2029`, which are line terminators in older JavaScript engines
     parse_script_quoted_text;
     /// Encode text used in a quoted text in the `<script>` element.
     encode_script_quoted_text;
@@ -204,4 +247,31 @@ encode_impl! {
     encode_script_quoted_text_to_vec;
     /// Write text used in a quoted text in the `<script>` element to a writer.
     encode_script_quoted_text_to_writer;
+    /// Write text used in a quoted text in the `<script>` element to a `fmt::Write`.
+    encode_script_quoted_text_to_fmt_writer;
+    /// Write text used in a quoted text in the `<script>` element to an `EncodeSink`.
+    encode_script_quoted_text_to_sink;
+}
+
+encode_impl! {
+    7;
+    /// The following substrings and characters are escaped:
+    ///
+    /// * `</script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive, as the HTML tokenizer recognizes the end tag) => `<\/script`
+    /// * `<!` => `<\!`, so that `<!--` cannot make the tokenizer enter the escaped script data state
+    /// * `` ` `` => `` \` ``
+    /// * `$` => `\$`, so that `${` cannot start a substitution
+    parse_script_backquoted_text;
+    /// Encode text used in a template literal in the `<script>` element.
+    encode_script_backquoted_text;
+    /// Write text used in a template literal in the `<script>` element to a mutable `String` reference and return the encoded string slice.
+    encode_script_backquoted_text_to_string;
+    /// Write text used in a template literal in the `<script>` element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_script_backquoted_text_to_vec;
+    /// Write text used in a template literal in the `<script>` element to a writer.
+    encode_script_backquoted_text_to_writer;
+    /// Write text used in a template literal in the `<script>` element to a `fmt::Write`.
+    encode_script_backquoted_text_to_fmt_writer;
+    /// Write text used in a template literal in the `<script>` element to an `EncodeSink`.
+    encode_script_backquoted_text_to_sink;
 }
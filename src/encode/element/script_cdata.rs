@@ -0,0 +1,146 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+use crate::functions::*;
+
+const CDATA_START: &str = "//<![CDATA[\n";
+const CDATA_END: &str = "\n//]]>";
+
+/// Whether a backslash needs to be inserted before `text_bytes[p]`.
+#[inline]
+fn escape_script_cdata(text_bytes: &[u8], p: usize) -> bool {
+    match text_bytes[p] {
+        b'/' => {
+            p >= 1
+                && text_bytes[p - 1] == b'<'
+                && is_tag_name_at(&text_bytes[(p + 1)..], b"script")
+        }
+        b'!' => p >= 1 && text_bytes[p - 1] == b'<',
+        b'>' => p >= 2 && &text_bytes[(p - 2)..p] == b"]]",
+        _ => false,
+    }
+}
+
+/// Encode text used in the `<script>` element of a page which can be served as either HTML or XHTML, wrapping it in `//<![CDATA[` and `//]]>`.
+///
+/// The following substrings are escaped:
+///
+/// * `</script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `<\/script`
+/// * `<!` => `<\!`
+/// * `]]>` => `]]\>`
+#[inline]
+pub fn encode_script_cdata<S: AsRef<str>>(text: S) -> String {
+    let mut s = String::new();
+
+    encode_script_cdata_to_string(text, &mut s);
+
+    s
+}
+
+/// Write text used in the `<script>` element of a page which can be served as either HTML or XHTML to a mutable `String` reference and return the encoded string slice, wrapping it in `//<![CDATA[` and `//]]>`.
+///
+/// The following substrings are escaped:
+///
+/// * `</script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `<\/script`
+/// * `<!` => `<\!`
+/// * `]]>` => `]]\>`
+#[inline]
+pub fn encode_script_cdata_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(encode_script_cdata_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Write text used in the `<script>` element of a page which can be served as either HTML or XHTML to a mutable `Vec<u8>` reference and return the encoded data slice, wrapping it in `//<![CDATA[` and `//]]>`.
+///
+/// The following substrings are escaped:
+///
+/// * `</script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `<\/script`
+/// * `<!` => `<\!`
+/// * `]]>` => `]]\>`
+#[inline]
+pub fn encode_script_cdata_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len() + CDATA_START.len() + CDATA_END.len());
+
+    let current_length = output.len();
+
+    encode_script_cdata_to_sink(text, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write text used in the `<script>` element of a page which can be served as either HTML or XHTML to a writer, wrapping it in `//<![CDATA[` and `//]]>`.
+///
+/// The following substrings are escaped:
+///
+/// * `</script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `<\/script`
+/// * `<!` => `<\!`
+/// * `]]>` => `]]\>`
+#[inline]
+pub fn encode_script_cdata_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_script_cdata_to_sink(text, &mut IoSink(output))
+}
+
+/// Write text used in the `<script>` element of a page which can be served as either HTML or XHTML to a `fmt::Write`, wrapping it in `//<![CDATA[` and `//]]>`.
+///
+/// The following substrings are escaped:
+///
+/// * `</script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `<\/script`
+/// * `<!` => `<\!`
+/// * `]]>` => `]]\>`
+#[inline]
+pub fn encode_script_cdata_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_script_cdata_to_sink(text, &mut FmtSink(output))
+}
+
+/// Write text used in the `<script>` element of a page which can be served as either HTML or XHTML to an `EncodeSink`, wrapping it in `//<![CDATA[` and `//]]>`.
+///
+/// The following substrings are escaped:
+///
+/// * `</script` followed by whitespace, `/`, `>` or the end of the text (case-insensitive) => `<\/script`
+/// * `<!` => `<\!`
+/// * `]]>` => `]]\>`
+pub fn encode_script_cdata_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    output.write_str(CDATA_START)?;
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while p < text_length {
+        if escape_script_cdata(text_bytes, p) {
+            output.write_str(unsafe { text.get_unchecked(start..p) })?;
+            start = p;
+            output.write_str("\\")?;
+        }
+
+        p += 1;
+    }
+
+    output.write_str(unsafe { text.get_unchecked(start..p) })?;
+    output.write_str(CDATA_END)
+}
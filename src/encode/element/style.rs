@@ -1,3 +1,4 @@
+use core::fmt;
 use core::str::from_utf8_unchecked;
 
 use alloc::borrow::Cow;
@@ -7,6 +8,11 @@ use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::io::{self, Write};
 
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
 macro_rules! parse_style {
     ($e:expr, $step:ident, $b:block, $bq:block $(, $($addi:expr),+)?) => {
         match $step {
@@ -20,6 +26,7 @@ macro_rules! parse_style {
             }
             1 => {
                 match $e {
+                    b'<' => $step = 1,
                     b'/' => $step = 2,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -31,6 +38,7 @@ macro_rules! parse_style {
             }
             2 => {
                 match $e {
+                    b'<' => $step = 1,
                     b's' | b'S' => $step = 3,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -42,6 +50,7 @@ macro_rules! parse_style {
             }
             3 => {
                 match $e {
+                    b'<' => $step = 1,
                     b't' | b'T' => $step = 4,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -53,6 +62,7 @@ macro_rules! parse_style {
             }
             4 => {
                 match $e {
+                    b'<' => $step = 1,
                     b'y' | b'Y' => $step = 5,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -64,6 +74,7 @@ macro_rules! parse_style {
             }
             5 => {
                 match $e {
+                    b'<' => $step = 1,
                     b'l' | b'L' => $step = 6,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -75,6 +86,7 @@ macro_rules! parse_style {
             }
             6 => {
                 match $e {
+                    b'<' => $step = 1,
                     b'e' | b'E' => $step = 7,
                     $(b'\\' => $step = 10,
                     $(| $addi)+ => {
@@ -86,7 +98,8 @@ macro_rules! parse_style {
             }
             7 => {
                 match $e {
-                    b'>' | 9..=13 | 28..=32 => {
+                    b'<' => $step = 1,
+                    b'>' | b'/' | b'\t' | b'\n' | b'\x0C' | b'\r' | b' ' => {
                         $step = 0;
                         $b
                     },
@@ -131,7 +144,7 @@ encode_impl! {
     6;
     /// The following substring is escaped:
     ///
-    /// * `</style>` => `<\/style>`
+    /// * `</style` followed by whitespace, `/`, `>` or the end of the text (case-insensitive, as the HTML tokenizer recognizes the end tag) => `<\/style`
     parse_style;
     /// Encode text used in the `<style>` element.
     encode_style;
@@ -141,13 +154,17 @@ encode_impl! {
     encode_style_to_vec;
     /// Write text used in the `<style>` element to a writer.
     encode_style_to_writer;
+    /// Write text used in the `<style>` element to a `fmt::Write`.
+    encode_style_to_fmt_writer;
+    /// Write text used in the `<style>` element to an `EncodeSink`.
+    encode_style_to_sink;
 }
 
 encode_impl! {
     6;
     /// The following substring and character are escaped:
     ///
-    /// * `</style>` => `<\/style>`
+    /// * `</style` followed by whitespace, `/`, `>` or the end of the text (case-insensitive, as the HTML tokenizer recognizes the end tag) => `<\/style`
     /// * `'` => `\'`
     parse_style_single_quoted_text;
     /// Encode text used in a single quoted text in the `<style>` element.
@@ -158,13 +175,17 @@ encode_impl! {
     encode_style_single_quoted_text_to_vec;
     /// Write text used in a single quoted text in the `<style>` element to a writer.
     encode_style_single_quoted_text_to_writer;
+    /// Write text used in a single quoted text in the `<style>` element to a `fmt::Write`.
+    encode_style_single_quoted_text_to_fmt_writer;
+    /// Write text used in a single quoted text in the `<style>` element to an `EncodeSink`.
+    encode_style_single_quoted_text_to_sink;
 }
 
 encode_impl! {
     6;
     /// The following substring and character are escaped:
     ///
-    /// * `</style>` => `<\/style>`
+    /// * `</style` followed by whitespace, `/`, `>` or the end of the text (case-insensitive, as the HTML tokenizer recognizes the end tag) => `<\/style`
     /// * `"` => `\"`
     parse_style_double_quoted_text;
     /// Encode text used in a double quoted text in the `<style>` element.
@@ -175,13 +196,17 @@ encode_impl! {
     encode_style_double_quoted_text_to_vec;
     /// Write text used in a double quoted text in the `<style>` element to a writer.
     encode_style_double_quoted_text_to_writer;
+    /// Write text used in a double quoted text in the `<style>` element to a `fmt::Write`.
+    encode_style_double_quoted_text_to_fmt_writer;
+    /// Write text used in a double quoted text in the `<style>` element to an `EncodeSink`.
+    encode_style_double_quoted_text_to_sink;
 }
 
 encode_impl! {
     6;
     /// The following substring and characters are escaped:
     ///
-    /// * `</style>` => `<\/style>`
+    /// * `</style` followed by whitespace, `/`, `>` or the end of the text (case-insensitive, as the HTML tokenizer recognizes the end tag) => `<\/style`
     /// * `"` => `\"`
     /// * `'` => `\'`
     parse_style_quoted_text;
@@ -193,4 +218,8 @@ encode_impl! {
     encode_style_quoted_text_to_vec;
     /// Write text used in a quoted text in the `<style>` element to a writer.
     encode_style_quoted_text_to_writer;
+    /// Write text used in a quoted text in the `<style>` element to a `fmt::Write`.
+    encode_style_quoted_text_to_fmt_writer;
+    /// Write text used in a quoted text in the `<style>` element to an `EncodeSink`.
+    encode_style_quoted_text_to_sink;
 }
@@ -0,0 +1,213 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use alloc::vec::Vec;
+
+use std::io;
+
+use super::EscapeContext;
+use crate::functions::{invalid_utf8, PendingChar};
+
+/// An asynchronous writer which escapes everything written through it for the given context before passing it to the inner writer, like `EscapingWriter` does, so an async web service can stream escaped output to a socket.
+///
+/// It implements `tokio::io::AsyncWrite` if the `tokio` feature is enabled and `futures_io::AsyncWrite` if the `futures-io` feature is enabled. Shut it down, or close it, at the end to write the buffered text and make sure no incomplete character is left.
+///
+/// ```
+/// # #[cfg(feature = "tokio")]
+/// # async fn f() -> std::io::Result<()> {
+/// use tokio::io::AsyncWriteExt;
+///
+/// use html_escape::{AsyncEscapingWriter, EscapeContext};
+///
+/// let mut writer = AsyncEscapingWriter::new(Vec::new(), EscapeContext::Text);
+///
+/// writer.write_all(b"<b>caf\xC3").await?;
+/// writer.write_all(b"\xA9 & co</b>").await?;
+/// writer.shutdown().await?;
+///
+/// assert_eq!(b"&lt;b&gt;caf\xC3\xA9 &amp; co&lt;/b&gt;", writer.get_ref().as_slice());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncEscapingWriter<W> {
+    inner: W,
+    context: EscapeContext,
+    pending: PendingChar,
+    /// The escaped text which has not been written to the inner writer yet, from `position`.
+    output: Vec<u8>,
+    position: usize,
+}
+
+impl<W> AsyncEscapingWriter<W> {
+    /// Create a writer which escapes for `context` and writes to `inner`.
+    #[inline]
+    pub fn new(inner: W, context: EscapeContext) -> AsyncEscapingWriter<W> {
+        AsyncEscapingWriter {
+            inner,
+            context,
+            pending: PendingChar::default(),
+            output: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// The context which the written text is escaped for.
+    #[inline]
+    pub fn context(&self) -> EscapeContext {
+        self.context
+    }
+
+    /// A reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner writer. Writing to it directly bypasses the escaping and may come before the buffered text.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Return the inner writer. The escaped text which has not been written to it yet is dropped.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Escape `buf` into the buffer, which has to be drained. An incomplete character at its end is held back.
+    fn escape(&mut self, buf: &[u8]) -> Result<(), io::Error> {
+        self.output.clear();
+        self.position = 0;
+
+        let context = self.context;
+        let output = &mut self.output;
+
+        self.pending.feed(buf, |text| {
+            context.encode_to_vec(text, output);
+
+            Ok(())
+        })
+    }
+}
+
+impl<W: Unpin> AsyncEscapingWriter<W> {
+    /// Write the buffered text with `poll_write` of the inner writer until it is drained.
+    fn poll_drain<
+        F: FnMut(Pin<&mut W>, &mut Context<'_>, &[u8]) -> Poll<Result<usize, io::Error>>,
+    >(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut poll_write: F,
+    ) -> Poll<Result<(), io::Error>> {
+        while self.position < self.output.len() {
+            match poll_write(Pin::new(&mut self.inner), cx, &self.output[self.position..]) {
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write the escaped text",
+                    )))
+                }
+                Poll::Ready(Ok(length)) => self.position += length,
+                Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    /// Like `poll_write` of `AsyncWrite`, which accepts `buf` once the previously buffered text is drained.
+    fn poll_write_with<
+        F: FnMut(Pin<&mut W>, &mut Context<'_>, &[u8]) -> Poll<Result<usize, io::Error>>,
+    >(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+        poll_write: F,
+    ) -> Poll<Result<usize, io::Error>> {
+        match self.poll_drain(cx, poll_write) {
+            Poll::Ready(Ok(())) => Poll::Ready(self.escape(buf).map(|_| buf.len())),
+            Poll::Ready(Err(error)) => Poll::Ready(Err(error)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    /// Like `poll_shutdown` or `poll_close` of `AsyncWrite` before the inner writer is shut down, failing if an incomplete character is left.
+    fn poll_finish<
+        F: FnMut(Pin<&mut W>, &mut Context<'_>, &[u8]) -> Poll<Result<usize, io::Error>>,
+    >(
+        &mut self,
+        cx: &mut Context<'_>,
+        poll_write: F,
+    ) -> Poll<Result<(), io::Error>> {
+        if !self.pending.is_empty() {
+            return Poll::Ready(Err(invalid_utf8()));
+        }
+
+        self.poll_drain(cx, poll_write)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin> tokio::io::AsyncWrite for AsyncEscapingWriter<W> {
+    /// Escape and buffer the whole `buf` once the previously buffered text is written, except for an incomplete character at its end, which is held back until the next call. Fail with an `io::Error` of the kind `InvalidData` if `buf` is not UTF-8.
+    #[inline]
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, io::Error>> {
+        self.get_mut().poll_write_with(cx, buf, W::poll_write)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        let this = self.get_mut();
+
+        match this.poll_drain(cx, W::poll_write) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.inner).poll_flush(cx),
+            other => other,
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        let this = self.get_mut();
+
+        match this.poll_finish(cx, W::poll_write) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.inner).poll_shutdown(cx),
+            other => other,
+        }
+    }
+}
+
+#[cfg(feature = "futures-io")]
+impl<W: futures_io::AsyncWrite + Unpin> futures_io::AsyncWrite for AsyncEscapingWriter<W> {
+    /// Escape and buffer the whole `buf` once the previously buffered text is written, except for an incomplete character at its end, which is held back until the next call. Fail with an `io::Error` of the kind `InvalidData` if `buf` is not UTF-8.
+    #[inline]
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, io::Error>> {
+        self.get_mut().poll_write_with(cx, buf, W::poll_write)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        let this = self.get_mut();
+
+        match this.poll_drain(cx, W::poll_write) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.inner).poll_flush(cx),
+            other => other,
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        let this = self.get_mut();
+
+        match this.poll_finish(cx, W::poll_write) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.inner).poll_close(cx),
+            other => other,
+        }
+    }
+}
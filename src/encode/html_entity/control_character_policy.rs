@@ -0,0 +1,14 @@
+/// How the `*_with_control_policy` encoders handle C0 control characters which are not allowed in HTML documents.
+///
+/// These are the characters from U+0000 to U+001F, except for tab (U+0009), line feed (U+000A), form feed (U+000C) and carriage return (U+000D).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControlCharacterPolicy {
+    /// Keep the control characters as they are, like the encoders without a policy do.
+    Keep,
+    /// Remove the control characters.
+    Strip,
+    /// Replace the control characters with U+FFFD REPLACEMENT CHARACTER.
+    Replace,
+    /// Escape the control characters to hexadecimal references, such as `&#x00;`.
+    Escape,
+}
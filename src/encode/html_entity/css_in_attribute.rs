@@ -0,0 +1,141 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::functions::*;
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+#[inline]
+fn is_css_escaped(e: u8) -> bool {
+    matches!(
+        e,
+        0..=0x1F
+            | 0x7F
+            | b'"'
+            | b'&'
+            | b'\''
+            | b'('
+            | b')'
+            | b'/'
+            | b';'
+            | b'<'
+            | b'>'
+            | b'\\'
+            | b'{'
+            | b'}'
+    )
+}
+
+/// Encode text used in a CSS string or value inside a `style` attribute, such as `style="font-family: '...'"`. The result is CSS-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Control characters, `"`, `&`, `'`, `(`, `)`, `/`, `;`, `<`, `>`, `\`, `{` and `}` are escaped to `\HH ` (a CSS hex escape terminated by a space).
+pub fn encode_css_in_attribute<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+    let mut e;
+
+    loop {
+        if p == text_length {
+            return Cow::from(text);
+        }
+
+        e = text_bytes[p];
+
+        if is_css_escaped(e) {
+            break;
+        }
+
+        p += 1;
+    }
+
+    let mut v = Vec::with_capacity(text_length + 4);
+
+    v.extend_from_slice(&text_bytes[..p]);
+
+    write_css_hex_to_vec(e, &mut v);
+
+    encode_css_in_attribute_to_vec(unsafe { from_utf8_unchecked(&text_bytes[(p + 1)..]) }, &mut v);
+
+    Cow::from(unsafe { String::from_utf8_unchecked(v) })
+}
+
+/// Write text used in a CSS string or value inside a `style` attribute to a mutable `String` reference and return the encoded string slice. The result is CSS-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Control characters, `"`, `&`, `'`, `(`, `)`, `/`, `;`, `<`, `>`, `\`, `{` and `}` are escaped to `\HH ` (a CSS hex escape terminated by a space).
+#[inline]
+pub fn encode_css_in_attribute_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(encode_css_in_attribute_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Write text used in a CSS string or value inside a `style` attribute to a mutable `Vec<u8>` reference and return the encoded data slice. The result is CSS-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Control characters, `"`, `&`, `'`, `(`, `)`, `/`, `;`, `<`, `>`, `\`, `{` and `}` are escaped to `\HH ` (a CSS hex escape terminated by a space).
+#[inline]
+pub fn encode_css_in_attribute_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    encode_css_in_attribute_to_sink(text, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write text used in a CSS string or value inside a `style` attribute to a writer. The result is CSS-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Control characters, `"`, `&`, `'`, `(`, `)`, `/`, `;`, `<`, `>`, `\`, `{` and `}` are escaped to `\HH ` (a CSS hex escape terminated by a space).
+#[inline]
+pub fn encode_css_in_attribute_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_css_in_attribute_to_sink(text, &mut IoSink(output))
+}
+
+/// Write text used in a CSS string or value inside a `style` attribute to a `fmt::Write`. The result is CSS-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Control characters, `"`, `&`, `'`, `(`, `)`, `/`, `;`, `<`, `>`, `\`, `{` and `}` are escaped to `\HH ` (a CSS hex escape terminated by a space).
+#[inline]
+pub fn encode_css_in_attribute_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_css_in_attribute_to_sink(text, &mut FmtSink(output))
+}
+
+/// Write text used in a CSS string or value inside a `style` attribute to an `EncodeSink`. The result is CSS-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Control characters, `"`, `&`, `'`, `(`, `)`, `/`, `;`, `<`, `>`, `\`, `{` and `}` are escaped to `\HH ` (a CSS hex escape terminated by a space).
+pub fn encode_css_in_attribute_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text = text.as_ref();
+
+    let mut start = 0;
+
+    for (p, e) in text.bytes().enumerate() {
+        if is_css_escaped(e) {
+            output.write_str(unsafe { text.get_unchecked(start..p) })?;
+            start = p + 1;
+            write_css_hex_to_sink(e, output)?;
+        }
+    }
+
+    output.write_str(unsafe { text.get_unchecked(start..) })
+}
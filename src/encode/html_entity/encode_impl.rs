@@ -0,0 +1,867 @@
+macro_rules! escape_impl {
+    (@inner [$dollar:tt] $name:ident; $($l:expr => $r:expr),+ $(,)*) => {
+        macro_rules! $name {
+            ($dollar e:expr) => {
+                match $dollar e {
+                    $($l => break $r,)+
+                    _ => (),
+                }
+            };
+            (check $dollar e:expr) => {
+                match $dollar e {
+                    $($l)|+ => true,
+                    _ => false,
+                }
+            };
+            (vec $dollar e:expr, $dollar v:ident, $dollar b:ident, $dollar start:ident, $dollar end:ident) => {
+                match $dollar e {
+                    $($l => {
+                        $dollar v.extend_from_slice(&$dollar b[$dollar start..$dollar end]);
+                        $dollar start = $dollar end + 1;
+                        $dollar v.extend_from_slice($r);
+                    })+
+                    _ => (),
+                }
+
+                $dollar end += 1;
+            };
+            (writer $dollar e:expr, $dollar w:ident, $dollar b:ident, $dollar start:ident, $dollar end:ident) => {
+                match $dollar e {
+                    $($l => {
+                        $dollar w.write_all(&$dollar b[$dollar start..$dollar end])?;
+                        $dollar start = $dollar end + 1;
+                        $dollar w.write_all($r)?;
+                    })+
+                    _ => (),
+                }
+
+                $dollar end += 1;
+            };
+            (sink $dollar e:expr, $dollar k:ident, $dollar t:ident, $dollar start:ident, $dollar end:ident) => {
+                match $dollar e {
+                    $($l => {
+                        $dollar k.write_str(unsafe { $dollar t.get_unchecked($dollar start..$dollar end) })?;
+                        $dollar start = $dollar end + 1;
+                        $dollar k.write_str(unsafe { from_utf8_unchecked($r) })?;
+                    })+
+                    _ => (),
+                }
+
+                $dollar end += 1;
+            };
+            (slice $dollar e:expr, $dollar w:ident, $dollar t:ident, $dollar start:ident, $dollar end:ident) => {
+                match $dollar e {
+                    $($l => {
+                        $dollar w.write_text($dollar t, $dollar start, $dollar end)?;
+                        $dollar w.write_escape($r, $dollar end)?;
+                        $dollar start = $dollar end + 1;
+                    })+
+                    _ => (),
+                }
+
+                $dollar end += 1;
+            };
+        }
+    };
+    ($name:ident; $($l:expr => $r:expr),+ $(,)*) => {
+        escape_impl! {
+            @inner [$]
+            $name;
+            $($l => $r.as_ref(),)*
+        }
+    };
+}
+
+macro_rules! encode_impl {
+    ($(#[$attr: meta])* $escape_macro:ident; $(#[$encode_attr: meta])* $encode_name: ident; $(#[$encode_to_string_attr: meta])* $encode_to_string_name: ident; $(#[$encode_to_vec_attr: meta])* $encode_to_vec_name: ident; $(#[$encode_to_writer_attr: meta])* $encode_to_writer_name: ident; $(#[$encode_to_fmt_writer_attr: meta])* $encode_to_fmt_writer_name: ident; $(#[$encode_to_slice_attr: meta])* $encode_to_slice_name: ident; $(#[$encode_to_sink_attr: meta])* $encode_to_sink_name: ident $(;)*) => {
+        $(#[$encode_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_name<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            let mut p = 0;
+            let mut e;
+
+            let first = loop {
+                if p == text_length {
+                    return Cow::from(text);
+                }
+
+                e = text_bytes[p];
+
+                $escape_macro!(e);
+
+                p += 1;
+            };
+
+            let mut v = Vec::with_capacity(text_length + 5);
+
+            v.extend_from_slice(&text_bytes[..p]);
+            v.extend_from_slice(first);
+
+            $encode_to_vec_name(unsafe { from_utf8_unchecked(&text_bytes[(p + 1)..]) }, &mut v);
+
+            Cow::from(unsafe { String::from_utf8_unchecked(v) })
+        }
+
+        $(#[$encode_to_string_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_string_name<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+            unsafe { from_utf8_unchecked($encode_to_vec_name(text, output.as_mut_vec())) }
+        }
+
+        $(#[$encode_to_vec_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_vec_name<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            output.reserve(text_length);
+
+            let current_length = output.len();
+
+            $encode_to_sink_name(text, output).unwrap_or_else(|error| match error {});
+
+            &output[current_length..]
+        }
+
+        #[cfg(feature = "std")]
+        $(#[$encode_to_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_writer_name<S: AsRef<str>, W: Write>(text: S, output: &mut W) -> Result<(), io::Error> {
+            $encode_to_sink_name(text, &mut IoSink(output))
+        }
+
+        $(#[$encode_to_fmt_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_fmt_writer_name<S: AsRef<str>, W: fmt::Write>(text: S, output: &mut W) -> Result<(), fmt::Error> {
+            $encode_to_sink_name(text, &mut FmtSink(output))
+        }
+
+        $(#[$encode_to_slice_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_slice_name<S: AsRef<str>>(text: S, output: &mut [u8]) -> Result<usize, BufferTooSmall> {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+
+            let mut output = SliceWriter::new(output);
+
+            let mut start = 0;
+            let mut end = 0;
+
+            for e in text_bytes.iter().copied() {
+                $escape_macro!(slice e, output, text, start, end);
+            }
+
+            output.write_text(text, start, end)?;
+
+            Ok(output.written())
+        }
+
+        $(#[$encode_to_sink_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_sink_name<S: AsRef<str>, W: EncodeSink>(text: S, output: &mut W) -> Result<(), W::Error> {
+            let text = text.as_ref();
+
+            let mut start = 0;
+            let mut end = 0;
+
+            for e in text.bytes() {
+                $escape_macro!(sink e, output, text, start, end);
+            }
+
+            output.write_str(unsafe { text.get_unchecked(start..end) })
+        }
+    };
+}
+
+macro_rules! encode_ascii_only_impl {
+    ($write_char_to_sink:ident; $(#[$attr: meta])* $escape_macro:ident; $(#[$encode_attr: meta])* $encode_name: ident; $(#[$encode_to_string_attr: meta])* $encode_to_string_name: ident; $(#[$encode_to_vec_attr: meta])* $encode_to_vec_name: ident; $(#[$encode_to_writer_attr: meta])* $encode_to_writer_name: ident; $(#[$encode_to_fmt_writer_attr: meta])* $encode_to_fmt_writer_name: ident; $(#[$encode_to_slice_attr: meta])* $encode_to_slice_name: ident; $(#[$encode_to_sink_attr: meta])* $encode_to_sink_name: ident $(;)*) => {
+        $(#[$encode_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_name<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            let p = match text_bytes.iter().position(|&e| e >= 128 || $escape_macro!(check e)) {
+                Some(p) => p,
+                None => return Cow::from(text),
+            };
+
+            let mut v = Vec::with_capacity(text_length + 8);
+
+            v.extend_from_slice(&text_bytes[..p]);
+
+            $encode_to_vec_name(unsafe { from_utf8_unchecked(&text_bytes[p..]) }, &mut v);
+
+            Cow::from(unsafe { String::from_utf8_unchecked(v) })
+        }
+
+        $(#[$encode_to_string_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_string_name<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+            unsafe { from_utf8_unchecked($encode_to_vec_name(text, output.as_mut_vec())) }
+        }
+
+        $(#[$encode_to_vec_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_vec_name<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            output.reserve(text_length);
+
+            let current_length = output.len();
+
+            $encode_to_sink_name(text, output).unwrap_or_else(|error| match error {});
+
+            &output[current_length..]
+        }
+
+        #[cfg(feature = "std")]
+        $(#[$encode_to_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_writer_name<S: AsRef<str>, W: Write>(text: S, output: &mut W) -> Result<(), io::Error> {
+            $encode_to_sink_name(text, &mut IoSink(output))
+        }
+
+        $(#[$encode_to_fmt_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_fmt_writer_name<S: AsRef<str>, W: fmt::Write>(text: S, output: &mut W) -> Result<(), fmt::Error> {
+            $encode_to_sink_name(text, &mut FmtSink(output))
+        }
+
+        $(#[$encode_to_slice_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_slice_name<S: AsRef<str>>(text: S, output: &mut [u8]) -> Result<usize, BufferTooSmall> {
+            let text = text.as_ref();
+
+            let mut output = SliceWriter::new(output);
+
+            let mut start = 0;
+            let mut end = 0;
+
+            for c in text.chars() {
+                if c.is_ascii() {
+                    let e = c as u8;
+
+                    $escape_macro!(slice e, output, text, start, end);
+                } else {
+                    output.write_text(text, start, end)?;
+                    output.write_escape_with(end, |output| $write_char_to_sink(c, output))?;
+                    end += c.len_utf8();
+                    start = end;
+                }
+            }
+
+            output.write_text(text, start, end)?;
+
+            Ok(output.written())
+        }
+
+        $(#[$encode_to_sink_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_sink_name<S: AsRef<str>, W: EncodeSink>(text: S, output: &mut W) -> Result<(), W::Error> {
+            let text = text.as_ref();
+
+            let mut start = 0;
+            let mut end = 0;
+
+            for c in text.chars() {
+                if c.is_ascii() {
+                    let e = c as u8;
+
+                    $escape_macro!(sink e, output, text, start, end);
+                } else {
+                    output.write_str(unsafe { text.get_unchecked(start..end) })?;
+                    end += c.len_utf8();
+                    start = end;
+                    $write_char_to_sink(c, output)?;
+                }
+            }
+
+            output.write_str(unsafe { text.get_unchecked(start..end) })
+        }
+    };
+}
+
+macro_rules! encode_with_format_impl {
+    ($non_ascii:expr; $(#[$attr: meta])* $escape_fn:ident; $(#[$encode_attr: meta])* $encode_name: ident; $(#[$encode_to_string_attr: meta])* $encode_to_string_name: ident; $(#[$encode_to_vec_attr: meta])* $encode_to_vec_name: ident; $(#[$encode_to_writer_attr: meta])* $encode_to_writer_name: ident; $(#[$encode_to_fmt_writer_attr: meta])* $encode_to_fmt_writer_name: ident; $(#[$encode_to_sink_attr: meta])* $encode_to_sink_name: ident $(;)*) => {
+        $(#[$encode_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_name<S: ?Sized + AsRef<str>>(text: &S, format: NumericEntityFormat) -> Cow<'_, str> {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            let p = match text_bytes.iter().position(|&e| {
+                if e >= 128 {
+                    $non_ascii
+                } else {
+                    !matches!($escape_fn(e), ByteEscape::Keep)
+                }
+            }) {
+                Some(p) => p,
+                None => return Cow::from(text),
+            };
+
+            let mut v = Vec::with_capacity(text_length + 8);
+
+            v.extend_from_slice(&text_bytes[..p]);
+
+            $encode_to_vec_name(unsafe { from_utf8_unchecked(&text_bytes[p..]) }, format, &mut v);
+
+            Cow::from(unsafe { String::from_utf8_unchecked(v) })
+        }
+
+        $(#[$encode_to_string_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_string_name<S: AsRef<str>>(text: S, format: NumericEntityFormat, output: &mut String) -> &str {
+            unsafe { from_utf8_unchecked($encode_to_vec_name(text, format, output.as_mut_vec())) }
+        }
+
+        $(#[$encode_to_vec_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_vec_name<S: AsRef<str>>(text: S, format: NumericEntityFormat, output: &mut Vec<u8>) -> &[u8] {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            output.reserve(text_length);
+
+            let current_length = output.len();
+
+            $encode_to_sink_name(text, format, output).unwrap_or_else(|error| match error {});
+
+            &output[current_length..]
+        }
+
+        #[cfg(feature = "std")]
+        $(#[$encode_to_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_writer_name<S: AsRef<str>, W: Write>(text: S, format: NumericEntityFormat, output: &mut W) -> Result<(), io::Error> {
+            $encode_to_sink_name(text, format, &mut IoSink(output))
+        }
+
+        $(#[$encode_to_fmt_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_fmt_writer_name<S: AsRef<str>, W: fmt::Write>(text: S, format: NumericEntityFormat, output: &mut W) -> Result<(), fmt::Error> {
+            $encode_to_sink_name(text, format, &mut FmtSink(output))
+        }
+
+        $(#[$encode_to_sink_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_sink_name<S: AsRef<str>, W: EncodeSink>(text: S, format: NumericEntityFormat, output: &mut W) -> Result<(), W::Error> {
+            let text = text.as_ref();
+
+            let mut start = 0;
+            let mut end = 0;
+
+            for c in text.chars() {
+                if c.is_ascii() {
+                    match $escape_fn(c as u8) {
+                        ByteEscape::Keep => (),
+                        ByteEscape::Named(r) => {
+                            output.write_str(unsafe { text.get_unchecked(start..end) })?;
+                            start = end + 1;
+                            output.write_str(unsafe { from_utf8_unchecked(r) })?;
+                        }
+                        ByteEscape::Numeric => {
+                            output.write_str(unsafe { text.get_unchecked(start..end) })?;
+                            start = end + 1;
+                            format.write_to_sink(c as u32, output)?;
+                        }
+                    }
+
+                    end += 1;
+                } else if $non_ascii {
+                    output.write_str(unsafe { text.get_unchecked(start..end) })?;
+                    end += c.len_utf8();
+                    start = end;
+                    format.write_to_sink(c as u32, output)?;
+                } else {
+                    end += c.len_utf8();
+                }
+            }
+
+            output.write_str(unsafe { text.get_unchecked(start..end) })
+        }
+    };
+}
+
+macro_rules! encode_with_control_policy_impl {
+    ($(#[$attr: meta])* $escape_macro:ident; $(#[$encode_attr: meta])* $encode_name: ident; $(#[$encode_to_string_attr: meta])* $encode_to_string_name: ident; $(#[$encode_to_vec_attr: meta])* $encode_to_vec_name: ident; $(#[$encode_to_writer_attr: meta])* $encode_to_writer_name: ident; $(#[$encode_to_fmt_writer_attr: meta])* $encode_to_fmt_writer_name: ident; $(#[$encode_to_slice_attr: meta])* $encode_to_slice_name: ident; $(#[$encode_to_sink_attr: meta])* $encode_to_sink_name: ident $(;)*) => {
+        $(#[$encode_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_name<S: ?Sized + AsRef<str>>(text: &S, policy: ControlCharacterPolicy) -> Cow<'_, str> {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            let p = match text_bytes.iter().position(|&e| {
+                $escape_macro!(check e) || (policy != ControlCharacterPolicy::Keep && is_invalid_control(e))
+            }) {
+                Some(p) => p,
+                None => return Cow::from(text),
+            };
+
+            let mut v = Vec::with_capacity(text_length + 5);
+
+            v.extend_from_slice(&text_bytes[..p]);
+
+            $encode_to_vec_name(unsafe { from_utf8_unchecked(&text_bytes[p..]) }, policy, &mut v);
+
+            Cow::from(unsafe { String::from_utf8_unchecked(v) })
+        }
+
+        $(#[$encode_to_string_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_string_name<S: AsRef<str>>(text: S, policy: ControlCharacterPolicy, output: &mut String) -> &str {
+            unsafe { from_utf8_unchecked($encode_to_vec_name(text, policy, output.as_mut_vec())) }
+        }
+
+        $(#[$encode_to_vec_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_vec_name<S: AsRef<str>>(text: S, policy: ControlCharacterPolicy, output: &mut Vec<u8>) -> &[u8] {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            output.reserve(text_length);
+
+            let current_length = output.len();
+
+            $encode_to_sink_name(text, policy, output).unwrap_or_else(|error| match error {});
+
+            &output[current_length..]
+        }
+
+        #[cfg(feature = "std")]
+        $(#[$encode_to_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_writer_name<S: AsRef<str>, W: Write>(text: S, policy: ControlCharacterPolicy, output: &mut W) -> Result<(), io::Error> {
+            $encode_to_sink_name(text, policy, &mut IoSink(output))
+        }
+
+        $(#[$encode_to_fmt_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_fmt_writer_name<S: AsRef<str>, W: fmt::Write>(text: S, policy: ControlCharacterPolicy, output: &mut W) -> Result<(), fmt::Error> {
+            $encode_to_sink_name(text, policy, &mut FmtSink(output))
+        }
+
+        $(#[$encode_to_slice_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_slice_name<S: AsRef<str>>(text: S, policy: ControlCharacterPolicy, output: &mut [u8]) -> Result<usize, BufferTooSmall> {
+            let text = text.as_ref();
+
+            let mut output = SliceWriter::new(output);
+
+            let mut start = 0;
+            let mut end = 0;
+
+            for e in text.bytes() {
+                if policy != ControlCharacterPolicy::Keep && is_invalid_control(e) {
+                    output.write_text(text, start, end)?;
+
+                    match policy {
+                        ControlCharacterPolicy::Replace => output.write_escape("\u{FFFD}".as_bytes(), end)?,
+                        ControlCharacterPolicy::Escape => output.write_escape_with(end, |output| write_hex_to_sink(e, output))?,
+                        _ => (),
+                    }
+
+                    start = end + 1;
+                    end += 1;
+                } else {
+                    $escape_macro!(slice e, output, text, start, end);
+                }
+            }
+
+            output.write_text(text, start, end)?;
+
+            Ok(output.written())
+        }
+
+        $(#[$encode_to_sink_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_sink_name<S: AsRef<str>, W: EncodeSink>(text: S, policy: ControlCharacterPolicy, output: &mut W) -> Result<(), W::Error> {
+            let text = text.as_ref();
+
+            let mut start = 0;
+            let mut end = 0;
+
+            for e in text.bytes() {
+                if policy != ControlCharacterPolicy::Keep && is_invalid_control(e) {
+                    output.write_str(unsafe { text.get_unchecked(start..end) })?;
+                    start = end + 1;
+
+                    match policy {
+                        ControlCharacterPolicy::Replace => output.write_str("\u{FFFD}")?,
+                        ControlCharacterPolicy::Escape => write_hex_to_sink(e, output)?,
+                        _ => (),
+                    }
+
+                    end += 1;
+                } else {
+                    $escape_macro!(sink e, output, text, start, end);
+                }
+            }
+
+            output.write_str(unsafe { text.get_unchecked(start..end) })
+        }
+    };
+}
+
+macro_rules! encode_with_unicode_escapes_impl {
+    ($(#[$attr: meta])* $escape_macro:ident; $(#[$encode_attr: meta])* $encode_name: ident; $(#[$encode_to_string_attr: meta])* $encode_to_string_name: ident; $(#[$encode_to_vec_attr: meta])* $encode_to_vec_name: ident; $(#[$encode_to_writer_attr: meta])* $encode_to_writer_name: ident; $(#[$encode_to_fmt_writer_attr: meta])* $encode_to_fmt_writer_name: ident; $(#[$encode_to_sink_attr: meta])* $encode_to_sink_name: ident $(;)*) => {
+        $(#[$encode_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_name<S: ?Sized + AsRef<str>>(text: &S, escapes: UnicodeEscapes) -> Cow<'_, str> {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            let p = match text.char_indices().find(|&(_, c)| {
+                if c.is_ascii() {
+                    let e = c as u8;
+
+                    $escape_macro!(check e)
+                } else {
+                    escapes.escapes(c)
+                }
+            }) {
+                Some((p, _)) => p,
+                None => return Cow::from(text),
+            };
+
+            let mut v = Vec::with_capacity(text_length + 8);
+
+            v.extend_from_slice(&text_bytes[..p]);
+
+            $encode_to_vec_name(unsafe { from_utf8_unchecked(&text_bytes[p..]) }, escapes, &mut v);
+
+            Cow::from(unsafe { String::from_utf8_unchecked(v) })
+        }
+
+        $(#[$encode_to_string_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_string_name<S: AsRef<str>>(text: S, escapes: UnicodeEscapes, output: &mut String) -> &str {
+            unsafe { from_utf8_unchecked($encode_to_vec_name(text, escapes, output.as_mut_vec())) }
+        }
+
+        $(#[$encode_to_vec_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_vec_name<S: AsRef<str>>(text: S, escapes: UnicodeEscapes, output: &mut Vec<u8>) -> &[u8] {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            output.reserve(text_length);
+
+            let current_length = output.len();
+
+            $encode_to_sink_name(text, escapes, output).unwrap_or_else(|error| match error {});
+
+            &output[current_length..]
+        }
+
+        #[cfg(feature = "std")]
+        $(#[$encode_to_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_writer_name<S: AsRef<str>, W: Write>(text: S, escapes: UnicodeEscapes, output: &mut W) -> Result<(), io::Error> {
+            $encode_to_sink_name(text, escapes, &mut IoSink(output))
+        }
+
+        $(#[$encode_to_fmt_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_fmt_writer_name<S: AsRef<str>, W: fmt::Write>(text: S, escapes: UnicodeEscapes, output: &mut W) -> Result<(), fmt::Error> {
+            $encode_to_sink_name(text, escapes, &mut FmtSink(output))
+        }
+
+        $(#[$encode_to_sink_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_sink_name<S: AsRef<str>, W: EncodeSink>(text: S, escapes: UnicodeEscapes, output: &mut W) -> Result<(), W::Error> {
+            let text = text.as_ref();
+
+            let mut start = 0;
+            let mut end = 0;
+
+            for c in text.chars() {
+                if c.is_ascii() {
+                    let e = c as u8;
+
+                    $escape_macro!(sink e, output, text, start, end);
+                } else if escapes.escapes(c) {
+                    output.write_str(unsafe { text.get_unchecked(start..end) })?;
+                    end += c.len_utf8();
+                    start = end;
+                    write_char_hex_to_sink(c, output)?;
+                } else {
+                    end += c.len_utf8();
+                }
+            }
+
+            output.write_str(unsafe { text.get_unchecked(start..end) })
+        }
+    };
+}
+
+macro_rules! encode_with_line_ending_policy_impl {
+    ($(#[$attr: meta])* $escape_macro:ident; $(#[$encode_attr: meta])* $encode_name: ident; $(#[$encode_to_string_attr: meta])* $encode_to_string_name: ident; $(#[$encode_to_vec_attr: meta])* $encode_to_vec_name: ident; $(#[$encode_to_writer_attr: meta])* $encode_to_writer_name: ident; $(#[$encode_to_fmt_writer_attr: meta])* $encode_to_fmt_writer_name: ident; $(#[$encode_to_sink_attr: meta])* $encode_to_sink_name: ident $(;)*) => {
+        $(#[$encode_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_name<S: ?Sized + AsRef<str>>(text: &S, policy: LineEndingPolicy) -> Cow<'_, str> {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            let p = match text_bytes.iter().position(|&e| {
+                $escape_macro!(check e) || (policy != LineEndingPolicy::Keep && e == b'\r')
+            }) {
+                Some(p) => p,
+                None => return Cow::from(text),
+            };
+
+            let mut v = Vec::with_capacity(text_length + 5);
+
+            v.extend_from_slice(&text_bytes[..p]);
+
+            $encode_to_vec_name(unsafe { from_utf8_unchecked(&text_bytes[p..]) }, policy, &mut v);
+
+            Cow::from(unsafe { String::from_utf8_unchecked(v) })
+        }
+
+        $(#[$encode_to_string_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_string_name<S: AsRef<str>>(text: S, policy: LineEndingPolicy, output: &mut String) -> &str {
+            unsafe { from_utf8_unchecked($encode_to_vec_name(text, policy, output.as_mut_vec())) }
+        }
+
+        $(#[$encode_to_vec_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_vec_name<S: AsRef<str>>(text: S, policy: LineEndingPolicy, output: &mut Vec<u8>) -> &[u8] {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            output.reserve(text_length);
+
+            let current_length = output.len();
+
+            $encode_to_sink_name(text, policy, output).unwrap_or_else(|error| match error {});
+
+            &output[current_length..]
+        }
+
+        #[cfg(feature = "std")]
+        $(#[$encode_to_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_writer_name<S: AsRef<str>, W: Write>(text: S, policy: LineEndingPolicy, output: &mut W) -> Result<(), io::Error> {
+            $encode_to_sink_name(text, policy, &mut IoSink(output))
+        }
+
+        $(#[$encode_to_fmt_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_fmt_writer_name<S: AsRef<str>, W: fmt::Write>(text: S, policy: LineEndingPolicy, output: &mut W) -> Result<(), fmt::Error> {
+            $encode_to_sink_name(text, policy, &mut FmtSink(output))
+        }
+
+        $(#[$encode_to_sink_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_sink_name<S: AsRef<str>, W: EncodeSink>(text: S, policy: LineEndingPolicy, output: &mut W) -> Result<(), W::Error> {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+
+            let mut start = 0;
+            let mut end = 0;
+
+            for e in text_bytes.iter().copied() {
+                if let Some(escaped) = policy.escape(text_bytes, end) {
+                    output.write_str(unsafe { text.get_unchecked(start..end) })?;
+                    start = end + 1;
+                    output.write_str(unsafe { from_utf8_unchecked(escaped) })?;
+
+                    end += 1;
+                } else {
+                    $escape_macro!(sink e, output, text, start, end);
+                }
+            }
+
+            output.write_str(unsafe { text.get_unchecked(start..end) })
+        }
+    };
+}
+
+macro_rules! encode_with_bom_policy_impl {
+    ($(#[$attr: meta])* $escape_macro:ident; $(#[$encode_attr: meta])* $encode_name: ident; $(#[$encode_to_string_attr: meta])* $encode_to_string_name: ident; $(#[$encode_to_vec_attr: meta])* $encode_to_vec_name: ident; $(#[$encode_to_writer_attr: meta])* $encode_to_writer_name: ident; $(#[$encode_to_fmt_writer_attr: meta])* $encode_to_fmt_writer_name: ident; $(#[$encode_to_sink_attr: meta])* $encode_to_sink_name: ident $(;)*) => {
+        $(#[$encode_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_name<S: ?Sized + AsRef<str>>(text: &S, policy: BomPolicy) -> Cow<'_, str> {
+            let original = text.as_ref();
+            let text = policy.strip(original);
+
+            if !text.chars().any(|c| {
+                if c.is_ascii() {
+                    let e = c as u8;
+
+                    $escape_macro!(check e)
+                } else {
+                    policy.escapes(c)
+                }
+            }) {
+                return Cow::from(text);
+            }
+
+            let mut v = Vec::with_capacity(text.len() + 8);
+
+            $encode_to_vec_name(original, policy, &mut v);
+
+            Cow::from(unsafe { String::from_utf8_unchecked(v) })
+        }
+
+        $(#[$encode_to_string_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_string_name<S: AsRef<str>>(text: S, policy: BomPolicy, output: &mut String) -> &str {
+            unsafe { from_utf8_unchecked($encode_to_vec_name(text, policy, output.as_mut_vec())) }
+        }
+
+        $(#[$encode_to_vec_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_vec_name<S: AsRef<str>>(text: S, policy: BomPolicy, output: &mut Vec<u8>) -> &[u8] {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            output.reserve(text_length);
+
+            let current_length = output.len();
+
+            $encode_to_sink_name(text, policy, output).unwrap_or_else(|error| match error {});
+
+            &output[current_length..]
+        }
+
+        #[cfg(feature = "std")]
+        $(#[$encode_to_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_writer_name<S: AsRef<str>, W: Write>(text: S, policy: BomPolicy, output: &mut W) -> Result<(), io::Error> {
+            $encode_to_sink_name(text, policy, &mut IoSink(output))
+        }
+
+        $(#[$encode_to_fmt_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_fmt_writer_name<S: AsRef<str>, W: fmt::Write>(text: S, policy: BomPolicy, output: &mut W) -> Result<(), fmt::Error> {
+            $encode_to_sink_name(text, policy, &mut FmtSink(output))
+        }
+
+        $(#[$encode_to_sink_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_sink_name<S: AsRef<str>, W: EncodeSink>(text: S, policy: BomPolicy, output: &mut W) -> Result<(), W::Error> {
+            let text = policy.strip(text.as_ref());
+
+            let mut start = 0;
+            let mut end = 0;
+
+            for c in text.chars() {
+                if c.is_ascii() {
+                    let e = c as u8;
+
+                    $escape_macro!(sink e, output, text, start, end);
+                } else if policy.escapes(c) {
+                    output.write_str(unsafe { text.get_unchecked(start..end) })?;
+                    end += c.len_utf8();
+                    start = end;
+                    write_char_hex_to_sink(c, output)?;
+                } else {
+                    end += c.len_utf8();
+                }
+            }
+
+            output.write_str(unsafe { text.get_unchecked(start..end) })
+        }
+    };
+}
@@ -0,0 +1,75 @@
+use core::fmt::{self, Display, Formatter};
+use core::str::from_utf8_unchecked;
+
+/// A character encoded by `encode_text_char`, which is stored inline, so escaping text character by character does not allocate. It can be used as a `str` or formatted with `Display`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EncodedChar {
+    bytes: [u8; 5],
+    length: u8,
+}
+
+impl EncodedChar {
+    #[inline]
+    fn from_escape(escape: &str) -> EncodedChar {
+        let mut bytes = [0u8; 5];
+
+        bytes[..escape.len()].copy_from_slice(escape.as_bytes());
+
+        EncodedChar {
+            bytes,
+            length: escape.len() as u8,
+        }
+    }
+
+    /// The encoded character as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        unsafe { from_utf8_unchecked(&self.bytes[..(self.length as usize)]) }
+    }
+}
+
+impl AsRef<str> for EncodedChar {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for EncodedChar {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Encode a character used as regular HTML text without allocating, for callers which escape text character by character, such as parsers and syntax highlighters.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+///
+/// ```
+/// assert_eq!("&lt;", html_escape::encode_text_char('<').as_str());
+/// assert_eq!("\u{E9}", html_escape::encode_text_char('\u{E9}').as_str());
+///
+/// assert_eq!("&amp;", format!("{}", html_escape::encode_text_char('&')));
+/// ```
+pub fn encode_text_char(c: char) -> EncodedChar {
+    match c {
+        '&' => EncodedChar::from_escape("&amp;"),
+        '<' => EncodedChar::from_escape("&lt;"),
+        '>' => EncodedChar::from_escape("&gt;"),
+        _ => {
+            let mut bytes = [0u8; 5];
+
+            let length = c.encode_utf8(&mut bytes).len();
+
+            EncodedChar {
+                bytes,
+                length: length as u8,
+            }
+        }
+    }
+}
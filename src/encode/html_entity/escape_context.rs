@@ -0,0 +1,40 @@
+#[cfg(any(feature = "std", feature = "futures-core"))]
+use alloc::vec::Vec;
+
+#[cfg(any(feature = "std", feature = "futures-core"))]
+use super::*;
+
+/// The context text is escaped for by `EscapingWriter` and the other adapters, which selects the encoder they use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EscapeContext {
+    /// Like `encode_text`.
+    Text,
+    /// Like `encode_double_quoted_attribute`.
+    DoubleQuotedAttribute,
+    /// Like `encode_single_quoted_attribute`.
+    SingleQuotedAttribute,
+    /// Like `encode_quoted_attribute`.
+    QuotedAttribute,
+    /// Like `encode_unquoted_attribute`.
+    UnquotedAttribute,
+    /// Like `encode_safe`.
+    Safe,
+}
+
+impl EscapeContext {
+    #[cfg(any(feature = "std", feature = "futures-core"))]
+    pub(super) fn encode_to_vec(self, text: &str, output: &mut Vec<u8>) {
+        match self {
+            EscapeContext::Text => encode_text_to_vec(text, output),
+            EscapeContext::DoubleQuotedAttribute => {
+                encode_double_quoted_attribute_to_vec(text, output)
+            }
+            EscapeContext::SingleQuotedAttribute => {
+                encode_single_quoted_attribute_to_vec(text, output)
+            }
+            EscapeContext::QuotedAttribute => encode_quoted_attribute_to_vec(text, output),
+            EscapeContext::UnquotedAttribute => encode_unquoted_attribute_to_vec(text, output),
+            EscapeContext::Safe => encode_safe_to_vec(text, output),
+        };
+    }
+}
@@ -0,0 +1,104 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use futures_core::Stream;
+
+use super::EscapeContext;
+
+/// A stream which yields the chunks of the inner stream escaped for the given context, so a handler which sends user content in chunks, such as server-sent events, can escape it with one adapter. The chunks can be of any type which can be borrowed as a `str`, such as `String`.
+///
+/// ```
+/// # use core::pin::Pin;
+/// # use core::task::{Context, Poll};
+/// use futures_core::Stream;
+///
+/// use html_escape::{EscapeContext, EscapeStream};
+///
+/// # struct Chunks(Vec<&'static str>);
+/// #
+/// # impl Stream for Chunks {
+/// #     type Item = &'static str;
+/// #
+/// #     fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<&'static str>> {
+/// #         Poll::Ready(if self.0.is_empty() { None } else { Some(self.0.remove(0)) })
+/// #     }
+/// # }
+/// #
+/// # let mut cx = Context::from_waker(core::task::Waker::noop());
+/// #
+/// let mut stream = EscapeStream::new(Chunks(vec!["<b>", "a & b"]), EscapeContext::Text);
+///
+/// assert_eq!(Poll::Ready(Some(String::from("&lt;b&gt;"))), Pin::new(&mut stream).poll_next(&mut cx));
+/// assert_eq!(Poll::Ready(Some(String::from("a &amp; b"))), Pin::new(&mut stream).poll_next(&mut cx));
+/// assert_eq!(Poll::Ready(None), Pin::new(&mut stream).poll_next(&mut cx));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EscapeStream<St> {
+    stream: St,
+    context: EscapeContext,
+}
+
+impl<St> EscapeStream<St> {
+    /// Create a stream which escapes the chunks of `stream` for `context`.
+    #[inline]
+    pub fn new(stream: St, context: EscapeContext) -> EscapeStream<St> {
+        EscapeStream {
+            stream,
+            context,
+        }
+    }
+
+    /// The context which the chunks are escaped for.
+    #[inline]
+    pub fn context(&self) -> EscapeContext {
+        self.context
+    }
+
+    /// A reference to the inner stream.
+    #[inline]
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// A mutable reference to the inner stream.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Return the inner stream.
+    #[inline]
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<T: AsRef<str>, St: Stream<Item = T> + Unpin> Stream for EscapeStream<St> {
+    type Item = String;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<String>> {
+        let this = self.get_mut();
+
+        match Pin::new(&mut this.stream).poll_next(cx) {
+            Poll::Ready(Some(chunk)) => {
+                let chunk = chunk.as_ref();
+
+                let mut v = Vec::with_capacity(chunk.len());
+
+                this.context.encode_to_vec(chunk, &mut v);
+
+                Poll::Ready(Some(unsafe { String::from_utf8_unchecked(v) }))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,143 @@
+use alloc::vec::Vec;
+
+use std::io::{self, Read};
+
+use super::EscapeContext;
+use crate::functions::{invalid_utf8, PendingChar};
+
+const CHUNK_SIZE: usize = 4096;
+
+/// A reader which escapes the text read from the inner reader for the given context, so a large file can be escaped while it is streamed instead of being loaded into memory.
+///
+/// The inner reader has to yield UTF-8, but a character may be split across its reads. An escape sequence is never split into it, and one which does not fit into the buffer of a `read` call is continued in the next call.
+///
+/// ```
+/// use std::io::Read;
+///
+/// use html_escape::{EscapeContext, EscapingReader};
+///
+/// let mut reader = EscapingReader::new("<b>caf\u{E9} & co</b>".as_bytes(), EscapeContext::Text);
+/// let mut s = String::new();
+///
+/// reader.read_to_string(&mut s).unwrap();
+///
+/// assert_eq!("&lt;b&gt;caf\u{E9} &amp; co&lt;/b&gt;", s);
+/// ```
+#[derive(Debug)]
+pub struct EscapingReader<R: Read> {
+    inner: R,
+    context: EscapeContext,
+    pending: PendingChar,
+    /// The escaped text which has not been read yet, from `position`.
+    output: Vec<u8>,
+    position: usize,
+    /// Whether the inner reader has yielded invalid UTF-8, which is reported once the text before it is read.
+    invalid: bool,
+}
+
+impl<R: Read> EscapingReader<R> {
+    /// Create a reader which reads from `inner` and escapes for `context`.
+    #[inline]
+    pub fn new(inner: R, context: EscapeContext) -> EscapingReader<R> {
+        EscapingReader {
+            inner,
+            context,
+            pending: PendingChar::default(),
+            output: Vec::new(),
+            position: 0,
+            invalid: false,
+        }
+    }
+
+    /// The context which the read text is escaped for.
+    #[inline]
+    pub fn context(&self) -> EscapeContext {
+        self.context
+    }
+
+    /// A reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner reader. Reading from it directly skips the text it yields.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Return the inner reader. The escaped text which has not been read yet is dropped.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read from the inner reader until there is escaped text to return or it ends.
+    fn fill(&mut self) -> Result<(), io::Error> {
+        if self.invalid {
+            return Err(invalid_utf8());
+        }
+
+        self.output.clear();
+        self.position = 0;
+
+        let mut chunk = [0u8; CHUNK_SIZE];
+
+        while self.output.is_empty() {
+            let length = self.inner.read(&mut chunk)?;
+
+            if length == 0 {
+                if !self.pending.is_empty() {
+                    return Err(invalid_utf8());
+                }
+
+                break;
+            }
+
+            let context = self.context;
+            let output = &mut self.output;
+
+            if self
+                .pending
+                .feed(&chunk[..length], |text| {
+                    context.encode_to_vec(text, output);
+
+                    Ok(())
+                })
+                .is_err()
+            {
+                self.invalid = true;
+
+                break;
+            }
+        }
+
+        if self.invalid && self.output.is_empty() {
+            return Err(invalid_utf8());
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for EscapingReader<R> {
+    /// Read the escaped text. Fail with an `io::Error` of the kind `InvalidData` if the inner reader does not yield UTF-8.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.position == self.output.len() {
+            self.fill()?;
+        }
+
+        let rest = &self.output[self.position..];
+        let length = rest.len().min(buf.len());
+
+        buf[..length].copy_from_slice(&rest[..length]);
+        self.position += length;
+
+        Ok(length)
+    }
+}
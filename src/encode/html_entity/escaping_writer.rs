@@ -0,0 +1,136 @@
+use std::io::{self, BufRead, Write};
+
+use super::*;
+use crate::functions::{copy_buffered, invalid_utf8, PendingChar};
+
+/// A writer which escapes everything written through it for the given context before passing it to the inner writer, so escaping can be combined with `io::copy` and serializers which write to a sink.
+///
+/// The written bytes have to be UTF-8, but a character may be split across `write` calls. Call `finish` at the end to make sure no incomplete character is left.
+///
+/// ```
+/// use std::io::Write;
+///
+/// use html_escape::{EscapeContext, EscapingWriter};
+///
+/// let mut writer = EscapingWriter::new(Vec::new(), EscapeContext::Text);
+///
+/// writer.write_all(b"<b>caf\xC3").unwrap();
+/// writer.write_all(b"\xA9 & co</b>").unwrap();
+///
+/// assert_eq!(b"&lt;b&gt;caf\xC3\xA9 &amp; co&lt;/b&gt;", writer.finish().unwrap().as_slice());
+/// ```
+#[derive(Debug)]
+pub struct EscapingWriter<W: Write> {
+    inner: W,
+    context: EscapeContext,
+    pending: PendingChar,
+}
+
+impl<W: Write> EscapingWriter<W> {
+    /// Create a writer which escapes for `context` and writes to `inner`.
+    #[inline]
+    pub fn new(inner: W, context: EscapeContext) -> EscapingWriter<W> {
+        EscapingWriter {
+            inner,
+            context,
+            pending: PendingChar::default(),
+        }
+    }
+
+    /// The context which the written text is escaped for.
+    #[inline]
+    pub fn context(&self) -> EscapeContext {
+        self.context
+    }
+
+    /// A reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner writer. Writing to it directly bypasses the escaping.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Flush the inner writer and return it. Fail with an `io::Error` of the kind `InvalidData` if the written bytes end with an incomplete character.
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        if !self.pending.is_empty() {
+            return Err(invalid_utf8());
+        }
+
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    fn escape(&mut self, text: &str) -> Result<(), io::Error> {
+        let output = &mut self.inner;
+
+        match self.context {
+            EscapeContext::Text => encode_text_to_writer(text, output),
+            EscapeContext::DoubleQuotedAttribute => {
+                encode_double_quoted_attribute_to_writer(text, output)
+            }
+            EscapeContext::SingleQuotedAttribute => {
+                encode_single_quoted_attribute_to_writer(text, output)
+            }
+            EscapeContext::QuotedAttribute => encode_quoted_attribute_to_writer(text, output),
+            EscapeContext::UnquotedAttribute => encode_unquoted_attribute_to_writer(text, output),
+            EscapeContext::Safe => encode_safe_to_writer(text, output),
+        }
+    }
+}
+
+impl<W: Write> Write for EscapingWriter<W> {
+    /// Escape and write the whole `buf`, except for an incomplete character at its end, which is held back until the next call. Fail with an `io::Error` of the kind `InvalidData` if `buf` is not UTF-8, in which case the text before the invalid bytes has already been written.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        let mut pending = self.pending;
+
+        let result = pending.feed(buf, |text| self.escape(text));
+
+        self.pending = pending;
+
+        result.map(|_| buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.inner.flush()
+    }
+}
+
+/// Escape everything read from `reader` for `context` and write it to `writer`, returning the number of bytes read, like `io::copy` does. No more than the buffer of `reader` is held in memory, and a character split across refills of the buffer is held back until it is complete, so large files can be escaped by wrapping them in a `BufReader`.
+///
+/// Fail with an `io::Error` of the kind `InvalidData` if the read bytes are not UTF-8, in which case the text before the invalid bytes has already been written.
+///
+/// ```
+/// use html_escape::EscapeContext;
+///
+/// let mut output = Vec::new();
+///
+/// let length = html_escape::encode_text_from_reader(
+///     "<b>caf\u{E9} & co</b>".as_bytes(),
+///     &mut output,
+///     EscapeContext::Text,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(17, length);
+/// assert_eq!("&lt;b&gt;caf\u{E9} &amp; co&lt;/b&gt;".as_bytes(), output.as_slice());
+/// ```
+pub fn encode_text_from_reader<R: BufRead, W: Write>(
+    reader: R,
+    writer: W,
+    context: EscapeContext,
+) -> Result<u64, io::Error> {
+    let mut writer = EscapingWriter::new(writer, context);
+
+    let length = copy_buffered(reader, &mut writer)?;
+
+    writer.finish()?;
+
+    Ok(length)
+}
@@ -0,0 +1,170 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::functions::*;
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+#[inline]
+fn is_percent_encoded(e: u8) -> bool {
+    matches!(
+        e,
+        0..=0x20
+            | b'"'
+            | b'\''
+            | b'<'
+            | b'>'
+            | b'\\'
+            | b'^'
+            | b'`'
+            | b'{'
+            | b'|'
+            | b'}'
+            | 0x7F..=0xFF
+    )
+}
+
+#[inline]
+fn write_href_escape_to_vec(e: u8, output: &mut Vec<u8>) {
+    if e == b'&' {
+        output.extend_from_slice(b"&amp;");
+    } else {
+        write_percent_to_vec(e, output);
+    }
+}
+
+#[inline]
+fn write_href_escape_to_sink<W: EncodeSink>(e: u8, output: &mut W) -> Result<(), W::Error> {
+    if e == b'&' {
+        output.write_str("&amp;")
+    } else {
+        write_percent_to_sink(e, output)
+    }
+}
+
+/// Encode a URL used in a quoted `href` or `src` attribute. Characters which are not allowed in URLs are percent-encoded first, and then the result is escaped for a quoted attribute.
+///
+/// Control characters, space, `"`, `'`, `<`, `>`, `\`, `^`, `` ` ``, `{`, `|`, `}` and every byte of non-ASCII characters are escaped to `%HH`. Existing percent-escapes are kept. `&` is escaped to `&amp;`.
+///
+/// The scheme of the URL is not checked, so `javascript:` URLs still need to be rejected by the caller.
+pub fn encode_href<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+    let mut e;
+
+    loop {
+        if p == text_length {
+            return Cow::from(text);
+        }
+
+        e = text_bytes[p];
+
+        if e == b'&' || is_percent_encoded(e) {
+            break;
+        }
+
+        p += 1;
+    }
+
+    let mut v = Vec::with_capacity(text_length + 4);
+
+    v.extend_from_slice(&text_bytes[..p]);
+
+    write_href_escape_to_vec(e, &mut v);
+
+    encode_href_to_vec(unsafe { from_utf8_unchecked(&text_bytes[(p + 1)..]) }, &mut v);
+
+    Cow::from(unsafe { String::from_utf8_unchecked(v) })
+}
+
+/// Write a URL used in a quoted `href` or `src` attribute to a mutable `String` reference and return the encoded string slice. Characters which are not allowed in URLs are percent-encoded first, and then the result is escaped for a quoted attribute.
+///
+/// Control characters, space, `"`, `'`, `<`, `>`, `\`, `^`, `` ` ``, `{`, `|`, `}` and every byte of non-ASCII characters are escaped to `%HH`. Existing percent-escapes are kept. `&` is escaped to `&amp;`.
+///
+/// The scheme of the URL is not checked, so `javascript:` URLs still need to be rejected by the caller.
+#[inline]
+pub fn encode_href_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(encode_href_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Write a URL used in a quoted `href` or `src` attribute to a mutable `Vec<u8>` reference and return the encoded data slice. Characters which are not allowed in URLs are percent-encoded first, and then the result is escaped for a quoted attribute.
+///
+/// Control characters, space, `"`, `'`, `<`, `>`, `\`, `^`, `` ` ``, `{`, `|`, `}` and every byte of non-ASCII characters are escaped to `%HH`. Existing percent-escapes are kept. `&` is escaped to `&amp;`.
+///
+/// The scheme of the URL is not checked, so `javascript:` URLs still need to be rejected by the caller.
+#[inline]
+pub fn encode_href_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    encode_href_to_sink(text, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write a URL used in a quoted `href` or `src` attribute to a writer. Characters which are not allowed in URLs are percent-encoded first, and then the result is escaped for a quoted attribute.
+///
+/// Control characters, space, `"`, `'`, `<`, `>`, `\`, `^`, `` ` ``, `{`, `|`, `}` and every byte of non-ASCII characters are escaped to `%HH`. Existing percent-escapes are kept. `&` is escaped to `&amp;`.
+///
+/// The scheme of the URL is not checked, so `javascript:` URLs still need to be rejected by the caller.
+#[inline]
+pub fn encode_href_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_href_to_sink(text, &mut IoSink(output))
+}
+
+/// Write a URL used in a quoted `href` or `src` attribute to a `fmt::Write`. Characters which are not allowed in URLs are percent-encoded first, and then the result is escaped for a quoted attribute.
+///
+/// Control characters, space, `"`, `'`, `<`, `>`, `\`, `^`, `` ` ``, `{`, `|`, `}` and every byte of non-ASCII characters are escaped to `%HH`. Existing percent-escapes are kept. `&` is escaped to `&amp;`.
+///
+/// The scheme of the URL is not checked, so `javascript:` URLs still need to be rejected by the caller.
+#[inline]
+pub fn encode_href_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_href_to_sink(text, &mut FmtSink(output))
+}
+
+/// Write a URL used in a quoted `href` or `src` attribute to an `EncodeSink`. Characters which are not allowed in URLs are percent-encoded first, and then the result is escaped for a quoted attribute.
+///
+/// Control characters, space, `"`, `'`, `<`, `>`, `\`, `^`, `` ` ``, `{`, `|`, `}` and every byte of non-ASCII characters are escaped to `%HH`. Existing percent-escapes are kept. `&` is escaped to `&amp;`.
+///
+/// The scheme of the URL is not checked, so `javascript:` URLs still need to be rejected by the caller.
+pub fn encode_href_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text_bytes = text.as_ref().as_bytes();
+
+    let mut start = 0;
+
+    for (p, e) in text_bytes.iter().copied().enumerate() {
+        if e == b'&' || is_percent_encoded(e) {
+            // every byte of a non-ASCII character is encoded, so the bytes in between are valid UTF-8
+            output.write_str(unsafe { from_utf8_unchecked(&text_bytes[start..p]) })?;
+            start = p + 1;
+            write_href_escape_to_sink(e, output)?;
+        }
+    }
+
+    output.write_str(unsafe { from_utf8_unchecked(&text_bytes[start..]) })
+}
@@ -0,0 +1,149 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::functions::*;
+use crate::utf8_width;
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+#[inline]
+fn is_js_literal(e: u8) -> bool {
+    !utf8_width::is_width_1(e)
+        || is_alphanumeric(e)
+        || matches!(e, b' ' | b',' | b'.' | b'-' | b'_')
+}
+
+#[inline]
+fn js_line_terminator(text_bytes: &[u8], p: usize) -> Option<&'static [u8]> {
+    if text_bytes[p] == 0xE2 {
+        match text_bytes.get((p + 1)..(p + 3)) {
+            Some(b"\x80\xA8") => Some(b"\\u2028"),
+            Some(b"\x80\xA9") => Some(b"\\u2029"),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// Encode text used in a JavaScript string literal inside an event handler attribute, such as `onclick="f('...')"`. The result is JavaScript-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Except for alphanumeric characters, space, `,`, `.`, `-` and `_`, all characters which are less than 128 are escaped to `\xHH`. `U+2028` and `U+2029` are escaped to `\u2028` and `\u2029`.
+pub fn encode_js_in_attribute<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+
+    loop {
+        if p == text_length {
+            return Cow::from(text);
+        }
+
+        let e = text_bytes[p];
+
+        if !is_js_literal(e) || js_line_terminator(text_bytes, p).is_some() {
+            break;
+        }
+
+        p += 1;
+    }
+
+    let mut v = Vec::with_capacity(text_length + 4);
+
+    v.extend_from_slice(&text_bytes[..p]);
+
+    encode_js_in_attribute_to_vec(unsafe { from_utf8_unchecked(&text_bytes[p..]) }, &mut v);
+
+    Cow::from(unsafe { String::from_utf8_unchecked(v) })
+}
+
+/// Write text used in a JavaScript string literal inside an event handler attribute to a mutable `String` reference and return the encoded string slice. The result is JavaScript-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Except for alphanumeric characters, space, `,`, `.`, `-` and `_`, all characters which are less than 128 are escaped to `\xHH`. `U+2028` and `U+2029` are escaped to `\u2028` and `\u2029`.
+#[inline]
+pub fn encode_js_in_attribute_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(encode_js_in_attribute_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Write text used in a JavaScript string literal inside an event handler attribute to a mutable `Vec<u8>` reference and return the encoded data slice. The result is JavaScript-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Except for alphanumeric characters, space, `,`, `.`, `-` and `_`, all characters which are less than 128 are escaped to `\xHH`. `U+2028` and `U+2029` are escaped to `\u2028` and `\u2029`.
+#[inline]
+pub fn encode_js_in_attribute_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    encode_js_in_attribute_to_sink(text, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write text used in a JavaScript string literal inside an event handler attribute to a writer. The result is JavaScript-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Except for alphanumeric characters, space, `,`, `.`, `-` and `_`, all characters which are less than 128 are escaped to `\xHH`. `U+2028` and `U+2029` are escaped to `\u2028` and `\u2029`.
+#[inline]
+pub fn encode_js_in_attribute_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_js_in_attribute_to_sink(text, &mut IoSink(output))
+}
+
+/// Write text used in a JavaScript string literal inside an event handler attribute to a `fmt::Write`. The result is JavaScript-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Except for alphanumeric characters, space, `,`, `.`, `-` and `_`, all characters which are less than 128 are escaped to `\xHH`. `U+2028` and `U+2029` are escaped to `\u2028` and `\u2029`.
+#[inline]
+pub fn encode_js_in_attribute_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_js_in_attribute_to_sink(text, &mut FmtSink(output))
+}
+
+/// Write text used in a JavaScript string literal inside an event handler attribute to an `EncodeSink`. The result is JavaScript-escaped and then safe for both single-quoted and double-quoted attributes.
+///
+/// Except for alphanumeric characters, space, `,`, `.`, `-` and `_`, all characters which are less than 128 are escaped to `\xHH`. `U+2028` and `U+2029` are escaped to `\u2028` and `\u2029`.
+pub fn encode_js_in_attribute_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while p < text_length {
+        let e = text_bytes[p];
+
+        if !is_js_literal(e) {
+            output.write_str(unsafe { text.get_unchecked(start..p) })?;
+            start = p + 1;
+            write_js_hex_to_sink(e, output)?;
+        } else if let Some(escaped) = js_line_terminator(text_bytes, p) {
+            output.write_str(unsafe { text.get_unchecked(start..p) })?;
+            start = p + 3;
+            output.write_str(unsafe { from_utf8_unchecked(escaped) })?;
+        }
+
+        p += 1;
+    }
+
+    output.write_str(unsafe { text.get_unchecked(start..p) })
+}
@@ -0,0 +1,34 @@
+/// How the `*_with_line_endings` encoders handle carriage returns.
+///
+/// Browsers normalize `\r\n` and `\r` to `\n` when parsing a document, so a `<textarea>` default value containing carriage returns does not round-trip byte-identically unless they are normalized or escaped beforehand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineEndingPolicy {
+    /// Keep the line endings as they are, like the encoders without a policy do.
+    Keep,
+    /// Normalize `\r\n` and `\r` to `\n`, like browsers do.
+    Normalize,
+    /// Escape carriage returns to `&#x0D;`, which browsers do not normalize.
+    Escape,
+}
+
+impl LineEndingPolicy {
+    /// The replacement of the byte at `p` if it is a carriage return which needs to be handled.
+    #[inline]
+    pub(crate) fn escape(self, text_bytes: &[u8], p: usize) -> Option<&'static [u8]> {
+        if text_bytes[p] != b'\r' {
+            return None;
+        }
+
+        match self {
+            LineEndingPolicy::Keep => None,
+            LineEndingPolicy::Normalize => {
+                if text_bytes.get(p + 1) == Some(&b'\n') {
+                    Some(b"")
+                } else {
+                    Some(b"\n")
+                }
+            }
+            LineEndingPolicy::Escape => Some(b"&#x0D;"),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use core::fmt;
+use core::str::{from_utf8, from_utf8_unchecked};
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use super::*;
+use crate::functions::for_each_utf8_segment;
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+/// Encode text used as content of HTML text elements from given bytes, such as a body of an HTTP request, without validating them first. Valid UTF-8 is escaped like `encode_text` does, and each invalid sequence is replaced with U+FFFD REPLACEMENT CHARACTER, like `String::from_utf8_lossy` does, so the result is always valid UTF-8.
+///
+/// ```
+/// assert_eq!(
+///     "&lt;b&gt;caf\u{FFFD} &amp; co".as_bytes(),
+///     html_escape::encode_text_lossy(b"<b>caf\xE9 & co").as_ref()
+/// );
+/// ```
+pub fn encode_text_lossy(text_bytes: &[u8]) -> Cow<'_, [u8]> {
+    match from_utf8(text_bytes) {
+        Ok(text) => match encode_text(text) {
+            Cow::Borrowed(text) => Cow::from(text.as_bytes()),
+            Cow::Owned(text) => Cow::from(text.into_bytes()),
+        },
+        Err(_) => {
+            let mut v = Vec::with_capacity(text_bytes.len() + 5);
+
+            encode_text_lossy_to_vec(text_bytes, &mut v);
+
+            Cow::from(v)
+        }
+    }
+}
+
+/// Write text used as content of HTML text elements from given bytes to a mutable `String` reference and return the encoded string slice, replacing each invalid UTF-8 sequence with U+FFFD REPLACEMENT CHARACTER.
+#[inline]
+pub fn encode_text_lossy_to_string<'a>(text_bytes: &[u8], output: &'a mut String) -> &'a str {
+    unsafe { from_utf8_unchecked(encode_text_lossy_to_vec(text_bytes, output.as_mut_vec())) }
+}
+
+/// Write text used as content of HTML text elements from given bytes to a mutable `Vec<u8>` reference and return the encoded data slice, replacing each invalid UTF-8 sequence with U+FFFD REPLACEMENT CHARACTER.
+#[inline]
+pub fn encode_text_lossy_to_vec<'a>(text_bytes: &[u8], output: &'a mut Vec<u8>) -> &'a [u8] {
+    output.reserve(text_bytes.len());
+
+    let current_length = output.len();
+
+    encode_text_lossy_to_sink(text_bytes, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write text used as content of HTML text elements from given bytes to a writer, replacing each invalid UTF-8 sequence with U+FFFD REPLACEMENT CHARACTER.
+#[inline]
+pub fn encode_text_lossy_to_writer<W: Write>(
+    text_bytes: &[u8],
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_text_lossy_to_sink(text_bytes, &mut IoSink(output))
+}
+
+/// Write text used as content of HTML text elements from given bytes to a `fmt::Write`, replacing each invalid UTF-8 sequence with U+FFFD REPLACEMENT CHARACTER.
+#[inline]
+pub fn encode_text_lossy_to_fmt_writer<W: fmt::Write>(
+    text_bytes: &[u8],
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_text_lossy_to_sink(text_bytes, &mut FmtSink(output))
+}
+
+/// Write text used as content of HTML text elements from given bytes to an `EncodeSink`, replacing each invalid UTF-8 sequence with U+FFFD REPLACEMENT CHARACTER.
+pub fn encode_text_lossy_to_sink<W: EncodeSink>(
+    text_bytes: &[u8],
+    output: &mut W,
+) -> Result<(), W::Error> {
+    for_each_utf8_segment(text_bytes, |segment, invalid| {
+        encode_text_to_sink(unsafe { from_utf8_unchecked(segment) }, output)?;
+
+        if invalid {
+            output.write_str("\u{FFFD}")?;
+        }
+
+        Ok(())
+    })
+}
@@ -1,5 +1,37 @@
+#[macro_use]
+mod encode_impl;
+
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+mod async_escaping_writer;
+mod control_character_policy;
+mod css_in_attribute;
+mod css_url;
+mod encoded_char;
+mod escape_context;
+#[cfg(feature = "futures-core")]
+mod escape_stream;
+#[cfg(feature = "std")]
+mod escaping_reader;
+#[cfg(feature = "std")]
+mod escaping_writer;
+mod href;
+mod js_in_attribute;
+mod line_ending_policy;
+mod lossy;
+mod numeric_entity_format;
+#[cfg(feature = "std")]
+mod os_str;
+mod preserving_entities;
+mod srcdoc;
+mod srcset;
+mod tables;
+mod text_to_html;
+mod unicode_escapes;
 mod unquoted_attribute;
+mod utf16;
+mod xml;
 
+use core::fmt;
 use core::str::from_utf8_unchecked;
 
 use alloc::borrow::Cow;
@@ -9,51 +41,43 @@ use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::io::{self, Write};
 
-pub use unquoted_attribute::*;
+use crate::buffer_too_small::SliceWriter;
+use crate::functions::*;
+use crate::{BomPolicy, BufferTooSmall, EncodeSink, FmtSink};
 
-macro_rules! escape_impl {
-    (@inner [$dollar:tt] $name:ident; $($l:expr => $r:expr),+ $(,)*) => {
-        macro_rules! $name {
-            ($dollar e:expr) => {
-                match $dollar e {
-                    $($l => break $r,)+
-                    _ => (),
-                }
-            };
-            (vec $dollar e:expr, $dollar v:ident, $dollar b:ident, $dollar start:ident, $dollar end:ident) => {
-                match $dollar e {
-                    $($l => {
-                        $dollar v.extend_from_slice(&$dollar b[$dollar start..$dollar end]);
-                        $dollar start = $dollar end + 1;
-                        $dollar v.extend_from_slice($r);
-                    })+
-                    _ => (),
-                }
-
-                $dollar end += 1;
-            };
-            (writer $dollar e:expr, $dollar w:ident, $dollar b:ident, $dollar start:ident, $dollar end:ident) => {
-                match $dollar e {
-                    $($l => {
-                        $dollar w.write_all(&$dollar b[$dollar start..$dollar end])?;
-                        $dollar start = $dollar end + 1;
-                        $dollar w.write_all($r)?;
-                    })+
-                    _ => (),
-                }
-
-                $dollar end += 1;
-            };
-        }
-    };
-    ($name:ident; $($l:expr => $r:expr),+ $(,)*) => {
-        escape_impl! {
-            @inner [$]
-            $name;
-            $($l => $r.as_ref(),)*
-        }
-    };
-}
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+use tables::*;
+
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+pub use async_escaping_writer::*;
+pub use control_character_policy::*;
+pub use css_in_attribute::*;
+pub use css_url::*;
+pub use encoded_char::*;
+pub use escape_context::*;
+#[cfg(feature = "futures-core")]
+pub use escape_stream::*;
+#[cfg(feature = "std")]
+pub use escaping_reader::*;
+#[cfg(feature = "std")]
+pub use escaping_writer::*;
+pub use href::*;
+pub use js_in_attribute::*;
+pub use line_ending_policy::*;
+pub use lossy::*;
+pub use numeric_entity_format::*;
+#[cfg(feature = "std")]
+pub use os_str::*;
+pub use preserving_entities::*;
+pub use srcdoc::*;
+pub use srcset::*;
+pub use text_to_html::*;
+pub use unicode_escapes::*;
+pub use unquoted_attribute::*;
+pub use utf16::*;
+pub use xml::*;
 
 escape_impl! {
     escape_text_minimal;
@@ -94,103 +118,66 @@ escape_impl! {
 }
 
 escape_impl! {
-    escape_safe;
+    escape_double_quote_backtick;
     b'&' => b"&amp;",
     b'<' => b"&lt;",
     b'>' => b"&gt;",
     b'"' => b"&quot;",
-    b'\'' => b"&#x27;",
-    b'/' => b"&#x2F;",
+    b'`' => b"&#x60;",
 }
 
-macro_rules! encode_impl {
-    ($(#[$attr: meta])* $escape_macro:ident; $(#[$encode_attr: meta])* $encode_name: ident; $(#[$encode_to_string_attr: meta])* $encode_to_string_name: ident; $(#[$encode_to_vec_attr: meta])* $encode_to_vec_name: ident; $(#[$encode_to_writer_attr: meta])* $encode_to_writer_name: ident $(;)*) => {
-        $(#[$encode_attr])*
-        ///
-        $(#[$attr])*
-        #[inline]
-        pub fn $encode_name<S: ?Sized + AsRef<str>>(text: &S) -> Cow<str> {
-            let text = text.as_ref();
-            let text_bytes = text.as_bytes();
-            let text_length = text_bytes.len();
-
-            let mut p = 0;
-            let mut e;
-
-            let first = loop {
-                if p == text_length {
-                    return Cow::from(text);
-                }
-
-                e = text_bytes[p];
-
-                $escape_macro!(e);
-
-                p += 1;
-            };
-
-            let mut v = Vec::with_capacity(text_length + 5);
-
-            v.extend_from_slice(&text_bytes[..p]);
-            v.extend_from_slice(first);
-
-            $encode_to_vec_name(unsafe { from_utf8_unchecked(&text_bytes[(p + 1)..]) }, &mut v);
-
-            Cow::from(unsafe { String::from_utf8_unchecked(v) })
-        }
-
-        $(#[$encode_to_string_attr])*
-        ///
-        $(#[$attr])*
-        #[inline]
-        pub fn $encode_to_string_name<S: AsRef<str>>(text: S, output: &mut String) -> &str {
-            unsafe { from_utf8_unchecked($encode_to_vec_name(text, output.as_mut_vec())) }
-        }
-
-        $(#[$encode_to_vec_attr])*
-        ///
-        $(#[$attr])*
-        #[inline]
-        pub fn $encode_to_vec_name<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
-            let text = text.as_ref();
-            let text_bytes = text.as_bytes();
-            let text_length = text_bytes.len();
-
-            output.reserve(text_length);
-
-            let current_length = output.len();
-
-            let mut start = 0;
-            let mut end = 0;
-
-            for e in text_bytes.iter().copied() {
-                $escape_macro!(vec e, output, text_bytes, start, end);
-            }
-
-            output.extend_from_slice(&text_bytes[start..end]);
+escape_impl! {
+    escape_single_quote_backtick;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'>' => b"&gt;",
+    b'\'' => b"&#x27;",
+    b'`' => b"&#x60;",
+}
 
-            &output[current_length..]
-        }
+escape_impl! {
+    escape_quote_backtick;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'>' => b"&gt;",
+    b'"' => b"&quot;",
+    b'\'' => b"&#x27;",
+    b'`' => b"&#x60;",
+}
 
-        #[cfg(feature = "std")]
-        $(#[$encode_to_writer_attr])*
-        ///
-        $(#[$attr])*
-        #[inline]
-        pub fn $encode_to_writer_name<S: AsRef<str>, W: Write>(text: S, output: &mut W) -> Result<(), io::Error> {
-            let text = text.as_ref();
-            let text_bytes = text.as_bytes();
+escape_impl! {
+    escape_text_apostrophe;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'>' => b"&gt;",
+    b'\'' => b"&#39;",
+}
 
-            let mut start = 0;
-            let mut end = 0;
+escape_impl! {
+    escape_quote_decimal;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'>' => b"&gt;",
+    b'"' => b"&quot;",
+    b'\'' => b"&#39;",
+}
 
-            for e in text_bytes.iter().copied() {
-                $escape_macro!(writer e, output, text_bytes, start, end);
-            }
+escape_impl! {
+    escape_quote_any;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'"' => b"&quot;",
+    b'\'' => b"&#x27;",
+}
 
-            output.write_all(&text_bytes[start..end])
-        }
-    };
+escape_impl! {
+    escape_safe;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'>' => b"&gt;",
+    b'"' => b"&quot;",
+    b'\'' => b"&#x27;",
+    b'/' => b"&#x2F;",
 }
 
 encode_impl! {
@@ -199,14 +186,20 @@ encode_impl! {
     /// * `&` => `&amp;`
     /// * `<` => `&lt;`
     escape_text_minimal;
-    /// Encode text used as regular HTML text.
+    /// Encode text used as regular HTML text with the smallest escape set the HTML specification allows for text nodes.
     encode_text_minimal;
-    /// Write text used as regular HTML text to a mutable `String` reference and return the encoded string slice.
+    /// Write text used as regular HTML text with the smallest escape set the HTML specification allows for text nodes to a mutable `String` reference and return the encoded string slice.
     encode_text_minimal_to_string;
-    /// Write text used as regular HTML text to a mutable `Vec<u8>` reference and return the encoded data slice.
+    /// Write text used as regular HTML text with the smallest escape set the HTML specification allows for text nodes to a mutable `Vec<u8>` reference and return the encoded data slice.
     encode_text_minimal_to_vec;
-    /// Write text used as regular HTML text to a writer.
+    /// Write text used as regular HTML text with the smallest escape set the HTML specification allows for text nodes to a writer.
     encode_text_minimal_to_writer;
+    /// Write text used as regular HTML text with the smallest escape set the HTML specification allows for text nodes to a `fmt::Write`.
+    encode_text_minimal_to_fmt_writer;
+    /// Write text used as regular HTML text with the smallest escape set the HTML specification allows for text nodes to a byte slice and return the length of the encoded data.
+    encode_text_minimal_to_slice;
+    /// Write text used as regular HTML text with the smallest escape set the HTML specification allows for text nodes to an `EncodeSink`.
+    encode_text_minimal_to_sink;
 }
 
 encode_impl! {
@@ -224,6 +217,12 @@ encode_impl! {
     encode_text_to_vec;
     /// Write text used as regular HTML text to a writer.
     encode_text_to_writer;
+    /// Write text used as regular HTML text to a `fmt::Write`.
+    encode_text_to_fmt_writer;
+    /// Write text used as regular HTML text to a byte slice and return the length of the encoded data.
+    encode_text_to_slice;
+    /// Write text used as regular HTML text to an `EncodeSink`.
+    encode_text_to_sink;
 }
 
 encode_impl! {
@@ -242,6 +241,12 @@ encode_impl! {
     encode_double_quoted_attribute_to_vec;
     /// Write text used in a double-quoted attribute to a writer.
     encode_double_quoted_attribute_to_writer;
+    /// Write text used in a double-quoted attribute to a `fmt::Write`.
+    encode_double_quoted_attribute_to_fmt_writer;
+    /// Write text used in a double-quoted attribute to a byte slice and return the length of the encoded data.
+    encode_double_quoted_attribute_to_slice;
+    /// Write text used in a double-quoted attribute to an `EncodeSink`.
+    encode_double_quoted_attribute_to_sink;
 }
 
 encode_impl! {
@@ -260,6 +265,12 @@ encode_impl! {
     encode_single_quoted_attribute_to_vec;
     /// Write text used in a single-quoted attribute to a writer.
     encode_single_quoted_attribute_to_writer;
+    /// Write text used in a single-quoted attribute to a `fmt::Write`.
+    encode_single_quoted_attribute_to_fmt_writer;
+    /// Write text used in a single-quoted attribute to a byte slice and return the length of the encoded data.
+    encode_single_quoted_attribute_to_slice;
+    /// Write text used in a single-quoted attribute to an `EncodeSink`.
+    encode_single_quoted_attribute_to_sink;
 }
 
 encode_impl! {
@@ -279,6 +290,167 @@ encode_impl! {
     encode_quoted_attribute_to_vec;
     /// Write text used in a quoted attribute to a writer.
     encode_quoted_attribute_to_writer;
+    /// Write text used in a quoted attribute to a `fmt::Write`.
+    encode_quoted_attribute_to_fmt_writer;
+    /// Write text used in a quoted attribute to a byte slice and return the length of the encoded data.
+    encode_quoted_attribute_to_slice;
+    /// Write text used in a quoted attribute to an `EncodeSink`.
+    encode_quoted_attribute_to_sink;
+}
+
+encode_impl! {
+    /// Only the characters which matter in an attribute value quoted by either `"` or `'` are escaped, so the result is safe whichever quote character the surrounding template uses:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    escape_quote_any;
+    /// Encode text used in an attribute quoted by either `"` or `'`.
+    encode_quoted_attribute_any;
+    /// Write text used in an attribute quoted by either `"` or `'` to a mutable `String` reference and return the encoded string slice.
+    encode_quoted_attribute_any_to_string;
+    /// Write text used in an attribute quoted by either `"` or `'` to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_quoted_attribute_any_to_vec;
+    /// Write text used in an attribute quoted by either `"` or `'` to a writer.
+    encode_quoted_attribute_any_to_writer;
+    /// Write text used in an attribute quoted by either `"` or `'` to a `fmt::Write`.
+    encode_quoted_attribute_any_to_fmt_writer;
+    /// Write text used in an attribute quoted by either `"` or `'` to a byte slice and return the length of the encoded data.
+    encode_quoted_attribute_any_to_slice;
+    /// Write text used in an attribute quoted by either `"` or `'` to an `EncodeSink`.
+    encode_quoted_attribute_any_to_sink;
+}
+
+encode_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `'` => `&#39;`
+    escape_text_apostrophe;
+    /// Encode text used as regular HTML text, also escaping apostrophes.
+    encode_text_apostrophe;
+    /// Write text used as regular HTML text to a mutable `String` reference and return the encoded string slice, also escaping apostrophes.
+    encode_text_apostrophe_to_string;
+    /// Write text used as regular HTML text to a mutable `Vec<u8>` reference and return the encoded data slice, also escaping apostrophes.
+    encode_text_apostrophe_to_vec;
+    /// Write text used as regular HTML text to a writer, also escaping apostrophes.
+    encode_text_apostrophe_to_writer;
+    /// Write text used as regular HTML text to a `fmt::Write`, also escaping apostrophes.
+    encode_text_apostrophe_to_fmt_writer;
+    /// Write text used as regular HTML text to a byte slice and return the length of the encoded data, also escaping apostrophes.
+    encode_text_apostrophe_to_slice;
+    /// Write text used as regular HTML text to an `EncodeSink`, also escaping apostrophes.
+    encode_text_apostrophe_to_sink;
+}
+
+encode_impl! {
+    /// The following characters are escaped, so the result can also be used in a single-quoted attribute or a context with unknown quoting:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#39;`
+    escape_quote_decimal;
+    /// Encode text used in a quoted attribute, escaping apostrophes as `&#39;`.
+    encode_quoted_attribute_apostrophe;
+    /// Write text used in a quoted attribute to a mutable `String` reference and return the encoded string slice, escaping apostrophes as `&#39;`.
+    encode_quoted_attribute_apostrophe_to_string;
+    /// Write text used in a quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, escaping apostrophes as `&#39;`.
+    encode_quoted_attribute_apostrophe_to_vec;
+    /// Write text used in a quoted attribute to a writer, escaping apostrophes as `&#39;`.
+    encode_quoted_attribute_apostrophe_to_writer;
+    /// Write text used in a quoted attribute to a `fmt::Write`, escaping apostrophes as `&#39;`.
+    encode_quoted_attribute_apostrophe_to_fmt_writer;
+    /// Write text used in a quoted attribute to a byte slice and return the length of the encoded data, escaping apostrophes as `&#39;`.
+    encode_quoted_attribute_apostrophe_to_slice;
+    /// Write text used in a quoted attribute to an `EncodeSink`, escaping apostrophes as `&#39;`.
+    encode_quoted_attribute_apostrophe_to_sink;
+}
+
+encode_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `` ` `` => `&#x60;`
+    ///
+    /// Old versions of Internet Explorer treat the backtick as an attribute value delimiter.
+    escape_double_quote_backtick;
+    /// Encode text used in a double-quoted attribute, also escaping backticks.
+    encode_double_quoted_attribute_legacy;
+    /// Write text used in a double-quoted attribute to a mutable `String` reference and return the encoded string slice, also escaping backticks.
+    encode_double_quoted_attribute_legacy_to_string;
+    /// Write text used in a double-quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, also escaping backticks.
+    encode_double_quoted_attribute_legacy_to_vec;
+    /// Write text used in a double-quoted attribute to a writer, also escaping backticks.
+    encode_double_quoted_attribute_legacy_to_writer;
+    /// Write text used in a double-quoted attribute to a `fmt::Write`, also escaping backticks.
+    encode_double_quoted_attribute_legacy_to_fmt_writer;
+    /// Write text used in a double-quoted attribute to a byte slice and return the length of the encoded data, also escaping backticks.
+    encode_double_quoted_attribute_legacy_to_slice;
+    /// Write text used in a double-quoted attribute to an `EncodeSink`, also escaping backticks.
+    encode_double_quoted_attribute_legacy_to_sink;
+}
+
+encode_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `'` => `&#x27;`
+    /// * `` ` `` => `&#x60;`
+    ///
+    /// Old versions of Internet Explorer treat the backtick as an attribute value delimiter.
+    escape_single_quote_backtick;
+    /// Encode text used in a single-quoted attribute, also escaping backticks.
+    encode_single_quoted_attribute_legacy;
+    /// Write text used in a single-quoted attribute to a mutable `String` reference and return the encoded string slice, also escaping backticks.
+    encode_single_quoted_attribute_legacy_to_string;
+    /// Write text used in a single-quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, also escaping backticks.
+    encode_single_quoted_attribute_legacy_to_vec;
+    /// Write text used in a single-quoted attribute to a writer, also escaping backticks.
+    encode_single_quoted_attribute_legacy_to_writer;
+    /// Write text used in a single-quoted attribute to a `fmt::Write`, also escaping backticks.
+    encode_single_quoted_attribute_legacy_to_fmt_writer;
+    /// Write text used in a single-quoted attribute to a byte slice and return the length of the encoded data, also escaping backticks.
+    encode_single_quoted_attribute_legacy_to_slice;
+    /// Write text used in a single-quoted attribute to an `EncodeSink`, also escaping backticks.
+    encode_single_quoted_attribute_legacy_to_sink;
+}
+
+encode_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    /// * `` ` `` => `&#x60;`
+    ///
+    /// Old versions of Internet Explorer treat the backtick as an attribute value delimiter.
+    escape_quote_backtick;
+    /// Encode text used in a quoted attribute, also escaping backticks.
+    encode_quoted_attribute_legacy;
+    /// Write text used in a quoted attribute to a mutable `String` reference and return the encoded string slice, also escaping backticks.
+    encode_quoted_attribute_legacy_to_string;
+    /// Write text used in a quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, also escaping backticks.
+    encode_quoted_attribute_legacy_to_vec;
+    /// Write text used in a quoted attribute to a writer, also escaping backticks.
+    encode_quoted_attribute_legacy_to_writer;
+    /// Write text used in a quoted attribute to a `fmt::Write`, also escaping backticks.
+    encode_quoted_attribute_legacy_to_fmt_writer;
+    /// Write text used in a quoted attribute to a byte slice and return the length of the encoded data, also escaping backticks.
+    encode_quoted_attribute_legacy_to_slice;
+    /// Write text used in a quoted attribute to an `EncodeSink`, also escaping backticks.
+    encode_quoted_attribute_legacy_to_sink;
 }
 
 encode_impl! {
@@ -299,4 +471,628 @@ encode_impl! {
     encode_safe_to_vec;
     /// Encode text to prevent special characters functioning and write it to a writer.
     encode_safe_to_writer;
+    /// Encode text to prevent special characters functioning and write it to a `fmt::Write`.
+    encode_safe_to_fmt_writer;
+    /// Encode text to prevent special characters functioning and write it to a byte slice and return the length of the encoded data.
+    encode_safe_to_slice;
+    /// Encode text to prevent special characters functioning and write it to an `EncodeSink`.
+    encode_safe_to_sink;
+}
+
+encode_with_control_policy_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * C0 control characters other than tab, line feed, form feed and carriage return => handled according to the policy
+    escape_text;
+    /// Encode text used as regular HTML text, handling invalid control characters according to the given policy.
+    encode_text_with_control_policy;
+    /// Write text used as regular HTML text to a mutable `String` reference and return the encoded string slice, handling invalid control characters according to the given policy.
+    encode_text_with_control_policy_to_string;
+    /// Write text used as regular HTML text to a mutable `Vec<u8>` reference and return the encoded data slice, handling invalid control characters according to the given policy.
+    encode_text_with_control_policy_to_vec;
+    /// Write text used as regular HTML text to a writer, handling invalid control characters according to the given policy.
+    encode_text_with_control_policy_to_writer;
+    /// Write text used as regular HTML text to a `fmt::Write`, handling invalid control characters according to the given policy.
+    encode_text_with_control_policy_to_fmt_writer;
+    /// Write text used as regular HTML text to a byte slice and return the length of the encoded data, handling invalid control characters according to the given policy.
+    encode_text_with_control_policy_to_slice;
+    /// Write text used as regular HTML text to an `EncodeSink`, handling invalid control characters according to the given policy.
+    encode_text_with_control_policy_to_sink;
+}
+
+encode_with_control_policy_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * C0 control characters other than tab, line feed, form feed and carriage return => handled according to the policy
+    escape_double_quote;
+    /// Encode text used in a double-quoted attribute, handling invalid control characters according to the given policy.
+    encode_double_quoted_attribute_with_control_policy;
+    /// Write text used in a double-quoted attribute to a mutable `String` reference and return the encoded string slice, handling invalid control characters according to the given policy.
+    encode_double_quoted_attribute_with_control_policy_to_string;
+    /// Write text used in a double-quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, handling invalid control characters according to the given policy.
+    encode_double_quoted_attribute_with_control_policy_to_vec;
+    /// Write text used in a double-quoted attribute to a writer, handling invalid control characters according to the given policy.
+    encode_double_quoted_attribute_with_control_policy_to_writer;
+    /// Write text used in a double-quoted attribute to a `fmt::Write`, handling invalid control characters according to the given policy.
+    encode_double_quoted_attribute_with_control_policy_to_fmt_writer;
+    /// Write text used in a double-quoted attribute to a byte slice and return the length of the encoded data, handling invalid control characters according to the given policy.
+    encode_double_quoted_attribute_with_control_policy_to_slice;
+    /// Write text used in a double-quoted attribute to an `EncodeSink`, handling invalid control characters according to the given policy.
+    encode_double_quoted_attribute_with_control_policy_to_sink;
+}
+
+encode_with_control_policy_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `'` => `&#x27;`
+    /// * C0 control characters other than tab, line feed, form feed and carriage return => handled according to the policy
+    escape_single_quote;
+    /// Encode text used in a single-quoted attribute, handling invalid control characters according to the given policy.
+    encode_single_quoted_attribute_with_control_policy;
+    /// Write text used in a single-quoted attribute to a mutable `String` reference and return the encoded string slice, handling invalid control characters according to the given policy.
+    encode_single_quoted_attribute_with_control_policy_to_string;
+    /// Write text used in a single-quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, handling invalid control characters according to the given policy.
+    encode_single_quoted_attribute_with_control_policy_to_vec;
+    /// Write text used in a single-quoted attribute to a writer, handling invalid control characters according to the given policy.
+    encode_single_quoted_attribute_with_control_policy_to_writer;
+    /// Write text used in a single-quoted attribute to a `fmt::Write`, handling invalid control characters according to the given policy.
+    encode_single_quoted_attribute_with_control_policy_to_fmt_writer;
+    /// Write text used in a single-quoted attribute to a byte slice and return the length of the encoded data, handling invalid control characters according to the given policy.
+    encode_single_quoted_attribute_with_control_policy_to_slice;
+    /// Write text used in a single-quoted attribute to an `EncodeSink`, handling invalid control characters according to the given policy.
+    encode_single_quoted_attribute_with_control_policy_to_sink;
+}
+
+encode_with_control_policy_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    /// * C0 control characters other than tab, line feed, form feed and carriage return => handled according to the policy
+    escape_quote;
+    /// Encode text used in a quoted attribute, handling invalid control characters according to the given policy.
+    encode_quoted_attribute_with_control_policy;
+    /// Write text used in a quoted attribute to a mutable `String` reference and return the encoded string slice, handling invalid control characters according to the given policy.
+    encode_quoted_attribute_with_control_policy_to_string;
+    /// Write text used in a quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, handling invalid control characters according to the given policy.
+    encode_quoted_attribute_with_control_policy_to_vec;
+    /// Write text used in a quoted attribute to a writer, handling invalid control characters according to the given policy.
+    encode_quoted_attribute_with_control_policy_to_writer;
+    /// Write text used in a quoted attribute to a `fmt::Write`, handling invalid control characters according to the given policy.
+    encode_quoted_attribute_with_control_policy_to_fmt_writer;
+    /// Write text used in a quoted attribute to a byte slice and return the length of the encoded data, handling invalid control characters according to the given policy.
+    encode_quoted_attribute_with_control_policy_to_slice;
+    /// Write text used in a quoted attribute to an `EncodeSink`, handling invalid control characters according to the given policy.
+    encode_quoted_attribute_with_control_policy_to_sink;
+}
+
+encode_with_line_ending_policy_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    ///
+    /// Carriage returns are kept, normalized to `\n` or escaped to `&#x0D;` according to the given policy.
+    escape_text;
+    /// Encode text used as regular HTML text, handling line endings according to the given policy.
+    encode_text_with_line_endings;
+    /// Write text used as regular HTML text to a mutable `String` reference and return the encoded string slice, handling line endings according to the given policy.
+    encode_text_with_line_endings_to_string;
+    /// Write text used as regular HTML text to a mutable `Vec<u8>` reference and return the encoded data slice, handling line endings according to the given policy.
+    encode_text_with_line_endings_to_vec;
+    /// Write text used as regular HTML text to a writer, handling line endings according to the given policy.
+    encode_text_with_line_endings_to_writer;
+    /// Write text used as regular HTML text to a `fmt::Write`, handling line endings according to the given policy.
+    encode_text_with_line_endings_to_fmt_writer;
+    /// Write text used as regular HTML text to an `EncodeSink`, handling line endings according to the given policy.
+    encode_text_with_line_endings_to_sink;
+}
+
+encode_with_unicode_escapes_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * non-ASCII characters in the given sets => `&#xHHHH;`
+    escape_text;
+    /// Encode text used as regular HTML text, also escaping the given sets of non-ASCII characters.
+    encode_text_with_unicode_escapes;
+    /// Write text used as regular HTML text to a mutable `String` reference and return the encoded string slice, also escaping the given sets of non-ASCII characters.
+    encode_text_with_unicode_escapes_to_string;
+    /// Write text used as regular HTML text to a mutable `Vec<u8>` reference and return the encoded data slice, also escaping the given sets of non-ASCII characters.
+    encode_text_with_unicode_escapes_to_vec;
+    /// Write text used as regular HTML text to a writer, also escaping the given sets of non-ASCII characters.
+    encode_text_with_unicode_escapes_to_writer;
+    /// Write text used as regular HTML text to a `fmt::Write`, also escaping the given sets of non-ASCII characters.
+    encode_text_with_unicode_escapes_to_fmt_writer;
+    /// Write text used as regular HTML text to an `EncodeSink`, also escaping the given sets of non-ASCII characters.
+    encode_text_with_unicode_escapes_to_sink;
+}
+
+encode_with_unicode_escapes_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * non-ASCII characters in the given sets => `&#xHHHH;`
+    escape_double_quote;
+    /// Encode text used in a double-quoted attribute, also escaping the given sets of non-ASCII characters.
+    encode_double_quoted_attribute_with_unicode_escapes;
+    /// Write text used in a double-quoted attribute to a mutable `String` reference and return the encoded string slice, also escaping the given sets of non-ASCII characters.
+    encode_double_quoted_attribute_with_unicode_escapes_to_string;
+    /// Write text used in a double-quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, also escaping the given sets of non-ASCII characters.
+    encode_double_quoted_attribute_with_unicode_escapes_to_vec;
+    /// Write text used in a double-quoted attribute to a writer, also escaping the given sets of non-ASCII characters.
+    encode_double_quoted_attribute_with_unicode_escapes_to_writer;
+    /// Write text used in a double-quoted attribute to a `fmt::Write`, also escaping the given sets of non-ASCII characters.
+    encode_double_quoted_attribute_with_unicode_escapes_to_fmt_writer;
+    /// Write text used in a double-quoted attribute to an `EncodeSink`, also escaping the given sets of non-ASCII characters.
+    encode_double_quoted_attribute_with_unicode_escapes_to_sink;
+}
+
+encode_with_unicode_escapes_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `'` => `&#x27;`
+    /// * non-ASCII characters in the given sets => `&#xHHHH;`
+    escape_single_quote;
+    /// Encode text used in a single-quoted attribute, also escaping the given sets of non-ASCII characters.
+    encode_single_quoted_attribute_with_unicode_escapes;
+    /// Write text used in a single-quoted attribute to a mutable `String` reference and return the encoded string slice, also escaping the given sets of non-ASCII characters.
+    encode_single_quoted_attribute_with_unicode_escapes_to_string;
+    /// Write text used in a single-quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, also escaping the given sets of non-ASCII characters.
+    encode_single_quoted_attribute_with_unicode_escapes_to_vec;
+    /// Write text used in a single-quoted attribute to a writer, also escaping the given sets of non-ASCII characters.
+    encode_single_quoted_attribute_with_unicode_escapes_to_writer;
+    /// Write text used in a single-quoted attribute to a `fmt::Write`, also escaping the given sets of non-ASCII characters.
+    encode_single_quoted_attribute_with_unicode_escapes_to_fmt_writer;
+    /// Write text used in a single-quoted attribute to an `EncodeSink`, also escaping the given sets of non-ASCII characters.
+    encode_single_quoted_attribute_with_unicode_escapes_to_sink;
+}
+
+encode_with_unicode_escapes_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    /// * non-ASCII characters in the given sets => `&#xHHHH;`
+    escape_quote;
+    /// Encode text used in a quoted attribute, also escaping the given sets of non-ASCII characters.
+    encode_quoted_attribute_with_unicode_escapes;
+    /// Write text used in a quoted attribute to a mutable `String` reference and return the encoded string slice, also escaping the given sets of non-ASCII characters.
+    encode_quoted_attribute_with_unicode_escapes_to_string;
+    /// Write text used in a quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, also escaping the given sets of non-ASCII characters.
+    encode_quoted_attribute_with_unicode_escapes_to_vec;
+    /// Write text used in a quoted attribute to a writer, also escaping the given sets of non-ASCII characters.
+    encode_quoted_attribute_with_unicode_escapes_to_writer;
+    /// Write text used in a quoted attribute to a `fmt::Write`, also escaping the given sets of non-ASCII characters.
+    encode_quoted_attribute_with_unicode_escapes_to_fmt_writer;
+    /// Write text used in a quoted attribute to an `EncodeSink`, also escaping the given sets of non-ASCII characters.
+    encode_quoted_attribute_with_unicode_escapes_to_sink;
+}
+
+encode_with_bom_policy_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    ///
+    /// A leading BOM is kept or removed and the other BOMs are kept or escaped to `&#xFEFF;` according to the given policy.
+    escape_text;
+    /// Encode text used as regular HTML text, handling BOMs according to the given policy.
+    encode_text_with_bom_policy;
+    /// Write text used as regular HTML text to a mutable `String` reference and return the encoded string slice, handling BOMs according to the given policy.
+    encode_text_with_bom_policy_to_string;
+    /// Write text used as regular HTML text to a mutable `Vec<u8>` reference and return the encoded data slice, handling BOMs according to the given policy.
+    encode_text_with_bom_policy_to_vec;
+    /// Write text used as regular HTML text to a writer, handling BOMs according to the given policy.
+    encode_text_with_bom_policy_to_writer;
+    /// Write text used as regular HTML text to a `fmt::Write`, handling BOMs according to the given policy.
+    encode_text_with_bom_policy_to_fmt_writer;
+    /// Write text used as regular HTML text to an `EncodeSink`, handling BOMs according to the given policy.
+    encode_text_with_bom_policy_to_sink;
+}
+
+encode_with_bom_policy_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    ///
+    /// A leading BOM is kept or removed and the other BOMs are kept or escaped to `&#xFEFF;` according to the given policy.
+    escape_quote;
+    /// Encode text used in a quoted attribute, handling BOMs according to the given policy.
+    encode_quoted_attribute_with_bom_policy;
+    /// Write text used in a quoted attribute to a mutable `String` reference and return the encoded string slice, handling BOMs according to the given policy.
+    encode_quoted_attribute_with_bom_policy_to_string;
+    /// Write text used in a quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, handling BOMs according to the given policy.
+    encode_quoted_attribute_with_bom_policy_to_vec;
+    /// Write text used in a quoted attribute to a writer, handling BOMs according to the given policy.
+    encode_quoted_attribute_with_bom_policy_to_writer;
+    /// Write text used in a quoted attribute to a `fmt::Write`, handling BOMs according to the given policy.
+    encode_quoted_attribute_with_bom_policy_to_fmt_writer;
+    /// Write text used in a quoted attribute to an `EncodeSink`, handling BOMs according to the given policy.
+    encode_quoted_attribute_with_bom_policy_to_sink;
+}
+
+encode_ascii_only_impl! {
+    write_char_hex_to_sink;
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * characters which are greater than or equal to 128 => `&#xHHHH;`
+    escape_text;
+    /// Encode text used as regular HTML text and make the result contain only ASCII characters.
+    encode_text_ascii_only;
+    /// Write text used as regular HTML text to a mutable `String` reference and return the encoded string slice. The encoded text contains only ASCII characters.
+    encode_text_ascii_only_to_string;
+    /// Write text used as regular HTML text to a mutable `Vec<u8>` reference and return the encoded data slice. The encoded data contains only ASCII characters.
+    encode_text_ascii_only_to_vec;
+    /// Write text used as regular HTML text to a writer. The written data contains only ASCII characters.
+    encode_text_ascii_only_to_writer;
+    /// Write text used as regular HTML text to a `fmt::Write`. The written data contains only ASCII characters.
+    encode_text_ascii_only_to_fmt_writer;
+    /// Write text used as regular HTML text to a byte slice and return the length of the encoded data. The written data contains only ASCII characters.
+    encode_text_ascii_only_to_slice;
+    /// Write text used as regular HTML text to an `EncodeSink`. The written data contains only ASCII characters.
+    encode_text_ascii_only_to_sink;
+}
+
+encode_ascii_only_impl! {
+    write_char_hex_to_sink;
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * characters which are greater than or equal to 128 => `&#xHHHH;`
+    escape_double_quote;
+    /// Encode text used in a double-quoted attribute and make the result contain only ASCII characters.
+    encode_double_quoted_attribute_ascii_only;
+    /// Write text used in a double-quoted attribute to a mutable `String` reference and return the encoded string slice. The encoded text contains only ASCII characters.
+    encode_double_quoted_attribute_ascii_only_to_string;
+    /// Write text used in a double-quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice. The encoded data contains only ASCII characters.
+    encode_double_quoted_attribute_ascii_only_to_vec;
+    /// Write text used in a double-quoted attribute to a writer. The written data contains only ASCII characters.
+    encode_double_quoted_attribute_ascii_only_to_writer;
+    /// Write text used in a double-quoted attribute to a `fmt::Write`. The written data contains only ASCII characters.
+    encode_double_quoted_attribute_ascii_only_to_fmt_writer;
+    /// Write text used in a double-quoted attribute to a byte slice and return the length of the encoded data. The written data contains only ASCII characters.
+    encode_double_quoted_attribute_ascii_only_to_slice;
+    /// Write text used in a double-quoted attribute to an `EncodeSink`. The written data contains only ASCII characters.
+    encode_double_quoted_attribute_ascii_only_to_sink;
+}
+
+encode_ascii_only_impl! {
+    write_char_hex_to_sink;
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `'` => `&#x27;`
+    /// * characters which are greater than or equal to 128 => `&#xHHHH;`
+    escape_single_quote;
+    /// Encode text used in a single-quoted attribute and make the result contain only ASCII characters.
+    encode_single_quoted_attribute_ascii_only;
+    /// Write text used in a single-quoted attribute to a mutable `String` reference and return the encoded string slice. The encoded text contains only ASCII characters.
+    encode_single_quoted_attribute_ascii_only_to_string;
+    /// Write text used in a single-quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice. The encoded data contains only ASCII characters.
+    encode_single_quoted_attribute_ascii_only_to_vec;
+    /// Write text used in a single-quoted attribute to a writer. The written data contains only ASCII characters.
+    encode_single_quoted_attribute_ascii_only_to_writer;
+    /// Write text used in a single-quoted attribute to a `fmt::Write`. The written data contains only ASCII characters.
+    encode_single_quoted_attribute_ascii_only_to_fmt_writer;
+    /// Write text used in a single-quoted attribute to a byte slice and return the length of the encoded data. The written data contains only ASCII characters.
+    encode_single_quoted_attribute_ascii_only_to_slice;
+    /// Write text used in a single-quoted attribute to an `EncodeSink`. The written data contains only ASCII characters.
+    encode_single_quoted_attribute_ascii_only_to_sink;
+}
+
+encode_ascii_only_impl! {
+    write_char_hex_to_sink;
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    /// * characters which are greater than or equal to 128 => `&#xHHHH;`
+    escape_quote;
+    /// Encode text used in a quoted attribute and make the result contain only ASCII characters.
+    encode_quoted_attribute_ascii_only;
+    /// Write text used in a quoted attribute to a mutable `String` reference and return the encoded string slice. The encoded text contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_to_string;
+    /// Write text used in a quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice. The encoded data contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_to_vec;
+    /// Write text used in a quoted attribute to a writer. The written data contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_to_writer;
+    /// Write text used in a quoted attribute to a `fmt::Write`. The written data contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_to_fmt_writer;
+    /// Write text used in a quoted attribute to a byte slice and return the length of the encoded data. The written data contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_to_slice;
+    /// Write text used in a quoted attribute to an `EncodeSink`. The written data contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_to_sink;
+}
+
+/// Encode text to prevent special characters functioning. Decimal references are used instead of hexadecimal ones.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+/// * `'` => `&#39;`
+/// * `/` => `&#47;`
+#[inline]
+pub fn encode_safe_decimal<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    encode_safe_with_format(text, NumericEntityFormat::DECIMAL)
+}
+
+/// Encode text to prevent special characters functioning and write it to a mutable `String` reference and return the encoded string slice. Decimal references are used instead of hexadecimal ones.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+/// * `'` => `&#39;`
+/// * `/` => `&#47;`
+#[inline]
+pub fn encode_safe_decimal_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    encode_safe_with_format_to_string(text, NumericEntityFormat::DECIMAL, output)
+}
+
+/// Encode text to prevent special characters functioning and write it to a mutable `Vec<u8>` reference and return the encoded data slice. Decimal references are used instead of hexadecimal ones.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+/// * `'` => `&#39;`
+/// * `/` => `&#47;`
+#[inline]
+pub fn encode_safe_decimal_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    encode_safe_with_format_to_vec(text, NumericEntityFormat::DECIMAL, output)
+}
+
+#[cfg(feature = "std")]
+/// Encode text to prevent special characters functioning and write it to a writer. Decimal references are used instead of hexadecimal ones.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+/// * `'` => `&#39;`
+/// * `/` => `&#47;`
+#[inline]
+pub fn encode_safe_decimal_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_safe_with_format_to_writer(text, NumericEntityFormat::DECIMAL, output)
+}
+
+/// Encode text to prevent special characters functioning and write it to a `fmt::Write`. Decimal references are used instead of hexadecimal ones.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+/// * `'` => `&#39;`
+/// * `/` => `&#47;`
+#[inline]
+pub fn encode_safe_decimal_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_safe_with_format_to_sink(text, NumericEntityFormat::DECIMAL, &mut FmtSink(output))
+}
+
+/// Encode text to prevent special characters functioning and write it to an `EncodeSink`. Decimal references are used instead of hexadecimal ones.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+/// * `'` => `&#39;`
+/// * `/` => `&#47;`
+#[inline]
+pub fn encode_safe_decimal_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    encode_safe_with_format_to_sink(text, NumericEntityFormat::DECIMAL, output)
+}
+
+/// Find the shortest HTML5 named entity of a character, without the `&` and `;`, such as `copy` for `©`. Only the entities which represent a single character are considered.
+///
+/// ```
+/// assert_eq!(Some("copy"), html_escape::named_entity_for('©'));
+/// assert_eq!(None, html_escape::named_entity_for('a'));
+/// ```
+#[inline]
+pub fn named_entity_for(c: char) -> Option<&'static str> {
+    CHAR_NAMED_ENTITIES
+        .binary_search_by(|(t_c, _)| t_c.cmp(&c))
+        .ok()
+        .map(|index| CHAR_NAMED_ENTITIES[index].1)
+}
+
+/// Find the HTML 4.01 named entity of a character, without the `&` and `;`, such as `copy` for `©`.
+#[inline]
+pub fn html4_named_entity_for(c: char) -> Option<&'static str> {
+    HTML4_CHAR_NAMED_ENTITIES
+        .binary_search_by(|(t_c, _)| t_c.cmp(&c))
+        .ok()
+        .map(|index| HTML4_CHAR_NAMED_ENTITIES[index].1)
+}
+
+#[inline]
+fn write_char_named_entity_to_sink<W: EncodeSink>(c: char, output: &mut W) -> Result<(), W::Error> {
+    match named_entity_for(c) {
+        Some(name) => {
+            output.write_str("&")?;
+            output.write_str(name)?;
+            output.write_str(";")
+        }
+        None => write_char_hex_to_sink(c, output),
+    }
+}
+
+encode_ascii_only_impl! {
+    write_char_named_entity_to_sink;
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * characters which are greater than or equal to 128 => their shortest named entities, such as `&copy;`, or `&#xHHHH;` if they have no named entity
+    escape_text;
+    /// Encode text used as regular HTML text and prefer named entities for non-ASCII characters.
+    encode_text_named_entities;
+    /// Write text used as regular HTML text to a mutable `String` reference and return the encoded string slice. Named entities are preferred for non-ASCII characters.
+    encode_text_named_entities_to_string;
+    /// Write text used as regular HTML text to a mutable `Vec<u8>` reference and return the encoded data slice. Named entities are preferred for non-ASCII characters.
+    encode_text_named_entities_to_vec;
+    /// Write text used as regular HTML text to a writer. Named entities are preferred for non-ASCII characters.
+    encode_text_named_entities_to_writer;
+    /// Write text used as regular HTML text to a `fmt::Write`. Named entities are preferred for non-ASCII characters.
+    encode_text_named_entities_to_fmt_writer;
+    /// Write text used as regular HTML text to a byte slice and return the length of the encoded data. Named entities are preferred for non-ASCII characters.
+    encode_text_named_entities_to_slice;
+    /// Write text used as regular HTML text to an `EncodeSink`. Named entities are preferred for non-ASCII characters.
+    encode_text_named_entities_to_sink;
+}
+
+encode_ascii_only_impl! {
+    write_char_named_entity_to_sink;
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    /// * characters which are greater than or equal to 128 => their shortest named entities, such as `&copy;`, or `&#xHHHH;` if they have no named entity
+    escape_quote;
+    /// Encode text used in a quoted attribute and prefer named entities for non-ASCII characters.
+    encode_quoted_attribute_named_entities;
+    /// Write text used in a quoted attribute to a mutable `String` reference and return the encoded string slice. Named entities are preferred for non-ASCII characters.
+    encode_quoted_attribute_named_entities_to_string;
+    /// Write text used in a quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice. Named entities are preferred for non-ASCII characters.
+    encode_quoted_attribute_named_entities_to_vec;
+    /// Write text used in a quoted attribute to a writer. Named entities are preferred for non-ASCII characters.
+    encode_quoted_attribute_named_entities_to_writer;
+    /// Write text used in a quoted attribute to a `fmt::Write`. Named entities are preferred for non-ASCII characters.
+    encode_quoted_attribute_named_entities_to_fmt_writer;
+    /// Write text used in a quoted attribute to a byte slice and return the length of the encoded data. Named entities are preferred for non-ASCII characters.
+    encode_quoted_attribute_named_entities_to_slice;
+    /// Write text used in a quoted attribute to an `EncodeSink`. Named entities are preferred for non-ASCII characters.
+    encode_quoted_attribute_named_entities_to_sink;
+}
+
+#[inline]
+fn write_char_html4_entity_to_sink<W: EncodeSink>(c: char, output: &mut W) -> Result<(), W::Error> {
+    match html4_named_entity_for(c) {
+        Some(name) => {
+            output.write_str("&")?;
+            output.write_str(name)?;
+            output.write_str(";")
+        }
+        None => NumericEntityFormat::DECIMAL.write_to_sink(c as u32, output),
+    }
+}
+
+encode_ascii_only_impl! {
+    write_char_html4_entity_to_sink;
+    /// Only HTML 4.01 named entities and decimal references are used, which are reliably supported by email clients. The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * characters which are greater than or equal to 128 => their HTML 4.01 named entities, such as `&eacute;`, or `&#DDDD;` if they have no such entity
+    escape_text;
+    /// Encode text used as regular HTML text in an email.
+    encode_text_email;
+    /// Write text used as regular HTML text in an email to a mutable `String` reference and return the encoded string slice.
+    encode_text_email_to_string;
+    /// Write text used as regular HTML text in an email to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_text_email_to_vec;
+    /// Write text used as regular HTML text in an email to a writer.
+    encode_text_email_to_writer;
+    /// Write text used as regular HTML text in an email to a `fmt::Write`.
+    encode_text_email_to_fmt_writer;
+    /// Write text used as regular HTML text in an email to a byte slice and return the length of the encoded data.
+    encode_text_email_to_slice;
+    /// Write text used as regular HTML text in an email to an `EncodeSink`.
+    encode_text_email_to_sink;
+}
+
+encode_ascii_only_impl! {
+    write_char_html4_entity_to_sink;
+    /// Only HTML 4.01 named entities and decimal references are used, which are reliably supported by email clients. The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#39;`
+    /// * characters which are greater than or equal to 128 => their HTML 4.01 named entities, such as `&eacute;`, or `&#DDDD;` if they have no such entity
+    escape_quote_decimal;
+    /// Encode text used in a quoted attribute in an email.
+    encode_quoted_attribute_email;
+    /// Write text used in a quoted attribute in an email to a mutable `String` reference and return the encoded string slice.
+    encode_quoted_attribute_email_to_string;
+    /// Write text used in a quoted attribute in an email to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_quoted_attribute_email_to_vec;
+    /// Write text used in a quoted attribute in an email to a writer.
+    encode_quoted_attribute_email_to_writer;
+    /// Write text used in a quoted attribute in an email to a `fmt::Write`.
+    encode_quoted_attribute_email_to_fmt_writer;
+    /// Write text used in a quoted attribute in an email to a byte slice and return the length of the encoded data.
+    encode_quoted_attribute_email_to_slice;
+    /// Write text used in a quoted attribute in an email to an `EncodeSink`.
+    encode_quoted_attribute_email_to_sink;
 }
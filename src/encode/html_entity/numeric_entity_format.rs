@@ -0,0 +1,297 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::functions::*;
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+/// The format of numeric character references generated by the `*_with_format` encoders.
+///
+/// The default format is uppercase hexadecimal with at least two digits, such as `&#x2F;`, which is what the other encoders generate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumericEntityFormat {
+    decimal: bool,
+    lowercase: bool,
+    min_digits: u8,
+}
+
+impl NumericEntityFormat {
+    /// Hexadecimal references with uppercase digits and at least two digits, such as `&#x2F;`.
+    pub const HEXADECIMAL: NumericEntityFormat = NumericEntityFormat {
+        decimal: false,
+        lowercase: false,
+        min_digits: 2,
+    };
+    /// Decimal references without zero-padding, such as `&#47;`.
+    pub const DECIMAL: NumericEntityFormat = NumericEntityFormat {
+        decimal: true,
+        lowercase: false,
+        min_digits: 1,
+    };
+
+    /// Use lowercase hexadecimal digits, such as `&#x2f;`. This has no effect on decimal references.
+    #[inline]
+    pub const fn lowercase(mut self, lowercase: bool) -> NumericEntityFormat {
+        self.lowercase = lowercase;
+
+        self
+    }
+
+    /// Pad the digits with zeros to at least `min_digits` digits, such as `&#x002F;` for 4.
+    #[inline]
+    pub const fn min_digits(mut self, min_digits: u8) -> NumericEntityFormat {
+        self.min_digits = min_digits;
+
+        self
+    }
+
+    /// Whether the references are decimal.
+    #[inline]
+    pub const fn is_decimal(&self) -> bool {
+        self.decimal
+    }
+
+    /// Whether the hexadecimal digits are lowercase.
+    #[inline]
+    pub const fn is_lowercase(&self) -> bool {
+        self.lowercase
+    }
+
+    /// The minimum number of digits.
+    #[inline]
+    pub const fn get_min_digits(&self) -> u8 {
+        self.min_digits
+    }
+
+    #[inline]
+    fn format_digits(self, number: u32, buffer: &mut [u8; 10]) -> &[u8] {
+        let radix = if self.decimal {
+            10
+        } else {
+            16
+        };
+
+        let letter = if self.lowercase {
+            b'a'
+        } else {
+            b'A'
+        };
+
+        let mut number = number;
+        let mut p = buffer.len();
+
+        loop {
+            let d = (number % radix) as u8;
+
+            p -= 1;
+
+            buffer[p] = if d >= 10 {
+                letter - 10 + d
+            } else {
+                b'0' + d
+            };
+
+            number /= radix;
+
+            if number == 0 {
+                break;
+            }
+        }
+
+        &buffer[p..]
+    }
+
+    #[inline]
+    fn prefix(self) -> &'static [u8] {
+        if self.decimal {
+            b"&#"
+        } else {
+            b"&#x"
+        }
+    }
+
+    #[inline]
+    pub(crate) fn write_to_sink<W: EncodeSink>(
+        self,
+        number: u32,
+        output: &mut W,
+    ) -> Result<(), W::Error> {
+        let mut buffer = [0u8; 10];
+        let digits = self.format_digits(number, &mut buffer);
+
+        output.write_str(unsafe { from_utf8_unchecked(self.prefix()) })?;
+
+        for _ in digits.len()..self.min_digits as usize {
+            output.write_str("0")?;
+        }
+
+        output.write_str(unsafe { from_utf8_unchecked(digits) })?;
+        output.write_str(";")
+    }
+}
+
+impl Default for NumericEntityFormat {
+    #[inline]
+    fn default() -> Self {
+        NumericEntityFormat::HEXADECIMAL
+    }
+}
+
+pub(crate) enum ByteEscape {
+    Keep,
+    Named(&'static [u8]),
+    Numeric,
+}
+
+#[inline]
+fn escape_unquoted_attribute(e: u8) -> ByteEscape {
+    match e {
+        b'&' => ByteEscape::Named(b"&amp;"),
+        b'<' => ByteEscape::Named(b"&lt;"),
+        b'>' => ByteEscape::Named(b"&gt;"),
+        b'"' => ByteEscape::Named(b"&quot;"),
+        _ if is_alphanumeric(e) => ByteEscape::Keep,
+        _ => ByteEscape::Numeric,
+    }
+}
+
+#[inline]
+fn escape_safe(e: u8) -> ByteEscape {
+    match e {
+        b'&' => ByteEscape::Named(b"&amp;"),
+        b'<' => ByteEscape::Named(b"&lt;"),
+        b'>' => ByteEscape::Named(b"&gt;"),
+        b'"' => ByteEscape::Named(b"&quot;"),
+        b'\'' | b'/' => ByteEscape::Numeric,
+        _ => ByteEscape::Keep,
+    }
+}
+
+#[inline]
+fn escape_text(e: u8) -> ByteEscape {
+    match e {
+        b'&' => ByteEscape::Named(b"&amp;"),
+        b'<' => ByteEscape::Named(b"&lt;"),
+        b'>' => ByteEscape::Named(b"&gt;"),
+        _ => ByteEscape::Keep,
+    }
+}
+
+#[inline]
+fn escape_quote(e: u8) -> ByteEscape {
+    match e {
+        b'&' => ByteEscape::Named(b"&amp;"),
+        b'<' => ByteEscape::Named(b"&lt;"),
+        b'>' => ByteEscape::Named(b"&gt;"),
+        b'"' => ByteEscape::Named(b"&quot;"),
+        b'\'' => ByteEscape::Numeric,
+        _ => ByteEscape::Keep,
+    }
+}
+
+encode_with_format_impl! {
+    false;
+    /// Except for alphanumeric characters, all characters which are less than 128 are escaped. The following characters are escaped to named entities:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    ///
+    /// Other non-alphanumeric characters are escaped to numeric references in the given format.
+    escape_unquoted_attribute;
+    /// Encode text used in an unquoted attribute with the given format of numeric references.
+    encode_unquoted_attribute_with_format;
+    /// Write text used in an unquoted attribute with the given format of numeric references to a mutable `String` reference and return the encoded string slice.
+    encode_unquoted_attribute_with_format_to_string;
+    /// Write text used in an unquoted attribute with the given format of numeric references to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_unquoted_attribute_with_format_to_vec;
+    /// Write text used in an unquoted attribute with the given format of numeric references to a writer.
+    encode_unquoted_attribute_with_format_to_writer;
+    /// Write text used in an unquoted attribute with the given format of numeric references to a `fmt::Write`.
+    encode_unquoted_attribute_with_format_to_fmt_writer;
+    /// Write text used in an unquoted attribute with the given format of numeric references to an `EncodeSink`.
+    encode_unquoted_attribute_with_format_to_sink;
+}
+
+encode_with_format_impl! {
+    false;
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => a numeric reference in the given format
+    /// * `/` => a numeric reference in the given format
+    escape_safe;
+    /// Encode text to prevent special characters functioning with the given format of numeric references.
+    encode_safe_with_format;
+    /// Encode text to prevent special characters functioning with the given format of numeric references and write it to a mutable `String` reference and return the encoded string slice.
+    encode_safe_with_format_to_string;
+    /// Encode text to prevent special characters functioning with the given format of numeric references and write it to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_safe_with_format_to_vec;
+    /// Encode text to prevent special characters functioning with the given format of numeric references and write it to a writer.
+    encode_safe_with_format_to_writer;
+    /// Encode text to prevent special characters functioning with the given format of numeric references and write it to a `fmt::Write`.
+    encode_safe_with_format_to_fmt_writer;
+    /// Encode text to prevent special characters functioning with the given format of numeric references and write it to an `EncodeSink`.
+    encode_safe_with_format_to_sink;
+}
+
+encode_with_format_impl! {
+    true;
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * characters which are greater than or equal to 128 => a numeric reference in the given format
+    escape_text;
+    /// Encode text used as regular HTML text with the given format of numeric references and make the result contain only ASCII characters.
+    encode_text_ascii_only_with_format;
+    /// Write text used as regular HTML text with the given format of numeric references to a mutable `String` reference and return the encoded string slice. The encoded text contains only ASCII characters.
+    encode_text_ascii_only_with_format_to_string;
+    /// Write text used as regular HTML text with the given format of numeric references to a mutable `Vec<u8>` reference and return the encoded data slice. The encoded data contains only ASCII characters.
+    encode_text_ascii_only_with_format_to_vec;
+    /// Write text used as regular HTML text with the given format of numeric references to a writer. The written data contains only ASCII characters.
+    encode_text_ascii_only_with_format_to_writer;
+    /// Write text used as regular HTML text with the given format of numeric references to a `fmt::Write`. The written data contains only ASCII characters.
+    encode_text_ascii_only_with_format_to_fmt_writer;
+    /// Write text used as regular HTML text with the given format of numeric references to an `EncodeSink`. The written data contains only ASCII characters.
+    encode_text_ascii_only_with_format_to_sink;
+}
+
+encode_with_format_impl! {
+    true;
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => a numeric reference in the given format
+    /// * characters which are greater than or equal to 128 => a numeric reference in the given format
+    escape_quote;
+    /// Encode text used in a quoted attribute with the given format of numeric references and make the result contain only ASCII characters.
+    encode_quoted_attribute_ascii_only_with_format;
+    /// Write text used in a quoted attribute with the given format of numeric references to a mutable `String` reference and return the encoded string slice. The encoded text contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_with_format_to_string;
+    /// Write text used in a quoted attribute with the given format of numeric references to a mutable `Vec<u8>` reference and return the encoded data slice. The encoded data contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_with_format_to_vec;
+    /// Write text used in a quoted attribute with the given format of numeric references to a writer. The written data contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_with_format_to_writer;
+    /// Write text used in a quoted attribute with the given format of numeric references to a `fmt::Write`. The written data contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_with_format_to_fmt_writer;
+    /// Write text used in a quoted attribute with the given format of numeric references to an `EncodeSink`. The written data contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_with_format_to_sink;
+}
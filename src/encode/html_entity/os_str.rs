@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::io::{self, Write};
+#[cfg(unix)]
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+
+use super::*;
+use crate::InvalidUtf8Policy;
+
+#[cfg(unix)]
+escape_impl! {
+    escape_os_str_text;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'>' => b"&gt;",
+}
+
+/// Encode an `OsStr`, such as a file name, used as regular HTML text, handling data which is not valid UTF-8 according to the given policy. `Keep` writes the invalid bytes as they are on Unix, where an `OsStr` is a sequence of arbitrary bytes, and is the same as `Replace` elsewhere.
+///
+/// ```
+/// use std::ffi::OsStr;
+///
+/// use html_escape::InvalidUtf8Policy;
+///
+/// assert_eq!(
+///     b"a &lt;b&gt;.txt",
+///     html_escape::encode_os_str(OsStr::new("a <b>.txt"), InvalidUtf8Policy::Replace).as_ref()
+/// );
+/// ```
+pub fn encode_os_str(text: &OsStr, policy: InvalidUtf8Policy) -> Cow<'_, [u8]> {
+    match text.to_str() {
+        Some(text) => match encode_text(text) {
+            Cow::Borrowed(text) => Cow::from(text.as_bytes()),
+            Cow::Owned(text) => Cow::from(text.into_bytes()),
+        },
+        None => {
+            let mut v = Vec::with_capacity(text.len() + 5);
+
+            encode_os_str_to_vec(text, policy, &mut v);
+
+            Cow::from(v)
+        }
+    }
+}
+
+/// Write an `OsStr` used as regular HTML text to a mutable `Vec<u8>` reference and return the encoded data slice, handling data which is not valid UTF-8 according to the given policy.
+pub fn encode_os_str_to_vec<'a>(
+    text: &OsStr,
+    policy: InvalidUtf8Policy,
+    output: &'a mut Vec<u8>,
+) -> &'a [u8] {
+    let current_length = output.len();
+
+    #[cfg(unix)]
+    {
+        let text_bytes = text.as_bytes();
+
+        match policy {
+            InvalidUtf8Policy::Keep => {
+                output.reserve(text_bytes.len());
+
+                let mut start = 0;
+                let mut end = 0;
+
+                for e in text_bytes.iter().copied() {
+                    escape_os_str_text!(vec e, output, text_bytes, start, end);
+                }
+
+                output.extend_from_slice(&text_bytes[start..end]);
+            }
+            InvalidUtf8Policy::Replace => {
+                encode_text_lossy_to_vec(text_bytes, output);
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = policy;
+
+        encode_text_to_vec(text.to_string_lossy(), output);
+    }
+
+    &output[current_length..]
+}
+
+/// Write an `OsStr` used as regular HTML text to a writer, handling data which is not valid UTF-8 according to the given policy.
+pub fn encode_os_str_to_writer<W: Write>(
+    text: &OsStr,
+    policy: InvalidUtf8Policy,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    #[cfg(unix)]
+    {
+        let text_bytes = text.as_bytes();
+
+        match policy {
+            InvalidUtf8Policy::Keep => {
+                let mut start = 0;
+                let mut end = 0;
+
+                for e in text_bytes.iter().copied() {
+                    escape_os_str_text!(writer e, output, text_bytes, start, end);
+                }
+
+                output.write_all(&text_bytes[start..end])
+            }
+            InvalidUtf8Policy::Replace => encode_text_lossy_to_writer(text_bytes, output),
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = policy;
+
+        encode_text_to_writer(text.to_string_lossy(), output)
+    }
+}
+
+/// Encode a `Path` used as regular HTML text, such as in a file browser or a build report, handling data which is not valid UTF-8 according to the given policy like `encode_os_str` does.
+#[inline]
+pub fn encode_path(path: &Path, policy: InvalidUtf8Policy) -> Cow<'_, [u8]> {
+    encode_os_str(path.as_os_str(), policy)
+}
+
+/// Write a `Path` used as regular HTML text to a mutable `Vec<u8>` reference and return the encoded data slice, handling data which is not valid UTF-8 according to the given policy like `encode_os_str` does.
+#[inline]
+pub fn encode_path_to_vec<'a>(
+    path: &Path,
+    policy: InvalidUtf8Policy,
+    output: &'a mut Vec<u8>,
+) -> &'a [u8] {
+    encode_os_str_to_vec(path.as_os_str(), policy, output)
+}
+
+/// Write a `Path` used as regular HTML text to a writer, handling data which is not valid UTF-8 according to the given policy like `encode_os_str` does.
+#[inline]
+pub fn encode_path_to_writer<W: Write>(
+    path: &Path,
+    policy: InvalidUtf8Policy,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_os_str_to_writer(path.as_os_str(), policy, output)
+}
@@ -0,0 +1,226 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::buffer_too_small::SliceWriter;
+use crate::functions::*;
+use crate::{BufferTooSmall, EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+macro_rules! encode_preserving_entities_impl {
+    ($(#[$attr: meta])* $escape_fn:ident; $(#[$encode_attr: meta])* $encode_name: ident; $(#[$encode_to_string_attr: meta])* $encode_to_string_name: ident; $(#[$encode_to_vec_attr: meta])* $encode_to_vec_name: ident; $(#[$encode_to_writer_attr: meta])* $encode_to_writer_name: ident; $(#[$encode_to_fmt_writer_attr: meta])* $encode_to_fmt_writer_name: ident; $(#[$encode_to_slice_attr: meta])* $encode_to_slice_name: ident; $(#[$encode_to_sink_attr: meta])* $encode_to_sink_name: ident $(;)*) => {
+        $(#[$encode_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_name<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            let mut p = 0;
+
+            let first = loop {
+                if p == text_length {
+                    return Cow::from(text);
+                }
+
+                if let Some(escaped) = $escape_fn(text_bytes, p) {
+                    break escaped;
+                }
+
+                p += 1;
+            };
+
+            let mut v = Vec::with_capacity(text_length + 5);
+
+            v.extend_from_slice(&text_bytes[..p]);
+            v.extend_from_slice(first);
+
+            $encode_to_vec_name(unsafe { from_utf8_unchecked(&text_bytes[(p + 1)..]) }, &mut v);
+
+            Cow::from(unsafe { String::from_utf8_unchecked(v) })
+        }
+
+        $(#[$encode_to_string_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_string_name<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+            unsafe { from_utf8_unchecked($encode_to_vec_name(text, output.as_mut_vec())) }
+        }
+
+        $(#[$encode_to_vec_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_vec_name<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            output.reserve(text_length);
+
+            let current_length = output.len();
+
+            $encode_to_sink_name(text, output).unwrap_or_else(|error| match error {});
+
+            &output[current_length..]
+        }
+
+        #[cfg(feature = "std")]
+        $(#[$encode_to_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_writer_name<S: AsRef<str>, W: Write>(text: S, output: &mut W) -> Result<(), io::Error> {
+            $encode_to_sink_name(text, &mut IoSink(output))
+        }
+
+        $(#[$encode_to_fmt_writer_attr])*
+        ///
+        $(#[$attr])*
+        #[inline]
+        pub fn $encode_to_fmt_writer_name<S: AsRef<str>, W: fmt::Write>(text: S, output: &mut W) -> Result<(), fmt::Error> {
+            $encode_to_sink_name(text, &mut FmtSink(output))
+        }
+
+        $(#[$encode_to_slice_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_slice_name<S: AsRef<str>>(text: S, output: &mut [u8]) -> Result<usize, BufferTooSmall> {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            let mut output = SliceWriter::new(output);
+
+            let mut p = 0;
+            let mut start = 0;
+
+            while p < text_length {
+                if let Some(escaped) = $escape_fn(text_bytes, p) {
+                    output.write_text(text, start, p)?;
+                    output.write_escape(escaped, p)?;
+                    start = p + 1;
+                }
+
+                p += 1;
+            }
+
+            output.write_text(text, start, p)?;
+
+            Ok(output.written())
+        }
+
+        $(#[$encode_to_sink_attr])*
+        ///
+        $(#[$attr])*
+        pub fn $encode_to_sink_name<S: AsRef<str>, W: EncodeSink>(text: S, output: &mut W) -> Result<(), W::Error> {
+            let text = text.as_ref();
+            let text_bytes = text.as_bytes();
+            let text_length = text_bytes.len();
+
+            let mut p = 0;
+            let mut start = 0;
+
+            while p < text_length {
+                if let Some(escaped) = $escape_fn(text_bytes, p) {
+                    output.write_str(unsafe { text.get_unchecked(start..p) })?;
+                    start = p + 1;
+                    output.write_str(unsafe { from_utf8_unchecked(escaped) })?;
+                }
+
+                p += 1;
+            }
+
+            output.write_str(unsafe { text.get_unchecked(start..p) })
+        }
+    };
+}
+
+#[inline]
+fn escape_preserving_entities(text_bytes: &[u8], p: usize) -> Option<&'static [u8]> {
+    match text_bytes[p] {
+        b'&' if !is_character_reference_at(text_bytes, p) => Some(b"&amp;"),
+        b'<' => Some(b"&lt;"),
+        b'>' => Some(b"&gt;"),
+        b'"' => Some(b"&quot;"),
+        b'\'' => Some(b"&#x27;"),
+        _ => None,
+    }
+}
+
+#[inline]
+fn escape_safe_idempotent(text_bytes: &[u8], p: usize) -> Option<&'static [u8]> {
+    match text_bytes[p] {
+        b'/' => Some(b"&#x2F;"),
+        _ => escape_preserving_entities(text_bytes, p),
+    }
+}
+
+encode_preserving_entities_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` which does not start a valid character reference => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    ///
+    /// A valid character reference is a known named entity such as `&amp;` or a numeric reference to a valid character such as `&#x27;`, terminated by `;`.
+    ///
+    /// The encoding is idempotent, which means encoding the encoded text again does not change it.
+    escape_preserving_entities;
+    /// Encode text used as regular HTML text or in a quoted attribute, leaving existing character references untouched.
+    encode_text_preserving_entities;
+    /// Write text used as regular HTML text or in a quoted attribute to a mutable `String` reference and return the encoded string slice, leaving existing character references untouched.
+    encode_text_preserving_entities_to_string;
+    /// Write text used as regular HTML text or in a quoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, leaving existing character references untouched.
+    encode_text_preserving_entities_to_vec;
+    /// Write text used as regular HTML text or in a quoted attribute to a writer, leaving existing character references untouched.
+    encode_text_preserving_entities_to_writer;
+    /// Write text used as regular HTML text or in a quoted attribute to a `fmt::Write`, leaving existing character references untouched.
+    encode_text_preserving_entities_to_fmt_writer;
+    /// Write text used as regular HTML text or in a quoted attribute to a byte slice and return the length of the encoded data, leaving existing character references untouched.
+    encode_text_preserving_entities_to_slice;
+    /// Write text used as regular HTML text or in a quoted attribute to an `EncodeSink`, leaving existing character references untouched.
+    encode_text_preserving_entities_to_sink;
+}
+
+encode_preserving_entities_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` which does not start a valid character reference => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    /// * `/` => `&#x2F;`
+    ///
+    /// A valid character reference is a known named entity such as `&amp;` or a numeric reference to a valid character such as `&#x27;`, terminated by `;`.
+    ///
+    /// The encoding is idempotent, which means `encode_safe_idempotent(encode_safe_idempotent(text)) == encode_safe_idempotent(text)`, so the text can safely pass through the encoder more than once.
+    escape_safe_idempotent;
+    /// Encode text to prevent special characters functioning, leaving existing character references untouched.
+    encode_safe_idempotent;
+    /// Encode text to prevent special characters functioning and write it to a mutable `String` reference and return the encoded string slice, leaving existing character references untouched.
+    encode_safe_idempotent_to_string;
+    /// Encode text to prevent special characters functioning and write it to a mutable `Vec<u8>` reference and return the encoded data slice, leaving existing character references untouched.
+    encode_safe_idempotent_to_vec;
+    /// Encode text to prevent special characters functioning and write it to a writer, leaving existing character references untouched.
+    encode_safe_idempotent_to_writer;
+    /// Encode text to prevent special characters functioning and write it to a `fmt::Write`, leaving existing character references untouched.
+    encode_safe_idempotent_to_fmt_writer;
+    /// Encode text to prevent special characters functioning and write it to a byte slice and return the length of the encoded data, leaving existing character references untouched.
+    encode_safe_idempotent_to_slice;
+    /// Encode text to prevent special characters functioning and write it to an `EncodeSink`, leaving existing character references untouched.
+    encode_safe_idempotent_to_sink;
+}
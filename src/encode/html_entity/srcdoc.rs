@@ -0,0 +1,83 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::buffer_too_small::SliceWriter;
+use crate::{BufferTooSmall, EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+escape_impl! {
+    escape_srcdoc;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'>' => b"&gt;",
+    b'"' => b"&quot;",
+    b'\'' => b"&#x27;",
+}
+
+escape_impl! {
+    escape_srcdoc_text;
+    b'&' => b"&amp;amp;",
+    b'<' => b"&amp;lt;",
+    b'>' => b"&amp;gt;",
+    b'"' => b"&quot;",
+    b'\'' => b"&#x27;",
+}
+
+encode_impl! {
+    /// The browser decodes the attribute value and parses the result as the HTML document of the frame, so the fragment keeps working as markup. The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    escape_srcdoc;
+    /// Encode an HTML fragment used in a quoted `srcdoc` attribute of an `<iframe>` element.
+    encode_srcdoc;
+    /// Write an HTML fragment used in a quoted `srcdoc` attribute of an `<iframe>` element to a mutable `String` reference and return the encoded string slice.
+    encode_srcdoc_to_string;
+    /// Write an HTML fragment used in a quoted `srcdoc` attribute of an `<iframe>` element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_srcdoc_to_vec;
+    /// Write an HTML fragment used in a quoted `srcdoc` attribute of an `<iframe>` element to a writer.
+    encode_srcdoc_to_writer;
+    /// Write an HTML fragment used in a quoted `srcdoc` attribute of an `<iframe>` element to a `fmt::Write`.
+    encode_srcdoc_to_fmt_writer;
+    /// Write an HTML fragment used in a quoted `srcdoc` attribute of an `<iframe>` element to a byte slice and return the length of the encoded data.
+    encode_srcdoc_to_slice;
+    /// Write an HTML fragment used in a quoted `srcdoc` attribute of an `<iframe>` element to an `EncodeSink`.
+    encode_srcdoc_to_sink;
+}
+
+encode_impl! {
+    /// This is the same as encoding the text with `encode_text` and then encoding the result with `encode_srcdoc`, so the text is shown as it is in the frame. The following characters are escaped:
+    ///
+    /// * `&` => `&amp;amp;`
+    /// * `<` => `&amp;lt;`
+    /// * `>` => `&amp;gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    escape_srcdoc_text;
+    /// Encode plain text used as the document in a quoted `srcdoc` attribute of an `<iframe>` element.
+    encode_srcdoc_text;
+    /// Write plain text used as the document in a quoted `srcdoc` attribute of an `<iframe>` element to a mutable `String` reference and return the encoded string slice.
+    encode_srcdoc_text_to_string;
+    /// Write plain text used as the document in a quoted `srcdoc` attribute of an `<iframe>` element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_srcdoc_text_to_vec;
+    /// Write plain text used as the document in a quoted `srcdoc` attribute of an `<iframe>` element to a writer.
+    encode_srcdoc_text_to_writer;
+    /// Write plain text used as the document in a quoted `srcdoc` attribute of an `<iframe>` element to a `fmt::Write`.
+    encode_srcdoc_text_to_fmt_writer;
+    /// Write plain text used as the document in a quoted `srcdoc` attribute of an `<iframe>` element to a byte slice and return the length of the encoded data.
+    encode_srcdoc_text_to_slice;
+    /// Write plain text used as the document in a quoted `srcdoc` attribute of an `<iframe>` element to an `EncodeSink`.
+    encode_srcdoc_text_to_sink;
+}
@@ -0,0 +1,61 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::buffer_too_small::SliceWriter;
+use crate::{BufferTooSmall, EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+escape_impl! {
+    escape_srcset;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'>' => b"&gt;",
+    b'"' => b"&quot;",
+    b'\'' => b"&#x27;",
+    b',' => b"%2C",
+    b'\t' => b"%09",
+    b'\n' => b"%0A",
+    b'\x0C' => b"%0C",
+    b'\r' => b"%0D",
+    b' ' => b"%20",
+}
+
+encode_impl! {
+    /// The following characters are escaped (commas and whitespace are percent-encoded so that the URL cannot be split into more image candidates):
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    /// * `,` => `%2C`
+    /// * `\t` => `%09`
+    /// * `\n` => `%0A`
+    /// * `\x0C` => `%0C`
+    /// * `\r` => `%0D`
+    /// * ` ` => `%20`
+    escape_srcset;
+    /// Encode a URL used as an image candidate in a quoted `srcset` attribute.
+    encode_srcset;
+    /// Write a URL used as an image candidate in a quoted `srcset` attribute to a mutable `String` reference and return the encoded string slice.
+    encode_srcset_to_string;
+    /// Write a URL used as an image candidate in a quoted `srcset` attribute to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_srcset_to_vec;
+    /// Write a URL used as an image candidate in a quoted `srcset` attribute to a writer.
+    encode_srcset_to_writer;
+    /// Write a URL used as an image candidate in a quoted `srcset` attribute to a `fmt::Write`.
+    encode_srcset_to_fmt_writer;
+    /// Write a URL used as an image candidate in a quoted `srcset` attribute to a byte slice and return the length of the encoded data.
+    encode_srcset_to_slice;
+    /// Write a URL used as an image candidate in a quoted `srcset` attribute to an `EncodeSink`.
+    encode_srcset_to_sink;
+}
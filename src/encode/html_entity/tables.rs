@@ -0,0 +1,1711 @@
+/// The table of characters and their shortest HTML named entities ordered by the characters.
+///
+/// Only the entities which represent a single character are included.
+pub(crate) static CHAR_NAMED_ENTITIES: [(char, &str); 1448] = [
+    ('\u{0009}', "Tab"),
+    ('\u{000A}', "NewLine"),
+    ('\u{0021}', "excl"),                 // EXCLAMATION MARK
+    ('\u{0022}', "quot"),                 // QUOTATION MARK
+    ('\u{0023}', "num"),                  // NUMBER SIGN
+    ('\u{0024}', "dollar"),               // DOLLAR SIGN
+    ('\u{0025}', "percnt"),               // PERCENT SIGN
+    ('\u{0026}', "amp"),                  // AMPERSAND
+    ('\u{0027}', "apos"),                 // APOSTROPHE
+    ('\u{0028}', "lpar"),                 // LEFT PARENTHESIS
+    ('\u{0029}', "rpar"),                 // RIGHT PARENTHESIS
+    ('\u{002A}', "ast"),                  // ASTERISK
+    ('\u{002B}', "plus"),                 // PLUS SIGN
+    ('\u{002C}', "comma"),                // COMMA
+    ('\u{002E}', "period"),               // FULL STOP
+    ('\u{002F}', "sol"),                  // SOLIDUS
+    ('\u{003A}', "colon"),                // COLON
+    ('\u{003B}', "semi"),                 // SEMICOLON
+    ('\u{003C}', "lt"),                   // LESS-THAN SIGN
+    ('\u{003D}', "equals"),               // EQUALS SIGN
+    ('\u{003E}', "gt"),                   // GREATER-THAN SIGN
+    ('\u{003F}', "quest"),                // QUESTION MARK
+    ('\u{0040}', "commat"),               // COMMERCIAL AT
+    ('\u{005B}', "lsqb"),                 // LEFT SQUARE BRACKET
+    ('\u{005C}', "bsol"),                 // REVERSE SOLIDUS
+    ('\u{005D}', "rsqb"),                 // RIGHT SQUARE BRACKET
+    ('\u{005E}', "Hat"),                  // CIRCUMFLEX ACCENT
+    ('\u{005F}', "lowbar"),               // LOW LINE
+    ('\u{0060}', "grave"),                // GRAVE ACCENT
+    ('\u{007B}', "lcub"),                 // LEFT CURLY BRACKET
+    ('\u{007C}', "verbar"),               // VERTICAL LINE
+    ('\u{007D}', "rcub"),                 // RIGHT CURLY BRACKET
+    ('\u{00A0}', "nbsp"),                 // NO-BREAK SPACE
+    ('\u{00A1}', "iexcl"),                // INVERTED EXCLAMATION MARK
+    ('\u{00A2}', "cent"),                 // CENT SIGN
+    ('\u{00A3}', "pound"),                // POUND SIGN
+    ('\u{00A4}', "curren"),               // CURRENCY SIGN
+    ('\u{00A5}', "yen"),                  // YEN SIGN
+    ('\u{00A6}', "brvbar"),               // BROKEN BAR
+    ('\u{00A7}', "sect"),                 // SECTION SIGN
+    ('\u{00A8}', "Dot"),                  // DIAERESIS
+    ('\u{00A9}', "copy"),                 // COPYRIGHT SIGN
+    ('\u{00AA}', "ordf"),                 // FEMININE ORDINAL INDICATOR
+    ('\u{00AB}', "laquo"),                // LEFT-POINTING DOUBLE ANGLE QUOTATION MARK
+    ('\u{00AC}', "not"),                  // NOT SIGN
+    ('\u{00AD}', "shy"),                  // SOFT HYPHEN
+    ('\u{00AE}', "reg"),                  // REGISTERED SIGN
+    ('\u{00AF}', "macr"),                 // MACRON
+    ('\u{00B0}', "deg"),                  // DEGREE SIGN
+    ('\u{00B1}', "plusmn"),               // PLUS-MINUS SIGN
+    ('\u{00B2}', "sup2"),                 // SUPERSCRIPT TWO
+    ('\u{00B3}', "sup3"),                 // SUPERSCRIPT THREE
+    ('\u{00B4}', "acute"),                // ACUTE ACCENT
+    ('\u{00B5}', "micro"),                // MICRO SIGN
+    ('\u{00B6}', "para"),                 // PILCROW SIGN
+    ('\u{00B7}', "middot"),               // MIDDLE DOT
+    ('\u{00B8}', "cedil"),                // CEDILLA
+    ('\u{00B9}', "sup1"),                 // SUPERSCRIPT ONE
+    ('\u{00BA}', "ordm"),                 // MASCULINE ORDINAL INDICATOR
+    ('\u{00BB}', "raquo"),                // RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK
+    ('\u{00BC}', "frac14"),               // VULGAR FRACTION ONE QUARTER
+    ('\u{00BD}', "frac12"),               // VULGAR FRACTION ONE HALF
+    ('\u{00BE}', "frac34"),               // VULGAR FRACTION THREE QUARTERS
+    ('\u{00BF}', "iquest"),               // INVERTED QUESTION MARK
+    ('\u{00C0}', "Agrave"),               // LATIN CAPITAL LETTER A WITH GRAVE
+    ('\u{00C1}', "Aacute"),               // LATIN CAPITAL LETTER A WITH ACUTE
+    ('\u{00C2}', "Acirc"),                // LATIN CAPITAL LETTER A WITH CIRCUMFLEX
+    ('\u{00C3}', "Atilde"),               // LATIN CAPITAL LETTER A WITH TILDE
+    ('\u{00C4}', "Auml"),                 // LATIN CAPITAL LETTER A WITH DIAERESIS
+    ('\u{00C5}', "Aring"),                // LATIN CAPITAL LETTER A WITH RING ABOVE
+    ('\u{00C6}', "AElig"),                // LATIN CAPITAL LETTER AE
+    ('\u{00C7}', "Ccedil"),               // LATIN CAPITAL LETTER C WITH CEDILLA
+    ('\u{00C8}', "Egrave"),               // LATIN CAPITAL LETTER E WITH GRAVE
+    ('\u{00C9}', "Eacute"),               // LATIN CAPITAL LETTER E WITH ACUTE
+    ('\u{00CA}', "Ecirc"),                // LATIN CAPITAL LETTER E WITH CIRCUMFLEX
+    ('\u{00CB}', "Euml"),                 // LATIN CAPITAL LETTER E WITH DIAERESIS
+    ('\u{00CC}', "Igrave"),               // LATIN CAPITAL LETTER I WITH GRAVE
+    ('\u{00CD}', "Iacute"),               // LATIN CAPITAL LETTER I WITH ACUTE
+    ('\u{00CE}', "Icirc"),                // LATIN CAPITAL LETTER I WITH CIRCUMFLEX
+    ('\u{00CF}', "Iuml"),                 // LATIN CAPITAL LETTER I WITH DIAERESIS
+    ('\u{00D0}', "ETH"),                  // LATIN CAPITAL LETTER ETH
+    ('\u{00D1}', "Ntilde"),               // LATIN CAPITAL LETTER N WITH TILDE
+    ('\u{00D2}', "Ograve"),               // LATIN CAPITAL LETTER O WITH GRAVE
+    ('\u{00D3}', "Oacute"),               // LATIN CAPITAL LETTER O WITH ACUTE
+    ('\u{00D4}', "Ocirc"),                // LATIN CAPITAL LETTER O WITH CIRCUMFLEX
+    ('\u{00D5}', "Otilde"),               // LATIN CAPITAL LETTER O WITH TILDE
+    ('\u{00D6}', "Ouml"),                 // LATIN CAPITAL LETTER O WITH DIAERESIS
+    ('\u{00D7}', "times"),                // MULTIPLICATION SIGN
+    ('\u{00D8}', "Oslash"),               // LATIN CAPITAL LETTER O WITH STROKE
+    ('\u{00D9}', "Ugrave"),               // LATIN CAPITAL LETTER U WITH GRAVE
+    ('\u{00DA}', "Uacute"),               // LATIN CAPITAL LETTER U WITH ACUTE
+    ('\u{00DB}', "Ucirc"),                // LATIN CAPITAL LETTER U WITH CIRCUMFLEX
+    ('\u{00DC}', "Uuml"),                 // LATIN CAPITAL LETTER U WITH DIAERESIS
+    ('\u{00DD}', "Yacute"),               // LATIN CAPITAL LETTER Y WITH ACUTE
+    ('\u{00DE}', "THORN"),                // LATIN CAPITAL LETTER THORN
+    ('\u{00DF}', "szlig"),                // LATIN SMALL LETTER SHARP S
+    ('\u{00E0}', "agrave"),               // LATIN SMALL LETTER A WITH GRAVE
+    ('\u{00E1}', "aacute"),               // LATIN SMALL LETTER A WITH ACUTE
+    ('\u{00E2}', "acirc"),                // LATIN SMALL LETTER A WITH CIRCUMFLEX
+    ('\u{00E3}', "atilde"),               // LATIN SMALL LETTER A WITH TILDE
+    ('\u{00E4}', "auml"),                 // LATIN SMALL LETTER A WITH DIAERESIS
+    ('\u{00E5}', "aring"),                // LATIN SMALL LETTER A WITH RING ABOVE
+    ('\u{00E6}', "aelig"),                // LATIN SMALL LETTER AE
+    ('\u{00E7}', "ccedil"),               // LATIN SMALL LETTER C WITH CEDILLA
+    ('\u{00E8}', "egrave"),               // LATIN SMALL LETTER E WITH GRAVE
+    ('\u{00E9}', "eacute"),               // LATIN SMALL LETTER E WITH ACUTE
+    ('\u{00EA}', "ecirc"),                // LATIN SMALL LETTER E WITH CIRCUMFLEX
+    ('\u{00EB}', "euml"),                 // LATIN SMALL LETTER E WITH DIAERESIS
+    ('\u{00EC}', "igrave"),               // LATIN SMALL LETTER I WITH GRAVE
+    ('\u{00ED}', "iacute"),               // LATIN SMALL LETTER I WITH ACUTE
+    ('\u{00EE}', "icirc"),                // LATIN SMALL LETTER I WITH CIRCUMFLEX
+    ('\u{00EF}', "iuml"),                 // LATIN SMALL LETTER I WITH DIAERESIS
+    ('\u{00F0}', "eth"),                  // LATIN SMALL LETTER ETH
+    ('\u{00F1}', "ntilde"),               // LATIN SMALL LETTER N WITH TILDE
+    ('\u{00F2}', "ograve"),               // LATIN SMALL LETTER O WITH GRAVE
+    ('\u{00F3}', "oacute"),               // LATIN SMALL LETTER O WITH ACUTE
+    ('\u{00F4}', "ocirc"),                // LATIN SMALL LETTER O WITH CIRCUMFLEX
+    ('\u{00F5}', "otilde"),               // LATIN SMALL LETTER O WITH TILDE
+    ('\u{00F6}', "ouml"),                 // LATIN SMALL LETTER O WITH DIAERESIS
+    ('\u{00F7}', "divide"),               // DIVISION SIGN
+    ('\u{00F8}', "oslash"),               // LATIN SMALL LETTER O WITH STROKE
+    ('\u{00F9}', "ugrave"),               // LATIN SMALL LETTER U WITH GRAVE
+    ('\u{00FA}', "uacute"),               // LATIN SMALL LETTER U WITH ACUTE
+    ('\u{00FB}', "ucirc"),                // LATIN SMALL LETTER U WITH CIRCUMFLEX
+    ('\u{00FC}', "uuml"),                 // LATIN SMALL LETTER U WITH DIAERESIS
+    ('\u{00FD}', "yacute"),               // LATIN SMALL LETTER Y WITH ACUTE
+    ('\u{00FE}', "thorn"),                // LATIN SMALL LETTER THORN
+    ('\u{00FF}', "yuml"),                 // LATIN SMALL LETTER Y WITH DIAERESIS
+    ('\u{0100}', "Amacr"),                // LATIN CAPITAL LETTER A WITH MACRON
+    ('\u{0101}', "amacr"),                // LATIN SMALL LETTER A WITH MACRON
+    ('\u{0102}', "Abreve"),               // LATIN CAPITAL LETTER A WITH BREVE
+    ('\u{0103}', "abreve"),               // LATIN SMALL LETTER A WITH BREVE
+    ('\u{0104}', "Aogon"),                // LATIN CAPITAL LETTER A WITH OGONEK
+    ('\u{0105}', "aogon"),                // LATIN SMALL LETTER A WITH OGONEK
+    ('\u{0106}', "Cacute"),               // LATIN CAPITAL LETTER C WITH ACUTE
+    ('\u{0107}', "cacute"),               // LATIN SMALL LETTER C WITH ACUTE
+    ('\u{0108}', "Ccirc"),                // LATIN CAPITAL LETTER C WITH CIRCUMFLEX
+    ('\u{0109}', "ccirc"),                // LATIN SMALL LETTER C WITH CIRCUMFLEX
+    ('\u{010A}', "Cdot"),                 // LATIN CAPITAL LETTER C WITH DOT ABOVE
+    ('\u{010B}', "cdot"),                 // LATIN SMALL LETTER C WITH DOT ABOVE
+    ('\u{010C}', "Ccaron"),               // LATIN CAPITAL LETTER C WITH CARON
+    ('\u{010D}', "ccaron"),               // LATIN SMALL LETTER C WITH CARON
+    ('\u{010E}', "Dcaron"),               // LATIN CAPITAL LETTER D WITH CARON
+    ('\u{010F}', "dcaron"),               // LATIN SMALL LETTER D WITH CARON
+    ('\u{0110}', "Dstrok"),               // LATIN CAPITAL LETTER D WITH STROKE
+    ('\u{0111}', "dstrok"),               // LATIN SMALL LETTER D WITH STROKE
+    ('\u{0112}', "Emacr"),                // LATIN CAPITAL LETTER E WITH MACRON
+    ('\u{0113}', "emacr"),                // LATIN SMALL LETTER E WITH MACRON
+    ('\u{0116}', "Edot"),                 // LATIN CAPITAL LETTER E WITH DOT ABOVE
+    ('\u{0117}', "edot"),                 // LATIN SMALL LETTER E WITH DOT ABOVE
+    ('\u{0118}', "Eogon"),                // LATIN CAPITAL LETTER E WITH OGONEK
+    ('\u{0119}', "eogon"),                // LATIN SMALL LETTER E WITH OGONEK
+    ('\u{011A}', "Ecaron"),               // LATIN CAPITAL LETTER E WITH CARON
+    ('\u{011B}', "ecaron"),               // LATIN SMALL LETTER E WITH CARON
+    ('\u{011C}', "Gcirc"),                // LATIN CAPITAL LETTER G WITH CIRCUMFLEX
+    ('\u{011D}', "gcirc"),                // LATIN SMALL LETTER G WITH CIRCUMFLEX
+    ('\u{011E}', "Gbreve"),               // LATIN CAPITAL LETTER G WITH BREVE
+    ('\u{011F}', "gbreve"),               // LATIN SMALL LETTER G WITH BREVE
+    ('\u{0120}', "Gdot"),                 // LATIN CAPITAL LETTER G WITH DOT ABOVE
+    ('\u{0121}', "gdot"),                 // LATIN SMALL LETTER G WITH DOT ABOVE
+    ('\u{0122}', "Gcedil"),               // LATIN CAPITAL LETTER G WITH CEDILLA
+    ('\u{0124}', "Hcirc"),                // LATIN CAPITAL LETTER H WITH CIRCUMFLEX
+    ('\u{0125}', "hcirc"),                // LATIN SMALL LETTER H WITH CIRCUMFLEX
+    ('\u{0126}', "Hstrok"),               // LATIN CAPITAL LETTER H WITH STROKE
+    ('\u{0127}', "hstrok"),               // LATIN SMALL LETTER H WITH STROKE
+    ('\u{0128}', "Itilde"),               // LATIN CAPITAL LETTER I WITH TILDE
+    ('\u{0129}', "itilde"),               // LATIN SMALL LETTER I WITH TILDE
+    ('\u{012A}', "Imacr"),                // LATIN CAPITAL LETTER I WITH MACRON
+    ('\u{012B}', "imacr"),                // LATIN SMALL LETTER I WITH MACRON
+    ('\u{012E}', "Iogon"),                // LATIN CAPITAL LETTER I WITH OGONEK
+    ('\u{012F}', "iogon"),                // LATIN SMALL LETTER I WITH OGONEK
+    ('\u{0130}', "Idot"),                 // LATIN CAPITAL LETTER I WITH DOT ABOVE
+    ('\u{0131}', "imath"),                // LATIN SMALL LETTER DOTLESS I
+    ('\u{0132}', "IJlig"),                // LATIN CAPITAL LIGATURE IJ
+    ('\u{0133}', "ijlig"),                // LATIN SMALL LIGATURE IJ
+    ('\u{0134}', "Jcirc"),                // LATIN CAPITAL LETTER J WITH CIRCUMFLEX
+    ('\u{0135}', "jcirc"),                // LATIN SMALL LETTER J WITH CIRCUMFLEX
+    ('\u{0136}', "Kcedil"),               // LATIN CAPITAL LETTER K WITH CEDILLA
+    ('\u{0137}', "kcedil"),               // LATIN SMALL LETTER K WITH CEDILLA
+    ('\u{0138}', "kgreen"),               // LATIN SMALL LETTER KRA
+    ('\u{0139}', "Lacute"),               // LATIN CAPITAL LETTER L WITH ACUTE
+    ('\u{013A}', "lacute"),               // LATIN SMALL LETTER L WITH ACUTE
+    ('\u{013B}', "Lcedil"),               // LATIN CAPITAL LETTER L WITH CEDILLA
+    ('\u{013C}', "lcedil"),               // LATIN SMALL LETTER L WITH CEDILLA
+    ('\u{013D}', "Lcaron"),               // LATIN CAPITAL LETTER L WITH CARON
+    ('\u{013E}', "lcaron"),               // LATIN SMALL LETTER L WITH CARON
+    ('\u{013F}', "Lmidot"),               // LATIN CAPITAL LETTER L WITH MIDDLE DOT
+    ('\u{0140}', "lmidot"),               // LATIN SMALL LETTER L WITH MIDDLE DOT
+    ('\u{0141}', "Lstrok"),               // LATIN CAPITAL LETTER L WITH STROKE
+    ('\u{0142}', "lstrok"),               // LATIN SMALL LETTER L WITH STROKE
+    ('\u{0143}', "Nacute"),               // LATIN CAPITAL LETTER N WITH ACUTE
+    ('\u{0144}', "nacute"),               // LATIN SMALL LETTER N WITH ACUTE
+    ('\u{0145}', "Ncedil"),               // LATIN CAPITAL LETTER N WITH CEDILLA
+    ('\u{0146}', "ncedil"),               // LATIN SMALL LETTER N WITH CEDILLA
+    ('\u{0147}', "Ncaron"),               // LATIN CAPITAL LETTER N WITH CARON
+    ('\u{0148}', "ncaron"),               // LATIN SMALL LETTER N WITH CARON
+    ('\u{0149}', "napos"),                // LATIN SMALL LETTER N PRECEDED BY APOSTROPHE
+    ('\u{014A}', "ENG"),                  // LATIN CAPITAL LETTER ENG
+    ('\u{014B}', "eng"),                  // LATIN SMALL LETTER ENG
+    ('\u{014C}', "Omacr"),                // LATIN CAPITAL LETTER O WITH MACRON
+    ('\u{014D}', "omacr"),                // LATIN SMALL LETTER O WITH MACRON
+    ('\u{0150}', "Odblac"),               // LATIN CAPITAL LETTER O WITH DOUBLE ACUTE
+    ('\u{0151}', "odblac"),               // LATIN SMALL LETTER O WITH DOUBLE ACUTE
+    ('\u{0152}', "OElig"),                // LATIN CAPITAL LIGATURE OE
+    ('\u{0153}', "oelig"),                // LATIN SMALL LIGATURE OE
+    ('\u{0154}', "Racute"),               // LATIN CAPITAL LETTER R WITH ACUTE
+    ('\u{0155}', "racute"),               // LATIN SMALL LETTER R WITH ACUTE
+    ('\u{0156}', "Rcedil"),               // LATIN CAPITAL LETTER R WITH CEDILLA
+    ('\u{0157}', "rcedil"),               // LATIN SMALL LETTER R WITH CEDILLA
+    ('\u{0158}', "Rcaron"),               // LATIN CAPITAL LETTER R WITH CARON
+    ('\u{0159}', "rcaron"),               // LATIN SMALL LETTER R WITH CARON
+    ('\u{015A}', "Sacute"),               // LATIN CAPITAL LETTER S WITH ACUTE
+    ('\u{015B}', "sacute"),               // LATIN SMALL LETTER S WITH ACUTE
+    ('\u{015C}', "Scirc"),                // LATIN CAPITAL LETTER S WITH CIRCUMFLEX
+    ('\u{015D}', "scirc"),                // LATIN SMALL LETTER S WITH CIRCUMFLEX
+    ('\u{015E}', "Scedil"),               // LATIN CAPITAL LETTER S WITH CEDILLA
+    ('\u{015F}', "scedil"),               // LATIN SMALL LETTER S WITH CEDILLA
+    ('\u{0160}', "Scaron"),               // LATIN CAPITAL LETTER S WITH CARON
+    ('\u{0161}', "scaron"),               // LATIN SMALL LETTER S WITH CARON
+    ('\u{0162}', "Tcedil"),               // LATIN CAPITAL LETTER T WITH CEDILLA
+    ('\u{0163}', "tcedil"),               // LATIN SMALL LETTER T WITH CEDILLA
+    ('\u{0164}', "Tcaron"),               // LATIN CAPITAL LETTER T WITH CARON
+    ('\u{0165}', "tcaron"),               // LATIN SMALL LETTER T WITH CARON
+    ('\u{0166}', "Tstrok"),               // LATIN CAPITAL LETTER T WITH STROKE
+    ('\u{0167}', "tstrok"),               // LATIN SMALL LETTER T WITH STROKE
+    ('\u{0168}', "Utilde"),               // LATIN CAPITAL LETTER U WITH TILDE
+    ('\u{0169}', "utilde"),               // LATIN SMALL LETTER U WITH TILDE
+    ('\u{016A}', "Umacr"),                // LATIN CAPITAL LETTER U WITH MACRON
+    ('\u{016B}', "umacr"),                // LATIN SMALL LETTER U WITH MACRON
+    ('\u{016C}', "Ubreve"),               // LATIN CAPITAL LETTER U WITH BREVE
+    ('\u{016D}', "ubreve"),               // LATIN SMALL LETTER U WITH BREVE
+    ('\u{016E}', "Uring"),                // LATIN CAPITAL LETTER U WITH RING ABOVE
+    ('\u{016F}', "uring"),                // LATIN SMALL LETTER U WITH RING ABOVE
+    ('\u{0170}', "Udblac"),               // LATIN CAPITAL LETTER U WITH DOUBLE ACUTE
+    ('\u{0171}', "udblac"),               // LATIN SMALL LETTER U WITH DOUBLE ACUTE
+    ('\u{0172}', "Uogon"),                // LATIN CAPITAL LETTER U WITH OGONEK
+    ('\u{0173}', "uogon"),                // LATIN SMALL LETTER U WITH OGONEK
+    ('\u{0174}', "Wcirc"),                // LATIN CAPITAL LETTER W WITH CIRCUMFLEX
+    ('\u{0175}', "wcirc"),                // LATIN SMALL LETTER W WITH CIRCUMFLEX
+    ('\u{0176}', "Ycirc"),                // LATIN CAPITAL LETTER Y WITH CIRCUMFLEX
+    ('\u{0177}', "ycirc"),                // LATIN SMALL LETTER Y WITH CIRCUMFLEX
+    ('\u{0178}', "Yuml"),                 // LATIN CAPITAL LETTER Y WITH DIAERESIS
+    ('\u{0179}', "Zacute"),               // LATIN CAPITAL LETTER Z WITH ACUTE
+    ('\u{017A}', "zacute"),               // LATIN SMALL LETTER Z WITH ACUTE
+    ('\u{017B}', "Zdot"),                 // LATIN CAPITAL LETTER Z WITH DOT ABOVE
+    ('\u{017C}', "zdot"),                 // LATIN SMALL LETTER Z WITH DOT ABOVE
+    ('\u{017D}', "Zcaron"),               // LATIN CAPITAL LETTER Z WITH CARON
+    ('\u{017E}', "zcaron"),               // LATIN SMALL LETTER Z WITH CARON
+    ('\u{0192}', "fnof"),                 // LATIN SMALL LETTER F WITH HOOK
+    ('\u{01B5}', "imped"),                // LATIN CAPITAL LETTER Z WITH STROKE
+    ('\u{01F5}', "gacute"),               // LATIN SMALL LETTER G WITH ACUTE
+    ('\u{0237}', "jmath"),                // LATIN SMALL LETTER DOTLESS J
+    ('\u{02C6}', "circ"),                 // MODIFIER LETTER CIRCUMFLEX ACCENT
+    ('\u{02C7}', "caron"),                // CARON
+    ('\u{02D8}', "breve"),                // BREVE
+    ('\u{02D9}', "dot"),                  // DOT ABOVE
+    ('\u{02DA}', "ring"),                 // RING ABOVE
+    ('\u{02DB}', "ogon"),                 // OGONEK
+    ('\u{02DC}', "tilde"),                // SMALL TILDE
+    ('\u{02DD}', "dblac"),                // DOUBLE ACUTE ACCENT
+    ('\u{0311}', "DownBreve"),            // COMBINING INVERTED BREVE
+    ('\u{0332}', "UnderBar"),             // COMBINING LOW LINE
+    ('\u{0391}', "Alpha"),                // GREEK CAPITAL LETTER ALPHA
+    ('\u{0392}', "Beta"),                 // GREEK CAPITAL LETTER BETA
+    ('\u{0393}', "Gamma"),                // GREEK CAPITAL LETTER GAMMA
+    ('\u{0394}', "Delta"),                // GREEK CAPITAL LETTER DELTA
+    ('\u{0395}', "Epsilon"),              // GREEK CAPITAL LETTER EPSILON
+    ('\u{0396}', "Zeta"),                 // GREEK CAPITAL LETTER ZETA
+    ('\u{0397}', "Eta"),                  // GREEK CAPITAL LETTER ETA
+    ('\u{0398}', "Theta"),                // GREEK CAPITAL LETTER THETA
+    ('\u{0399}', "Iota"),                 // GREEK CAPITAL LETTER IOTA
+    ('\u{039A}', "Kappa"),                // GREEK CAPITAL LETTER KAPPA
+    ('\u{039B}', "Lambda"),               // GREEK CAPITAL LETTER LAMDA
+    ('\u{039C}', "Mu"),                   // GREEK CAPITAL LETTER MU
+    ('\u{039D}', "Nu"),                   // GREEK CAPITAL LETTER NU
+    ('\u{039E}', "Xi"),                   // GREEK CAPITAL LETTER XI
+    ('\u{039F}', "Omicron"),              // GREEK CAPITAL LETTER OMICRON
+    ('\u{03A0}', "Pi"),                   // GREEK CAPITAL LETTER PI
+    ('\u{03A1}', "Rho"),                  // GREEK CAPITAL LETTER RHO
+    ('\u{03A3}', "Sigma"),                // GREEK CAPITAL LETTER SIGMA
+    ('\u{03A4}', "Tau"),                  // GREEK CAPITAL LETTER TAU
+    ('\u{03A5}', "Upsilon"),              // GREEK CAPITAL LETTER UPSILON
+    ('\u{03A6}', "Phi"),                  // GREEK CAPITAL LETTER PHI
+    ('\u{03A7}', "Chi"),                  // GREEK CAPITAL LETTER CHI
+    ('\u{03A8}', "Psi"),                  // GREEK CAPITAL LETTER PSI
+    ('\u{03A9}', "Omega"),                // GREEK CAPITAL LETTER OMEGA
+    ('\u{03B1}', "alpha"),                // GREEK SMALL LETTER ALPHA
+    ('\u{03B2}', "beta"),                 // GREEK SMALL LETTER BETA
+    ('\u{03B3}', "gamma"),                // GREEK SMALL LETTER GAMMA
+    ('\u{03B4}', "delta"),                // GREEK SMALL LETTER DELTA
+    ('\u{03B5}', "epsiv"),                // GREEK SMALL LETTER EPSILON
+    ('\u{03B6}', "zeta"),                 // GREEK SMALL LETTER ZETA
+    ('\u{03B7}', "eta"),                  // GREEK SMALL LETTER ETA
+    ('\u{03B8}', "theta"),                // GREEK SMALL LETTER THETA
+    ('\u{03B9}', "iota"),                 // GREEK SMALL LETTER IOTA
+    ('\u{03BA}', "kappa"),                // GREEK SMALL LETTER KAPPA
+    ('\u{03BB}', "lambda"),               // GREEK SMALL LETTER LAMDA
+    ('\u{03BC}', "mu"),                   // GREEK SMALL LETTER MU
+    ('\u{03BD}', "nu"),                   // GREEK SMALL LETTER NU
+    ('\u{03BE}', "xi"),                   // GREEK SMALL LETTER XI
+    ('\u{03BF}', "omicron"),              // GREEK SMALL LETTER OMICRON
+    ('\u{03C0}', "pi"),                   // GREEK SMALL LETTER PI
+    ('\u{03C1}', "rho"),                  // GREEK SMALL LETTER RHO
+    ('\u{03C2}', "sigmav"),               // GREEK SMALL LETTER FINAL SIGMA
+    ('\u{03C3}', "sigma"),                // GREEK SMALL LETTER SIGMA
+    ('\u{03C4}', "tau"),                  // GREEK SMALL LETTER TAU
+    ('\u{03C5}', "upsi"),                 // GREEK SMALL LETTER UPSILON
+    ('\u{03C6}', "phi"),                  // GREEK SMALL LETTER PHI
+    ('\u{03C7}', "chi"),                  // GREEK SMALL LETTER CHI
+    ('\u{03C8}', "psi"),                  // GREEK SMALL LETTER PSI
+    ('\u{03C9}', "omega"),                // GREEK SMALL LETTER OMEGA
+    ('\u{03D1}', "thetav"),               // GREEK THETA SYMBOL
+    ('\u{03D2}', "Upsi"),                 // GREEK UPSILON WITH HOOK SYMBOL
+    ('\u{03D5}', "straightphi"),          // GREEK PHI SYMBOL
+    ('\u{03D6}', "piv"),                  // GREEK PI SYMBOL
+    ('\u{03DC}', "Gammad"),               // GREEK LETTER DIGAMMA
+    ('\u{03DD}', "gammad"),               // GREEK SMALL LETTER DIGAMMA
+    ('\u{03F0}', "kappav"),               // GREEK KAPPA SYMBOL
+    ('\u{03F1}', "rhov"),                 // GREEK RHO SYMBOL
+    ('\u{03F5}', "epsi"),                 // GREEK LUNATE EPSILON SYMBOL
+    ('\u{03F6}', "bepsi"),                // GREEK REVERSED LUNATE EPSILON SYMBOL
+    ('\u{0401}', "IOcy"),                 // CYRILLIC CAPITAL LETTER IO
+    ('\u{0402}', "DJcy"),                 // CYRILLIC CAPITAL LETTER DJE
+    ('\u{0403}', "GJcy"),                 // CYRILLIC CAPITAL LETTER GJE
+    ('\u{0404}', "Jukcy"),                // CYRILLIC CAPITAL LETTER UKRAINIAN IE
+    ('\u{0405}', "DScy"),                 // CYRILLIC CAPITAL LETTER DZE
+    ('\u{0406}', "Iukcy"),                // CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0407}', "YIcy"),                 // CYRILLIC CAPITAL LETTER YI
+    ('\u{0408}', "Jsercy"),               // CYRILLIC CAPITAL LETTER JE
+    ('\u{0409}', "LJcy"),                 // CYRILLIC CAPITAL LETTER LJE
+    ('\u{040A}', "NJcy"),                 // CYRILLIC CAPITAL LETTER NJE
+    ('\u{040B}', "TSHcy"),                // CYRILLIC CAPITAL LETTER TSHE
+    ('\u{040C}', "KJcy"),                 // CYRILLIC CAPITAL LETTER KJE
+    ('\u{040E}', "Ubrcy"),                // CYRILLIC CAPITAL LETTER SHORT U
+    ('\u{040F}', "DZcy"),                 // CYRILLIC CAPITAL LETTER DZHE
+    ('\u{0410}', "Acy"),                  // CYRILLIC CAPITAL LETTER A
+    ('\u{0411}', "Bcy"),                  // CYRILLIC CAPITAL LETTER BE
+    ('\u{0412}', "Vcy"),                  // CYRILLIC CAPITAL LETTER VE
+    ('\u{0413}', "Gcy"),                  // CYRILLIC CAPITAL LETTER GHE
+    ('\u{0414}', "Dcy"),                  // CYRILLIC CAPITAL LETTER DE
+    ('\u{0415}', "IEcy"),                 // CYRILLIC CAPITAL LETTER IE
+    ('\u{0416}', "ZHcy"),                 // CYRILLIC CAPITAL LETTER ZHE
+    ('\u{0417}', "Zcy"),                  // CYRILLIC CAPITAL LETTER ZE
+    ('\u{0418}', "Icy"),                  // CYRILLIC CAPITAL LETTER I
+    ('\u{0419}', "Jcy"),                  // CYRILLIC CAPITAL LETTER SHORT I
+    ('\u{041A}', "Kcy"),                  // CYRILLIC CAPITAL LETTER KA
+    ('\u{041B}', "Lcy"),                  // CYRILLIC CAPITAL LETTER EL
+    ('\u{041C}', "Mcy"),                  // CYRILLIC CAPITAL LETTER EM
+    ('\u{041D}', "Ncy"),                  // CYRILLIC CAPITAL LETTER EN
+    ('\u{041E}', "Ocy"),                  // CYRILLIC CAPITAL LETTER O
+    ('\u{041F}', "Pcy"),                  // CYRILLIC CAPITAL LETTER PE
+    ('\u{0420}', "Rcy"),                  // CYRILLIC CAPITAL LETTER ER
+    ('\u{0421}', "Scy"),                  // CYRILLIC CAPITAL LETTER ES
+    ('\u{0422}', "Tcy"),                  // CYRILLIC CAPITAL LETTER TE
+    ('\u{0423}', "Ucy"),                  // CYRILLIC CAPITAL LETTER U
+    ('\u{0424}', "Fcy"),                  // CYRILLIC CAPITAL LETTER EF
+    ('\u{0425}', "KHcy"),                 // CYRILLIC CAPITAL LETTER HA
+    ('\u{0426}', "TScy"),                 // CYRILLIC CAPITAL LETTER TSE
+    ('\u{0427}', "CHcy"),                 // CYRILLIC CAPITAL LETTER CHE
+    ('\u{0428}', "SHcy"),                 // CYRILLIC CAPITAL LETTER SHA
+    ('\u{0429}', "SHCHcy"),               // CYRILLIC CAPITAL LETTER SHCHA
+    ('\u{042A}', "HARDcy"),               // CYRILLIC CAPITAL LETTER HARD SIGN
+    ('\u{042B}', "Ycy"),                  // CYRILLIC CAPITAL LETTER YERU
+    ('\u{042C}', "SOFTcy"),               // CYRILLIC CAPITAL LETTER SOFT SIGN
+    ('\u{042D}', "Ecy"),                  // CYRILLIC CAPITAL LETTER E
+    ('\u{042E}', "YUcy"),                 // CYRILLIC CAPITAL LETTER YU
+    ('\u{042F}', "YAcy"),                 // CYRILLIC CAPITAL LETTER YA
+    ('\u{0430}', "acy"),                  // CYRILLIC SMALL LETTER A
+    ('\u{0431}', "bcy"),                  // CYRILLIC SMALL LETTER BE
+    ('\u{0432}', "vcy"),                  // CYRILLIC SMALL LETTER VE
+    ('\u{0433}', "gcy"),                  // CYRILLIC SMALL LETTER GHE
+    ('\u{0434}', "dcy"),                  // CYRILLIC SMALL LETTER DE
+    ('\u{0435}', "iecy"),                 // CYRILLIC SMALL LETTER IE
+    ('\u{0436}', "zhcy"),                 // CYRILLIC SMALL LETTER ZHE
+    ('\u{0437}', "zcy"),                  // CYRILLIC SMALL LETTER ZE
+    ('\u{0438}', "icy"),                  // CYRILLIC SMALL LETTER I
+    ('\u{0439}', "jcy"),                  // CYRILLIC SMALL LETTER SHORT I
+    ('\u{043A}', "kcy"),                  // CYRILLIC SMALL LETTER KA
+    ('\u{043B}', "lcy"),                  // CYRILLIC SMALL LETTER EL
+    ('\u{043C}', "mcy"),                  // CYRILLIC SMALL LETTER EM
+    ('\u{043D}', "ncy"),                  // CYRILLIC SMALL LETTER EN
+    ('\u{043E}', "ocy"),                  // CYRILLIC SMALL LETTER O
+    ('\u{043F}', "pcy"),                  // CYRILLIC SMALL LETTER PE
+    ('\u{0440}', "rcy"),                  // CYRILLIC SMALL LETTER ER
+    ('\u{0441}', "scy"),                  // CYRILLIC SMALL LETTER ES
+    ('\u{0442}', "tcy"),                  // CYRILLIC SMALL LETTER TE
+    ('\u{0443}', "ucy"),                  // CYRILLIC SMALL LETTER U
+    ('\u{0444}', "fcy"),                  // CYRILLIC SMALL LETTER EF
+    ('\u{0445}', "khcy"),                 // CYRILLIC SMALL LETTER HA
+    ('\u{0446}', "tscy"),                 // CYRILLIC SMALL LETTER TSE
+    ('\u{0447}', "chcy"),                 // CYRILLIC SMALL LETTER CHE
+    ('\u{0448}', "shcy"),                 // CYRILLIC SMALL LETTER SHA
+    ('\u{0449}', "shchcy"),               // CYRILLIC SMALL LETTER SHCHA
+    ('\u{044A}', "hardcy"),               // CYRILLIC SMALL LETTER HARD SIGN
+    ('\u{044B}', "ycy"),                  // CYRILLIC SMALL LETTER YERU
+    ('\u{044C}', "softcy"),               // CYRILLIC SMALL LETTER SOFT SIGN
+    ('\u{044D}', "ecy"),                  // CYRILLIC SMALL LETTER E
+    ('\u{044E}', "yucy"),                 // CYRILLIC SMALL LETTER YU
+    ('\u{044F}', "yacy"),                 // CYRILLIC SMALL LETTER YA
+    ('\u{0451}', "iocy"),                 // CYRILLIC SMALL LETTER IO
+    ('\u{0452}', "djcy"),                 // CYRILLIC SMALL LETTER DJE
+    ('\u{0453}', "gjcy"),                 // CYRILLIC SMALL LETTER GJE
+    ('\u{0454}', "jukcy"),                // CYRILLIC SMALL LETTER UKRAINIAN IE
+    ('\u{0455}', "dscy"),                 // CYRILLIC SMALL LETTER DZE
+    ('\u{0456}', "iukcy"),                // CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0457}', "yicy"),                 // CYRILLIC SMALL LETTER YI
+    ('\u{0458}', "jsercy"),               // CYRILLIC SMALL LETTER JE
+    ('\u{0459}', "ljcy"),                 // CYRILLIC SMALL LETTER LJE
+    ('\u{045A}', "njcy"),                 // CYRILLIC SMALL LETTER NJE
+    ('\u{045B}', "tshcy"),                // CYRILLIC SMALL LETTER TSHE
+    ('\u{045C}', "kjcy"),                 // CYRILLIC SMALL LETTER KJE
+    ('\u{045E}', "ubrcy"),                // CYRILLIC SMALL LETTER SHORT U
+    ('\u{045F}', "dzcy"),                 // CYRILLIC SMALL LETTER DZHE
+    ('\u{2002}', "ensp"),                 // EN SPACE
+    ('\u{2003}', "emsp"),                 // EM SPACE
+    ('\u{2004}', "emsp13"),               // THREE-PER-EM SPACE
+    ('\u{2005}', "emsp14"),               // FOUR-PER-EM SPACE
+    ('\u{2007}', "numsp"),                // FIGURE SPACE
+    ('\u{2008}', "puncsp"),               // PUNCTUATION SPACE
+    ('\u{2009}', "thinsp"),               // THIN SPACE
+    ('\u{200A}', "hairsp"),               // HAIR SPACE
+    ('\u{200B}', "ZeroWidthSpace"),       // ZERO WIDTH SPACE
+    ('\u{200C}', "zwnj"),                 // ZERO WIDTH NON-JOINER
+    ('\u{200D}', "zwj"),                  // ZERO WIDTH JOINER
+    ('\u{200E}', "lrm"),                  // LEFT-TO-RIGHT MARK
+    ('\u{200F}', "rlm"),                  // RIGHT-TO-LEFT MARK
+    ('\u{2010}', "hyphen"),               // HYPHEN
+    ('\u{2013}', "ndash"),                // EN DASH
+    ('\u{2014}', "mdash"),                // EM DASH
+    ('\u{2015}', "horbar"),               // HORIZONTAL BAR
+    ('\u{2016}', "Verbar"),               // DOUBLE VERTICAL LINE
+    ('\u{2018}', "lsquo"),                // LEFT SINGLE QUOTATION MARK
+    ('\u{2019}', "rsquo"),                // RIGHT SINGLE QUOTATION MARK
+    ('\u{201A}', "lsquor"),               // SINGLE LOW-9 QUOTATION MARK
+    ('\u{201C}', "ldquo"),                // LEFT DOUBLE QUOTATION MARK
+    ('\u{201D}', "rdquo"),                // RIGHT DOUBLE QUOTATION MARK
+    ('\u{201E}', "ldquor"),               // DOUBLE LOW-9 QUOTATION MARK
+    ('\u{2020}', "dagger"),               // DAGGER
+    ('\u{2021}', "Dagger"),               // DOUBLE DAGGER
+    ('\u{2022}', "bull"),                 // BULLET
+    ('\u{2025}', "nldr"),                 // TWO DOT LEADER
+    ('\u{2026}', "hellip"),               // HORIZONTAL ELLIPSIS
+    ('\u{2030}', "permil"),               // PER MILLE SIGN
+    ('\u{2031}', "pertenk"),              // PER TEN THOUSAND SIGN
+    ('\u{2032}', "prime"),                // PRIME
+    ('\u{2033}', "Prime"),                // DOUBLE PRIME
+    ('\u{2034}', "tprime"),               // TRIPLE PRIME
+    ('\u{2035}', "bprime"),               // REVERSED PRIME
+    ('\u{2039}', "lsaquo"),               // SINGLE LEFT-POINTING ANGLE QUOTATION MARK
+    ('\u{203A}', "rsaquo"),               // SINGLE RIGHT-POINTING ANGLE QUOTATION MARK
+    ('\u{203E}', "oline"),                // OVERLINE
+    ('\u{2041}', "caret"),                // CARET INSERTION POINT
+    ('\u{2043}', "hybull"),               // HYPHEN BULLET
+    ('\u{2044}', "frasl"),                // FRACTION SLASH
+    ('\u{204F}', "bsemi"),                // REVERSED SEMICOLON
+    ('\u{2057}', "qprime"),               // QUADRUPLE PRIME
+    ('\u{205F}', "MediumSpace"),          // MEDIUM MATHEMATICAL SPACE
+    ('\u{2060}', "NoBreak"),              // WORD JOINER
+    ('\u{2061}', "ApplyFunction"),        // FUNCTION APPLICATION
+    ('\u{2062}', "InvisibleTimes"),       // INVISIBLE TIMES
+    ('\u{2063}', "InvisibleComma"),       // INVISIBLE SEPARATOR
+    ('\u{20AC}', "euro"),                 // EURO SIGN
+    ('\u{20DB}', "tdot"),                 // COMBINING THREE DOTS ABOVE
+    ('\u{20DC}', "DotDot"),               // COMBINING FOUR DOTS ABOVE
+    ('\u{2102}', "Copf"),                 // DOUBLE-STRUCK CAPITAL C
+    ('\u{2105}', "incare"),               // CARE OF
+    ('\u{210A}', "gscr"),                 // SCRIPT SMALL G
+    ('\u{210B}', "hamilt"),               // SCRIPT CAPITAL H
+    ('\u{210C}', "Hfr"),                  // BLACK-LETTER CAPITAL H
+    ('\u{210D}', "quaternions"),          // DOUBLE-STRUCK CAPITAL H
+    ('\u{210E}', "planckh"),              // PLANCK CONSTANT
+    ('\u{210F}', "planck"),               // PLANCK CONSTANT OVER TWO PI
+    ('\u{2110}', "Iscr"),                 // SCRIPT CAPITAL I
+    ('\u{2111}', "image"),                // BLACK-LETTER CAPITAL I
+    ('\u{2112}', "Lscr"),                 // SCRIPT CAPITAL L
+    ('\u{2113}', "ell"),                  // SCRIPT SMALL L
+    ('\u{2115}', "Nopf"),                 // DOUBLE-STRUCK CAPITAL N
+    ('\u{2116}', "numero"),               // NUMERO SIGN
+    ('\u{2117}', "copysr"),               // SOUND RECORDING COPYRIGHT
+    ('\u{2118}', "weierp"),               // SCRIPT CAPITAL P
+    ('\u{2119}', "Popf"),                 // DOUBLE-STRUCK CAPITAL P
+    ('\u{211A}', "rationals"),            // DOUBLE-STRUCK CAPITAL Q
+    ('\u{211B}', "Rscr"),                 // SCRIPT CAPITAL R
+    ('\u{211C}', "real"),                 // BLACK-LETTER CAPITAL R
+    ('\u{211D}', "reals"),                // DOUBLE-STRUCK CAPITAL R
+    ('\u{211E}', "rx"),                   // PRESCRIPTION TAKE
+    ('\u{2122}', "trade"),                // TRADE MARK SIGN
+    ('\u{2124}', "integers"),             // DOUBLE-STRUCK CAPITAL Z
+    ('\u{2126}', "ohm"),                  // OHM SIGN
+    ('\u{2127}', "mho"),                  // INVERTED OHM SIGN
+    ('\u{2128}', "Zfr"),                  // BLACK-LETTER CAPITAL Z
+    ('\u{2129}', "iiota"),                // TURNED GREEK SMALL LETTER IOTA
+    ('\u{212B}', "angst"),                // ANGSTROM SIGN
+    ('\u{212C}', "bernou"),               // SCRIPT CAPITAL B
+    ('\u{212D}', "Cfr"),                  // BLACK-LETTER CAPITAL C
+    ('\u{212F}', "escr"),                 // SCRIPT SMALL E
+    ('\u{2130}', "Escr"),                 // SCRIPT CAPITAL E
+    ('\u{2131}', "Fscr"),                 // SCRIPT CAPITAL F
+    ('\u{2133}', "phmmat"),               // SCRIPT CAPITAL M
+    ('\u{2134}', "order"),                // SCRIPT SMALL O
+    ('\u{2135}', "alefsym"),              // ALEF SYMBOL
+    ('\u{2136}', "beth"),                 // BET SYMBOL
+    ('\u{2137}', "gimel"),                // GIMEL SYMBOL
+    ('\u{2138}', "daleth"),               // DALET SYMBOL
+    ('\u{2145}', "CapitalDifferentialD"), // DOUBLE-STRUCK ITALIC CAPITAL D
+    ('\u{2146}', "DifferentialD"),        // DOUBLE-STRUCK ITALIC SMALL D
+    ('\u{2147}', "ExponentialE"),         // DOUBLE-STRUCK ITALIC SMALL E
+    ('\u{2148}', "ImaginaryI"),           // DOUBLE-STRUCK ITALIC SMALL I
+    ('\u{2153}', "frac13"),               // VULGAR FRACTION ONE THIRD
+    ('\u{2154}', "frac23"),               // VULGAR FRACTION TWO THIRDS
+    ('\u{2155}', "frac15"),               // VULGAR FRACTION ONE FIFTH
+    ('\u{2156}', "frac25"),               // VULGAR FRACTION TWO FIFTHS
+    ('\u{2157}', "frac35"),               // VULGAR FRACTION THREE FIFTHS
+    ('\u{2158}', "frac45"),               // VULGAR FRACTION FOUR FIFTHS
+    ('\u{2159}', "frac16"),               // VULGAR FRACTION ONE SIXTH
+    ('\u{215A}', "frac56"),               // VULGAR FRACTION FIVE SIXTHS
+    ('\u{215B}', "frac18"),               // VULGAR FRACTION ONE EIGHTH
+    ('\u{215C}', "frac38"),               // VULGAR FRACTION THREE EIGHTHS
+    ('\u{215D}', "frac58"),               // VULGAR FRACTION FIVE EIGHTHS
+    ('\u{215E}', "frac78"),               // VULGAR FRACTION SEVEN EIGHTHS
+    ('\u{2190}', "larr"),                 // LEFTWARDS ARROW
+    ('\u{2191}', "uarr"),                 // UPWARDS ARROW
+    ('\u{2192}', "rarr"),                 // RIGHTWARDS ARROW
+    ('\u{2193}', "darr"),                 // DOWNWARDS ARROW
+    ('\u{2194}', "harr"),                 // LEFT RIGHT ARROW
+    ('\u{2195}', "varr"),                 // UP DOWN ARROW
+    ('\u{2196}', "nwarr"),                // NORTH WEST ARROW
+    ('\u{2197}', "nearr"),                // NORTH EAST ARROW
+    ('\u{2198}', "searr"),                // SOUTH EAST ARROW
+    ('\u{2199}', "swarr"),                // SOUTH WEST ARROW
+    ('\u{219A}', "nlarr"),                // LEFTWARDS ARROW WITH STROKE
+    ('\u{219B}', "nrarr"),                // RIGHTWARDS ARROW WITH STROKE
+    ('\u{219D}', "rarrw"),                // RIGHTWARDS WAVE ARROW
+    ('\u{219E}', "Larr"),                 // LEFTWARDS TWO HEADED ARROW
+    ('\u{219F}', "Uarr"),                 // UPWARDS TWO HEADED ARROW
+    ('\u{21A0}', "Rarr"),                 // RIGHTWARDS TWO HEADED ARROW
+    ('\u{21A1}', "Darr"),                 // DOWNWARDS TWO HEADED ARROW
+    ('\u{21A2}', "larrtl"),               // LEFTWARDS ARROW WITH TAIL
+    ('\u{21A3}', "rarrtl"),               // RIGHTWARDS ARROW WITH TAIL
+    ('\u{21A4}', "LeftTeeArrow"),         // LEFTWARDS ARROW FROM BAR
+    ('\u{21A5}', "UpTeeArrow"),           // UPWARDS ARROW FROM BAR
+    ('\u{21A6}', "map"),                  // RIGHTWARDS ARROW FROM BAR
+    ('\u{21A7}', "DownTeeArrow"),         // DOWNWARDS ARROW FROM BAR
+    ('\u{21A9}', "larrhk"),               // LEFTWARDS ARROW WITH HOOK
+    ('\u{21AA}', "rarrhk"),               // RIGHTWARDS ARROW WITH HOOK
+    ('\u{21AB}', "larrlp"),               // LEFTWARDS ARROW WITH LOOP
+    ('\u{21AC}', "rarrlp"),               // RIGHTWARDS ARROW WITH LOOP
+    ('\u{21AD}', "harrw"),                // LEFT RIGHT WAVE ARROW
+    ('\u{21AE}', "nharr"),                // LEFT RIGHT ARROW WITH STROKE
+    ('\u{21B0}', "lsh"),                  // UPWARDS ARROW WITH TIP LEFTWARDS
+    ('\u{21B1}', "rsh"),                  // UPWARDS ARROW WITH TIP RIGHTWARDS
+    ('\u{21B2}', "ldsh"),                 // DOWNWARDS ARROW WITH TIP LEFTWARDS
+    ('\u{21B3}', "rdsh"),                 // DOWNWARDS ARROW WITH TIP RIGHTWARDS
+    ('\u{21B5}', "crarr"),                // DOWNWARDS ARROW WITH CORNER LEFTWARDS
+    ('\u{21B6}', "cularr"),               // ANTICLOCKWISE TOP SEMICIRCLE ARROW
+    ('\u{21B7}', "curarr"),               // CLOCKWISE TOP SEMICIRCLE ARROW
+    ('\u{21BA}', "olarr"),                // ANTICLOCKWISE OPEN CIRCLE ARROW
+    ('\u{21BB}', "orarr"),                // CLOCKWISE OPEN CIRCLE ARROW
+    ('\u{21BC}', "lharu"),                // LEFTWARDS HARPOON WITH BARB UPWARDS
+    ('\u{21BD}', "lhard"),                // LEFTWARDS HARPOON WITH BARB DOWNWARDS
+    ('\u{21BE}', "uharr"),                // UPWARDS HARPOON WITH BARB RIGHTWARDS
+    ('\u{21BF}', "uharl"),                // UPWARDS HARPOON WITH BARB LEFTWARDS
+    ('\u{21C0}', "rharu"),                // RIGHTWARDS HARPOON WITH BARB UPWARDS
+    ('\u{21C1}', "rhard"),                // RIGHTWARDS HARPOON WITH BARB DOWNWARDS
+    ('\u{21C2}', "dharr"),                // DOWNWARDS HARPOON WITH BARB RIGHTWARDS
+    ('\u{21C3}', "dharl"),                // DOWNWARDS HARPOON WITH BARB LEFTWARDS
+    ('\u{21C4}', "rlarr"),                // RIGHTWARDS ARROW OVER LEFTWARDS ARROW
+    ('\u{21C5}', "udarr"),                // UPWARDS ARROW LEFTWARDS OF DOWNWARDS ARROW
+    ('\u{21C6}', "lrarr"),                // LEFTWARDS ARROW OVER RIGHTWARDS ARROW
+    ('\u{21C7}', "llarr"),                // LEFTWARDS PAIRED ARROWS
+    ('\u{21C8}', "uuarr"),                // UPWARDS PAIRED ARROWS
+    ('\u{21C9}', "rrarr"),                // RIGHTWARDS PAIRED ARROWS
+    ('\u{21CA}', "ddarr"),                // DOWNWARDS PAIRED ARROWS
+    ('\u{21CB}', "lrhar"),                // LEFTWARDS HARPOON OVER RIGHTWARDS HARPOON
+    ('\u{21CC}', "rlhar"),                // RIGHTWARDS HARPOON OVER LEFTWARDS HARPOON
+    ('\u{21CD}', "nlArr"),                // LEFTWARDS DOUBLE ARROW WITH STROKE
+    ('\u{21CE}', "nhArr"),                // LEFT RIGHT DOUBLE ARROW WITH STROKE
+    ('\u{21CF}', "nrArr"),                // RIGHTWARDS DOUBLE ARROW WITH STROKE
+    ('\u{21D0}', "lArr"),                 // LEFTWARDS DOUBLE ARROW
+    ('\u{21D1}', "uArr"),                 // UPWARDS DOUBLE ARROW
+    ('\u{21D2}', "rArr"),                 // RIGHTWARDS DOUBLE ARROW
+    ('\u{21D3}', "dArr"),                 // DOWNWARDS DOUBLE ARROW
+    ('\u{21D4}', "hArr"),                 // LEFT RIGHT DOUBLE ARROW
+    ('\u{21D5}', "vArr"),                 // UP DOWN DOUBLE ARROW
+    ('\u{21D6}', "nwArr"),                // NORTH WEST DOUBLE ARROW
+    ('\u{21D7}', "neArr"),                // NORTH EAST DOUBLE ARROW
+    ('\u{21D8}', "seArr"),                // SOUTH EAST DOUBLE ARROW
+    ('\u{21D9}', "swArr"),                // SOUTH WEST DOUBLE ARROW
+    ('\u{21DA}', "lAarr"),                // LEFTWARDS TRIPLE ARROW
+    ('\u{21DB}', "rAarr"),                // RIGHTWARDS TRIPLE ARROW
+    ('\u{21DD}', "zigrarr"),              // RIGHTWARDS SQUIGGLE ARROW
+    ('\u{21E4}', "larrb"),                // LEFTWARDS ARROW TO BAR
+    ('\u{21E5}', "rarrb"),                // RIGHTWARDS ARROW TO BAR
+    ('\u{21F5}', "duarr"),                // DOWNWARDS ARROW LEFTWARDS OF UPWARDS ARROW
+    ('\u{21FD}', "loarr"),                // LEFTWARDS OPEN-HEADED ARROW
+    ('\u{21FE}', "roarr"),                // RIGHTWARDS OPEN-HEADED ARROW
+    ('\u{21FF}', "hoarr"),                // LEFT RIGHT OPEN-HEADED ARROW
+    ('\u{2200}', "forall"),               // FOR ALL
+    ('\u{2201}', "comp"),                 // COMPLEMENT
+    ('\u{2202}', "part"),                 // PARTIAL DIFFERENTIAL
+    ('\u{2203}', "exist"),                // THERE EXISTS
+    ('\u{2204}', "nexist"),               // THERE DOES NOT EXIST
+    ('\u{2205}', "empty"),                // EMPTY SET
+    ('\u{2207}', "nabla"),                // NABLA
+    ('\u{2208}', "isin"),                 // ELEMENT OF
+    ('\u{2209}', "notin"),                // NOT AN ELEMENT OF
+    ('\u{220B}', "niv"),                  // CONTAINS AS MEMBER
+    ('\u{220C}', "notni"),                // DOES NOT CONTAIN AS MEMBER
+    ('\u{220F}', "prod"),                 // N-ARY PRODUCT
+    ('\u{2210}', "coprod"),               // N-ARY COPRODUCT
+    ('\u{2211}', "sum"),                  // N-ARY SUMMATION
+    ('\u{2212}', "minus"),                // MINUS SIGN
+    ('\u{2213}', "mnplus"),               // MINUS-OR-PLUS SIGN
+    ('\u{2214}', "plusdo"),               // DOT PLUS
+    ('\u{2216}', "setmn"),                // SET MINUS
+    ('\u{2217}', "lowast"),               // ASTERISK OPERATOR
+    ('\u{2218}', "compfn"),               // RING OPERATOR
+    ('\u{221A}', "radic"),                // SQUARE ROOT
+    ('\u{221D}', "prop"),                 // PROPORTIONAL TO
+    ('\u{221E}', "infin"),                // INFINITY
+    ('\u{221F}', "angrt"),                // RIGHT ANGLE
+    ('\u{2220}', "ang"),                  // ANGLE
+    ('\u{2221}', "angmsd"),               // MEASURED ANGLE
+    ('\u{2222}', "angsph"),               // SPHERICAL ANGLE
+    ('\u{2223}', "mid"),                  // DIVIDES
+    ('\u{2224}', "nmid"),                 // DOES NOT DIVIDE
+    ('\u{2225}', "par"),                  // PARALLEL TO
+    ('\u{2226}', "npar"),                 // NOT PARALLEL TO
+    ('\u{2227}', "and"),                  // LOGICAL AND
+    ('\u{2228}', "or"),                   // LOGICAL OR
+    ('\u{2229}', "cap"),                  // INTERSECTION
+    ('\u{222A}', "cup"),                  // UNION
+    ('\u{222B}', "int"),                  // INTEGRAL
+    ('\u{222C}', "Int"),                  // DOUBLE INTEGRAL
+    ('\u{222D}', "tint"),                 // TRIPLE INTEGRAL
+    ('\u{222E}', "conint"),               // CONTOUR INTEGRAL
+    ('\u{222F}', "Conint"),               // SURFACE INTEGRAL
+    ('\u{2230}', "Cconint"),              // VOLUME INTEGRAL
+    ('\u{2231}', "cwint"),                // CLOCKWISE INTEGRAL
+    ('\u{2232}', "cwconint"),             // CLOCKWISE CONTOUR INTEGRAL
+    ('\u{2233}', "awconint"),             // ANTICLOCKWISE CONTOUR INTEGRAL
+    ('\u{2234}', "there4"),               // THEREFORE
+    ('\u{2235}', "becaus"),               // BECAUSE
+    ('\u{2236}', "ratio"),                // RATIO
+    ('\u{2237}', "Colon"),                // PROPORTION
+    ('\u{2238}', "minusd"),               // DOT MINUS
+    ('\u{223A}', "mDDot"),                // GEOMETRIC PROPORTION
+    ('\u{223B}', "homtht"),               // HOMOTHETIC
+    ('\u{223C}', "sim"),                  // TILDE OPERATOR
+    ('\u{223D}', "bsim"),                 // REVERSED TILDE
+    ('\u{223E}', "ac"),                   // INVERTED LAZY S
+    ('\u{223F}', "acd"),                  // SINE WAVE
+    ('\u{2240}', "wreath"),               // WREATH PRODUCT
+    ('\u{2241}', "nsim"),                 // NOT TILDE
+    ('\u{2242}', "esim"),                 // MINUS TILDE
+    ('\u{2243}', "sime"),                 // ASYMPTOTICALLY EQUAL TO
+    ('\u{2244}', "nsime"),                // NOT ASYMPTOTICALLY EQUAL TO
+    ('\u{2245}', "cong"),                 // APPROXIMATELY EQUAL TO
+    ('\u{2246}', "simne"),                // APPROXIMATELY BUT NOT ACTUALLY EQUAL TO
+    ('\u{2247}', "ncong"),                // NEITHER APPROXIMATELY NOR ACTUALLY EQUAL TO
+    ('\u{2248}', "asymp"),                // ALMOST EQUAL TO
+    ('\u{2249}', "nap"),                  // NOT ALMOST EQUAL TO
+    ('\u{224A}', "ape"),                  // ALMOST EQUAL OR EQUAL TO
+    ('\u{224B}', "apid"),                 // TRIPLE TILDE
+    ('\u{224C}', "bcong"),                // ALL EQUAL TO
+    ('\u{224D}', "asympeq"),              // EQUIVALENT TO
+    ('\u{224E}', "bump"),                 // GEOMETRICALLY EQUIVALENT TO
+    ('\u{224F}', "bumpe"),                // DIFFERENCE BETWEEN
+    ('\u{2250}', "esdot"),                // APPROACHES THE LIMIT
+    ('\u{2251}', "eDot"),                 // GEOMETRICALLY EQUAL TO
+    ('\u{2252}', "efDot"),                // APPROXIMATELY EQUAL TO OR THE IMAGE OF
+    ('\u{2253}', "erDot"),                // IMAGE OF OR APPROXIMATELY EQUAL TO
+    ('\u{2254}', "colone"),               // COLON EQUALS
+    ('\u{2255}', "ecolon"),               // EQUALS COLON
+    ('\u{2256}', "ecir"),                 // RING IN EQUAL TO
+    ('\u{2257}', "cire"),                 // RING EQUAL TO
+    ('\u{2259}', "wedgeq"),               // ESTIMATES
+    ('\u{225A}', "veeeq"),                // EQUIANGULAR TO
+    ('\u{225C}', "trie"),                 // DELTA EQUAL TO
+    ('\u{225F}', "equest"),               // QUESTIONED EQUAL TO
+    ('\u{2260}', "ne"),                   // NOT EQUAL TO
+    ('\u{2261}', "equiv"),                // IDENTICAL TO
+    ('\u{2262}', "nequiv"),               // NOT IDENTICAL TO
+    ('\u{2264}', "le"),                   // LESS-THAN OR EQUAL TO
+    ('\u{2265}', "ge"),                   // GREATER-THAN OR EQUAL TO
+    ('\u{2266}', "lE"),                   // LESS-THAN OVER EQUAL TO
+    ('\u{2267}', "gE"),                   // GREATER-THAN OVER EQUAL TO
+    ('\u{2268}', "lnE"),                  // LESS-THAN BUT NOT EQUAL TO
+    ('\u{2269}', "gnE"),                  // GREATER-THAN BUT NOT EQUAL TO
+    ('\u{226A}', "Lt"),                   // MUCH LESS-THAN
+    ('\u{226B}', "Gt"),                   // MUCH GREATER-THAN
+    ('\u{226C}', "twixt"),                // BETWEEN
+    ('\u{226D}', "NotCupCap"),            // NOT EQUIVALENT TO
+    ('\u{226E}', "nlt"),                  // NOT LESS-THAN
+    ('\u{226F}', "ngt"),                  // NOT GREATER-THAN
+    ('\u{2270}', "nle"),                  // NEITHER LESS-THAN NOR EQUAL TO
+    ('\u{2271}', "nge"),                  // NEITHER GREATER-THAN NOR EQUAL TO
+    ('\u{2272}', "lsim"),                 // LESS-THAN OR EQUIVALENT TO
+    ('\u{2273}', "gsim"),                 // GREATER-THAN OR EQUIVALENT TO
+    ('\u{2274}', "nlsim"),                // NEITHER LESS-THAN NOR EQUIVALENT TO
+    ('\u{2275}', "ngsim"),                // NEITHER GREATER-THAN NOR EQUIVALENT TO
+    ('\u{2276}', "lg"),                   // LESS-THAN OR GREATER-THAN
+    ('\u{2277}', "gl"),                   // GREATER-THAN OR LESS-THAN
+    ('\u{2278}', "ntlg"),                 // NEITHER LESS-THAN NOR GREATER-THAN
+    ('\u{2279}', "ntgl"),                 // NEITHER GREATER-THAN NOR LESS-THAN
+    ('\u{227A}', "pr"),                   // PRECEDES
+    ('\u{227B}', "sc"),                   // SUCCEEDS
+    ('\u{227C}', "prcue"),                // PRECEDES OR EQUAL TO
+    ('\u{227D}', "sccue"),                // SUCCEEDS OR EQUAL TO
+    ('\u{227E}', "prsim"),                // PRECEDES OR EQUIVALENT TO
+    ('\u{227F}', "scsim"),                // SUCCEEDS OR EQUIVALENT TO
+    ('\u{2280}', "npr"),                  // DOES NOT PRECEDE
+    ('\u{2281}', "nsc"),                  // DOES NOT SUCCEED
+    ('\u{2282}', "sub"),                  // SUBSET OF
+    ('\u{2283}', "sup"),                  // SUPERSET OF
+    ('\u{2284}', "nsub"),                 // NOT A SUBSET OF
+    ('\u{2285}', "nsup"),                 // NOT A SUPERSET OF
+    ('\u{2286}', "sube"),                 // SUBSET OF OR EQUAL TO
+    ('\u{2287}', "supe"),                 // SUPERSET OF OR EQUAL TO
+    ('\u{2288}', "nsube"),                // NEITHER A SUBSET OF NOR EQUAL TO
+    ('\u{2289}', "nsupe"),                // NEITHER A SUPERSET OF NOR EQUAL TO
+    ('\u{228A}', "subne"),                // SUBSET OF WITH NOT EQUAL TO
+    ('\u{228B}', "supne"),                // SUPERSET OF WITH NOT EQUAL TO
+    ('\u{228D}', "cupdot"),               // MULTISET MULTIPLICATION
+    ('\u{228E}', "uplus"),                // MULTISET UNION
+    ('\u{228F}', "sqsub"),                // SQUARE IMAGE OF
+    ('\u{2290}', "sqsup"),                // SQUARE ORIGINAL OF
+    ('\u{2291}', "sqsube"),               // SQUARE IMAGE OF OR EQUAL TO
+    ('\u{2292}', "sqsupe"),               // SQUARE ORIGINAL OF OR EQUAL TO
+    ('\u{2293}', "sqcap"),                // SQUARE CAP
+    ('\u{2294}', "sqcup"),                // SQUARE CUP
+    ('\u{2295}', "oplus"),                // CIRCLED PLUS
+    ('\u{2296}', "ominus"),               // CIRCLED MINUS
+    ('\u{2297}', "otimes"),               // CIRCLED TIMES
+    ('\u{2298}', "osol"),                 // CIRCLED DIVISION SLASH
+    ('\u{2299}', "odot"),                 // CIRCLED DOT OPERATOR
+    ('\u{229A}', "ocir"),                 // CIRCLED RING OPERATOR
+    ('\u{229B}', "oast"),                 // CIRCLED ASTERISK OPERATOR
+    ('\u{229D}', "odash"),                // CIRCLED DASH
+    ('\u{229E}', "plusb"),                // SQUARED PLUS
+    ('\u{229F}', "minusb"),               // SQUARED MINUS
+    ('\u{22A0}', "timesb"),               // SQUARED TIMES
+    ('\u{22A1}', "sdotb"),                // SQUARED DOT OPERATOR
+    ('\u{22A2}', "vdash"),                // RIGHT TACK
+    ('\u{22A3}', "dashv"),                // LEFT TACK
+    ('\u{22A4}', "top"),                  // DOWN TACK
+    ('\u{22A5}', "bottom"),               // UP TACK
+    ('\u{22A7}', "models"),               // MODELS
+    ('\u{22A8}', "vDash"),                // TRUE
+    ('\u{22A9}', "Vdash"),                // FORCES
+    ('\u{22AA}', "Vvdash"),               // TRIPLE VERTICAL BAR RIGHT TURNSTILE
+    ('\u{22AB}', "VDash"),                // DOUBLE VERTICAL BAR DOUBLE RIGHT TURNSTILE
+    ('\u{22AC}', "nvdash"),               // DOES NOT PROVE
+    ('\u{22AD}', "nvDash"),               // NOT TRUE
+    ('\u{22AE}', "nVdash"),               // DOES NOT FORCE
+    ('\u{22AF}', "nVDash"),               // NEGATED DOUBLE VERTICAL BAR DOUBLE RIGHT TURNSTILE
+    ('\u{22B0}', "prurel"),               // PRECEDES UNDER RELATION
+    ('\u{22B2}', "vltri"),                // NORMAL SUBGROUP OF
+    ('\u{22B3}', "vrtri"),                // CONTAINS AS NORMAL SUBGROUP
+    ('\u{22B4}', "ltrie"),                // NORMAL SUBGROUP OF OR EQUAL TO
+    ('\u{22B5}', "rtrie"),                // CONTAINS AS NORMAL SUBGROUP OR EQUAL TO
+    ('\u{22B6}', "origof"),               // ORIGINAL OF
+    ('\u{22B7}', "imof"),                 // IMAGE OF
+    ('\u{22B8}', "mumap"),                // MULTIMAP
+    ('\u{22B9}', "hercon"),               // HERMITIAN CONJUGATE MATRIX
+    ('\u{22BA}', "intcal"),               // INTERCALATE
+    ('\u{22BB}', "veebar"),               // XOR
+    ('\u{22BD}', "barvee"),               // NOR
+    ('\u{22BE}', "angrtvb"),              // RIGHT ANGLE WITH ARC
+    ('\u{22BF}', "lrtri"),                // RIGHT TRIANGLE
+    ('\u{22C0}', "xwedge"),               // N-ARY LOGICAL AND
+    ('\u{22C1}', "xvee"),                 // N-ARY LOGICAL OR
+    ('\u{22C2}', "xcap"),                 // N-ARY INTERSECTION
+    ('\u{22C3}', "xcup"),                 // N-ARY UNION
+    ('\u{22C4}', "diam"),                 // DIAMOND OPERATOR
+    ('\u{22C5}', "sdot"),                 // DOT OPERATOR
+    ('\u{22C6}', "sstarf"),               // STAR OPERATOR
+    ('\u{22C7}', "divonx"),               // DIVISION TIMES
+    ('\u{22C8}', "bowtie"),               // BOWTIE
+    ('\u{22C9}', "ltimes"),               // LEFT NORMAL FACTOR SEMIDIRECT PRODUCT
+    ('\u{22CA}', "rtimes"),               // RIGHT NORMAL FACTOR SEMIDIRECT PRODUCT
+    ('\u{22CB}', "lthree"),               // LEFT SEMIDIRECT PRODUCT
+    ('\u{22CC}', "rthree"),               // RIGHT SEMIDIRECT PRODUCT
+    ('\u{22CD}', "bsime"),                // REVERSED TILDE EQUALS
+    ('\u{22CE}', "cuvee"),                // CURLY LOGICAL OR
+    ('\u{22CF}', "cuwed"),                // CURLY LOGICAL AND
+    ('\u{22D0}', "Sub"),                  // DOUBLE SUBSET
+    ('\u{22D1}', "Sup"),                  // DOUBLE SUPERSET
+    ('\u{22D2}', "Cap"),                  // DOUBLE INTERSECTION
+    ('\u{22D3}', "Cup"),                  // DOUBLE UNION
+    ('\u{22D4}', "fork"),                 // PITCHFORK
+    ('\u{22D5}', "epar"),                 // EQUAL AND PARALLEL TO
+    ('\u{22D6}', "ltdot"),                // LESS-THAN WITH DOT
+    ('\u{22D7}', "gtdot"),                // GREATER-THAN WITH DOT
+    ('\u{22D8}', "Ll"),                   // VERY MUCH LESS-THAN
+    ('\u{22D9}', "Gg"),                   // VERY MUCH GREATER-THAN
+    ('\u{22DA}', "leg"),                  // LESS-THAN EQUAL TO OR GREATER-THAN
+    ('\u{22DB}', "gel"),                  // GREATER-THAN EQUAL TO OR LESS-THAN
+    ('\u{22DE}', "cuepr"),                // EQUAL TO OR PRECEDES
+    ('\u{22DF}', "cuesc"),                // EQUAL TO OR SUCCEEDS
+    ('\u{22E0}', "nprcue"),               // DOES NOT PRECEDE OR EQUAL
+    ('\u{22E1}', "nsccue"),               // DOES NOT SUCCEED OR EQUAL
+    ('\u{22E2}', "nsqsube"),              // NOT SQUARE IMAGE OF OR EQUAL TO
+    ('\u{22E3}', "nsqsupe"),              // NOT SQUARE ORIGINAL OF OR EQUAL TO
+    ('\u{22E6}', "lnsim"),                // LESS-THAN BUT NOT EQUIVALENT TO
+    ('\u{22E7}', "gnsim"),                // GREATER-THAN BUT NOT EQUIVALENT TO
+    ('\u{22E8}', "prnsim"),               // PRECEDES BUT NOT EQUIVALENT TO
+    ('\u{22E9}', "scnsim"),               // SUCCEEDS BUT NOT EQUIVALENT TO
+    ('\u{22EA}', "nltri"),                // NOT NORMAL SUBGROUP OF
+    ('\u{22EB}', "nrtri"),                // DOES NOT CONTAIN AS NORMAL SUBGROUP
+    ('\u{22EC}', "nltrie"),               // NOT NORMAL SUBGROUP OF OR EQUAL TO
+    ('\u{22ED}', "nrtrie"),               // DOES NOT CONTAIN AS NORMAL SUBGROUP OR EQUAL
+    ('\u{22EE}', "vellip"),               // VERTICAL ELLIPSIS
+    ('\u{22EF}', "ctdot"),                // MIDLINE HORIZONTAL ELLIPSIS
+    ('\u{22F0}', "utdot"),                // UP RIGHT DIAGONAL ELLIPSIS
+    ('\u{22F1}', "dtdot"),                // DOWN RIGHT DIAGONAL ELLIPSIS
+    ('\u{22F2}', "disin"),                // ELEMENT OF WITH LONG HORIZONTAL STROKE
+    ('\u{22F3}', "isinsv"),               // ELEMENT OF WITH VERTICAL BAR AT END OF HORIZONTAL STROKE
+    ('\u{22F4}', "isins"),                // SMALL ELEMENT OF WITH VERTICAL BAR AT END OF HORIZONTAL STROKE
+    ('\u{22F5}', "isindot"),              // ELEMENT OF WITH DOT ABOVE
+    ('\u{22F6}', "notinvc"),              // ELEMENT OF WITH OVERBAR
+    ('\u{22F7}', "notinvb"),              // SMALL ELEMENT OF WITH OVERBAR
+    ('\u{22F9}', "isinE"),                // ELEMENT OF WITH TWO HORIZONTAL STROKES
+    ('\u{22FA}', "nisd"),                 // CONTAINS WITH LONG HORIZONTAL STROKE
+    ('\u{22FB}', "xnis"),                 // CONTAINS WITH VERTICAL BAR AT END OF HORIZONTAL STROKE
+    ('\u{22FC}', "nis"),                  // SMALL CONTAINS WITH VERTICAL BAR AT END OF HORIZONTAL STROKE
+    ('\u{22FD}', "notnivc"),              // CONTAINS WITH OVERBAR
+    ('\u{22FE}', "notnivb"),              // SMALL CONTAINS WITH OVERBAR
+    ('\u{2305}', "barwed"),               // PROJECTIVE
+    ('\u{2306}', "Barwed"),               // PERSPECTIVE
+    ('\u{2308}', "lceil"),                // LEFT CEILING
+    ('\u{2309}', "rceil"),                // RIGHT CEILING
+    ('\u{230A}', "lfloor"),               // LEFT FLOOR
+    ('\u{230B}', "rfloor"),               // RIGHT FLOOR
+    ('\u{230C}', "drcrop"),               // BOTTOM RIGHT CROP
+    ('\u{230D}', "dlcrop"),               // BOTTOM LEFT CROP
+    ('\u{230E}', "urcrop"),               // TOP RIGHT CROP
+    ('\u{230F}', "ulcrop"),               // TOP LEFT CROP
+    ('\u{2310}', "bnot"),                 // REVERSED NOT SIGN
+    ('\u{2312}', "profline"),             // ARC
+    ('\u{2313}', "profsurf"),             // SEGMENT
+    ('\u{2315}', "telrec"),               // TELEPHONE RECORDER
+    ('\u{2316}', "target"),               // POSITION INDICATOR
+    ('\u{231C}', "ulcorn"),               // TOP LEFT CORNER
+    ('\u{231D}', "urcorn"),               // TOP RIGHT CORNER
+    ('\u{231E}', "dlcorn"),               // BOTTOM LEFT CORNER
+    ('\u{231F}', "drcorn"),               // BOTTOM RIGHT CORNER
+    ('\u{2322}', "frown"),                // FROWN
+    ('\u{2323}', "smile"),                // SMILE
+    ('\u{232D}', "cylcty"),               // CYLINDRICITY
+    ('\u{232E}', "profalar"),             // ALL AROUND-PROFILE
+    ('\u{2336}', "topbot"),               // APL FUNCTIONAL SYMBOL I-BEAM
+    ('\u{233D}', "ovbar"),                // APL FUNCTIONAL SYMBOL CIRCLE STILE
+    ('\u{233F}', "solbar"),               // APL FUNCTIONAL SYMBOL SLASH BAR
+    ('\u{237C}', "angzarr"),              // RIGHT ANGLE WITH DOWNWARDS ZIGZAG ARROW
+    ('\u{23B0}', "lmoust"),               // UPPER LEFT OR LOWER RIGHT CURLY BRACKET SECTION
+    ('\u{23B1}', "rmoust"),               // UPPER RIGHT OR LOWER LEFT CURLY BRACKET SECTION
+    ('\u{23B4}', "tbrk"),                 // TOP SQUARE BRACKET
+    ('\u{23B5}', "bbrk"),                 // BOTTOM SQUARE BRACKET
+    ('\u{23B6}', "bbrktbrk"),             // BOTTOM SQUARE BRACKET OVER TOP SQUARE BRACKET
+    ('\u{23DC}', "OverParenthesis"),      // TOP PARENTHESIS
+    ('\u{23DD}', "UnderParenthesis"),     // BOTTOM PARENTHESIS
+    ('\u{23DE}', "OverBrace"),            // TOP CURLY BRACKET
+    ('\u{23DF}', "UnderBrace"),           // BOTTOM CURLY BRACKET
+    ('\u{23E2}', "trpezium"),             // WHITE TRAPEZIUM
+    ('\u{23E7}', "elinters"),             // ELECTRICAL INTERSECTION
+    ('\u{2423}', "blank"),                // OPEN BOX
+    ('\u{24C8}', "oS"),                   // CIRCLED LATIN CAPITAL LETTER S
+    ('\u{2500}', "boxh"),                 // BOX DRAWINGS LIGHT HORIZONTAL
+    ('\u{2502}', "boxv"),                 // BOX DRAWINGS LIGHT VERTICAL
+    ('\u{250C}', "boxdr"),                // BOX DRAWINGS LIGHT DOWN AND RIGHT
+    ('\u{2510}', "boxdl"),                // BOX DRAWINGS LIGHT DOWN AND LEFT
+    ('\u{2514}', "boxur"),                // BOX DRAWINGS LIGHT UP AND RIGHT
+    ('\u{2518}', "boxul"),                // BOX DRAWINGS LIGHT UP AND LEFT
+    ('\u{251C}', "boxvr"),                // BOX DRAWINGS LIGHT VERTICAL AND RIGHT
+    ('\u{2524}', "boxvl"),                // BOX DRAWINGS LIGHT VERTICAL AND LEFT
+    ('\u{252C}', "boxhd"),                // BOX DRAWINGS LIGHT DOWN AND HORIZONTAL
+    ('\u{2534}', "boxhu"),                // BOX DRAWINGS LIGHT UP AND HORIZONTAL
+    ('\u{253C}', "boxvh"),                // BOX DRAWINGS LIGHT VERTICAL AND HORIZONTAL
+    ('\u{2550}', "boxH"),                 // BOX DRAWINGS DOUBLE HORIZONTAL
+    ('\u{2551}', "boxV"),                 // BOX DRAWINGS DOUBLE VERTICAL
+    ('\u{2552}', "boxdR"),                // BOX DRAWINGS DOWN SINGLE AND RIGHT DOUBLE
+    ('\u{2553}', "boxDr"),                // BOX DRAWINGS DOWN DOUBLE AND RIGHT SINGLE
+    ('\u{2554}', "boxDR"),                // BOX DRAWINGS DOUBLE DOWN AND RIGHT
+    ('\u{2555}', "boxdL"),                // BOX DRAWINGS DOWN SINGLE AND LEFT DOUBLE
+    ('\u{2556}', "boxDl"),                // BOX DRAWINGS DOWN DOUBLE AND LEFT SINGLE
+    ('\u{2557}', "boxDL"),                // BOX DRAWINGS DOUBLE DOWN AND LEFT
+    ('\u{2558}', "boxuR"),                // BOX DRAWINGS UP SINGLE AND RIGHT DOUBLE
+    ('\u{2559}', "boxUr"),                // BOX DRAWINGS UP DOUBLE AND RIGHT SINGLE
+    ('\u{255A}', "boxUR"),                // BOX DRAWINGS DOUBLE UP AND RIGHT
+    ('\u{255B}', "boxuL"),                // BOX DRAWINGS UP SINGLE AND LEFT DOUBLE
+    ('\u{255C}', "boxUl"),                // BOX DRAWINGS UP DOUBLE AND LEFT SINGLE
+    ('\u{255D}', "boxUL"),                // BOX DRAWINGS DOUBLE UP AND LEFT
+    ('\u{255E}', "boxvR"),                // BOX DRAWINGS VERTICAL SINGLE AND RIGHT DOUBLE
+    ('\u{255F}', "boxVr"),                // BOX DRAWINGS VERTICAL DOUBLE AND RIGHT SINGLE
+    ('\u{2560}', "boxVR"),                // BOX DRAWINGS DOUBLE VERTICAL AND RIGHT
+    ('\u{2561}', "boxvL"),                // BOX DRAWINGS VERTICAL SINGLE AND LEFT DOUBLE
+    ('\u{2562}', "boxVl"),                // BOX DRAWINGS VERTICAL DOUBLE AND LEFT SINGLE
+    ('\u{2563}', "boxVL"),                // BOX DRAWINGS DOUBLE VERTICAL AND LEFT
+    ('\u{2564}', "boxHd"),                // BOX DRAWINGS DOWN SINGLE AND HORIZONTAL DOUBLE
+    ('\u{2565}', "boxhD"),                // BOX DRAWINGS DOWN DOUBLE AND HORIZONTAL SINGLE
+    ('\u{2566}', "boxHD"),                // BOX DRAWINGS DOUBLE DOWN AND HORIZONTAL
+    ('\u{2567}', "boxHu"),                // BOX DRAWINGS UP SINGLE AND HORIZONTAL DOUBLE
+    ('\u{2568}', "boxhU"),                // BOX DRAWINGS UP DOUBLE AND HORIZONTAL SINGLE
+    ('\u{2569}', "boxHU"),                // BOX DRAWINGS DOUBLE UP AND HORIZONTAL
+    ('\u{256A}', "boxvH"),                // BOX DRAWINGS VERTICAL SINGLE AND HORIZONTAL DOUBLE
+    ('\u{256B}', "boxVh"),                // BOX DRAWINGS VERTICAL DOUBLE AND HORIZONTAL SINGLE
+    ('\u{256C}', "boxVH"),                // BOX DRAWINGS DOUBLE VERTICAL AND HORIZONTAL
+    ('\u{2580}', "uhblk"),                // UPPER HALF BLOCK
+    ('\u{2584}', "lhblk"),                // LOWER HALF BLOCK
+    ('\u{2588}', "block"),                // FULL BLOCK
+    ('\u{2591}', "blk14"),                // LIGHT SHADE
+    ('\u{2592}', "blk12"),                // MEDIUM SHADE
+    ('\u{2593}', "blk34"),                // DARK SHADE
+    ('\u{25A1}', "squ"),                  // WHITE SQUARE
+    ('\u{25AA}', "squf"),                 // BLACK SMALL SQUARE
+    ('\u{25AB}', "EmptyVerySmallSquare"), // WHITE SMALL SQUARE
+    ('\u{25AD}', "rect"),                 // WHITE RECTANGLE
+    ('\u{25AE}', "marker"),               // BLACK VERTICAL RECTANGLE
+    ('\u{25B1}', "fltns"),                // WHITE PARALLELOGRAM
+    ('\u{25B3}', "xutri"),                // WHITE UP-POINTING TRIANGLE
+    ('\u{25B4}', "utrif"),                // BLACK UP-POINTING SMALL TRIANGLE
+    ('\u{25B5}', "utri"),                 // WHITE UP-POINTING SMALL TRIANGLE
+    ('\u{25B8}', "rtrif"),                // BLACK RIGHT-POINTING SMALL TRIANGLE
+    ('\u{25B9}', "rtri"),                 // WHITE RIGHT-POINTING SMALL TRIANGLE
+    ('\u{25BD}', "xdtri"),                // WHITE DOWN-POINTING TRIANGLE
+    ('\u{25BE}', "dtrif"),                // BLACK DOWN-POINTING SMALL TRIANGLE
+    ('\u{25BF}', "dtri"),                 // WHITE DOWN-POINTING SMALL TRIANGLE
+    ('\u{25C2}', "ltrif"),                // BLACK LEFT-POINTING SMALL TRIANGLE
+    ('\u{25C3}', "ltri"),                 // WHITE LEFT-POINTING SMALL TRIANGLE
+    ('\u{25CA}', "loz"),                  // LOZENGE
+    ('\u{25CB}', "cir"),                  // WHITE CIRCLE
+    ('\u{25EC}', "tridot"),               // WHITE UP-POINTING TRIANGLE WITH DOT
+    ('\u{25EF}', "xcirc"),                // LARGE CIRCLE
+    ('\u{25F8}', "ultri"),                // UPPER LEFT TRIANGLE
+    ('\u{25F9}', "urtri"),                // UPPER RIGHT TRIANGLE
+    ('\u{25FA}', "lltri"),                // LOWER LEFT TRIANGLE
+    ('\u{25FB}', "EmptySmallSquare"),     // WHITE MEDIUM SQUARE
+    ('\u{25FC}', "FilledSmallSquare"),    // BLACK MEDIUM SQUARE
+    ('\u{2605}', "starf"),                // BLACK STAR
+    ('\u{2606}', "star"),                 // WHITE STAR
+    ('\u{260E}', "phone"),                // BLACK TELEPHONE
+    ('\u{2640}', "female"),               // FEMALE SIGN
+    ('\u{2642}', "male"),                 // MALE SIGN
+    ('\u{2660}', "spades"),               // BLACK SPADE SUIT
+    ('\u{2663}', "clubs"),                // BLACK CLUB SUIT
+    ('\u{2665}', "hearts"),               // BLACK HEART SUIT
+    ('\u{2666}', "diams"),                // BLACK DIAMOND SUIT
+    ('\u{266A}', "sung"),                 // EIGHTH NOTE
+    ('\u{266D}', "flat"),                 // MUSIC FLAT SIGN
+    ('\u{266E}', "natur"),                // MUSIC NATURAL SIGN
+    ('\u{266F}', "sharp"),                // MUSIC SHARP SIGN
+    ('\u{2713}', "check"),                // CHECK MARK
+    ('\u{2717}', "cross"),                // BALLOT X
+    ('\u{2720}', "malt"),                 // MALTESE CROSS
+    ('\u{2736}', "sext"),                 // SIX POINTED BLACK STAR
+    ('\u{2758}', "VerticalSeparator"),    // LIGHT VERTICAL BAR
+    ('\u{2772}', "lbbrk"),                // LIGHT LEFT TORTOISE SHELL BRACKET ORNAMENT
+    ('\u{2773}', "rbbrk"),                // LIGHT RIGHT TORTOISE SHELL BRACKET ORNAMENT
+    ('\u{27E6}', "lobrk"),                // MATHEMATICAL LEFT WHITE SQUARE BRACKET
+    ('\u{27E7}', "robrk"),                // MATHEMATICAL RIGHT WHITE SQUARE BRACKET
+    ('\u{27E8}', "lang"),                 // MATHEMATICAL LEFT ANGLE BRACKET
+    ('\u{27E9}', "rang"),                 // MATHEMATICAL RIGHT ANGLE BRACKET
+    ('\u{27EA}', "Lang"),                 // MATHEMATICAL LEFT DOUBLE ANGLE BRACKET
+    ('\u{27EB}', "Rang"),                 // MATHEMATICAL RIGHT DOUBLE ANGLE BRACKET
+    ('\u{27EC}', "loang"),                // MATHEMATICAL LEFT WHITE TORTOISE SHELL BRACKET
+    ('\u{27ED}', "roang"),                // MATHEMATICAL RIGHT WHITE TORTOISE SHELL BRACKET
+    ('\u{27F5}', "xlarr"),                // LONG LEFTWARDS ARROW
+    ('\u{27F6}', "xrarr"),                // LONG RIGHTWARDS ARROW
+    ('\u{27F7}', "xharr"),                // LONG LEFT RIGHT ARROW
+    ('\u{27F8}', "xlArr"),                // LONG LEFTWARDS DOUBLE ARROW
+    ('\u{27F9}', "xrArr"),                // LONG RIGHTWARDS DOUBLE ARROW
+    ('\u{27FA}', "xhArr"),                // LONG LEFT RIGHT DOUBLE ARROW
+    ('\u{27FC}', "xmap"),                 // LONG RIGHTWARDS ARROW FROM BAR
+    ('\u{27FF}', "dzigrarr"),             // LONG RIGHTWARDS SQUIGGLE ARROW
+    ('\u{2902}', "nvlArr"),               // LEFTWARDS DOUBLE ARROW WITH VERTICAL STROKE
+    ('\u{2903}', "nvrArr"),               // RIGHTWARDS DOUBLE ARROW WITH VERTICAL STROKE
+    ('\u{2904}', "nvHarr"),               // LEFT RIGHT DOUBLE ARROW WITH VERTICAL STROKE
+    ('\u{2905}', "Map"),                  // RIGHTWARDS TWO-HEADED ARROW FROM BAR
+    ('\u{290C}', "lbarr"),                // LEFTWARDS DOUBLE DASH ARROW
+    ('\u{290D}', "rbarr"),                // RIGHTWARDS DOUBLE DASH ARROW
+    ('\u{290E}', "lBarr"),                // LEFTWARDS TRIPLE DASH ARROW
+    ('\u{290F}', "rBarr"),                // RIGHTWARDS TRIPLE DASH ARROW
+    ('\u{2910}', "RBarr"),                // RIGHTWARDS TWO-HEADED TRIPLE DASH ARROW
+    ('\u{2911}', "DDotrahd"),             // RIGHTWARDS ARROW WITH DOTTED STEM
+    ('\u{2912}', "UpArrowBar"),           // UPWARDS ARROW TO BAR
+    ('\u{2913}', "DownArrowBar"),         // DOWNWARDS ARROW TO BAR
+    ('\u{2916}', "Rarrtl"),               // RIGHTWARDS TWO-HEADED ARROW WITH TAIL
+    ('\u{2919}', "latail"),               // LEFTWARDS ARROW-TAIL
+    ('\u{291A}', "ratail"),               // RIGHTWARDS ARROW-TAIL
+    ('\u{291B}', "lAtail"),               // LEFTWARDS DOUBLE ARROW-TAIL
+    ('\u{291C}', "rAtail"),               // RIGHTWARDS DOUBLE ARROW-TAIL
+    ('\u{291D}', "larrfs"),               // LEFTWARDS ARROW TO BLACK DIAMOND
+    ('\u{291E}', "rarrfs"),               // RIGHTWARDS ARROW TO BLACK DIAMOND
+    ('\u{291F}', "larrbfs"),              // LEFTWARDS ARROW FROM BAR TO BLACK DIAMOND
+    ('\u{2920}', "rarrbfs"),              // RIGHTWARDS ARROW FROM BAR TO BLACK DIAMOND
+    ('\u{2923}', "nwarhk"),               // NORTH WEST ARROW WITH HOOK
+    ('\u{2924}', "nearhk"),               // NORTH EAST ARROW WITH HOOK
+    ('\u{2925}', "searhk"),               // SOUTH EAST ARROW WITH HOOK
+    ('\u{2926}', "swarhk"),               // SOUTH WEST ARROW WITH HOOK
+    ('\u{2927}', "nwnear"),               // NORTH WEST ARROW AND NORTH EAST ARROW
+    ('\u{2928}', "nesear"),               // NORTH EAST ARROW AND SOUTH EAST ARROW
+    ('\u{2929}', "seswar"),               // SOUTH EAST ARROW AND SOUTH WEST ARROW
+    ('\u{292A}', "swnwar"),               // SOUTH WEST ARROW AND NORTH WEST ARROW
+    ('\u{2933}', "rarrc"),                // WAVE ARROW POINTING DIRECTLY RIGHT
+    ('\u{2935}', "cudarrr"),              // ARROW POINTING RIGHTWARDS THEN CURVING DOWNWARDS
+    ('\u{2936}', "ldca"),                 // ARROW POINTING DOWNWARDS THEN CURVING LEFTWARDS
+    ('\u{2937}', "rdca"),                 // ARROW POINTING DOWNWARDS THEN CURVING RIGHTWARDS
+    ('\u{2938}', "cudarrl"),              // RIGHT-SIDE ARC CLOCKWISE ARROW
+    ('\u{2939}', "larrpl"),               // LEFT-SIDE ARC ANTICLOCKWISE ARROW
+    ('\u{293C}', "curarrm"),              // TOP ARC CLOCKWISE ARROW WITH MINUS
+    ('\u{293D}', "cularrp"),              // TOP ARC ANTICLOCKWISE ARROW WITH PLUS
+    ('\u{2945}', "rarrpl"),               // RIGHTWARDS ARROW WITH PLUS BELOW
+    ('\u{2948}', "harrcir"),              // LEFT RIGHT ARROW THROUGH SMALL CIRCLE
+    ('\u{2949}', "Uarrocir"),             // UPWARDS TWO-HEADED ARROW FROM SMALL CIRCLE
+    ('\u{294A}', "lurdshar"),             // LEFT BARB UP RIGHT BARB DOWN HARPOON
+    ('\u{294B}', "ldrushar"),             // LEFT BARB DOWN RIGHT BARB UP HARPOON
+    ('\u{294E}', "LeftRightVector"),      // LEFT BARB UP RIGHT BARB UP HARPOON
+    ('\u{294F}', "RightUpDownVector"),    // UP BARB RIGHT DOWN BARB RIGHT HARPOON
+    ('\u{2950}', "DownLeftRightVector"),  // LEFT BARB DOWN RIGHT BARB DOWN HARPOON
+    ('\u{2951}', "LeftUpDownVector"),     // UP BARB LEFT DOWN BARB LEFT HARPOON
+    ('\u{2952}', "LeftVectorBar"),        // LEFTWARDS HARPOON WITH BARB UP TO BAR
+    ('\u{2953}', "RightVectorBar"),       // RIGHTWARDS HARPOON WITH BARB UP TO BAR
+    ('\u{2954}', "RightUpVectorBar"),     // UPWARDS HARPOON WITH BARB RIGHT TO BAR
+    ('\u{2955}', "RightDownVectorBar"),   // DOWNWARDS HARPOON WITH BARB RIGHT TO BAR
+    ('\u{2956}', "DownLeftVectorBar"),    // LEFTWARDS HARPOON WITH BARB DOWN TO BAR
+    ('\u{2957}', "DownRightVectorBar"),   // RIGHTWARDS HARPOON WITH BARB DOWN TO BAR
+    ('\u{2958}', "LeftUpVectorBar"),      // UPWARDS HARPOON WITH BARB LEFT TO BAR
+    ('\u{2959}', "LeftDownVectorBar"),    // DOWNWARDS HARPOON WITH BARB LEFT TO BAR
+    ('\u{295A}', "LeftTeeVector"),        // LEFTWARDS HARPOON WITH BARB UP FROM BAR
+    ('\u{295B}', "RightTeeVector"),       // RIGHTWARDS HARPOON WITH BARB UP FROM BAR
+    ('\u{295C}', "RightUpTeeVector"),     // UPWARDS HARPOON WITH BARB RIGHT FROM BAR
+    ('\u{295D}', "RightDownTeeVector"),   // DOWNWARDS HARPOON WITH BARB RIGHT FROM BAR
+    ('\u{295E}', "DownLeftTeeVector"),    // LEFTWARDS HARPOON WITH BARB DOWN FROM BAR
+    ('\u{295F}', "DownRightTeeVector"),   // RIGHTWARDS HARPOON WITH BARB DOWN FROM BAR
+    ('\u{2960}', "LeftUpTeeVector"),      // UPWARDS HARPOON WITH BARB LEFT FROM BAR
+    ('\u{2961}', "LeftDownTeeVector"),    // DOWNWARDS HARPOON WITH BARB LEFT FROM BAR
+    ('\u{2962}', "lHar"),                 // LEFTWARDS HARPOON WITH BARB UP ABOVE LEFTWARDS HARPOON WITH BARB DOWN
+    ('\u{2963}', "uHar"),                 // UPWARDS HARPOON WITH BARB LEFT BESIDE UPWARDS HARPOON WITH BARB RIGHT
+    ('\u{2964}', "rHar"),                 // RIGHTWARDS HARPOON WITH BARB UP ABOVE RIGHTWARDS HARPOON WITH BARB DOWN
+    ('\u{2965}', "dHar"),                 // DOWNWARDS HARPOON WITH BARB LEFT BESIDE DOWNWARDS HARPOON WITH BARB RIGHT
+    ('\u{2966}', "luruhar"),              // LEFTWARDS HARPOON WITH BARB UP ABOVE RIGHTWARDS HARPOON WITH BARB UP
+    ('\u{2967}', "ldrdhar"),              // LEFTWARDS HARPOON WITH BARB DOWN ABOVE RIGHTWARDS HARPOON WITH BARB DOWN
+    ('\u{2968}', "ruluhar"),              // RIGHTWARDS HARPOON WITH BARB UP ABOVE LEFTWARDS HARPOON WITH BARB UP
+    ('\u{2969}', "rdldhar"),              // RIGHTWARDS HARPOON WITH BARB DOWN ABOVE LEFTWARDS HARPOON WITH BARB DOWN
+    ('\u{296A}', "lharul"),               // LEFTWARDS HARPOON WITH BARB UP ABOVE LONG DASH
+    ('\u{296B}', "llhard"),               // LEFTWARDS HARPOON WITH BARB DOWN BELOW LONG DASH
+    ('\u{296C}', "rharul"),               // RIGHTWARDS HARPOON WITH BARB UP ABOVE LONG DASH
+    ('\u{296D}', "lrhard"),               // RIGHTWARDS HARPOON WITH BARB DOWN BELOW LONG DASH
+    ('\u{296E}', "udhar"),                // UPWARDS HARPOON WITH BARB LEFT BESIDE DOWNWARDS HARPOON WITH BARB RIGHT
+    ('\u{296F}', "duhar"),                // DOWNWARDS HARPOON WITH BARB LEFT BESIDE UPWARDS HARPOON WITH BARB RIGHT
+    ('\u{2970}', "RoundImplies"),         // RIGHT DOUBLE ARROW WITH ROUNDED HEAD
+    ('\u{2971}', "erarr"),                // EQUALS SIGN ABOVE RIGHTWARDS ARROW
+    ('\u{2972}', "simrarr"),              // TILDE OPERATOR ABOVE RIGHTWARDS ARROW
+    ('\u{2973}', "larrsim"),              // LEFTWARDS ARROW ABOVE TILDE OPERATOR
+    ('\u{2974}', "rarrsim"),              // RIGHTWARDS ARROW ABOVE TILDE OPERATOR
+    ('\u{2975}', "rarrap"),               // RIGHTWARDS ARROW ABOVE ALMOST EQUAL TO
+    ('\u{2976}', "ltlarr"),               // LESS-THAN ABOVE LEFTWARDS ARROW
+    ('\u{2978}', "gtrarr"),               // GREATER-THAN ABOVE RIGHTWARDS ARROW
+    ('\u{2979}', "subrarr"),              // SUBSET ABOVE RIGHTWARDS ARROW
+    ('\u{297B}', "suplarr"),              // SUPERSET ABOVE LEFTWARDS ARROW
+    ('\u{297C}', "lfisht"),               // LEFT FISH TAIL
+    ('\u{297D}', "rfisht"),               // RIGHT FISH TAIL
+    ('\u{297E}', "ufisht"),               // UP FISH TAIL
+    ('\u{297F}', "dfisht"),               // DOWN FISH TAIL
+    ('\u{2985}', "lopar"),                // LEFT WHITE PARENTHESIS
+    ('\u{2986}', "ropar"),                // RIGHT WHITE PARENTHESIS
+    ('\u{298B}', "lbrke"),                // LEFT SQUARE BRACKET WITH UNDERBAR
+    ('\u{298C}', "rbrke"),                // RIGHT SQUARE BRACKET WITH UNDERBAR
+    ('\u{298D}', "lbrkslu"),              // LEFT SQUARE BRACKET WITH TICK IN TOP CORNER
+    ('\u{298E}', "rbrksld"),              // RIGHT SQUARE BRACKET WITH TICK IN BOTTOM CORNER
+    ('\u{298F}', "lbrksld"),              // LEFT SQUARE BRACKET WITH TICK IN BOTTOM CORNER
+    ('\u{2990}', "rbrkslu"),              // RIGHT SQUARE BRACKET WITH TICK IN TOP CORNER
+    ('\u{2991}', "langd"),                // LEFT ANGLE BRACKET WITH DOT
+    ('\u{2992}', "rangd"),                // RIGHT ANGLE BRACKET WITH DOT
+    ('\u{2993}', "lparlt"),               // LEFT ARC LESS-THAN BRACKET
+    ('\u{2994}', "rpargt"),               // RIGHT ARC GREATER-THAN BRACKET
+    ('\u{2995}', "gtlPar"),               // DOUBLE LEFT ARC GREATER-THAN BRACKET
+    ('\u{2996}', "ltrPar"),               // DOUBLE RIGHT ARC LESS-THAN BRACKET
+    ('\u{299A}', "vzigzag"),              // VERTICAL ZIGZAG LINE
+    ('\u{299C}', "vangrt"),               // RIGHT ANGLE VARIANT WITH SQUARE
+    ('\u{299D}', "angrtvbd"),             // MEASURED RIGHT ANGLE WITH DOT
+    ('\u{29A4}', "ange"),                 // ANGLE WITH UNDERBAR
+    ('\u{29A5}', "range"),                // REVERSED ANGLE WITH UNDERBAR
+    ('\u{29A6}', "dwangle"),              // OBLIQUE ANGLE OPENING UP
+    ('\u{29A7}', "uwangle"),              // OBLIQUE ANGLE OPENING DOWN
+    ('\u{29A8}', "angmsdaa"),             // MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING UP AND RIGHT
+    ('\u{29A9}', "angmsdab"),             // MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING UP AND LEFT
+    ('\u{29AA}', "angmsdac"),             // MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING DOWN AND RIGHT
+    ('\u{29AB}', "angmsdad"),             // MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING DOWN AND LEFT
+    ('\u{29AC}', "angmsdae"),             // MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING RIGHT AND UP
+    ('\u{29AD}', "angmsdaf"),             // MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING LEFT AND UP
+    ('\u{29AE}', "angmsdag"),             // MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING RIGHT AND DOWN
+    ('\u{29AF}', "angmsdah"),             // MEASURED ANGLE WITH OPEN ARM ENDING IN ARROW POINTING LEFT AND DOWN
+    ('\u{29B0}', "bemptyv"),              // REVERSED EMPTY SET
+    ('\u{29B1}', "demptyv"),              // EMPTY SET WITH OVERBAR
+    ('\u{29B2}', "cemptyv"),              // EMPTY SET WITH SMALL CIRCLE ABOVE
+    ('\u{29B3}', "raemptyv"),             // EMPTY SET WITH RIGHT ARROW ABOVE
+    ('\u{29B4}', "laemptyv"),             // EMPTY SET WITH LEFT ARROW ABOVE
+    ('\u{29B5}', "ohbar"),                // CIRCLE WITH HORIZONTAL BAR
+    ('\u{29B6}', "omid"),                 // CIRCLED VERTICAL BAR
+    ('\u{29B7}', "opar"),                 // CIRCLED PARALLEL
+    ('\u{29B9}', "operp"),                // CIRCLED PERPENDICULAR
+    ('\u{29BB}', "olcross"),              // CIRCLE WITH SUPERIMPOSED X
+    ('\u{29BC}', "odsold"),               // CIRCLED ANTICLOCKWISE-ROTATED DIVISION SIGN
+    ('\u{29BE}', "olcir"),                // CIRCLED WHITE BULLET
+    ('\u{29BF}', "ofcir"),                // CIRCLED BULLET
+    ('\u{29C0}', "olt"),                  // CIRCLED LESS-THAN
+    ('\u{29C1}', "ogt"),                  // CIRCLED GREATER-THAN
+    ('\u{29C2}', "cirscir"),              // CIRCLE WITH SMALL CIRCLE TO THE RIGHT
+    ('\u{29C3}', "cirE"),                 // CIRCLE WITH TWO HORIZONTAL STROKES TO THE RIGHT
+    ('\u{29C4}', "solb"),                 // SQUARED RISING DIAGONAL SLASH
+    ('\u{29C5}', "bsolb"),                // SQUARED FALLING DIAGONAL SLASH
+    ('\u{29C9}', "boxbox"),               // TWO JOINED SQUARES
+    ('\u{29CD}', "trisb"),                // TRIANGLE WITH SERIFS AT BOTTOM
+    ('\u{29CE}', "rtriltri"),             // RIGHT TRIANGLE ABOVE LEFT TRIANGLE
+    ('\u{29CF}', "LeftTriangleBar"),      // LEFT TRIANGLE BESIDE VERTICAL BAR
+    ('\u{29D0}', "RightTriangleBar"),     // VERTICAL BAR BESIDE RIGHT TRIANGLE
+    ('\u{29DA}', "race"),                 // LEFT DOUBLE WIGGLY FENCE
+    ('\u{29DC}', "iinfin"),               // INCOMPLETE INFINITY
+    ('\u{29DD}', "infintie"),             // TIE OVER INFINITY
+    ('\u{29DE}', "nvinfin"),              // INFINITY NEGATED WITH VERTICAL BAR
+    ('\u{29E3}', "eparsl"),               // EQUALS SIGN AND SLANTED PARALLEL
+    ('\u{29E4}', "smeparsl"),             // EQUALS SIGN AND SLANTED PARALLEL WITH TILDE ABOVE
+    ('\u{29E5}', "eqvparsl"),             // IDENTICAL TO AND SLANTED PARALLEL
+    ('\u{29EB}', "lozf"),                 // BLACK LOZENGE
+    ('\u{29F4}', "RuleDelayed"),          // RULE-DELAYED
+    ('\u{29F6}', "dsol"),                 // SOLIDUS WITH OVERBAR
+    ('\u{2A00}', "xodot"),                // N-ARY CIRCLED DOT OPERATOR
+    ('\u{2A01}', "xoplus"),               // N-ARY CIRCLED PLUS OPERATOR
+    ('\u{2A02}', "xotime"),               // N-ARY CIRCLED TIMES OPERATOR
+    ('\u{2A04}', "xuplus"),               // N-ARY UNION OPERATOR WITH PLUS
+    ('\u{2A06}', "xsqcup"),               // N-ARY SQUARE UNION OPERATOR
+    ('\u{2A0C}', "qint"),                 // QUADRUPLE INTEGRAL OPERATOR
+    ('\u{2A0D}', "fpartint"),             // FINITE PART INTEGRAL
+    ('\u{2A10}', "cirfnint"),             // CIRCULATION FUNCTION
+    ('\u{2A11}', "awint"),                // ANTICLOCKWISE INTEGRATION
+    ('\u{2A12}', "rppolint"),             // LINE INTEGRATION WITH RECTANGULAR PATH AROUND POLE
+    ('\u{2A13}', "scpolint"),             // LINE INTEGRATION WITH SEMICIRCULAR PATH AROUND POLE
+    ('\u{2A14}', "npolint"),              // LINE INTEGRATION NOT INCLUDING THE POLE
+    ('\u{2A15}', "pointint"),             // INTEGRAL AROUND A POINT OPERATOR
+    ('\u{2A16}', "quatint"),              // QUATERNION INTEGRAL OPERATOR
+    ('\u{2A17}', "intlarhk"),             // INTEGRAL WITH LEFTWARDS ARROW WITH HOOK
+    ('\u{2A22}', "pluscir"),              // PLUS SIGN WITH SMALL CIRCLE ABOVE
+    ('\u{2A23}', "plusacir"),             // PLUS SIGN WITH CIRCUMFLEX ACCENT ABOVE
+    ('\u{2A24}', "simplus"),              // PLUS SIGN WITH TILDE ABOVE
+    ('\u{2A25}', "plusdu"),               // PLUS SIGN WITH DOT BELOW
+    ('\u{2A26}', "plussim"),              // PLUS SIGN WITH TILDE BELOW
+    ('\u{2A27}', "plustwo"),              // PLUS SIGN WITH SUBSCRIPT TWO
+    ('\u{2A29}', "mcomma"),               // MINUS SIGN WITH COMMA ABOVE
+    ('\u{2A2A}', "minusdu"),              // MINUS SIGN WITH DOT BELOW
+    ('\u{2A2D}', "loplus"),               // PLUS SIGN IN LEFT HALF CIRCLE
+    ('\u{2A2E}', "roplus"),               // PLUS SIGN IN RIGHT HALF CIRCLE
+    ('\u{2A2F}', "Cross"),                // VECTOR OR CROSS PRODUCT
+    ('\u{2A30}', "timesd"),               // MULTIPLICATION SIGN WITH DOT ABOVE
+    ('\u{2A31}', "timesbar"),             // MULTIPLICATION SIGN WITH UNDERBAR
+    ('\u{2A33}', "smashp"),               // SMASH PRODUCT
+    ('\u{2A34}', "lotimes"),              // MULTIPLICATION SIGN IN LEFT HALF CIRCLE
+    ('\u{2A35}', "rotimes"),              // MULTIPLICATION SIGN IN RIGHT HALF CIRCLE
+    ('\u{2A36}', "otimesas"),             // CIRCLED MULTIPLICATION SIGN WITH CIRCUMFLEX ACCENT
+    ('\u{2A37}', "Otimes"),               // MULTIPLICATION SIGN IN DOUBLE CIRCLE
+    ('\u{2A38}', "odiv"),                 // CIRCLED DIVISION SIGN
+    ('\u{2A39}', "triplus"),              // PLUS SIGN IN TRIANGLE
+    ('\u{2A3A}', "triminus"),             // MINUS SIGN IN TRIANGLE
+    ('\u{2A3B}', "tritime"),              // MULTIPLICATION SIGN IN TRIANGLE
+    ('\u{2A3C}', "iprod"),                // INTERIOR PRODUCT
+    ('\u{2A3F}', "amalg"),                // AMALGAMATION OR COPRODUCT
+    ('\u{2A40}', "capdot"),               // INTERSECTION WITH DOT
+    ('\u{2A42}', "ncup"),                 // UNION WITH OVERBAR
+    ('\u{2A43}', "ncap"),                 // INTERSECTION WITH OVERBAR
+    ('\u{2A44}', "capand"),               // INTERSECTION WITH LOGICAL AND
+    ('\u{2A45}', "cupor"),                // UNION WITH LOGICAL OR
+    ('\u{2A46}', "cupcap"),               // UNION ABOVE INTERSECTION
+    ('\u{2A47}', "capcup"),               // INTERSECTION ABOVE UNION
+    ('\u{2A48}', "cupbrcap"),             // UNION ABOVE BAR ABOVE INTERSECTION
+    ('\u{2A49}', "capbrcup"),             // INTERSECTION ABOVE BAR ABOVE UNION
+    ('\u{2A4A}', "cupcup"),               // UNION BESIDE AND JOINED WITH UNION
+    ('\u{2A4B}', "capcap"),               // INTERSECTION BESIDE AND JOINED WITH INTERSECTION
+    ('\u{2A4C}', "ccups"),                // CLOSED UNION WITH SERIFS
+    ('\u{2A4D}', "ccaps"),                // CLOSED INTERSECTION WITH SERIFS
+    ('\u{2A50}', "ccupssm"),              // CLOSED UNION WITH SERIFS AND SMASH PRODUCT
+    ('\u{2A53}', "And"),                  // DOUBLE LOGICAL AND
+    ('\u{2A54}', "Or"),                   // DOUBLE LOGICAL OR
+    ('\u{2A55}', "andand"),               // TWO INTERSECTING LOGICAL AND
+    ('\u{2A56}', "oror"),                 // TWO INTERSECTING LOGICAL OR
+    ('\u{2A57}', "orslope"),              // SLOPING LARGE OR
+    ('\u{2A58}', "andslope"),             // SLOPING LARGE AND
+    ('\u{2A5A}', "andv"),                 // LOGICAL AND WITH MIDDLE STEM
+    ('\u{2A5B}', "orv"),                  // LOGICAL OR WITH MIDDLE STEM
+    ('\u{2A5C}', "andd"),                 // LOGICAL AND WITH HORIZONTAL DASH
+    ('\u{2A5D}', "ord"),                  // LOGICAL OR WITH HORIZONTAL DASH
+    ('\u{2A5F}', "wedbar"),               // LOGICAL AND WITH UNDERBAR
+    ('\u{2A66}', "sdote"),                // EQUALS SIGN WITH DOT BELOW
+    ('\u{2A6A}', "simdot"),               // TILDE OPERATOR WITH DOT ABOVE
+    ('\u{2A6D}', "congdot"),              // CONGRUENT WITH DOT ABOVE
+    ('\u{2A6E}', "easter"),               // EQUALS WITH ASTERISK
+    ('\u{2A6F}', "apacir"),               // ALMOST EQUAL TO WITH CIRCUMFLEX ACCENT
+    ('\u{2A70}', "apE"),                  // APPROXIMATELY EQUAL OR EQUAL TO
+    ('\u{2A71}', "eplus"),                // EQUALS SIGN ABOVE PLUS SIGN
+    ('\u{2A72}', "pluse"),                // PLUS SIGN ABOVE EQUALS SIGN
+    ('\u{2A73}', "Esim"),                 // EQUALS SIGN ABOVE TILDE OPERATOR
+    ('\u{2A74}', "Colone"),               // DOUBLE COLON EQUAL
+    ('\u{2A75}', "Equal"),                // TWO CONSECUTIVE EQUALS SIGNS
+    ('\u{2A77}', "eDDot"),                // EQUALS SIGN WITH TWO DOTS ABOVE AND TWO DOTS BELOW
+    ('\u{2A78}', "equivDD"),              // EQUIVALENT WITH FOUR DOTS ABOVE
+    ('\u{2A79}', "ltcir"),                // LESS-THAN WITH CIRCLE INSIDE
+    ('\u{2A7A}', "gtcir"),                // GREATER-THAN WITH CIRCLE INSIDE
+    ('\u{2A7B}', "ltquest"),              // LESS-THAN WITH QUESTION MARK ABOVE
+    ('\u{2A7C}', "gtquest"),              // GREATER-THAN WITH QUESTION MARK ABOVE
+    ('\u{2A7D}', "les"),                  // LESS-THAN OR SLANTED EQUAL TO
+    ('\u{2A7E}', "ges"),                  // GREATER-THAN OR SLANTED EQUAL TO
+    ('\u{2A7F}', "lesdot"),               // LESS-THAN OR SLANTED EQUAL TO WITH DOT INSIDE
+    ('\u{2A80}', "gesdot"),               // GREATER-THAN OR SLANTED EQUAL TO WITH DOT INSIDE
+    ('\u{2A81}', "lesdoto"),              // LESS-THAN OR SLANTED EQUAL TO WITH DOT ABOVE
+    ('\u{2A82}', "gesdoto"),              // GREATER-THAN OR SLANTED EQUAL TO WITH DOT ABOVE
+    ('\u{2A83}', "lesdotor"),             // LESS-THAN OR SLANTED EQUAL TO WITH DOT ABOVE RIGHT
+    ('\u{2A84}', "gesdotol"),             // GREATER-THAN OR SLANTED EQUAL TO WITH DOT ABOVE LEFT
+    ('\u{2A85}', "lap"),                  // LESS-THAN OR APPROXIMATE
+    ('\u{2A86}', "gap"),                  // GREATER-THAN OR APPROXIMATE
+    ('\u{2A87}', "lne"),                  // LESS-THAN AND SINGLE-LINE NOT EQUAL TO
+    ('\u{2A88}', "gne"),                  // GREATER-THAN AND SINGLE-LINE NOT EQUAL TO
+    ('\u{2A89}', "lnap"),                 // LESS-THAN AND NOT APPROXIMATE
+    ('\u{2A8A}', "gnap"),                 // GREATER-THAN AND NOT APPROXIMATE
+    ('\u{2A8B}', "lEg"),                  // LESS-THAN ABOVE DOUBLE-LINE EQUAL ABOVE GREATER-THAN
+    ('\u{2A8C}', "gEl"),                  // GREATER-THAN ABOVE DOUBLE-LINE EQUAL ABOVE LESS-THAN
+    ('\u{2A8D}', "lsime"),                // LESS-THAN ABOVE SIMILAR OR EQUAL
+    ('\u{2A8E}', "gsime"),                // GREATER-THAN ABOVE SIMILAR OR EQUAL
+    ('\u{2A8F}', "lsimg"),                // LESS-THAN ABOVE SIMILAR ABOVE GREATER-THAN
+    ('\u{2A90}', "gsiml"),                // GREATER-THAN ABOVE SIMILAR ABOVE LESS-THAN
+    ('\u{2A91}', "lgE"),                  // LESS-THAN ABOVE GREATER-THAN ABOVE DOUBLE-LINE EQUAL
+    ('\u{2A92}', "glE"),                  // GREATER-THAN ABOVE LESS-THAN ABOVE DOUBLE-LINE EQUAL
+    ('\u{2A93}', "lesges"),               // LESS-THAN ABOVE SLANTED EQUAL ABOVE GREATER-THAN ABOVE SLANTED EQUAL
+    ('\u{2A94}', "gesles"),               // GREATER-THAN ABOVE SLANTED EQUAL ABOVE LESS-THAN ABOVE SLANTED EQUAL
+    ('\u{2A95}', "els"),                  // SLANTED EQUAL TO OR LESS-THAN
+    ('\u{2A96}', "egs"),                  // SLANTED EQUAL TO OR GREATER-THAN
+    ('\u{2A97}', "elsdot"),               // SLANTED EQUAL TO OR LESS-THAN WITH DOT INSIDE
+    ('\u{2A98}', "egsdot"),               // SLANTED EQUAL TO OR GREATER-THAN WITH DOT INSIDE
+    ('\u{2A99}', "el"),                   // DOUBLE-LINE EQUAL TO OR LESS-THAN
+    ('\u{2A9A}', "eg"),                   // DOUBLE-LINE EQUAL TO OR GREATER-THAN
+    ('\u{2A9D}', "siml"),                 // SIMILAR OR LESS-THAN
+    ('\u{2A9E}', "simg"),                 // SIMILAR OR GREATER-THAN
+    ('\u{2A9F}', "simlE"),                // SIMILAR ABOVE LESS-THAN ABOVE EQUALS SIGN
+    ('\u{2AA0}', "simgE"),                // SIMILAR ABOVE GREATER-THAN ABOVE EQUALS SIGN
+    ('\u{2AA1}', "LessLess"),             // DOUBLE NESTED LESS-THAN
+    ('\u{2AA2}', "GreaterGreater"),       // DOUBLE NESTED GREATER-THAN
+    ('\u{2AA4}', "glj"),                  // GREATER-THAN OVERLAPPING LESS-THAN
+    ('\u{2AA5}', "gla"),                  // GREATER-THAN BESIDE LESS-THAN
+    ('\u{2AA6}', "ltcc"),                 // LESS-THAN CLOSED BY CURVE
+    ('\u{2AA7}', "gtcc"),                 // GREATER-THAN CLOSED BY CURVE
+    ('\u{2AA8}', "lescc"),                // LESS-THAN CLOSED BY CURVE ABOVE SLANTED EQUAL
+    ('\u{2AA9}', "gescc"),                // GREATER-THAN CLOSED BY CURVE ABOVE SLANTED EQUAL
+    ('\u{2AAA}', "smt"),                  // SMALLER THAN
+    ('\u{2AAB}', "lat"),                  // LARGER THAN
+    ('\u{2AAC}', "smte"),                 // SMALLER THAN OR EQUAL TO
+    ('\u{2AAD}', "late"),                 // LARGER THAN OR EQUAL TO
+    ('\u{2AAE}', "bumpE"),                // EQUALS SIGN WITH BUMPY ABOVE
+    ('\u{2AAF}', "pre"),                  // PRECEDES ABOVE SINGLE-LINE EQUALS SIGN
+    ('\u{2AB0}', "sce"),                  // SUCCEEDS ABOVE SINGLE-LINE EQUALS SIGN
+    ('\u{2AB3}', "prE"),                  // PRECEDES ABOVE EQUALS SIGN
+    ('\u{2AB4}', "scE"),                  // SUCCEEDS ABOVE EQUALS SIGN
+    ('\u{2AB5}', "prnE"),                 // PRECEDES ABOVE NOT EQUAL TO
+    ('\u{2AB6}', "scnE"),                 // SUCCEEDS ABOVE NOT EQUAL TO
+    ('\u{2AB7}', "prap"),                 // PRECEDES ABOVE ALMOST EQUAL TO
+    ('\u{2AB8}', "scap"),                 // SUCCEEDS ABOVE ALMOST EQUAL TO
+    ('\u{2AB9}', "prnap"),                // PRECEDES ABOVE NOT ALMOST EQUAL TO
+    ('\u{2ABA}', "scnap"),                // SUCCEEDS ABOVE NOT ALMOST EQUAL TO
+    ('\u{2ABB}', "Pr"),                   // DOUBLE PRECEDES
+    ('\u{2ABC}', "Sc"),                   // DOUBLE SUCCEEDS
+    ('\u{2ABD}', "subdot"),               // SUBSET WITH DOT
+    ('\u{2ABE}', "supdot"),               // SUPERSET WITH DOT
+    ('\u{2ABF}', "subplus"),              // SUBSET WITH PLUS SIGN BELOW
+    ('\u{2AC0}', "supplus"),              // SUPERSET WITH PLUS SIGN BELOW
+    ('\u{2AC1}', "submult"),              // SUBSET WITH MULTIPLICATION SIGN BELOW
+    ('\u{2AC2}', "supmult"),              // SUPERSET WITH MULTIPLICATION SIGN BELOW
+    ('\u{2AC3}', "subedot"),              // SUBSET OF OR EQUAL TO WITH DOT ABOVE
+    ('\u{2AC4}', "supedot"),              // SUPERSET OF OR EQUAL TO WITH DOT ABOVE
+    ('\u{2AC5}', "subE"),                 // SUBSET OF ABOVE EQUALS SIGN
+    ('\u{2AC6}', "supE"),                 // SUPERSET OF ABOVE EQUALS SIGN
+    ('\u{2AC7}', "subsim"),               // SUBSET OF ABOVE TILDE OPERATOR
+    ('\u{2AC8}', "supsim"),               // SUPERSET OF ABOVE TILDE OPERATOR
+    ('\u{2ACB}', "subnE"),                // SUBSET OF ABOVE NOT EQUAL TO
+    ('\u{2ACC}', "supnE"),                // SUPERSET OF ABOVE NOT EQUAL TO
+    ('\u{2ACF}', "csub"),                 // CLOSED SUBSET
+    ('\u{2AD0}', "csup"),                 // CLOSED SUPERSET
+    ('\u{2AD1}', "csube"),                // CLOSED SUBSET OR EQUAL TO
+    ('\u{2AD2}', "csupe"),                // CLOSED SUPERSET OR EQUAL TO
+    ('\u{2AD3}', "subsup"),               // SUBSET ABOVE SUPERSET
+    ('\u{2AD4}', "supsub"),               // SUPERSET ABOVE SUBSET
+    ('\u{2AD5}', "subsub"),               // SUBSET ABOVE SUBSET
+    ('\u{2AD6}', "supsup"),               // SUPERSET ABOVE SUPERSET
+    ('\u{2AD7}', "suphsub"),              // SUPERSET BESIDE SUBSET
+    ('\u{2AD8}', "supdsub"),              // SUPERSET BESIDE AND JOINED BY DASH WITH SUBSET
+    ('\u{2AD9}', "forkv"),                // ELEMENT OF OPENING DOWNWARDS
+    ('\u{2ADA}', "topfork"),              // PITCHFORK WITH TEE TOP
+    ('\u{2ADB}', "mlcp"),                 // TRANSVERSAL INTERSECTION
+    ('\u{2AE4}', "Dashv"),                // VERTICAL BAR DOUBLE LEFT TURNSTILE
+    ('\u{2AE6}', "Vdashl"),               // LONG DASH FROM LEFT MEMBER OF DOUBLE VERTICAL
+    ('\u{2AE7}', "Barv"),                 // SHORT DOWN TACK WITH OVERBAR
+    ('\u{2AE8}', "vBar"),                 // SHORT UP TACK WITH UNDERBAR
+    ('\u{2AE9}', "vBarv"),                // SHORT UP TACK ABOVE SHORT DOWN TACK
+    ('\u{2AEB}', "Vbar"),                 // DOUBLE UP TACK
+    ('\u{2AEC}', "Not"),                  // DOUBLE STROKE NOT SIGN
+    ('\u{2AED}', "bNot"),                 // REVERSED DOUBLE STROKE NOT SIGN
+    ('\u{2AEE}', "rnmid"),                // DOES NOT DIVIDE WITH REVERSED NEGATION SLASH
+    ('\u{2AEF}', "cirmid"),               // VERTICAL LINE WITH CIRCLE ABOVE
+    ('\u{2AF0}', "midcir"),               // VERTICAL LINE WITH CIRCLE BELOW
+    ('\u{2AF1}', "topcir"),               // DOWN TACK WITH CIRCLE BELOW
+    ('\u{2AF2}', "nhpar"),                // PARALLEL WITH HORIZONTAL STROKE
+    ('\u{2AF3}', "parsim"),               // PARALLEL WITH TILDE OPERATOR
+    ('\u{2AFD}', "parsl"),                // DOUBLE SOLIDUS OPERATOR
+    ('\u{FB00}', "fflig"),                // LATIN SMALL LIGATURE FF
+    ('\u{FB01}', "filig"),                // LATIN SMALL LIGATURE FI
+    ('\u{FB02}', "fllig"),                // LATIN SMALL LIGATURE FL
+    ('\u{FB03}', "ffilig"),               // LATIN SMALL LIGATURE FFI
+    ('\u{FB04}', "ffllig"),               // LATIN SMALL LIGATURE FFL
+    ('\u{1D49C}', "Ascr"),                // MATHEMATICAL SCRIPT CAPITAL A
+    ('\u{1D49E}', "Cscr"),                // MATHEMATICAL SCRIPT CAPITAL C
+    ('\u{1D49F}', "Dscr"),                // MATHEMATICAL SCRIPT CAPITAL D
+    ('\u{1D4A2}', "Gscr"),                // MATHEMATICAL SCRIPT CAPITAL G
+    ('\u{1D4A5}', "Jscr"),                // MATHEMATICAL SCRIPT CAPITAL J
+    ('\u{1D4A6}', "Kscr"),                // MATHEMATICAL SCRIPT CAPITAL K
+    ('\u{1D4A9}', "Nscr"),                // MATHEMATICAL SCRIPT CAPITAL N
+    ('\u{1D4AA}', "Oscr"),                // MATHEMATICAL SCRIPT CAPITAL O
+    ('\u{1D4AB}', "Pscr"),                // MATHEMATICAL SCRIPT CAPITAL P
+    ('\u{1D4AC}', "Qscr"),                // MATHEMATICAL SCRIPT CAPITAL Q
+    ('\u{1D4AE}', "Sscr"),                // MATHEMATICAL SCRIPT CAPITAL S
+    ('\u{1D4AF}', "Tscr"),                // MATHEMATICAL SCRIPT CAPITAL T
+    ('\u{1D4B0}', "Uscr"),                // MATHEMATICAL SCRIPT CAPITAL U
+    ('\u{1D4B1}', "Vscr"),                // MATHEMATICAL SCRIPT CAPITAL V
+    ('\u{1D4B2}', "Wscr"),                // MATHEMATICAL SCRIPT CAPITAL W
+    ('\u{1D4B3}', "Xscr"),                // MATHEMATICAL SCRIPT CAPITAL X
+    ('\u{1D4B4}', "Yscr"),                // MATHEMATICAL SCRIPT CAPITAL Y
+    ('\u{1D4B5}', "Zscr"),                // MATHEMATICAL SCRIPT CAPITAL Z
+    ('\u{1D4B6}', "ascr"),                // MATHEMATICAL SCRIPT SMALL A
+    ('\u{1D4B7}', "bscr"),                // MATHEMATICAL SCRIPT SMALL B
+    ('\u{1D4B8}', "cscr"),                // MATHEMATICAL SCRIPT SMALL C
+    ('\u{1D4B9}', "dscr"),                // MATHEMATICAL SCRIPT SMALL D
+    ('\u{1D4BB}', "fscr"),                // MATHEMATICAL SCRIPT SMALL F
+    ('\u{1D4BD}', "hscr"),                // MATHEMATICAL SCRIPT SMALL H
+    ('\u{1D4BE}', "iscr"),                // MATHEMATICAL SCRIPT SMALL I
+    ('\u{1D4BF}', "jscr"),                // MATHEMATICAL SCRIPT SMALL J
+    ('\u{1D4C0}', "kscr"),                // MATHEMATICAL SCRIPT SMALL K
+    ('\u{1D4C1}', "lscr"),                // MATHEMATICAL SCRIPT SMALL L
+    ('\u{1D4C2}', "mscr"),                // MATHEMATICAL SCRIPT SMALL M
+    ('\u{1D4C3}', "nscr"),                // MATHEMATICAL SCRIPT SMALL N
+    ('\u{1D4C5}', "pscr"),                // MATHEMATICAL SCRIPT SMALL P
+    ('\u{1D4C6}', "qscr"),                // MATHEMATICAL SCRIPT SMALL Q
+    ('\u{1D4C7}', "rscr"),                // MATHEMATICAL SCRIPT SMALL R
+    ('\u{1D4C8}', "sscr"),                // MATHEMATICAL SCRIPT SMALL S
+    ('\u{1D4C9}', "tscr"),                // MATHEMATICAL SCRIPT SMALL T
+    ('\u{1D4CA}', "uscr"),                // MATHEMATICAL SCRIPT SMALL U
+    ('\u{1D4CB}', "vscr"),                // MATHEMATICAL SCRIPT SMALL V
+    ('\u{1D4CC}', "wscr"),                // MATHEMATICAL SCRIPT SMALL W
+    ('\u{1D4CD}', "xscr"),                // MATHEMATICAL SCRIPT SMALL X
+    ('\u{1D4CE}', "yscr"),                // MATHEMATICAL SCRIPT SMALL Y
+    ('\u{1D4CF}', "zscr"),                // MATHEMATICAL SCRIPT SMALL Z
+    ('\u{1D504}', "Afr"),                 // MATHEMATICAL FRAKTUR CAPITAL A
+    ('\u{1D505}', "Bfr"),                 // MATHEMATICAL FRAKTUR CAPITAL B
+    ('\u{1D507}', "Dfr"),                 // MATHEMATICAL FRAKTUR CAPITAL D
+    ('\u{1D508}', "Efr"),                 // MATHEMATICAL FRAKTUR CAPITAL E
+    ('\u{1D509}', "Ffr"),                 // MATHEMATICAL FRAKTUR CAPITAL F
+    ('\u{1D50A}', "Gfr"),                 // MATHEMATICAL FRAKTUR CAPITAL G
+    ('\u{1D50D}', "Jfr"),                 // MATHEMATICAL FRAKTUR CAPITAL J
+    ('\u{1D50E}', "Kfr"),                 // MATHEMATICAL FRAKTUR CAPITAL K
+    ('\u{1D50F}', "Lfr"),                 // MATHEMATICAL FRAKTUR CAPITAL L
+    ('\u{1D510}', "Mfr"),                 // MATHEMATICAL FRAKTUR CAPITAL M
+    ('\u{1D511}', "Nfr"),                 // MATHEMATICAL FRAKTUR CAPITAL N
+    ('\u{1D512}', "Ofr"),                 // MATHEMATICAL FRAKTUR CAPITAL O
+    ('\u{1D513}', "Pfr"),                 // MATHEMATICAL FRAKTUR CAPITAL P
+    ('\u{1D514}', "Qfr"),                 // MATHEMATICAL FRAKTUR CAPITAL Q
+    ('\u{1D516}', "Sfr"),                 // MATHEMATICAL FRAKTUR CAPITAL S
+    ('\u{1D517}', "Tfr"),                 // MATHEMATICAL FRAKTUR CAPITAL T
+    ('\u{1D518}', "Ufr"),                 // MATHEMATICAL FRAKTUR CAPITAL U
+    ('\u{1D519}', "Vfr"),                 // MATHEMATICAL FRAKTUR CAPITAL V
+    ('\u{1D51A}', "Wfr"),                 // MATHEMATICAL FRAKTUR CAPITAL W
+    ('\u{1D51B}', "Xfr"),                 // MATHEMATICAL FRAKTUR CAPITAL X
+    ('\u{1D51C}', "Yfr"),                 // MATHEMATICAL FRAKTUR CAPITAL Y
+    ('\u{1D51E}', "afr"),                 // MATHEMATICAL FRAKTUR SMALL A
+    ('\u{1D51F}', "bfr"),                 // MATHEMATICAL FRAKTUR SMALL B
+    ('\u{1D520}', "cfr"),                 // MATHEMATICAL FRAKTUR SMALL C
+    ('\u{1D521}', "dfr"),                 // MATHEMATICAL FRAKTUR SMALL D
+    ('\u{1D522}', "efr"),                 // MATHEMATICAL FRAKTUR SMALL E
+    ('\u{1D523}', "ffr"),                 // MATHEMATICAL FRAKTUR SMALL F
+    ('\u{1D524}', "gfr"),                 // MATHEMATICAL FRAKTUR SMALL G
+    ('\u{1D525}', "hfr"),                 // MATHEMATICAL FRAKTUR SMALL H
+    ('\u{1D526}', "ifr"),                 // MATHEMATICAL FRAKTUR SMALL I
+    ('\u{1D527}', "jfr"),                 // MATHEMATICAL FRAKTUR SMALL J
+    ('\u{1D528}', "kfr"),                 // MATHEMATICAL FRAKTUR SMALL K
+    ('\u{1D529}', "lfr"),                 // MATHEMATICAL FRAKTUR SMALL L
+    ('\u{1D52A}', "mfr"),                 // MATHEMATICAL FRAKTUR SMALL M
+    ('\u{1D52B}', "nfr"),                 // MATHEMATICAL FRAKTUR SMALL N
+    ('\u{1D52C}', "ofr"),                 // MATHEMATICAL FRAKTUR SMALL O
+    ('\u{1D52D}', "pfr"),                 // MATHEMATICAL FRAKTUR SMALL P
+    ('\u{1D52E}', "qfr"),                 // MATHEMATICAL FRAKTUR SMALL Q
+    ('\u{1D52F}', "rfr"),                 // MATHEMATICAL FRAKTUR SMALL R
+    ('\u{1D530}', "sfr"),                 // MATHEMATICAL FRAKTUR SMALL S
+    ('\u{1D531}', "tfr"),                 // MATHEMATICAL FRAKTUR SMALL T
+    ('\u{1D532}', "ufr"),                 // MATHEMATICAL FRAKTUR SMALL U
+    ('\u{1D533}', "vfr"),                 // MATHEMATICAL FRAKTUR SMALL V
+    ('\u{1D534}', "wfr"),                 // MATHEMATICAL FRAKTUR SMALL W
+    ('\u{1D535}', "xfr"),                 // MATHEMATICAL FRAKTUR SMALL X
+    ('\u{1D536}', "yfr"),                 // MATHEMATICAL FRAKTUR SMALL Y
+    ('\u{1D537}', "zfr"),                 // MATHEMATICAL FRAKTUR SMALL Z
+    ('\u{1D538}', "Aopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL A
+    ('\u{1D539}', "Bopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL B
+    ('\u{1D53B}', "Dopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL D
+    ('\u{1D53C}', "Eopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL E
+    ('\u{1D53D}', "Fopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL F
+    ('\u{1D53E}', "Gopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL G
+    ('\u{1D540}', "Iopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL I
+    ('\u{1D541}', "Jopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL J
+    ('\u{1D542}', "Kopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL K
+    ('\u{1D543}', "Lopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL L
+    ('\u{1D544}', "Mopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL M
+    ('\u{1D546}', "Oopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL O
+    ('\u{1D54A}', "Sopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL S
+    ('\u{1D54B}', "Topf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL T
+    ('\u{1D54C}', "Uopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL U
+    ('\u{1D54D}', "Vopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL V
+    ('\u{1D54E}', "Wopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL W
+    ('\u{1D54F}', "Xopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL X
+    ('\u{1D550}', "Yopf"),                // MATHEMATICAL DOUBLE-STRUCK CAPITAL Y
+    ('\u{1D552}', "aopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL A
+    ('\u{1D553}', "bopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL B
+    ('\u{1D554}', "copf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL C
+    ('\u{1D555}', "dopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL D
+    ('\u{1D556}', "eopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL E
+    ('\u{1D557}', "fopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL F
+    ('\u{1D558}', "gopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL G
+    ('\u{1D559}', "hopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL H
+    ('\u{1D55A}', "iopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL I
+    ('\u{1D55B}', "jopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL J
+    ('\u{1D55C}', "kopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL K
+    ('\u{1D55D}', "lopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL L
+    ('\u{1D55E}', "mopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL M
+    ('\u{1D55F}', "nopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL N
+    ('\u{1D560}', "oopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL O
+    ('\u{1D561}', "popf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL P
+    ('\u{1D562}', "qopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL Q
+    ('\u{1D563}', "ropf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL R
+    ('\u{1D564}', "sopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL S
+    ('\u{1D565}', "topf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL T
+    ('\u{1D566}', "uopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL U
+    ('\u{1D567}', "vopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL V
+    ('\u{1D568}', "wopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL W
+    ('\u{1D569}', "xopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL X
+    ('\u{1D56A}', "yopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL Y
+    ('\u{1D56B}', "zopf"),                // MATHEMATICAL DOUBLE-STRUCK SMALL Z
+];
+
+/// The table of characters and their HTML 4.01 named entities ordered by the characters.
+///
+/// Source: https://www.w3.org/TR/html401/sgml/entities.html
+pub(crate) static HTML4_CHAR_NAMED_ENTITIES: [(char, &str); 252] = [
+    ('\u{0022}', "quot"),     // QUOTATION MARK
+    ('\u{0026}', "amp"),      // AMPERSAND
+    ('\u{003C}', "lt"),       // LESS-THAN SIGN
+    ('\u{003E}', "gt"),       // GREATER-THAN SIGN
+    ('\u{00A0}', "nbsp"),     // NO-BREAK SPACE
+    ('\u{00A1}', "iexcl"),    // INVERTED EXCLAMATION MARK
+    ('\u{00A2}', "cent"),     // CENT SIGN
+    ('\u{00A3}', "pound"),    // POUND SIGN
+    ('\u{00A4}', "curren"),   // CURRENCY SIGN
+    ('\u{00A5}', "yen"),      // YEN SIGN
+    ('\u{00A6}', "brvbar"),   // BROKEN BAR
+    ('\u{00A7}', "sect"),     // SECTION SIGN
+    ('\u{00A8}', "uml"),      // DIAERESIS
+    ('\u{00A9}', "copy"),     // COPYRIGHT SIGN
+    ('\u{00AA}', "ordf"),     // FEMININE ORDINAL INDICATOR
+    ('\u{00AB}', "laquo"),    // LEFT-POINTING DOUBLE ANGLE QUOTATION MARK
+    ('\u{00AC}', "not"),      // NOT SIGN
+    ('\u{00AD}', "shy"),      // SOFT HYPHEN
+    ('\u{00AE}', "reg"),      // REGISTERED SIGN
+    ('\u{00AF}', "macr"),     // MACRON
+    ('\u{00B0}', "deg"),      // DEGREE SIGN
+    ('\u{00B1}', "plusmn"),   // PLUS-MINUS SIGN
+    ('\u{00B2}', "sup2"),     // SUPERSCRIPT TWO
+    ('\u{00B3}', "sup3"),     // SUPERSCRIPT THREE
+    ('\u{00B4}', "acute"),    // ACUTE ACCENT
+    ('\u{00B5}', "micro"),    // MICRO SIGN
+    ('\u{00B6}', "para"),     // PILCROW SIGN
+    ('\u{00B7}', "middot"),   // MIDDLE DOT
+    ('\u{00B8}', "cedil"),    // CEDILLA
+    ('\u{00B9}', "sup1"),     // SUPERSCRIPT ONE
+    ('\u{00BA}', "ordm"),     // MASCULINE ORDINAL INDICATOR
+    ('\u{00BB}', "raquo"),    // RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK
+    ('\u{00BC}', "frac14"),   // VULGAR FRACTION ONE QUARTER
+    ('\u{00BD}', "frac12"),   // VULGAR FRACTION ONE HALF
+    ('\u{00BE}', "frac34"),   // VULGAR FRACTION THREE QUARTERS
+    ('\u{00BF}', "iquest"),   // INVERTED QUESTION MARK
+    ('\u{00C0}', "Agrave"),   // LATIN CAPITAL LETTER A WITH GRAVE
+    ('\u{00C1}', "Aacute"),   // LATIN CAPITAL LETTER A WITH ACUTE
+    ('\u{00C2}', "Acirc"),    // LATIN CAPITAL LETTER A WITH CIRCUMFLEX
+    ('\u{00C3}', "Atilde"),   // LATIN CAPITAL LETTER A WITH TILDE
+    ('\u{00C4}', "Auml"),     // LATIN CAPITAL LETTER A WITH DIAERESIS
+    ('\u{00C5}', "Aring"),    // LATIN CAPITAL LETTER A WITH RING ABOVE
+    ('\u{00C6}', "AElig"),    // LATIN CAPITAL LETTER AE
+    ('\u{00C7}', "Ccedil"),   // LATIN CAPITAL LETTER C WITH CEDILLA
+    ('\u{00C8}', "Egrave"),   // LATIN CAPITAL LETTER E WITH GRAVE
+    ('\u{00C9}', "Eacute"),   // LATIN CAPITAL LETTER E WITH ACUTE
+    ('\u{00CA}', "Ecirc"),    // LATIN CAPITAL LETTER E WITH CIRCUMFLEX
+    ('\u{00CB}', "Euml"),     // LATIN CAPITAL LETTER E WITH DIAERESIS
+    ('\u{00CC}', "Igrave"),   // LATIN CAPITAL LETTER I WITH GRAVE
+    ('\u{00CD}', "Iacute"),   // LATIN CAPITAL LETTER I WITH ACUTE
+    ('\u{00CE}', "Icirc"),    // LATIN CAPITAL LETTER I WITH CIRCUMFLEX
+    ('\u{00CF}', "Iuml"),     // LATIN CAPITAL LETTER I WITH DIAERESIS
+    ('\u{00D0}', "ETH"),      // LATIN CAPITAL LETTER ETH
+    ('\u{00D1}', "Ntilde"),   // LATIN CAPITAL LETTER N WITH TILDE
+    ('\u{00D2}', "Ograve"),   // LATIN CAPITAL LETTER O WITH GRAVE
+    ('\u{00D3}', "Oacute"),   // LATIN CAPITAL LETTER O WITH ACUTE
+    ('\u{00D4}', "Ocirc"),    // LATIN CAPITAL LETTER O WITH CIRCUMFLEX
+    ('\u{00D5}', "Otilde"),   // LATIN CAPITAL LETTER O WITH TILDE
+    ('\u{00D6}', "Ouml"),     // LATIN CAPITAL LETTER O WITH DIAERESIS
+    ('\u{00D7}', "times"),    // MULTIPLICATION SIGN
+    ('\u{00D8}', "Oslash"),   // LATIN CAPITAL LETTER O WITH STROKE
+    ('\u{00D9}', "Ugrave"),   // LATIN CAPITAL LETTER U WITH GRAVE
+    ('\u{00DA}', "Uacute"),   // LATIN CAPITAL LETTER U WITH ACUTE
+    ('\u{00DB}', "Ucirc"),    // LATIN CAPITAL LETTER U WITH CIRCUMFLEX
+    ('\u{00DC}', "Uuml"),     // LATIN CAPITAL LETTER U WITH DIAERESIS
+    ('\u{00DD}', "Yacute"),   // LATIN CAPITAL LETTER Y WITH ACUTE
+    ('\u{00DE}', "THORN"),    // LATIN CAPITAL LETTER THORN
+    ('\u{00DF}', "szlig"),    // LATIN SMALL LETTER SHARP S
+    ('\u{00E0}', "agrave"),   // LATIN SMALL LETTER A WITH GRAVE
+    ('\u{00E1}', "aacute"),   // LATIN SMALL LETTER A WITH ACUTE
+    ('\u{00E2}', "acirc"),    // LATIN SMALL LETTER A WITH CIRCUMFLEX
+    ('\u{00E3}', "atilde"),   // LATIN SMALL LETTER A WITH TILDE
+    ('\u{00E4}', "auml"),     // LATIN SMALL LETTER A WITH DIAERESIS
+    ('\u{00E5}', "aring"),    // LATIN SMALL LETTER A WITH RING ABOVE
+    ('\u{00E6}', "aelig"),    // LATIN SMALL LETTER AE
+    ('\u{00E7}', "ccedil"),   // LATIN SMALL LETTER C WITH CEDILLA
+    ('\u{00E8}', "egrave"),   // LATIN SMALL LETTER E WITH GRAVE
+    ('\u{00E9}', "eacute"),   // LATIN SMALL LETTER E WITH ACUTE
+    ('\u{00EA}', "ecirc"),    // LATIN SMALL LETTER E WITH CIRCUMFLEX
+    ('\u{00EB}', "euml"),     // LATIN SMALL LETTER E WITH DIAERESIS
+    ('\u{00EC}', "igrave"),   // LATIN SMALL LETTER I WITH GRAVE
+    ('\u{00ED}', "iacute"),   // LATIN SMALL LETTER I WITH ACUTE
+    ('\u{00EE}', "icirc"),    // LATIN SMALL LETTER I WITH CIRCUMFLEX
+    ('\u{00EF}', "iuml"),     // LATIN SMALL LETTER I WITH DIAERESIS
+    ('\u{00F0}', "eth"),      // LATIN SMALL LETTER ETH
+    ('\u{00F1}', "ntilde"),   // LATIN SMALL LETTER N WITH TILDE
+    ('\u{00F2}', "ograve"),   // LATIN SMALL LETTER O WITH GRAVE
+    ('\u{00F3}', "oacute"),   // LATIN SMALL LETTER O WITH ACUTE
+    ('\u{00F4}', "ocirc"),    // LATIN SMALL LETTER O WITH CIRCUMFLEX
+    ('\u{00F5}', "otilde"),   // LATIN SMALL LETTER O WITH TILDE
+    ('\u{00F6}', "ouml"),     // LATIN SMALL LETTER O WITH DIAERESIS
+    ('\u{00F7}', "divide"),   // DIVISION SIGN
+    ('\u{00F8}', "oslash"),   // LATIN SMALL LETTER O WITH STROKE
+    ('\u{00F9}', "ugrave"),   // LATIN SMALL LETTER U WITH GRAVE
+    ('\u{00FA}', "uacute"),   // LATIN SMALL LETTER U WITH ACUTE
+    ('\u{00FB}', "ucirc"),    // LATIN SMALL LETTER U WITH CIRCUMFLEX
+    ('\u{00FC}', "uuml"),     // LATIN SMALL LETTER U WITH DIAERESIS
+    ('\u{00FD}', "yacute"),   // LATIN SMALL LETTER Y WITH ACUTE
+    ('\u{00FE}', "thorn"),    // LATIN SMALL LETTER THORN
+    ('\u{00FF}', "yuml"),     // LATIN SMALL LETTER Y WITH DIAERESIS
+    ('\u{0152}', "OElig"),    // LATIN CAPITAL LIGATURE OE
+    ('\u{0153}', "oelig"),    // LATIN SMALL LIGATURE OE
+    ('\u{0160}', "Scaron"),   // LATIN CAPITAL LETTER S WITH CARON
+    ('\u{0161}', "scaron"),   // LATIN SMALL LETTER S WITH CARON
+    ('\u{0178}', "Yuml"),     // LATIN CAPITAL LETTER Y WITH DIAERESIS
+    ('\u{0192}', "fnof"),     // LATIN SMALL LETTER F WITH HOOK
+    ('\u{02C6}', "circ"),     // MODIFIER LETTER CIRCUMFLEX ACCENT
+    ('\u{02DC}', "tilde"),    // SMALL TILDE
+    ('\u{0391}', "Alpha"),    // GREEK CAPITAL LETTER ALPHA
+    ('\u{0392}', "Beta"),     // GREEK CAPITAL LETTER BETA
+    ('\u{0393}', "Gamma"),    // GREEK CAPITAL LETTER GAMMA
+    ('\u{0394}', "Delta"),    // GREEK CAPITAL LETTER DELTA
+    ('\u{0395}', "Epsilon"),  // GREEK CAPITAL LETTER EPSILON
+    ('\u{0396}', "Zeta"),     // GREEK CAPITAL LETTER ZETA
+    ('\u{0397}', "Eta"),      // GREEK CAPITAL LETTER ETA
+    ('\u{0398}', "Theta"),    // GREEK CAPITAL LETTER THETA
+    ('\u{0399}', "Iota"),     // GREEK CAPITAL LETTER IOTA
+    ('\u{039A}', "Kappa"),    // GREEK CAPITAL LETTER KAPPA
+    ('\u{039B}', "Lambda"),   // GREEK CAPITAL LETTER LAMDA
+    ('\u{039C}', "Mu"),       // GREEK CAPITAL LETTER MU
+    ('\u{039D}', "Nu"),       // GREEK CAPITAL LETTER NU
+    ('\u{039E}', "Xi"),       // GREEK CAPITAL LETTER XI
+    ('\u{039F}', "Omicron"),  // GREEK CAPITAL LETTER OMICRON
+    ('\u{03A0}', "Pi"),       // GREEK CAPITAL LETTER PI
+    ('\u{03A1}', "Rho"),      // GREEK CAPITAL LETTER RHO
+    ('\u{03A3}', "Sigma"),    // GREEK CAPITAL LETTER SIGMA
+    ('\u{03A4}', "Tau"),      // GREEK CAPITAL LETTER TAU
+    ('\u{03A5}', "Upsilon"),  // GREEK CAPITAL LETTER UPSILON
+    ('\u{03A6}', "Phi"),      // GREEK CAPITAL LETTER PHI
+    ('\u{03A7}', "Chi"),      // GREEK CAPITAL LETTER CHI
+    ('\u{03A8}', "Psi"),      // GREEK CAPITAL LETTER PSI
+    ('\u{03A9}', "Omega"),    // GREEK CAPITAL LETTER OMEGA
+    ('\u{03B1}', "alpha"),    // GREEK SMALL LETTER ALPHA
+    ('\u{03B2}', "beta"),     // GREEK SMALL LETTER BETA
+    ('\u{03B3}', "gamma"),    // GREEK SMALL LETTER GAMMA
+    ('\u{03B4}', "delta"),    // GREEK SMALL LETTER DELTA
+    ('\u{03B5}', "epsilon"),  // GREEK SMALL LETTER EPSILON
+    ('\u{03B6}', "zeta"),     // GREEK SMALL LETTER ZETA
+    ('\u{03B7}', "eta"),      // GREEK SMALL LETTER ETA
+    ('\u{03B8}', "theta"),    // GREEK SMALL LETTER THETA
+    ('\u{03B9}', "iota"),     // GREEK SMALL LETTER IOTA
+    ('\u{03BA}', "kappa"),    // GREEK SMALL LETTER KAPPA
+    ('\u{03BB}', "lambda"),   // GREEK SMALL LETTER LAMDA
+    ('\u{03BC}', "mu"),       // GREEK SMALL LETTER MU
+    ('\u{03BD}', "nu"),       // GREEK SMALL LETTER NU
+    ('\u{03BE}', "xi"),       // GREEK SMALL LETTER XI
+    ('\u{03BF}', "omicron"),  // GREEK SMALL LETTER OMICRON
+    ('\u{03C0}', "pi"),       // GREEK SMALL LETTER PI
+    ('\u{03C1}', "rho"),      // GREEK SMALL LETTER RHO
+    ('\u{03C2}', "sigmaf"),   // GREEK SMALL LETTER FINAL SIGMA
+    ('\u{03C3}', "sigma"),    // GREEK SMALL LETTER SIGMA
+    ('\u{03C4}', "tau"),      // GREEK SMALL LETTER TAU
+    ('\u{03C5}', "upsilon"),  // GREEK SMALL LETTER UPSILON
+    ('\u{03C6}', "phi"),      // GREEK SMALL LETTER PHI
+    ('\u{03C7}', "chi"),      // GREEK SMALL LETTER CHI
+    ('\u{03C8}', "psi"),      // GREEK SMALL LETTER PSI
+    ('\u{03C9}', "omega"),    // GREEK SMALL LETTER OMEGA
+    ('\u{03D1}', "thetasym"), // GREEK THETA SYMBOL
+    ('\u{03D2}', "upsih"),    // GREEK UPSILON WITH HOOK SYMBOL
+    ('\u{03D6}', "piv"),      // GREEK PI SYMBOL
+    ('\u{2002}', "ensp"),     // EN SPACE
+    ('\u{2003}', "emsp"),     // EM SPACE
+    ('\u{2009}', "thinsp"),   // THIN SPACE
+    ('\u{200C}', "zwnj"),     // ZERO WIDTH NON-JOINER
+    ('\u{200D}', "zwj"),      // ZERO WIDTH JOINER
+    ('\u{200E}', "lrm"),      // LEFT-TO-RIGHT MARK
+    ('\u{200F}', "rlm"),      // RIGHT-TO-LEFT MARK
+    ('\u{2013}', "ndash"),    // EN DASH
+    ('\u{2014}', "mdash"),    // EM DASH
+    ('\u{2018}', "lsquo"),    // LEFT SINGLE QUOTATION MARK
+    ('\u{2019}', "rsquo"),    // RIGHT SINGLE QUOTATION MARK
+    ('\u{201A}', "sbquo"),    // SINGLE LOW-9 QUOTATION MARK
+    ('\u{201C}', "ldquo"),    // LEFT DOUBLE QUOTATION MARK
+    ('\u{201D}', "rdquo"),    // RIGHT DOUBLE QUOTATION MARK
+    ('\u{201E}', "bdquo"),    // DOUBLE LOW-9 QUOTATION MARK
+    ('\u{2020}', "dagger"),   // DAGGER
+    ('\u{2021}', "Dagger"),   // DOUBLE DAGGER
+    ('\u{2022}', "bull"),     // BULLET
+    ('\u{2026}', "hellip"),   // HORIZONTAL ELLIPSIS
+    ('\u{2030}', "permil"),   // PER MILLE SIGN
+    ('\u{2032}', "prime"),    // PRIME
+    ('\u{2033}', "Prime"),    // DOUBLE PRIME
+    ('\u{2039}', "lsaquo"),   // SINGLE LEFT-POINTING ANGLE QUOTATION MARK
+    ('\u{203A}', "rsaquo"),   // SINGLE RIGHT-POINTING ANGLE QUOTATION MARK
+    ('\u{203E}', "oline"),    // OVERLINE
+    ('\u{2044}', "frasl"),    // FRACTION SLASH
+    ('\u{20AC}', "euro"),     // EURO SIGN
+    ('\u{2111}', "image"),    // BLACK-LETTER CAPITAL I
+    ('\u{2118}', "weierp"),   // SCRIPT CAPITAL P
+    ('\u{211C}', "real"),     // BLACK-LETTER CAPITAL R
+    ('\u{2122}', "trade"),    // TRADE MARK SIGN
+    ('\u{2135}', "alefsym"),  // ALEF SYMBOL
+    ('\u{2190}', "larr"),     // LEFTWARDS ARROW
+    ('\u{2191}', "uarr"),     // UPWARDS ARROW
+    ('\u{2192}', "rarr"),     // RIGHTWARDS ARROW
+    ('\u{2193}', "darr"),     // DOWNWARDS ARROW
+    ('\u{2194}', "harr"),     // LEFT RIGHT ARROW
+    ('\u{21B5}', "crarr"),    // DOWNWARDS ARROW WITH CORNER LEFTWARDS
+    ('\u{21D0}', "lArr"),     // LEFTWARDS DOUBLE ARROW
+    ('\u{21D1}', "uArr"),     // UPWARDS DOUBLE ARROW
+    ('\u{21D2}', "rArr"),     // RIGHTWARDS DOUBLE ARROW
+    ('\u{21D3}', "dArr"),     // DOWNWARDS DOUBLE ARROW
+    ('\u{21D4}', "hArr"),     // LEFT RIGHT DOUBLE ARROW
+    ('\u{2200}', "forall"),   // FOR ALL
+    ('\u{2202}', "part"),     // PARTIAL DIFFERENTIAL
+    ('\u{2203}', "exist"),    // THERE EXISTS
+    ('\u{2205}', "empty"),    // EMPTY SET
+    ('\u{2207}', "nabla"),    // NABLA
+    ('\u{2208}', "isin"),     // ELEMENT OF
+    ('\u{2209}', "notin"),    // NOT AN ELEMENT OF
+    ('\u{220B}', "ni"),       // CONTAINS AS MEMBER
+    ('\u{220F}', "prod"),     // N-ARY PRODUCT
+    ('\u{2211}', "sum"),      // N-ARY SUMMATION
+    ('\u{2212}', "minus"),    // MINUS SIGN
+    ('\u{2217}', "lowast"),   // ASTERISK OPERATOR
+    ('\u{221A}', "radic"),    // SQUARE ROOT
+    ('\u{221D}', "prop"),     // PROPORTIONAL TO
+    ('\u{221E}', "infin"),    // INFINITY
+    ('\u{2220}', "ang"),      // ANGLE
+    ('\u{2227}', "and"),      // LOGICAL AND
+    ('\u{2228}', "or"),       // LOGICAL OR
+    ('\u{2229}', "cap"),      // INTERSECTION
+    ('\u{222A}', "cup"),      // UNION
+    ('\u{222B}', "int"),      // INTEGRAL
+    ('\u{2234}', "there4"),   // THEREFORE
+    ('\u{223C}', "sim"),      // TILDE OPERATOR
+    ('\u{2245}', "cong"),     // APPROXIMATELY EQUAL TO
+    ('\u{2248}', "asymp"),    // ALMOST EQUAL TO
+    ('\u{2260}', "ne"),       // NOT EQUAL TO
+    ('\u{2261}', "equiv"),    // IDENTICAL TO
+    ('\u{2264}', "le"),       // LESS-THAN OR EQUAL TO
+    ('\u{2265}', "ge"),       // GREATER-THAN OR EQUAL TO
+    ('\u{2282}', "sub"),      // SUBSET OF
+    ('\u{2283}', "sup"),      // SUPERSET OF
+    ('\u{2284}', "nsub"),     // NOT A SUBSET OF
+    ('\u{2286}', "sube"),     // SUBSET OF OR EQUAL TO
+    ('\u{2287}', "supe"),     // SUPERSET OF OR EQUAL TO
+    ('\u{2295}', "oplus"),    // CIRCLED PLUS
+    ('\u{2297}', "otimes"),   // CIRCLED TIMES
+    ('\u{22A5}', "perp"),     // UP TACK
+    ('\u{22C5}', "sdot"),     // DOT OPERATOR
+    ('\u{2308}', "lceil"),    // LEFT CEILING
+    ('\u{2309}', "rceil"),    // RIGHT CEILING
+    ('\u{230A}', "lfloor"),   // LEFT FLOOR
+    ('\u{230B}', "rfloor"),   // RIGHT FLOOR
+    ('\u{2329}', "lang"),     // LEFT-POINTING ANGLE BRACKET
+    ('\u{232A}', "rang"),     // RIGHT-POINTING ANGLE BRACKET
+    ('\u{25CA}', "loz"),      // LOZENGE
+    ('\u{2660}', "spades"),   // BLACK SPADE SUIT
+    ('\u{2663}', "clubs"),    // BLACK CLUB SUIT
+    ('\u{2665}', "hearts"),   // BLACK HEART SUIT
+    ('\u{2666}', "diams"),    // BLACK DIAMOND SUIT
+];
@@ -0,0 +1,197 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+#[inline]
+fn is_blank(e: Option<&u8>) -> bool {
+    matches!(e, None | Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r'))
+}
+
+#[inline]
+fn escape_text_to_html(text_bytes: &[u8], p: usize) -> Option<&'static [u8]> {
+    match text_bytes[p] {
+        b'&' => Some(b"&amp;"),
+        b'<' => Some(b"&lt;"),
+        b'>' => Some(b"&gt;"),
+        b'\r' => Some(b"<br>\r"),
+        b'\n' if p == 0 || text_bytes[p - 1] != b'\r' => Some(b"<br>\n"),
+        b'\t' => Some(b"&nbsp;&nbsp;&nbsp;&nbsp;"),
+        b' ' if p == 0 || is_blank(text_bytes.get(p - 1)) || is_blank(text_bytes.get(p + 1)) => {
+            Some(b"&nbsp;")
+        }
+        _ => None,
+    }
+}
+
+/// Encode plain text, such as a comment or a log, to be displayed as regular HTML text with its line breaks and spaces.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * a line break (`\n`, `\r\n` or `\r`) => `<br>` followed by the line break
+/// * `\t` => `&nbsp;&nbsp;&nbsp;&nbsp;`
+/// * a space which is not a single space between two words => `&nbsp;`
+pub fn text_to_html<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+
+    let first = loop {
+        if p == text_length {
+            return Cow::from(text);
+        }
+
+        if let Some(escaped) = escape_text_to_html(text_bytes, p) {
+            break escaped;
+        }
+
+        p += 1;
+    };
+
+    let mut v = Vec::with_capacity(text_length + 5);
+
+    v.extend_from_slice(&text_bytes[..p]);
+    v.extend_from_slice(first);
+
+    p += 1;
+
+    let mut start = p;
+
+    while p < text_length {
+        if let Some(escaped) = escape_text_to_html(text_bytes, p) {
+            v.extend_from_slice(&text_bytes[start..p]);
+            start = p + 1;
+            v.extend_from_slice(escaped);
+        }
+
+        p += 1;
+    }
+
+    v.extend_from_slice(&text_bytes[start..p]);
+
+    Cow::from(unsafe { String::from_utf8_unchecked(v) })
+}
+
+/// Write plain text, such as a comment or a log, to be displayed as regular HTML text with its line breaks and spaces to a mutable `String` reference and return the encoded string slice.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * a line break (`\n`, `\r\n` or `\r`) => `<br>` followed by the line break
+/// * `\t` => `&nbsp;&nbsp;&nbsp;&nbsp;`
+/// * a space which is not a single space between two words => `&nbsp;`
+#[inline]
+pub fn text_to_html_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(text_to_html_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Write plain text, such as a comment or a log, to be displayed as regular HTML text with its line breaks and spaces to a mutable `Vec<u8>` reference and return the encoded data slice.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * a line break (`\n`, `\r\n` or `\r`) => `<br>` followed by the line break
+/// * `\t` => `&nbsp;&nbsp;&nbsp;&nbsp;`
+/// * a space which is not a single space between two words => `&nbsp;`
+#[inline]
+pub fn text_to_html_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    text_to_html_to_sink(text, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write plain text, such as a comment or a log, to be displayed as regular HTML text with its line breaks and spaces to a writer.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * a line break (`\n`, `\r\n` or `\r`) => `<br>` followed by the line break
+/// * `\t` => `&nbsp;&nbsp;&nbsp;&nbsp;`
+/// * a space which is not a single space between two words => `&nbsp;`
+#[inline]
+pub fn text_to_html_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    text_to_html_to_sink(text, &mut IoSink(output))
+}
+
+/// Write plain text, such as a comment or a log, to be displayed as regular HTML text with its line breaks and spaces to a `fmt::Write`.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * a line break (`\n`, `\r\n` or `\r`) => `<br>` followed by the line break
+/// * `\t` => `&nbsp;&nbsp;&nbsp;&nbsp;`
+/// * a space which is not a single space between two words => `&nbsp;`
+#[inline]
+pub fn text_to_html_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    text_to_html_to_sink(text, &mut FmtSink(output))
+}
+
+/// Write plain text, such as a comment or a log, to be displayed as regular HTML text with its line breaks and spaces to an `EncodeSink`.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * a line break (`\n`, `\r\n` or `\r`) => `<br>` followed by the line break
+/// * `\t` => `&nbsp;&nbsp;&nbsp;&nbsp;`
+/// * a space which is not a single space between two words => `&nbsp;`
+pub fn text_to_html_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while p < text_length {
+        if let Some(escaped) = escape_text_to_html(text_bytes, p) {
+            output.write_str(unsafe { text.get_unchecked(start..p) })?;
+            start = p + 1;
+            output.write_str(unsafe { from_utf8_unchecked(escaped) })?;
+        }
+
+        p += 1;
+    }
+
+    output.write_str(unsafe { text.get_unchecked(start..p) })
+}
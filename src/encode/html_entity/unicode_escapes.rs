@@ -0,0 +1,61 @@
+use core::ops::BitOr;
+
+/// The sets of non-ASCII characters which the `*_with_unicode_escapes` encoders escape to hexadecimal references, such as `&#x202E;`.
+///
+/// The sets can be combined with `|`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct UnicodeEscapes(u8);
+
+impl UnicodeEscapes {
+    /// No non-ASCII characters are escaped.
+    pub const NONE: UnicodeEscapes = UnicodeEscapes(0);
+    /// The bidirectional control characters U+202A to U+202E and U+2066 to U+2069, which can make the displayed text differ from its logical order (Trojan Source).
+    pub const BIDI_CONTROLS: UnicodeEscapes = UnicodeEscapes(1);
+    /// The zero-width and invisible characters U+200B to U+200D, U+2060 and U+FEFF, which are escaped so that they become visible in the markup.
+    pub const INVISIBLE: UnicodeEscapes = UnicodeEscapes(1 << 1);
+    /// The non-ASCII whitespace and separator characters U+0085, U+00A0, U+1680, U+2000 to U+200A, U+2028, U+2029, U+202F, U+205F and U+3000, which some parsers treat as delimiters.
+    pub const WHITESPACE: UnicodeEscapes = UnicodeEscapes(1 << 2);
+
+    /// Combine two sets.
+    #[inline]
+    pub const fn union(self, other: UnicodeEscapes) -> UnicodeEscapes {
+        UnicodeEscapes(self.0 | other.0)
+    }
+
+    /// Whether all characters of `other` are in this set.
+    #[inline]
+    pub const fn contains(self, other: UnicodeEscapes) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub(crate) fn escapes(self, c: char) -> bool {
+        match c {
+            '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}' => {
+                self.contains(UnicodeEscapes::BIDI_CONTROLS)
+            }
+            '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}' => {
+                self.contains(UnicodeEscapes::INVISIBLE)
+            }
+            '\u{85}'
+            | '\u{A0}'
+            | '\u{1680}'
+            | '\u{2000}'..='\u{200A}'
+            | '\u{2028}'
+            | '\u{2029}'
+            | '\u{202F}'
+            | '\u{205F}'
+            | '\u{3000}' => self.contains(UnicodeEscapes::WHITESPACE),
+            _ => false,
+        }
+    }
+}
+
+impl BitOr for UnicodeEscapes {
+    type Output = UnicodeEscapes;
+
+    #[inline]
+    fn bitor(self, other: UnicodeEscapes) -> UnicodeEscapes {
+        self.union(other)
+    }
+}
@@ -1,3 +1,4 @@
+use core::fmt;
 use core::str::from_utf8_unchecked;
 
 use alloc::borrow::Cow;
@@ -8,7 +9,14 @@ use alloc::vec::Vec;
 use std::io::{self, Write};
 
 use crate::functions::*;
+use super::numeric_entity_format::*;
+use super::unicode_escapes::*;
+use crate::buffer_too_small::SliceWriter;
 use crate::utf8_width;
+use crate::{BufferTooSmall, EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
 
 /// Encode text used in an unquoted attribute. Except for alphanumeric characters, escape all characters which are less than 128.
 ///
@@ -20,7 +28,7 @@ use crate::utf8_width;
 /// * `"` => `&quot;`
 ///
 /// Other non-alphanumeric characters are escaped to `&#xHH;`.
-pub fn encode_unquoted_attribute<S: ?Sized + AsRef<str>>(text: &S) -> Cow<str> {
+pub fn encode_unquoted_attribute<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
     let text = text.as_ref();
     let text_bytes = text.as_bytes();
 
@@ -82,39 +90,219 @@ pub fn encode_unquoted_attribute_to_string<S: AsRef<str>>(text: S, output: &mut
 /// * `"` => `&quot;`
 ///
 /// Other non-alphanumeric characters are escaped to `&#xHH;`.
+#[inline]
 pub fn encode_unquoted_attribute_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
     let text = text.as_ref();
-    let text_bytes = text.as_bytes();
-    let text_length = text_bytes.len();
 
-    output.reserve(text_length);
+    output.reserve(text.len());
 
     let current_length = output.len();
 
-    let mut p = 0;
-    let mut e;
+    encode_unquoted_attribute_to_sink(text, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write text used in an unquoted attribute to a writer. Except for alphanumeric characters, escape all characters which are less than 128.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters are escaped to `&#xHH;`.
+#[inline]
+pub fn encode_unquoted_attribute_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_unquoted_attribute_to_sink(text, &mut IoSink(output))
+}
+
+/// Write text used in an unquoted attribute to a `fmt::Write`. Except for alphanumeric characters, escape all characters which are less than 128.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters are escaped to `&#xHH;`.
+#[inline]
+pub fn encode_unquoted_attribute_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_unquoted_attribute_to_sink(text, &mut FmtSink(output))
+}
+
+/// Write text used in an unquoted attribute to a byte slice and return the length of the encoded data. Except for alphanumeric characters, escape all characters which are less than 128.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters are escaped to `&#xHH;`.
+pub fn encode_unquoted_attribute_to_slice<S: AsRef<str>>(
+    text: S,
+    output: &mut [u8],
+) -> Result<usize, BufferTooSmall> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+
+    let mut output = SliceWriter::new(output);
 
     let mut start = 0;
 
-    while p < text_length {
-        e = text_bytes[p];
+    for (p, e) in text_bytes.iter().copied().enumerate() {
+        if utf8_width::is_width_1(e) && !is_alphanumeric(e) {
+            output.write_text(text, start, p)?;
+            start = p + 1;
+
+            let mut buffer = [0u8; 6];
+
+            output.write_escape(html_entity_to_bytes(e, &mut buffer), p)?;
+        }
+    }
 
+    output.write_text(text, start, text_bytes.len())?;
+
+    Ok(output.written())
+}
+
+/// Write text used in an unquoted attribute to an `EncodeSink`. Except for alphanumeric characters, escape all characters which are less than 128.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters are escaped to `&#xHH;`.
+pub fn encode_unquoted_attribute_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text = text.as_ref();
+
+    let mut start = 0;
+
+    for (p, e) in text.bytes().enumerate() {
         if utf8_width::is_width_1(e) && !is_alphanumeric(e) {
-            output.extend_from_slice(&text_bytes[start..p]);
+            output.write_str(unsafe { text.get_unchecked(start..p) })?;
             start = p + 1;
-            write_html_entity_to_vec(e, output);
+            write_html_entity_to_sink(e, output)?;
         }
+    }
 
-        p += 1;
+    output.write_str(unsafe { text.get_unchecked(start..) })
+}
+
+/// Encode text used in an unquoted attribute, also escaping the given sets of non-ASCII characters.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters which are less than 128 and non-ASCII characters in the given sets, such as `UnicodeEscapes::WHITESPACE`, are escaped to `&#xHH;`.
+pub fn encode_unquoted_attribute_with_unicode_escapes<S: ?Sized + AsRef<str>>(
+    text: &S,
+    escapes: UnicodeEscapes,
+) -> Cow<'_, str> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let p = match text.char_indices().find(|&(_, c)| {
+        if c.is_ascii() {
+            !is_alphanumeric(c as u8)
+        } else {
+            escapes.escapes(c)
+        }
+    }) {
+        Some((p, _)) => p,
+        None => return Cow::from(text),
+    };
+
+    let mut v = Vec::with_capacity(text_length + 8);
+
+    v.extend_from_slice(&text_bytes[..p]);
+
+    encode_unquoted_attribute_with_unicode_escapes_to_vec(
+        unsafe { from_utf8_unchecked(&text_bytes[p..]) },
+        escapes,
+        &mut v,
+    );
+
+    Cow::from(unsafe { String::from_utf8_unchecked(v) })
+}
+
+/// Write text used in an unquoted attribute to a mutable `String` reference and return the encoded string slice, also escaping the given sets of non-ASCII characters.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters which are less than 128 and non-ASCII characters in the given sets, such as `UnicodeEscapes::WHITESPACE`, are escaped to `&#xHH;`.
+#[inline]
+pub fn encode_unquoted_attribute_with_unicode_escapes_to_string<S: AsRef<str>>(
+    text: S,
+    escapes: UnicodeEscapes,
+    output: &mut String,
+) -> &str {
+    unsafe {
+        from_utf8_unchecked(encode_unquoted_attribute_with_unicode_escapes_to_vec(
+            text,
+            escapes,
+            output.as_mut_vec(),
+        ))
     }
+}
 
-    output.extend_from_slice(&text_bytes[start..p]);
+/// Write text used in an unquoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice, also escaping the given sets of non-ASCII characters.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters which are less than 128 and non-ASCII characters in the given sets, such as `UnicodeEscapes::WHITESPACE`, are escaped to `&#xHH;`.
+#[inline]
+pub fn encode_unquoted_attribute_with_unicode_escapes_to_vec<S: AsRef<str>>(
+    text: S,
+    escapes: UnicodeEscapes,
+    output: &mut Vec<u8>,
+) -> &[u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    encode_unquoted_attribute_with_unicode_escapes_to_sink(text, escapes, output)
+        .unwrap_or_else(|error| match error {});
 
     &output[current_length..]
 }
 
 #[cfg(feature = "std")]
-/// Write text used in an unquoted attribute to a writer. Except for alphanumeric characters, escape all characters which are less than 128.
+/// Write text used in an unquoted attribute to a writer, also escaping the given sets of non-ASCII characters.
 ///
 /// The following characters are escaped to named entities:
 ///
@@ -123,31 +311,228 @@ pub fn encode_unquoted_attribute_to_vec<S: AsRef<str>>(text: S, output: &mut Vec
 /// * `>` => `&gt;`
 /// * `"` => `&quot;`
 ///
-/// Other non-alphanumeric characters are escaped to `&#xHH;`.
-pub fn encode_unquoted_attribute_to_writer<S: AsRef<str>, W: Write>(
+/// Other non-alphanumeric characters which are less than 128 and non-ASCII characters in the given sets, such as `UnicodeEscapes::WHITESPACE`, are escaped to `&#xHH;`.
+#[inline]
+pub fn encode_unquoted_attribute_with_unicode_escapes_to_writer<S: AsRef<str>, W: Write>(
     text: S,
+    escapes: UnicodeEscapes,
     output: &mut W,
 ) -> Result<(), io::Error> {
-    let text = text.as_ref();
-    let text_bytes = text.as_bytes();
-    let text_length = text_bytes.len();
+    encode_unquoted_attribute_with_unicode_escapes_to_sink(text, escapes, &mut IoSink(output))
+}
 
-    let mut p = 0;
-    let mut e;
+/// Write text used in an unquoted attribute to a `fmt::Write`, also escaping the given sets of non-ASCII characters.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters which are less than 128 and non-ASCII characters in the given sets, such as `UnicodeEscapes::WHITESPACE`, are escaped to `&#xHH;`.
+#[inline]
+pub fn encode_unquoted_attribute_with_unicode_escapes_to_fmt_writer<
+    S: AsRef<str>,
+    W: fmt::Write,
+>(
+    text: S,
+    escapes: UnicodeEscapes,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_unquoted_attribute_with_unicode_escapes_to_sink(text, escapes, &mut FmtSink(output))
+}
+
+/// Write text used in an unquoted attribute to an `EncodeSink`, also escaping the given sets of non-ASCII characters.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters which are less than 128 and non-ASCII characters in the given sets, such as `UnicodeEscapes::WHITESPACE`, are escaped to `&#xHH;`.
+pub fn encode_unquoted_attribute_with_unicode_escapes_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    escapes: UnicodeEscapes,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text = text.as_ref();
 
     let mut start = 0;
+    let mut end = 0;
+
+    for c in text.chars() {
+        if c.is_ascii() {
+            let e = c as u8;
+
+            if !is_alphanumeric(e) {
+                output.write_str(unsafe { text.get_unchecked(start..end) })?;
+                start = end + 1;
+                write_html_entity_to_sink(e, output)?;
+            }
+
+            end += 1;
+        } else if escapes.escapes(c) {
+            output.write_str(unsafe { text.get_unchecked(start..end) })?;
+            end += c.len_utf8();
+            start = end;
+            write_char_hex_to_sink(c, output)?;
+        } else {
+            end += c.len_utf8();
+        }
+    }
 
-    while p < text_length {
-        e = text_bytes[p];
+    output.write_str(unsafe { text.get_unchecked(start..end) })
+}
 
-        if utf8_width::is_width_1(e) && !is_alphanumeric(e) {
-            output.write_all(&text_bytes[start..p])?;
-            start = p + 1;
-            write_html_entity_to_writer(e, output)?;
-        }
+/// Encode text used in an unquoted attribute. Except for alphanumeric characters, escape all characters which are less than 128.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters are escaped to decimal references `&#DD;`.
+#[inline]
+pub fn encode_unquoted_attribute_decimal<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    encode_unquoted_attribute_with_format(text, NumericEntityFormat::DECIMAL)
+}
 
-        p += 1;
-    }
+/// Write text used in an unquoted attribute to a mutable `String` reference and return the encoded string slice. Except for alphanumeric characters, escape all characters which are less than 128.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters are escaped to decimal references `&#DD;`.
+#[inline]
+pub fn encode_unquoted_attribute_decimal_to_string<S: AsRef<str>>(
+    text: S,
+    output: &mut String,
+) -> &str {
+    encode_unquoted_attribute_with_format_to_string(text, NumericEntityFormat::DECIMAL, output)
+}
+
+/// Write text used in an unquoted attribute to a mutable `Vec<u8>` reference and return the encoded data slice. Except for alphanumeric characters, escape all characters which are less than 128.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters are escaped to decimal references `&#DD;`.
+#[inline]
+pub fn encode_unquoted_attribute_decimal_to_vec<S: AsRef<str>>(
+    text: S,
+    output: &mut Vec<u8>,
+) -> &[u8] {
+    encode_unquoted_attribute_with_format_to_vec(text, NumericEntityFormat::DECIMAL, output)
+}
+
+#[cfg(feature = "std")]
+/// Write text used in an unquoted attribute to a writer. Except for alphanumeric characters, escape all characters which are less than 128.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters are escaped to decimal references `&#DD;`.
+#[inline]
+pub fn encode_unquoted_attribute_decimal_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_unquoted_attribute_with_format_to_writer(text, NumericEntityFormat::DECIMAL, output)
+}
+
+/// Write text used in an unquoted attribute to a `fmt::Write`. Except for alphanumeric characters, escape all characters which are less than 128.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters are escaped to decimal references `&#DD;`.
+#[inline]
+pub fn encode_unquoted_attribute_decimal_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_unquoted_attribute_with_format_to_fmt_writer(text, NumericEntityFormat::DECIMAL, output)
+}
+
+/// Write text used in an unquoted attribute to an `EncodeSink`. Except for alphanumeric characters, escape all characters which are less than 128.
+///
+/// The following characters are escaped to named entities:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+/// * `"` => `&quot;`
+///
+/// Other non-alphanumeric characters are escaped to decimal references `&#DD;`.
+#[inline]
+pub fn encode_unquoted_attribute_decimal_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    encode_unquoted_attribute_with_format_to_sink(text, NumericEntityFormat::DECIMAL, output)
+}
+
+escape_impl! {
+    escape_unquoted_attribute_minimal;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'>' => b"&gt;",
+    b'"' => b"&quot;",
+    b'\'' => b"&#x27;",
+    b'`' => b"&#x60;",
+    b'=' => b"&#x3D;",
+    b'\t' => b"&#x09;",
+    b'\n' => b"&#x0A;",
+    b'\x0C' => b"&#x0C;",
+    b'\r' => b"&#x0D;",
+    b' ' => b"&#x20;",
+}
 
-    output.write_all(&text_bytes[start..p])
+encode_impl! {
+    /// Only the characters which are not allowed in an unquoted attribute value by the HTML specification are escaped, so characters like `-`, `_`, `.` and `/` stay literal:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&#x27;`
+    /// * `` ` `` => `&#x60;`
+    /// * `=` => `&#x3D;`
+    /// * tab, line feed, form feed, carriage return and space => `&#x09;`, `&#x0A;`, `&#x0C;`, `&#x0D;` and `&#x20;`
+    escape_unquoted_attribute_minimal;
+    /// Encode text used in an unquoted attribute with as few escapes as possible.
+    encode_unquoted_attribute_minimal;
+    /// Write text used in an unquoted attribute with as few escapes as possible to a mutable `String` reference and return the encoded string slice.
+    encode_unquoted_attribute_minimal_to_string;
+    /// Write text used in an unquoted attribute with as few escapes as possible to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_unquoted_attribute_minimal_to_vec;
+    /// Write text used in an unquoted attribute with as few escapes as possible to a writer.
+    encode_unquoted_attribute_minimal_to_writer;
+    /// Write text used in an unquoted attribute with as few escapes as possible to a `fmt::Write`.
+    encode_unquoted_attribute_minimal_to_fmt_writer;
+    /// Write text used in an unquoted attribute with as few escapes as possible to a byte slice and return the length of the encoded data.
+    encode_unquoted_attribute_minimal_to_slice;
+    /// Write text used in an unquoted attribute with as few escapes as possible to an `EncodeSink`.
+    encode_unquoted_attribute_minimal_to_sink;
 }
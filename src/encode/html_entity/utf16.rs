@@ -0,0 +1,72 @@
+use core::char::decode_utf16;
+
+use alloc::vec::Vec;
+
+/// How the UTF-16 functions, such as `encode_text_utf16`, handle a surrogate which is not part of a surrogate pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoneSurrogatePolicy {
+    /// Keep the lone surrogate as it is, so a JavaScript string survives the round trip unchanged.
+    Keep,
+    /// Replace the lone surrogate with U+FFFD REPLACEMENT CHARACTER, so the result is valid UTF-16.
+    Replace,
+}
+
+impl LoneSurrogatePolicy {
+    #[inline]
+    pub(crate) fn handle(self, surrogate: u16) -> u16 {
+        match self {
+            LoneSurrogatePolicy::Keep => surrogate,
+            LoneSurrogatePolicy::Replace => 0xFFFD,
+        }
+    }
+}
+
+/// Encode text given as UTF-16, such as a string from a Windows API or JavaScript, used as regular HTML text, handling lone surrogates according to the given policy. Surrogate pairs are kept as they are.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `<` => `&lt;`
+/// * `>` => `&gt;`
+///
+/// ```
+/// use html_escape::LoneSurrogatePolicy;
+///
+/// let text: Vec<u16> = "<\u{1F600}>".encode_utf16().collect();
+///
+/// assert_eq!(
+///     "&lt;\u{1F600}&gt;".encode_utf16().collect::<Vec<u16>>(),
+///     html_escape::encode_text_utf16(&text, LoneSurrogatePolicy::Keep)
+/// );
+/// ```
+#[inline]
+pub fn encode_text_utf16(text: &[u16], policy: LoneSurrogatePolicy) -> Vec<u16> {
+    let mut v = Vec::with_capacity(text.len());
+
+    encode_text_utf16_to_vec(text, policy, &mut v);
+
+    v
+}
+
+/// Write text given as UTF-16 used as regular HTML text to a mutable `Vec<u16>` reference and return the encoded data slice, handling lone surrogates according to the given policy.
+pub fn encode_text_utf16_to_vec<'a>(
+    text: &[u16],
+    policy: LoneSurrogatePolicy,
+    output: &'a mut Vec<u16>,
+) -> &'a [u16] {
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    for c in decode_utf16(text.iter().copied()) {
+        match c {
+            Ok('&') => output.extend("&amp;".encode_utf16()),
+            Ok('<') => output.extend("&lt;".encode_utf16()),
+            Ok('>') => output.extend("&gt;".encode_utf16()),
+            Ok(c) => output.extend_from_slice(c.encode_utf16(&mut [0u16; 2])),
+            Err(error) => output.push(policy.handle(error.unpaired_surrogate())),
+        }
+    }
+
+    &output[current_length..]
+}
@@ -0,0 +1,159 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::buffer_too_small::SliceWriter;
+use crate::{BufferTooSmall, EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+escape_impl! {
+    escape_xml_text;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'>' => b"&gt;",
+}
+
+escape_impl! {
+    escape_xml_attribute;
+    b'&' => b"&amp;",
+    b'<' => b"&lt;",
+    b'>' => b"&gt;",
+    b'"' => b"&quot;",
+    b'\'' => b"&apos;",
+    b'\t' => b"&#x9;",
+    b'\n' => b"&#xA;",
+    b'\r' => b"&#xD;",
+}
+
+encode_impl! {
+    /// The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    escape_xml_text;
+    /// Encode text used as XML character data.
+    encode_xml_text;
+    /// Write text used as XML character data to a mutable `String` reference and return the encoded string slice.
+    encode_xml_text_to_string;
+    /// Write text used as XML character data to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_xml_text_to_vec;
+    /// Write text used as XML character data to a writer.
+    encode_xml_text_to_writer;
+    /// Write text used as XML character data to a `fmt::Write`.
+    encode_xml_text_to_fmt_writer;
+    /// Write text used as XML character data to a byte slice and return the length of the encoded data.
+    encode_xml_text_to_slice;
+    /// Write text used as XML character data to an `EncodeSink`.
+    encode_xml_text_to_sink;
+}
+
+encode_impl! {
+    /// The following characters are escaped (the whitespace characters are escaped to survive attribute-value normalization):
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&apos;`
+    /// * `\t` => `&#x9;`
+    /// * `\n` => `&#xA;`
+    /// * `\r` => `&#xD;`
+    escape_xml_attribute;
+    /// Encode text used in a quoted XML attribute.
+    encode_xml_attribute;
+    /// Write text used in a quoted XML attribute to a mutable `String` reference and return the encoded string slice.
+    encode_xml_attribute_to_string;
+    /// Write text used in a quoted XML attribute to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_xml_attribute_to_vec;
+    /// Write text used in a quoted XML attribute to a writer.
+    encode_xml_attribute_to_writer;
+    /// Write text used in a quoted XML attribute to a `fmt::Write`.
+    encode_xml_attribute_to_fmt_writer;
+    /// Write text used in a quoted XML attribute to a byte slice and return the length of the encoded data.
+    encode_xml_attribute_to_slice;
+    /// Write text used in a quoted XML attribute to an `EncodeSink`.
+    encode_xml_attribute_to_sink;
+}
+
+encode_impl! {
+    /// The markup is escaped once for the XML layer, so existing entities in the fragment are preserved through the XML parser (`&amp;` becomes `&amp;amp;`), which is what RSS `<description>` and Atom `type="html"` content expect. The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    escape_xml_text;
+    /// Encode an HTML fragment used as escaped HTML in an XML element.
+    encode_html_in_xml;
+    /// Write an HTML fragment used as escaped HTML in an XML element to a mutable `String` reference and return the encoded string slice.
+    encode_html_in_xml_to_string;
+    /// Write an HTML fragment used as escaped HTML in an XML element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_html_in_xml_to_vec;
+    /// Write an HTML fragment used as escaped HTML in an XML element to a writer.
+    encode_html_in_xml_to_writer;
+    /// Write an HTML fragment used as escaped HTML in an XML element to a `fmt::Write`.
+    encode_html_in_xml_to_fmt_writer;
+    /// Write an HTML fragment used as escaped HTML in an XML element to a byte slice and return the length of the encoded data.
+    encode_html_in_xml_to_slice;
+    /// Write an HTML fragment used as escaped HTML in an XML element to an `EncodeSink`.
+    encode_html_in_xml_to_sink;
+}
+
+encode_impl! {
+    /// Only the entities predefined by XML are used, so the result is valid both in inline `<svg>` and `<math>` elements of an HTML document and in standalone SVG and MathML files. The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    escape_xml_text;
+    /// Encode text used in an SVG or MathML element.
+    encode_foreign_text;
+    /// Write text used in an SVG or MathML element to a mutable `String` reference and return the encoded string slice.
+    encode_foreign_text_to_string;
+    /// Write text used in an SVG or MathML element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_foreign_text_to_vec;
+    /// Write text used in an SVG or MathML element to a writer.
+    encode_foreign_text_to_writer;
+    /// Write text used in an SVG or MathML element to a `fmt::Write`.
+    encode_foreign_text_to_fmt_writer;
+    /// Write text used in an SVG or MathML element to a byte slice and return the length of the encoded data.
+    encode_foreign_text_to_slice;
+    /// Write text used in an SVG or MathML element to an `EncodeSink`.
+    encode_foreign_text_to_sink;
+}
+
+encode_impl! {
+    /// Only the entities predefined by XML are used, so the result is valid both in inline `<svg>` and `<math>` elements of an HTML document and in standalone SVG and MathML files. The following characters are escaped:
+    ///
+    /// * `&` => `&amp;`
+    /// * `<` => `&lt;`
+    /// * `>` => `&gt;`
+    /// * `"` => `&quot;`
+    /// * `'` => `&apos;`
+    /// * `\t` => `&#x9;`
+    /// * `\n` => `&#xA;`
+    /// * `\r` => `&#xD;`
+    escape_xml_attribute;
+    /// Encode text used in a quoted attribute of an SVG or MathML element.
+    encode_foreign_attribute;
+    /// Write text used in a quoted attribute of an SVG or MathML element to a mutable `String` reference and return the encoded string slice.
+    encode_foreign_attribute_to_string;
+    /// Write text used in a quoted attribute of an SVG or MathML element to a mutable `Vec<u8>` reference and return the encoded data slice.
+    encode_foreign_attribute_to_vec;
+    /// Write text used in a quoted attribute of an SVG or MathML element to a writer.
+    encode_foreign_attribute_to_writer;
+    /// Write text used in a quoted attribute of an SVG or MathML element to a `fmt::Write`.
+    encode_foreign_attribute_to_fmt_writer;
+    /// Write text used in a quoted attribute of an SVG or MathML element to a byte slice and return the length of the encoded data.
+    encode_foreign_attribute_to_slice;
+    /// Write text used in a quoted attribute of an SVG or MathML element to an `EncodeSink`.
+    encode_foreign_attribute_to_sink;
+}
@@ -0,0 +1,175 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+#[inline]
+fn escape_comment(text_bytes: &[u8], p: usize, dash: &mut bool) -> Option<&'static [u8]> {
+    match text_bytes[p] {
+        b'&' => {
+            *dash = false;
+
+            Some(b"&amp;")
+        }
+        b'>' if p == 0 => {
+            *dash = false;
+
+            Some(b"&gt;")
+        }
+        b'-' => {
+            if *dash || p + 1 == text_bytes.len() || (p == 0 && text_bytes[1] == b'>') {
+                *dash = false;
+
+                Some(b"&#x2D;")
+            } else {
+                *dash = true;
+
+                None
+            }
+        }
+        _ => {
+            *dash = false;
+
+            None
+        }
+    }
+}
+
+/// Encode text used in a comment (`<!-- ... -->`).
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `>` at the beginning of the text => `&gt;`
+/// * `-` which would make a `--`, a leading `->` or a trailing `-` => `&#x2D;`
+pub fn encode_comment<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+    let mut dash = false;
+
+    loop {
+        if p == text_length {
+            return Cow::from(text);
+        }
+
+        if escape_comment(text_bytes, p, &mut dash).is_some() {
+            break;
+        }
+
+        p += 1;
+    }
+
+    let mut v = Vec::with_capacity(text_length + 5);
+
+    encode_comment_to_vec(text, &mut v);
+
+    Cow::from(unsafe { String::from_utf8_unchecked(v) })
+}
+
+/// Write text used in a comment (`<!-- ... -->`) to a mutable `String` reference and return the encoded string slice.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `>` at the beginning of the text => `&gt;`
+/// * `-` which would make a `--`, a leading `->` or a trailing `-` => `&#x2D;`
+#[inline]
+pub fn encode_comment_to_string<S: AsRef<str>>(text: S, output: &mut String) -> &str {
+    unsafe { from_utf8_unchecked(encode_comment_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Write text used in a comment (`<!-- ... -->`) to a mutable `Vec<u8>` reference and return the encoded data slice.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `>` at the beginning of the text => `&gt;`
+/// * `-` which would make a `--`, a leading `->` or a trailing `-` => `&#x2D;`
+#[inline]
+pub fn encode_comment_to_vec<S: AsRef<str>>(text: S, output: &mut Vec<u8>) -> &[u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    encode_comment_to_sink(text, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write text used in a comment (`<!-- ... -->`) to a writer.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `>` at the beginning of the text => `&gt;`
+/// * `-` which would make a `--`, a leading `->` or a trailing `-` => `&#x2D;`
+#[inline]
+pub fn encode_comment_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_comment_to_sink(text, &mut IoSink(output))
+}
+
+/// Write text used in a comment (`<!-- ... -->`) to a `fmt::Write`.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `>` at the beginning of the text => `&gt;`
+/// * `-` which would make a `--`, a leading `->` or a trailing `-` => `&#x2D;`
+#[inline]
+pub fn encode_comment_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_comment_to_sink(text, &mut FmtSink(output))
+}
+
+/// Write text used in a comment (`<!-- ... -->`) to an `EncodeSink`.
+///
+/// The following characters are escaped:
+///
+/// * `&` => `&amp;`
+/// * `>` at the beginning of the text => `&gt;`
+/// * `-` which would make a `--`, a leading `->` or a trailing `-` => `&#x2D;`
+pub fn encode_comment_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+    let mut start = 0;
+    let mut dash = false;
+
+    while p < text_length {
+        if let Some(escaped) = escape_comment(text_bytes, p, &mut dash) {
+            output.write_str(unsafe { text.get_unchecked(start..p) })?;
+            start = p + 1;
+            output.write_str(unsafe { from_utf8_unchecked(escaped) })?;
+        }
+
+        p += 1;
+    }
+
+    output.write_str(unsafe { text.get_unchecked(start..p) })
+}
@@ -0,0 +1,7 @@
+mod cdata;
+mod comment;
+mod processing_instruction;
+
+pub use cdata::*;
+pub use comment::*;
+pub use processing_instruction::*;
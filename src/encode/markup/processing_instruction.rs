@@ -0,0 +1,146 @@
+use core::fmt;
+use core::str::from_utf8_unchecked;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::{EncodeSink, FmtSink};
+
+#[cfg(feature = "std")]
+use crate::IoSink;
+
+#[inline]
+fn is_processing_instruction_end(text_bytes: &[u8], p: usize) -> bool {
+    text_bytes[p] == b'>' && p >= 1 && text_bytes[p - 1] == b'?'
+}
+
+/// Encode text used in a processing instruction (`<?target ... ?>`).
+///
+/// The following substring is escaped:
+///
+/// * `?>` => `?&gt;`
+pub fn encode_processing_instruction<S: ?Sized + AsRef<str>>(text: &S) -> Cow<'_, str> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+
+    loop {
+        if p == text_length {
+            return Cow::from(text);
+        }
+
+        if is_processing_instruction_end(text_bytes, p) {
+            break;
+        }
+
+        p += 1;
+    }
+
+    let mut v = Vec::with_capacity(text_length + 3);
+
+    v.extend_from_slice(&text_bytes[..p]);
+    v.extend_from_slice(b"&gt;");
+
+    encode_processing_instruction_to_vec(
+        unsafe { from_utf8_unchecked(&text_bytes[(p + 1)..]) },
+        &mut v,
+    );
+
+    Cow::from(unsafe { String::from_utf8_unchecked(v) })
+}
+
+/// Write text used in a processing instruction (`<?target ... ?>`) to a mutable `String` reference and return the encoded string slice.
+///
+/// The following substring is escaped:
+///
+/// * `?>` => `?&gt;`
+#[inline]
+pub fn encode_processing_instruction_to_string<S: AsRef<str>>(
+    text: S,
+    output: &mut String,
+) -> &str {
+    unsafe { from_utf8_unchecked(encode_processing_instruction_to_vec(text, output.as_mut_vec())) }
+}
+
+/// Write text used in a processing instruction (`<?target ... ?>`) to a mutable `Vec<u8>` reference and return the encoded data slice.
+///
+/// The following substring is escaped:
+///
+/// * `?>` => `?&gt;`
+#[inline]
+pub fn encode_processing_instruction_to_vec<S: AsRef<str>>(
+    text: S,
+    output: &mut Vec<u8>,
+) -> &[u8] {
+    let text = text.as_ref();
+
+    output.reserve(text.len());
+
+    let current_length = output.len();
+
+    encode_processing_instruction_to_sink(text, output).unwrap_or_else(|error| match error {});
+
+    &output[current_length..]
+}
+
+#[cfg(feature = "std")]
+/// Write text used in a processing instruction (`<?target ... ?>`) to a writer.
+///
+/// The following substring is escaped:
+///
+/// * `?>` => `?&gt;`
+#[inline]
+pub fn encode_processing_instruction_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), io::Error> {
+    encode_processing_instruction_to_sink(text, &mut IoSink(output))
+}
+
+/// Write text used in a processing instruction (`<?target ... ?>`) to a `fmt::Write`.
+///
+/// The following substring is escaped:
+///
+/// * `?>` => `?&gt;`
+#[inline]
+pub fn encode_processing_instruction_to_fmt_writer<S: AsRef<str>, W: fmt::Write>(
+    text: S,
+    output: &mut W,
+) -> Result<(), fmt::Error> {
+    encode_processing_instruction_to_sink(text, &mut FmtSink(output))
+}
+
+/// Write text used in a processing instruction (`<?target ... ?>`) to an `EncodeSink`.
+///
+/// The following substring is escaped:
+///
+/// * `?>` => `?&gt;`
+pub fn encode_processing_instruction_to_sink<S: AsRef<str>, W: EncodeSink>(
+    text: S,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let text = text.as_ref();
+    let text_bytes = text.as_bytes();
+    let text_length = text_bytes.len();
+
+    let mut p = 0;
+    let mut start = 0;
+
+    while p < text_length {
+        if is_processing_instruction_end(text_bytes, p) {
+            output.write_str(unsafe { text.get_unchecked(start..p) })?;
+            start = p + 1;
+            output.write_str("&gt;")?;
+        }
+
+        p += 1;
+    }
+
+    output.write_str(unsafe { text.get_unchecked(start..p) })
+}
@@ -1,5 +1,9 @@
+mod attribute_name;
 mod element;
 mod html_entity;
+mod markup;
 
+pub use attribute_name::*;
 pub use element::*;
 pub use html_entity::*;
+pub use markup::*;
@@ -0,0 +1,106 @@
+use core::convert::Infallible;
+use core::fmt;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::buffer_too_small::SliceWriter;
+use crate::BufferTooSmall;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An output target of the `*_to_sink` functions, which the `_to_string`, `_to_vec`, `_to_writer` and `_to_fmt_writer` functions are built on.
+///
+/// It is implemented for `String` and `Vec<u8>`, which cannot fail, and for `FmtSink` and `IoSink`, which wrap a `fmt::Write` and an `io::Write`. A custom sink, such as a ring buffer or a rope builder, can be used by implementing one of those two traits for it and wrapping it.
+///
+/// ```
+/// let mut s = String::new();
+///
+/// html_escape::encode_text_to_sink("a < b", &mut s).unwrap();
+/// html_escape::encode_text_to_sink(" & c", &mut html_escape::FmtSink(&mut s)).unwrap();
+///
+/// assert_eq!("a &lt; b &amp; c", s);
+/// ```
+pub trait EncodeSink: private::Sealed {
+    /// The error of writing to the sink.
+    type Error;
+
+    /// Append `s` to the sink.
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error>;
+}
+
+impl private::Sealed for String {}
+
+impl EncodeSink for String {
+    type Error = Infallible;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+        self.push_str(s);
+
+        Ok(())
+    }
+}
+
+impl private::Sealed for Vec<u8> {}
+
+impl EncodeSink for Vec<u8> {
+    type Error = Infallible;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+        self.extend_from_slice(s.as_bytes());
+
+        Ok(())
+    }
+}
+
+/// An `EncodeSink` which writes to a `fmt::Write`, such as a `Formatter`.
+#[derive(Debug)]
+pub struct FmtSink<W: fmt::Write>(pub W);
+
+impl<W: fmt::Write> private::Sealed for FmtSink<W> {}
+
+impl<W: fmt::Write> EncodeSink for FmtSink<W> {
+    type Error = fmt::Error;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        self.0.write_str(s)
+    }
+}
+
+/// An `EncodeSink` which writes to an `io::Write`.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct IoSink<W: io::Write>(pub W);
+
+#[cfg(feature = "std")]
+impl<W: io::Write> private::Sealed for IoSink<W> {}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> EncodeSink for IoSink<W> {
+    type Error = io::Error;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), io::Error> {
+        self.0.write_all(s.as_bytes())
+    }
+}
+
+impl private::Sealed for SliceWriter<'_> {}
+
+/// Only used by `SliceWriter::write_escape_with`, which reports the consumed input.
+impl EncodeSink for SliceWriter<'_> {
+    type Error = BufferTooSmall;
+
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), BufferTooSmall> {
+        self.write_escape(s.as_bytes(), 0)
+    }
+}
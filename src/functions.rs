@@ -1,11 +1,17 @@
+use core::str::{from_utf8, from_utf8_unchecked};
+
 use alloc::vec::Vec;
 
 #[cfg(feature = "std")]
-use std::io::{self, Write};
+use std::io::{self, BufRead, Write};
+
+#[cfg(feature = "std")]
+use crate::utf8_width;
+use crate::EncodeSink;
 
 #[inline]
 pub(crate) fn is_alphanumeric(e: u8) -> bool {
-    b'0' <= e && e <= b'9' || b'a' <= e && e <= b'z' || b'A' <= e && e <= b'Z'
+    e.is_ascii_alphanumeric()
 }
 
 #[inline]
@@ -41,10 +47,180 @@ pub(crate) fn write_hex_to_vec(e: u8, output: &mut Vec<u8>) {
     };
 }
 
+#[inline]
+pub(crate) fn write_hex_digits_to_vec(e: u8, output: &mut Vec<u8>) {
+    let he = e >> 4;
+    let le = e & 0xF;
+
+    output.push(if he >= 10 {
+        b'A' - 10 + he
+    } else {
+        b'0' + he
+    });
+
+    output.push(if le >= 10 {
+        b'A' - 10 + le
+    } else {
+        b'0' + le
+    });
+}
+
+/// The two uppercase hexadecimal digits of `e`.
+#[inline]
+fn hex_digits(e: u8) -> [u8; 2] {
+    let hex = |d: u8| {
+        if d >= 10 {
+            b'A' - 10 + d
+        } else {
+            b'0' + d
+        }
+    };
+
+    [hex(e >> 4), hex(e & 0xF)]
+}
+
+#[inline]
+pub(crate) fn write_js_hex_to_sink<W: EncodeSink>(e: u8, output: &mut W) -> Result<(), W::Error> {
+    let [he, le] = hex_digits(e);
+
+    output.write_str(unsafe { from_utf8_unchecked(&[b'\\', b'x', he, le]) })
+}
+
+#[inline]
+pub(crate) fn write_css_hex_to_vec(e: u8, output: &mut Vec<u8>) {
+    output.push(b'\\');
+
+    write_hex_digits_to_vec(e, output);
+
+    output.push(b' ');
+}
+
+#[inline]
+pub(crate) fn write_css_hex_to_sink<W: EncodeSink>(e: u8, output: &mut W) -> Result<(), W::Error> {
+    let [he, le] = hex_digits(e);
+
+    output.write_str(unsafe { from_utf8_unchecked(&[b'\\', he, le, b' ']) })
+}
+
+#[inline]
+pub(crate) fn write_percent_to_vec(e: u8, output: &mut Vec<u8>) {
+    output.push(b'%');
+
+    write_hex_digits_to_vec(e, output);
+}
+
+#[inline]
+pub(crate) fn write_percent_to_sink<W: EncodeSink>(e: u8, output: &mut W) -> Result<(), W::Error> {
+    let [he, le] = hex_digits(e);
+
+    output.write_str(unsafe { from_utf8_unchecked(&[b'%', he, le]) })
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn invalid_utf8() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+}
+
+/// The beginning of a character which is split across writes or reads of a byte stream.
 #[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct PendingChar {
+    bytes: [u8; 4],
+    length: usize,
+}
+
+#[cfg(feature = "std")]
+impl PendingChar {
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Pass the complete characters of the pending bytes followed by `buf` to `f`, keeping an incomplete character at the end. Fail with an `io::Error` of the kind `InvalidData` on invalid UTF-8, after the text before it is passed.
+    pub(crate) fn feed<F: FnMut(&str) -> Result<(), io::Error>>(
+        &mut self,
+        mut buf: &[u8],
+        mut f: F,
+    ) -> Result<(), io::Error> {
+        if self.length > 0 {
+            let width = utf8_width::get_width(self.bytes[0]);
+            let needed = (width - self.length).min(buf.len());
+
+            self.bytes[self.length..(self.length + needed)].copy_from_slice(&buf[..needed]);
+            self.length += needed;
+            buf = &buf[needed..];
+
+            let length = self.length;
+
+            match from_utf8(&self.bytes[..length]) {
+                Ok(c) => {
+                    self.length = 0;
+
+                    f(c)?;
+                }
+                Err(error) if error.error_len().is_none() => return Ok(()),
+                Err(_) => {
+                    self.length = 0;
+
+                    return Err(invalid_utf8());
+                }
+            }
+        }
+
+        match from_utf8(buf) {
+            Ok(text) => f(text),
+            Err(error) => {
+                let valid_up_to = error.valid_up_to();
+
+                f(unsafe { from_utf8_unchecked(&buf[..valid_up_to]) })?;
+
+                if error.error_len().is_some() {
+                    return Err(invalid_utf8());
+                }
+
+                let tail = &buf[valid_up_to..];
+
+                self.bytes[..tail.len()].copy_from_slice(tail);
+                self.length = tail.len();
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn write_hex_to_sink<W: EncodeSink>(e: u8, output: &mut W) -> Result<(), W::Error> {
+    let [he, le] = hex_digits(e);
+
+    output.write_str(unsafe { from_utf8_unchecked(&[b'&', b'#', b'x', he, le, b';']) })
+}
+
 #[inline]
-pub(crate) fn write_hex_to_writer<W: Write>(e: u8, output: &mut W) -> Result<(), io::Error> {
-    output.write_fmt(format_args!("&#x{:02X};", e))
+pub(crate) fn write_char_hex_to_sink<W: EncodeSink>(
+    c: char,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let number = c as u32;
+    let digits = (32 - number.leading_zeros()).div_ceil(4) as usize;
+
+    // `&#x`, at most 6 digits and `;`
+    let mut buffer = *b"&#x000000;";
+
+    for (i, d) in buffer[3..(3 + digits)].iter_mut().rev().enumerate() {
+        let n = ((number >> (i * 4)) & 0xF) as u8;
+
+        *d = if n >= 10 {
+            b'A' - 10 + n
+        } else {
+            b'0' + n
+        };
+    }
+
+    buffer[3 + digits] = b';';
+
+    output.write_str(unsafe { from_utf8_unchecked(&buffer[..(4 + digits)]) })
 }
 
 #[inline]
@@ -58,21 +234,40 @@ pub(crate) fn write_html_entity_to_vec(e: u8, output: &mut Vec<u8>) {
     }
 }
 
-#[cfg(feature = "std")]
+/// The escape sequence `write_html_entity_to_vec` writes, in `buffer` if it is a hexadecimal one.
 #[inline]
-pub(crate) fn write_html_entity_to_writer<W: Write>(
-    e: u8,
-    output: &mut W,
-) -> Result<(), io::Error> {
+pub(crate) fn html_entity_to_bytes(e: u8, buffer: &mut [u8; 6]) -> &[u8] {
     match e {
-        b'&' => output.write_all(b"&amp;"),
-        b'<' => output.write_all(b"&lt;"),
-        b'>' => output.write_all(b"&gt;"),
-        b'"' => output.write_all(b"&quot;"),
-        _ => write_hex_to_writer(e, output),
+        b'&' => b"&amp;",
+        b'<' => b"&lt;",
+        b'>' => b"&gt;",
+        b'"' => b"&quot;",
+        _ => {
+            let hex = |d: u8| {
+                if d >= 10 {
+                    b'A' - 10 + d
+                } else {
+                    b'0' + d
+                }
+            };
+
+            *buffer = [b'&', b'#', b'x', hex(e >> 4), hex(e & 0xF), b';'];
+
+            buffer
+        }
     }
 }
 
+#[inline]
+pub(crate) fn write_html_entity_to_sink<W: EncodeSink>(
+    e: u8,
+    output: &mut W,
+) -> Result<(), W::Error> {
+    let mut buffer = [0u8; 6];
+
+    output.write_str(unsafe { from_utf8_unchecked(html_entity_to_bytes(e, &mut buffer)) })
+}
+
 #[inline]
 pub(crate) fn write_char_to_vec(c: char, output: &mut Vec<u8>) {
     let width = c.len_utf8();
@@ -96,3 +291,132 @@ pub(crate) fn write_char_to_writer<W: Write>(c: char, output: &mut W) -> Result<
 
     output.write_all(&buffer[..length])
 }
+
+/// Whether `text_bytes` starts with the tag name `name` (ASCII case-insensitive) and the tag name ends there.
+#[inline]
+pub(crate) fn is_tag_name_at(text_bytes: &[u8], name: &[u8]) -> bool {
+    let name_length = name.len();
+
+    text_bytes.len() >= name_length
+        && text_bytes[..name_length].eq_ignore_ascii_case(name)
+        && matches!(
+            text_bytes.get(name_length).copied(),
+            None | Some(b'\t') | Some(b'\n') | Some(b'\x0C') | Some(b'\r') | Some(b' ') | Some(b'/')
+                | Some(b'>')
+        )
+}
+
+/// Whether `text_bytes[p..]` starts with a named or numeric character reference which can be decoded by `decode_html_entities`.
+#[inline]
+pub(crate) fn is_character_reference_at(text_bytes: &[u8], p: usize) -> bool {
+    let text_bytes = &text_bytes[p..];
+
+    if text_bytes.first() != Some(&b'&') {
+        return false;
+    }
+
+    // the longest name, `CounterClockwiseContourIntegral`, has 31 characters
+    let end = match text_bytes.iter().take(34).position(|&e| e == b';') {
+        Some(end) => end,
+        None => return false,
+    };
+
+    let body = &text_bytes[1..end];
+
+    match body {
+        [b'#', b'x', hex @ ..] | [b'#', b'X', hex @ ..] => {
+            !hex.is_empty()
+                && hex.len() <= 8
+                && hex.iter().all(u8::is_ascii_hexdigit)
+                && u32::from_str_radix(unsafe { core::str::from_utf8_unchecked(hex) }, 16)
+                    .ok()
+                    .and_then(core::char::from_u32)
+                    .is_some()
+        }
+        [b'#', digits @ ..] => {
+            !digits.is_empty()
+                && digits.len() <= 10
+                && digits.iter().all(u8::is_ascii_digit)
+                && unsafe { core::str::from_utf8_unchecked(digits) }
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(core::char::from_u32)
+                    .is_some()
+        }
+        _ => {
+            body.iter().all(u8::is_ascii_alphanumeric)
+                && crate::NAMED_ENTITIES.binary_search_by(|(t_name, _)| t_name.cmp(&body)).is_ok()
+        }
+    }
+}
+
+/// Write everything read from `reader` to `writer` through the buffer of `reader`, retrying reads which are interrupted, and return the number of bytes read.
+#[cfg(feature = "std")]
+pub(crate) fn copy_buffered<R: BufRead, W: Write>(
+    mut reader: R,
+    writer: &mut W,
+) -> Result<u64, io::Error> {
+    let mut length = 0;
+
+    loop {
+        let buffer = match reader.fill_buf() {
+            Ok(buffer) => buffer,
+            Err(ref error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+
+        if buffer.is_empty() {
+            return Ok(length);
+        }
+
+        let buffer_length = buffer.len();
+
+        writer.write_all(buffer)?;
+        reader.consume(buffer_length);
+
+        length += buffer_length as u64;
+    }
+}
+
+/// Call `f` with each valid UTF-8 segment of `text_bytes` and whether the segment is followed by an invalid sequence.
+#[inline]
+pub(crate) fn for_each_utf8_segment<E>(
+    mut text_bytes: &[u8],
+    mut f: impl FnMut(&[u8], bool) -> Result<(), E>,
+) -> Result<(), E> {
+    loop {
+        match from_utf8(text_bytes) {
+            Ok(_) => return f(text_bytes, false),
+            Err(error) => {
+                let valid_up_to = error.valid_up_to();
+
+                f(&text_bytes[..valid_up_to], true)?;
+
+                match error.error_len() {
+                    Some(length) => text_bytes = &text_bytes[(valid_up_to + length)..],
+                    None => return Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Whether `e` is a C0 control character which is not ASCII whitespace.
+#[inline]
+pub(crate) fn is_invalid_control(e: u8) -> bool {
+    e < 0x20 && !matches!(e, b'\t' | b'\n' | b'\x0C' | b'\r')
+}
+
+/// The JavaScript escape of U+2028 LINE SEPARATOR or U+2029 PARAGRAPH SEPARATOR if `text_bytes[p..]` starts with either of them.
+#[inline]
+pub(crate) fn escape_line_separator(text_bytes: &[u8], p: usize) -> Option<&'static [u8]> {
+    if text_bytes[p] == 0xE2 && text_bytes.get(p + 1) == Some(&0x80) {
+        match text_bytes.get(p + 2) {
+            Some(0xA8) => Some(b"\\u2028"),
+            Some(0xA9) => Some(b"\\u2029"),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
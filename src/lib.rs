@@ -17,7 +17,7 @@ extern crate html_escape;
 assert_eq!("a &gt; b &amp;&amp; a &lt; c", html_escape::encode_text("a > b && a < c"));
 ```
 
-The functions suffixed with `_to_writer`, `_to_vec` or `_to_string` are useful to generate HTML.
+The functions suffixed with `_to_writer`, `_to_fmt_writer`, `_to_vec` or `_to_string` are useful to generate HTML. The `_to_fmt_writer` ones write to a `core::fmt::Write`, such as a `Formatter`, and are also available without std. Some encoders and decoders also have a `_to_slice` variant which writes to a caller-provided buffer without allocating and returns a `BufferTooSmall` error telling how much input is consumed if the buffer is full. These are the text and attribute encoders which escape each character on its own, such as `encode_text`, `encode_safe`, `encode_unquoted_attribute` and their `_minimal`, `_legacy`, `_apostrophe`, `_ascii_only`, `_named_entities`, `_email`, `_with_control_policy` and `_preserving_entities` forms, the XML, `srcset` and `srcdoc` encoders, and `decode_html_entities`, `decode_html_entities_legacy` and `decode_html_entities_in_attribute`. The `<script>`, `<style>`, RCDATA, comment and CDATA encoders have none, because how they escape a character depends on the text before it, so the rest of the input could not be continued with another buffer. Every encoder of `str` text, and the main decoders such as `decode_html_entities`, also have a `_to_sink` variant which is generic over `EncodeSink`, implemented by `String`, `Vec<u8>`, `FmtSink` and `IoSink`, so one function can write to any of them. Their `_to_vec`, `_to_writer` and `_to_fmt_writer` variants are built on it.
 
 ```rust
 extern crate html_escape;
@@ -57,6 +57,56 @@ version = "*"
 default-features = false
 ```
 
+## HTML 4.01 Entities
+
+Enable the `html4-entities` feature to decode with the 252 named entities of HTML 4.01 instead of all the HTML5 ones, which makes the binary smaller.
+
+```toml
+[dependencies.html-escape]
+version = "*"
+features = ["html4-entities"]
+```
+
+## NFC Normalization
+
+Enable the `unicode-normalization` feature to be able to normalize the decoded text to NFC with `DecodeOptions::normalize_nfc`, so that `e&#x301;` is decoded to `é`.
+
+```toml
+[dependencies.html-escape]
+version = "*"
+features = ["unicode-normalization"]
+```
+
+## Tokio
+
+Enable the `tokio` feature to write to a `tokio::io::AsyncWrite` with the functions suffixed with `_to_tokio_writer`, such as `encode_text_to_tokio_writer`, or through an `AsyncEscapingWriter`. Every `_to_writer` function of `str` text has one, except those which also return statistics, offsets or an audit, or take a callback, such as `decode_html_entities_with_stats` and `splice_decoded_region`. The functions of bytes, OS strings and paths, such as `encode_text_lossy`, `encode_path` and `decode_html_entities_bytes`, have none either; write their `_to_vec` output with `write_all` instead, or use an `AsyncEscapingWriter`.
+
+```toml
+[dependencies.html-escape]
+version = "*"
+features = ["tokio"]
+```
+
+## futures-io
+
+Enable the `futures-io` feature to write to a `futures_io::AsyncWrite`, which async-std and smol use, with the functions suffixed with `_to_futures_writer`, such as `encode_text_to_futures_writer`, or through an `AsyncEscapingWriter`. It does not depend on any runtime. Every `_to_writer` function of `str` text has one, except those which also return statistics, offsets or an audit, or take a callback, such as `decode_html_entities_with_stats` and `splice_decoded_region`. The functions of bytes, OS strings and paths, such as `encode_text_lossy`, `encode_path` and `decode_html_entities_bytes`, have none either; write their `_to_vec` output with `write_all` instead, or use an `AsyncEscapingWriter`.
+
+```toml
+[dependencies.html-escape]
+version = "*"
+features = ["futures-io"]
+```
+
+## Streams
+
+Enable the `futures-core` feature to escape or decode the chunks of a `futures_core::Stream`, such as the events of a server-sent events handler, with an `EscapeStream`, a `DecodeStream` or a `TryDecodeStream`, which checks the strictness and the output limit of its `DecodeOptions`. It does not need std.
+
+```toml
+[dependencies.html-escape]
+version = "*"
+features = ["futures-core"]
+```
+
 ## Benchmark
 
 ```bash
@@ -70,9 +120,23 @@ extern crate alloc;
 
 extern crate utf8_width;
 
+mod bom_policy;
+mod buffer_too_small;
 mod decode;
 mod encode;
+mod encode_sink;
 mod functions;
+#[cfg(feature = "futures-io")]
+mod futures_writer;
+#[cfg(feature = "tokio")]
+mod tokio_writer;
 
+pub use bom_policy::*;
+pub use buffer_too_small::*;
 pub use decode::*;
 pub use encode::*;
+pub use encode_sink::*;
+#[cfg(feature = "futures-io")]
+pub use futures_writer::*;
+#[cfg(feature = "tokio")]
+pub use tokio_writer::*;
@@ -0,0 +1,332 @@
+use std::io;
+
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::*;
+
+macro_rules! to_tokio_writer_impl {
+    ($($(#[$attr: meta])* $function: ident$(($($arg: ident: $arg_ty: ty),+))? => $name: ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[inline]
+            pub async fn $name<S: AsRef<str>, W: ?Sized + AsyncWrite + Unpin>(
+                text: S,
+                $($($arg: $arg_ty,)+)?
+                output: &mut W,
+            ) -> Result<(), io::Error> {
+                output.write_all($function(text.as_ref() $($(, $arg)+)?).as_bytes()).await
+            }
+        )*
+    };
+}
+
+macro_rules! try_to_tokio_writer_impl {
+    ($($(#[$attr: meta])* $function: ident$(($($arg: ident: $arg_ty: ty),+))? => $name: ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[inline]
+            pub async fn $name<S: AsRef<str>, W: ?Sized + AsyncWrite + Unpin>(
+                text: S,
+                $($($arg: $arg_ty,)+)?
+                output: &mut W,
+            ) -> Result<(), io::Error> {
+                let decoded = $function(text.as_ref() $($(, $arg)+)?)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+                output.write_all(decoded.as_bytes()).await
+            }
+        )*
+    };
+}
+
+to_tokio_writer_impl! {
+    /// Write text used as regular HTML text to a `tokio::io::AsyncWrite`.
+    encode_text => encode_text_to_tokio_writer;
+
+    /// Write text used as regular HTML text with the smallest escape set the HTML specification allows for text nodes to a `tokio::io::AsyncWrite`.
+    encode_text_minimal => encode_text_minimal_to_tokio_writer;
+
+    /// Write text used in a double-quoted attribute to a `tokio::io::AsyncWrite`.
+    encode_double_quoted_attribute => encode_double_quoted_attribute_to_tokio_writer;
+
+    /// Write text used in a single-quoted attribute to a `tokio::io::AsyncWrite`.
+    encode_single_quoted_attribute => encode_single_quoted_attribute_to_tokio_writer;
+
+    /// Write text used in a quoted attribute to a `tokio::io::AsyncWrite`.
+    encode_quoted_attribute => encode_quoted_attribute_to_tokio_writer;
+
+    /// Write text used in an unquoted attribute to a `tokio::io::AsyncWrite`.
+    encode_unquoted_attribute => encode_unquoted_attribute_to_tokio_writer;
+
+    /// Encode text to prevent special characters functioning and write it to a `tokio::io::AsyncWrite`.
+    encode_safe => encode_safe_to_tokio_writer;
+
+    /// Write text used in the `<script>` element to a `tokio::io::AsyncWrite`.
+    encode_script => encode_script_to_tokio_writer;
+
+    /// Write text used in the `<style>` element to a `tokio::io::AsyncWrite`.
+    encode_style => encode_style_to_tokio_writer;
+
+    /// Write text used in a double quoted text in the `<script>` element to a `tokio::io::AsyncWrite`.
+    encode_script_double_quoted_text => encode_script_double_quoted_text_to_tokio_writer;
+
+    /// Write text used in a single quoted text in the `<script>` element to a `tokio::io::AsyncWrite`.
+    encode_script_single_quoted_text => encode_script_single_quoted_text_to_tokio_writer;
+
+    /// Write text used in a quoted text in the `<script>` element to a `tokio::io::AsyncWrite`.
+    encode_script_quoted_text => encode_script_quoted_text_to_tokio_writer;
+
+    /// Write text used in a template literal in the `<script>` element to a `tokio::io::AsyncWrite`.
+    encode_script_backquoted_text => encode_script_backquoted_text_to_tokio_writer;
+
+    /// Write text used in a double quoted text in the `<style>` element to a `tokio::io::AsyncWrite`.
+    encode_style_double_quoted_text => encode_style_double_quoted_text_to_tokio_writer;
+
+    /// Write text used in a single quoted text in the `<style>` element to a `tokio::io::AsyncWrite`.
+    encode_style_single_quoted_text => encode_style_single_quoted_text_to_tokio_writer;
+
+    /// Write text used in a quoted text in the `<style>` element to a `tokio::io::AsyncWrite`.
+    encode_style_quoted_text => encode_style_quoted_text_to_tokio_writer;
+
+    /// Decode html entities in a given string to a `tokio::io::AsyncWrite`.
+    decode_html_entities => decode_html_entities_to_tokio_writer;
+
+    /// Decode html entities in a given string to a `tokio::io::AsyncWrite` like browsers do in an attribute value, also decoding the legacy named entities such as `&amp` and numeric references without the trailing semicolon.
+    decode_html_entities_in_attribute => decode_html_entities_in_attribute_to_tokio_writer;
+
+    /// Decode html entities in a given string like browsers do in text content to a `tokio::io::AsyncWrite`, also decoding the legacy named entities such as `&amp`, `&lt` and `&copy` and numeric references without the trailing semicolon.
+    decode_html_entities_legacy => decode_html_entities_legacy_to_tokio_writer;
+
+    /// Write text from the `<script>` element to a `tokio::io::AsyncWrite`.
+    decode_script => decode_script_to_tokio_writer;
+
+    /// Write text from the `<style>` element to a `tokio::io::AsyncWrite`.
+    decode_style => decode_style_to_tokio_writer;
+
+    /// Write text from a double quoted text in the `<script>` element to a `tokio::io::AsyncWrite`.
+    decode_script_double_quoted_text => decode_script_double_quoted_text_to_tokio_writer;
+
+    /// Write text from a single quoted text in the `<script>` element to a `tokio::io::AsyncWrite`.
+    decode_script_single_quoted_text => decode_script_single_quoted_text_to_tokio_writer;
+
+    /// Write text from a quoted text in the `<script>` element to a `tokio::io::AsyncWrite`.
+    decode_script_quoted_text => decode_script_quoted_text_to_tokio_writer;
+
+    /// Write text from a template literal in the `<script>` element to a `tokio::io::AsyncWrite`.
+    decode_script_backquoted_text => decode_script_backquoted_text_to_tokio_writer;
+
+    /// Write text from a double quoted text in the `<style>` element to a `tokio::io::AsyncWrite`.
+    decode_style_double_quoted_text => decode_style_double_quoted_text_to_tokio_writer;
+
+    /// Write text from a single quoted text in the `<style>` element to a `tokio::io::AsyncWrite`.
+    decode_style_single_quoted_text => decode_style_single_quoted_text_to_tokio_writer;
+
+    /// Write text from a quoted text in the `<style>` element to a `tokio::io::AsyncWrite`.
+    decode_style_quoted_text => decode_style_quoted_text_to_tokio_writer;
+
+    /// Decode text which is encoded by `encode_comment` to a `tokio::io::AsyncWrite`.
+    decode_comment => decode_comment_to_tokio_writer;
+
+    /// Decode a URL copied from a `href` or `src` attribute, such as `?a=1&amp;b=2`, to a `tokio::io::AsyncWrite`. Character references are decoded like browsers do in an attribute value, so `?a=1&copy=2` stays as it is. Percent-escapes such as `%20` and `%26` are kept, since decoding them is up to the URL parser.
+    decode_href => decode_href_to_tokio_writer;
+
+    /// Decode html entities in a given string except those of `<`, `>`, `&`, `"` and `'`, such as `&lt;` and `&#38;`, to a `tokio::io::AsyncWrite`, so the result is readable but still safe to be embedded in html as text or in a quoted attribute value.
+    decode_html_entities_except_markup => decode_html_entities_except_markup_to_tokio_writer;
+
+    /// Decode only the named references in a given string to a `tokio::io::AsyncWrite`, keeping numeric references such as `&#x26;` as they are.
+    decode_named_entities => decode_named_entities_to_tokio_writer;
+
+    /// Decode only the numeric references in a given string to a `tokio::io::AsyncWrite`, keeping named references such as `&amp;` as they are.
+    decode_numeric_entities => decode_numeric_entities_to_tokio_writer;
+
+    /// Write text used in a CDATA section (`<![CDATA[ ... ]]>`) to a `tokio::io::AsyncWrite`.
+    encode_cdata => encode_cdata_to_tokio_writer;
+
+    /// Write text used in a comment (`<!-- ... -->`) to a `tokio::io::AsyncWrite`.
+    encode_comment => encode_comment_to_tokio_writer;
+
+    /// Write text used in a CSS string or value inside a `style` attribute to a `tokio::io::AsyncWrite`. The result is CSS-escaped and then safe for both single-quoted and double-quoted attributes.
+    encode_css_in_attribute => encode_css_in_attribute_to_tokio_writer;
+
+    /// Write a URL used in an unquoted CSS `url(...)` to a `tokio::io::AsyncWrite`. The result needs no further HTML escaping, so it can be used both in the `<style>` element and in a quoted `style` attribute.
+    encode_css_url => encode_css_url_to_tokio_writer;
+
+    /// Write text used in a double-quoted attribute to a `tokio::io::AsyncWrite`. The written data contains only ASCII characters.
+    encode_double_quoted_attribute_ascii_only => encode_double_quoted_attribute_ascii_only_to_tokio_writer;
+
+    /// Write text used in a double-quoted attribute to a `tokio::io::AsyncWrite`, also escaping backticks.
+    encode_double_quoted_attribute_legacy => encode_double_quoted_attribute_legacy_to_tokio_writer;
+
+    /// Write text used in a quoted attribute of an SVG or MathML element to a `tokio::io::AsyncWrite`.
+    encode_foreign_attribute => encode_foreign_attribute_to_tokio_writer;
+
+    /// Write text used in an SVG or MathML element to a `tokio::io::AsyncWrite`.
+    encode_foreign_text => encode_foreign_text_to_tokio_writer;
+
+    /// Write a URL used in a quoted `href` or `src` attribute to a `tokio::io::AsyncWrite`. Characters which are not allowed in URLs are percent-encoded first, and then the result is escaped for a quoted attribute.
+    encode_href => encode_href_to_tokio_writer;
+
+    /// Write an HTML fragment used as escaped HTML in an XML element to a `tokio::io::AsyncWrite`.
+    encode_html_in_xml => encode_html_in_xml_to_tokio_writer;
+
+    /// Write text used in a JavaScript string literal inside an event handler attribute to a `tokio::io::AsyncWrite`. The result is JavaScript-escaped and then safe for both single-quoted and double-quoted attributes.
+    encode_js_in_attribute => encode_js_in_attribute_to_tokio_writer;
+
+    /// Write JSON text used in the `<script>` element to a `tokio::io::AsyncWrite`. The JSON text itself must be valid.
+    encode_json_for_script => encode_json_for_script_to_tokio_writer;
+
+    /// Write text used in a processing instruction (`<?target ... ?>`) to a `tokio::io::AsyncWrite`.
+    encode_processing_instruction => encode_processing_instruction_to_tokio_writer;
+
+    /// Write text used in an attribute quoted by either `"` or `'` to a `tokio::io::AsyncWrite`.
+    encode_quoted_attribute_any => encode_quoted_attribute_any_to_tokio_writer;
+
+    /// Write text used in a quoted attribute to a `tokio::io::AsyncWrite`, escaping apostrophes as `&#39;`.
+    encode_quoted_attribute_apostrophe => encode_quoted_attribute_apostrophe_to_tokio_writer;
+
+    /// Write text used in a quoted attribute to a `tokio::io::AsyncWrite`. The written data contains only ASCII characters.
+    encode_quoted_attribute_ascii_only => encode_quoted_attribute_ascii_only_to_tokio_writer;
+
+    /// Write text used in a quoted attribute in an email to a `tokio::io::AsyncWrite`.
+    encode_quoted_attribute_email => encode_quoted_attribute_email_to_tokio_writer;
+
+    /// Write text used in a quoted attribute to a `tokio::io::AsyncWrite`, also escaping backticks.
+    encode_quoted_attribute_legacy => encode_quoted_attribute_legacy_to_tokio_writer;
+
+    /// Write text used in a quoted attribute to a `tokio::io::AsyncWrite`. Named entities are preferred for non-ASCII characters.
+    encode_quoted_attribute_named_entities => encode_quoted_attribute_named_entities_to_tokio_writer;
+
+    /// Write text used in an RCDATA element such as `<title>` and `<textarea>` to a `tokio::io::AsyncWrite`.
+    encode_rcdata => encode_rcdata_to_tokio_writer;
+
+    /// Encode text to prevent special characters functioning and write it to a `tokio::io::AsyncWrite`. Decimal references are used instead of hexadecimal ones.
+    encode_safe_decimal => encode_safe_decimal_to_tokio_writer;
+
+    /// Encode text to prevent special characters functioning and write it to a `tokio::io::AsyncWrite`, leaving existing character references untouched.
+    encode_safe_idempotent => encode_safe_idempotent_to_tokio_writer;
+
+    /// Write text used in the `<script>` element of a page which can be served as either HTML or XHTML to a `tokio::io::AsyncWrite`, wrapping it in `//<![CDATA[` and `//]]>`.
+    encode_script_cdata => encode_script_cdata_to_tokio_writer;
+
+    /// Write text used in a single-quoted attribute to a `tokio::io::AsyncWrite`. The written data contains only ASCII characters.
+    encode_single_quoted_attribute_ascii_only => encode_single_quoted_attribute_ascii_only_to_tokio_writer;
+
+    /// Write text used in a single-quoted attribute to a `tokio::io::AsyncWrite`, also escaping backticks.
+    encode_single_quoted_attribute_legacy => encode_single_quoted_attribute_legacy_to_tokio_writer;
+
+    /// Write an HTML fragment used in a quoted `srcdoc` attribute of an `<iframe>` element to a `tokio::io::AsyncWrite`.
+    encode_srcdoc => encode_srcdoc_to_tokio_writer;
+
+    /// Write plain text used as the document in a quoted `srcdoc` attribute of an `<iframe>` element to a `tokio::io::AsyncWrite`.
+    encode_srcdoc_text => encode_srcdoc_text_to_tokio_writer;
+
+    /// Write a URL used as an image candidate in a quoted `srcset` attribute to a `tokio::io::AsyncWrite`.
+    encode_srcset => encode_srcset_to_tokio_writer;
+
+    /// Write text used as regular HTML text to a `tokio::io::AsyncWrite`, also escaping apostrophes.
+    encode_text_apostrophe => encode_text_apostrophe_to_tokio_writer;
+
+    /// Write text used as regular HTML text to a `tokio::io::AsyncWrite`. The written data contains only ASCII characters.
+    encode_text_ascii_only => encode_text_ascii_only_to_tokio_writer;
+
+    /// Write text used as regular HTML text in an email to a `tokio::io::AsyncWrite`.
+    encode_text_email => encode_text_email_to_tokio_writer;
+
+    /// Write text used as regular HTML text to a `tokio::io::AsyncWrite`. Named entities are preferred for non-ASCII characters.
+    encode_text_named_entities => encode_text_named_entities_to_tokio_writer;
+
+    /// Write text used as regular HTML text or in a quoted attribute to a `tokio::io::AsyncWrite`, leaving existing character references untouched.
+    encode_text_preserving_entities => encode_text_preserving_entities_to_tokio_writer;
+
+    /// Write text used in an unquoted attribute to a `tokio::io::AsyncWrite`. Except for alphanumeric characters, escape all characters which are less than 128.
+    encode_unquoted_attribute_decimal => encode_unquoted_attribute_decimal_to_tokio_writer;
+
+    /// Write text used in an unquoted attribute with as few escapes as possible to a `tokio::io::AsyncWrite`.
+    encode_unquoted_attribute_minimal => encode_unquoted_attribute_minimal_to_tokio_writer;
+
+    /// Write text used in a quoted XML attribute to a `tokio::io::AsyncWrite`.
+    encode_xml_attribute => encode_xml_attribute_to_tokio_writer;
+
+    /// Write text used as XML character data to a `tokio::io::AsyncWrite`.
+    encode_xml_text => encode_xml_text_to_tokio_writer;
+
+    /// Write a string sanitized to be used as an attribute name to a `tokio::io::AsyncWrite`.
+    sanitize_attribute_name => sanitize_attribute_name_to_tokio_writer;
+
+    /// Write plain text, such as a comment or a log, to be displayed as regular HTML text with its line breaks and spaces to a `tokio::io::AsyncWrite`.
+    text_to_html => text_to_html_to_tokio_writer;
+
+    /// Decode html entities in a given string to a `tokio::io::AsyncWrite`, keeping the named entities in `names`, such as `["lt", "gt"]` for `&lt;` and `&gt;`, as they are. Any other reference which is decoded to the same text as one of them, such as `&#60;` or `&LT;` for `lt`, is kept as well.
+    decode_html_entities_except(names: &[&str]) => decode_html_entities_except_to_tokio_writer;
+
+    /// Decode only the named entities in `names`, such as `["amp", "quot"]` for `&amp;` and `&quot;`, in a given string to a `tokio::io::AsyncWrite`, keeping the other references, including numeric ones, as they are.
+    decode_html_entities_only(names: &[&str]) => decode_html_entities_only_to_tokio_writer;
+
+    /// Decode html entities in a given string again and again until nothing more is decoded, or at most `max_iterations` times, to a `tokio::io::AsyncWrite`.
+    decode_html_entities_repeatedly(max_iterations: usize) => decode_html_entities_repeatedly_to_tokio_writer;
+
+    /// Decode html entities in a given string to a `tokio::io::AsyncWrite`, removing a leading BOM according to the given policy.
+    decode_html_entities_with_bom_policy(policy: BomPolicy) => decode_html_entities_with_bom_policy_to_tokio_writer;
+
+    /// Write text used as regular HTML text to a `tokio::io::AsyncWrite`, handling invalid control characters according to the given policy.
+    encode_text_with_control_policy(policy: ControlCharacterPolicy) => encode_text_with_control_policy_to_tokio_writer;
+
+    /// Write text used in a double-quoted attribute to a `tokio::io::AsyncWrite`, handling invalid control characters according to the given policy.
+    encode_double_quoted_attribute_with_control_policy(policy: ControlCharacterPolicy) => encode_double_quoted_attribute_with_control_policy_to_tokio_writer;
+
+    /// Write text used in a single-quoted attribute to a `tokio::io::AsyncWrite`, handling invalid control characters according to the given policy.
+    encode_single_quoted_attribute_with_control_policy(policy: ControlCharacterPolicy) => encode_single_quoted_attribute_with_control_policy_to_tokio_writer;
+
+    /// Write text used in a quoted attribute to a `tokio::io::AsyncWrite`, handling invalid control characters according to the given policy.
+    encode_quoted_attribute_with_control_policy(policy: ControlCharacterPolicy) => encode_quoted_attribute_with_control_policy_to_tokio_writer;
+
+    /// Write text used as regular HTML text to a `tokio::io::AsyncWrite`, also escaping the given sets of non-ASCII characters.
+    encode_text_with_unicode_escapes(escapes: UnicodeEscapes) => encode_text_with_unicode_escapes_to_tokio_writer;
+
+    /// Write text used in a double-quoted attribute to a `tokio::io::AsyncWrite`, also escaping the given sets of non-ASCII characters.
+    encode_double_quoted_attribute_with_unicode_escapes(escapes: UnicodeEscapes) => encode_double_quoted_attribute_with_unicode_escapes_to_tokio_writer;
+
+    /// Write text used in a single-quoted attribute to a `tokio::io::AsyncWrite`, also escaping the given sets of non-ASCII characters.
+    encode_single_quoted_attribute_with_unicode_escapes(escapes: UnicodeEscapes) => encode_single_quoted_attribute_with_unicode_escapes_to_tokio_writer;
+
+    /// Write text used in a quoted attribute to a `tokio::io::AsyncWrite`, also escaping the given sets of non-ASCII characters.
+    encode_quoted_attribute_with_unicode_escapes(escapes: UnicodeEscapes) => encode_quoted_attribute_with_unicode_escapes_to_tokio_writer;
+
+    /// Write text used in an unquoted attribute to a `tokio::io::AsyncWrite`, also escaping the given sets of non-ASCII characters.
+    encode_unquoted_attribute_with_unicode_escapes(escapes: UnicodeEscapes) => encode_unquoted_attribute_with_unicode_escapes_to_tokio_writer;
+
+    /// Write text used in a quoted attribute with the given format of numeric references to a `tokio::io::AsyncWrite`. The written data contains only ASCII characters.
+    encode_quoted_attribute_ascii_only_with_format(format: NumericEntityFormat) => encode_quoted_attribute_ascii_only_with_format_to_tokio_writer;
+
+    /// Encode text to prevent special characters functioning with the given format of numeric references and write it to a `tokio::io::AsyncWrite`.
+    encode_safe_with_format(format: NumericEntityFormat) => encode_safe_with_format_to_tokio_writer;
+
+    /// Write text used as regular HTML text with the given format of numeric references to a `tokio::io::AsyncWrite`. The written data contains only ASCII characters.
+    encode_text_ascii_only_with_format(format: NumericEntityFormat) => encode_text_ascii_only_with_format_to_tokio_writer;
+
+    /// Write text used in an unquoted attribute with the given format of numeric references to a `tokio::io::AsyncWrite`.
+    encode_unquoted_attribute_with_format(format: NumericEntityFormat) => encode_unquoted_attribute_with_format_to_tokio_writer;
+
+    /// Write text used in a quoted attribute to a `tokio::io::AsyncWrite`, handling BOMs according to the given policy.
+    encode_quoted_attribute_with_bom_policy(policy: BomPolicy) => encode_quoted_attribute_with_bom_policy_to_tokio_writer;
+
+    /// Write text used as regular HTML text to a `tokio::io::AsyncWrite`, handling BOMs according to the given policy.
+    encode_text_with_bom_policy(policy: BomPolicy) => encode_text_with_bom_policy_to_tokio_writer;
+
+    /// Write text used in an RCDATA element such as `<title>` and `<textarea>` to a `tokio::io::AsyncWrite`, handling line endings according to the given policy.
+    encode_rcdata_with_line_endings(policy: LineEndingPolicy) => encode_rcdata_with_line_endings_to_tokio_writer;
+
+    /// Write text used as regular HTML text to a `tokio::io::AsyncWrite`, handling line endings according to the given policy.
+    encode_text_with_line_endings(policy: LineEndingPolicy) => encode_text_with_line_endings_to_tokio_writer;
+}
+
+try_to_tokio_writer_impl! {
+    /// Decode html entities in a given string to a `tokio::io::AsyncWrite`, failing on the first malformed character reference instead of keeping it as it is. The `DecodeError` is wrapped in an `io::Error` of the kind `InvalidData`, and nothing is written on failure.
+    try_decode_html_entities => try_decode_html_entities_to_tokio_writer;
+
+    /// Decode XML character data or an attribute value to a `tokio::io::AsyncWrite`, failing on the first malformed reference or HTML-only name such as `&nbsp;`. The `DecodeError` is wrapped in an `io::Error` of the kind `InvalidData`, and nothing is written on failure.
+    decode_xml_entities => decode_xml_entities_to_tokio_writer;
+
+    /// Decode html entities in a given string to a `tokio::io::AsyncWrite` according to the given options. In strict mode, with `ReplacementPolicy::Error` or if the output limit is exceeded, the `DecodeError` is wrapped in an `io::Error` of the kind `InvalidData`, and nothing is written on failure.
+    decode_html_entities_with(options: &DecodeOptions) => decode_html_entities_with_to_tokio_writer;
+}
@@ -1,6 +1,6 @@
 extern crate html_escape;
 
-const SCRIPT_CASES: [(&str, &str); 4] = [
+const SCRIPT_CASES: [(&str, &str); 13] = [
     ("", ""),
     ("哈囉，中文！", "哈囉，中文！"),
     (r"alert('<script><\/scrIpt >');", "alert('<script></scrIpt >');"),
@@ -8,6 +8,15 @@ const SCRIPT_CASES: [(&str, &str); 4] = [
         r"alert('<script><\/script>');alert('<script><\/script >');",
         "alert('<script></script>');alert('<script></script >');",
     ),
+    ("<\\/ScRiPt/><\\/script\t><\\/script\n>", "</ScRiPt/></script\t></script\n>"),
+    ("</scripts></script-x>", "</scripts></script-x>"),
+    (r"'<\!--<script><\/script>'", "'<!--<script></script>'"),
+    (r"<<\/script>", "<</script>"),
+    (r"</sc<\/script>", "</sc</script>"),
+    (r"</scrip<\/script >", "</scrip</script >"),
+    (r"<<\!--", "<<!--"),
+    (r"a<\/script", "a</script"),
+    (r"{{x}} <\/SCRIPT", "{{x}} </SCRIPT"),
 ];
 
 #[test]
@@ -60,7 +69,7 @@ fn decode_script_to_writer() {
     }
 }
 
-const SCRIPT_SINGLE_QUOTED_CASES: [(&str, &str); 8] = [
+const SCRIPT_SINGLE_QUOTED_CASES: [(&str, &str); 9] = [
     ("", ""),
     ("哈囉，中文！", "哈囉，中文！"),
     (r"alert(\'<script><\/scrIpt >\');", "alert('<script></scrIpt >');"),
@@ -72,6 +81,7 @@ const SCRIPT_SINGLE_QUOTED_CASES: [(&str, &str); 8] = [
     (r"\'<\'/script>", "'<'/script>"),
     (r"</scri\'pt>", "</scri'pt>"),
     (r"\'</scri\'pt>", "'</scri'pt>"),
+    (r"\'<\!--\'", "'<!--'"),
 ];
 
 #[test]
@@ -130,7 +140,7 @@ fn decode_script_single_quoted_text_to_writer() {
     }
 }
 
-const SCRIPT_QUOTED_CASES: [(&str, &str); 5] = [
+const SCRIPT_QUOTED_CASES: [(&str, &str); 7] = [
     ("", ""),
     ("哈囉，中文！", "哈囉，中文！"),
     (r"alert(\'<script><\/scrIpt >\');", "alert('<script></scrIpt >');"),
@@ -139,6 +149,8 @@ const SCRIPT_QUOTED_CASES: [(&str, &str); 5] = [
         "alert(\"<script></script>\");alert('<script></script >');",
     ),
     (r"<\/script>1\'2\'3", "</script>1'2'3"),
+    (r"\'<<\/script>\'", "'<</script>'"),
+    (r"</scrip<\/script >", "</scrip</script >"),
 ];
 
 #[test]
@@ -197,7 +209,136 @@ fn decode_script_quoted_text_to_writer() {
     }
 }
 
-const STYLE_CASES: [(&str, &str); 4] = [
+#[test]
+fn encode_script_quoted_text_line_separators() {
+    let text = "a\u{2028}b\u{2029}'<\u{2028}/script>";
+
+    assert_eq!(
+        r"a\u2028b\u2029\'<\u2028/script>",
+        html_escape::encode_script_single_quoted_text(text)
+    );
+    assert_eq!(
+        r"a\u2028b\u2029'<\u2028/script>",
+        html_escape::encode_script_double_quoted_text(text)
+    );
+    assert_eq!(r"a\u2028b\u2029\'<\u2028/script>", html_escape::encode_script_quoted_text(text));
+
+    let mut s = String::new();
+    html_escape::encode_script_quoted_text_to_string(text, &mut s);
+    assert_eq!(r"a\u2028b\u2029\'<\u2028/script>", s);
+
+    #[cfg(feature = "std")]
+    {
+        let mut v = Vec::new();
+        html_escape::encode_script_quoted_text_to_writer("\u{2029}<\u{2028}", &mut v).unwrap();
+        assert_eq!(br"\u2029<\u2028", v.as_slice());
+    }
+
+    assert_eq!("\u{2028}", html_escape::encode_script("\u{2028}"));
+    assert_eq!("\u{2028}", html_escape::encode_script_backquoted_text("\u{2028}"));
+}
+
+const SCRIPT_BACKQUOTED_CASES: [(&str, &str); 6] = [
+    ("", ""),
+    ("哈囉，中文！", "哈囉，中文！"),
+    (r"Hello \`\${name}\`!", "Hello `${name}`!"),
+    (r"'<\/script>' <\!-- \$1", "'</script>' <!-- $1"),
+    (r#""a" 'b' {c}"#, r#""a" 'b' {c}"#),
+    (r"\`</sc<\/script>\`", "`</sc</script>`"),
+];
+
+#[test]
+fn encode_script_backquoted_text() {
+    for (expect, text) in SCRIPT_BACKQUOTED_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_script_backquoted_text(text));
+    }
+}
+
+#[test]
+fn encode_script_backquoted_text_to_string() {
+    for (expect, text) in SCRIPT_BACKQUOTED_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_script_backquoted_text_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_script_backquoted_text_to_writer() {
+    for (expect, text) in SCRIPT_BACKQUOTED_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_script_backquoted_text_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_script_backquoted_text() {
+    for (text, expect) in SCRIPT_BACKQUOTED_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_script_backquoted_text(text));
+    }
+}
+
+#[test]
+fn decode_script_backquoted_text_to_string() {
+    for (text, expect) in SCRIPT_BACKQUOTED_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::decode_script_backquoted_text_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_script_backquoted_text_to_writer() {
+    for (text, expect) in SCRIPT_BACKQUOTED_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_script_backquoted_text_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const SCRIPT_CDATA_CASES: [(&str, &str); 4] = [
+    ("//<![CDATA[\n\n//]]>", ""),
+    ("//<![CDATA[\nif (a < b && c) {}\n//]]>", "if (a < b && c) {}"),
+    ("//<![CDATA[\nx = '<\\/SCRIPT>]]\\> <\\!--';\n//]]>", "x = '</SCRIPT>]]> <!--';"),
+    ("//<![CDATA[\n'<\\/script\n//]]>", "'</script"),
+];
+
+#[test]
+fn encode_script_cdata() {
+    for (expect, text) in SCRIPT_CDATA_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_script_cdata(text));
+    }
+}
+
+#[test]
+fn encode_script_cdata_to_string() {
+    for (expect, text) in SCRIPT_CDATA_CASES.iter().copied() {
+        let mut s = String::from("<script>");
+
+        assert_eq!(expect, html_escape::encode_script_cdata_to_string(text, &mut s));
+        assert_eq!(format!("<script>{}", expect), s);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_script_cdata_to_writer() {
+    for (expect, text) in SCRIPT_CDATA_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_script_cdata_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const STYLE_CASES: [(&str, &str); 10] = [
     ("", ""),
     ("哈囉，中文！", "哈囉，中文！"),
     (r"div::after { content: '<style><\/stYle >';}", "div::after { content: '<style></stYle >';}"),
@@ -205,6 +346,12 @@ const STYLE_CASES: [(&str, &str); 4] = [
         r"div::after { content: '<style><\/style>';} label::after { content: '<style><\/style >';}",
         "div::after { content: '<style></style>';} label::after { content: '<style></style >';}",
     ),
+    ("<\\/STYLE/><\\/style\t><\\/style\r>", "</STYLE/></style\t></style\r>"),
+    ("</styles></style-x>", "</styles></style-x>"),
+    (r"<<\/style>", "<</style>"),
+    (r"</s<\/style>", "</s</style>"),
+    (r"a<\/style", "a</style"),
+    (r"{{x}} <\/STYLE", "{{x}} </STYLE"),
 ];
 
 #[test]
@@ -327,7 +474,7 @@ fn decode_style_single_quoted_text_to_writer() {
     }
 }
 
-const STYLE_QUOTED_CASES: [(&str, &str); 5] = [
+const STYLE_QUOTED_CASES: [(&str, &str); 6] = [
     ("", ""),
     ("哈囉，中文！", "哈囉，中文！"),
     (r"div::after { content: \'<style><\/stYle >\';}", "div::after { content: '<style></stYle >';}"),
@@ -336,6 +483,7 @@ const STYLE_QUOTED_CASES: [(&str, &str); 5] = [
         "div::after { content: \"<style></style>\";} label::after { content: '<style></style >';}",
     ),
     (r"<\/style>1\'2\'3", "</style>1'2'3"),
+    (r"\'<<\/style>\'", "'<</style>'"),
 ];
 
 #[test]
@@ -393,3 +541,174 @@ fn decode_style_quoted_text_to_writer() {
         assert_eq!(expect.as_bytes(), v.as_slice());
     }
 }
+
+const RCDATA_CASES: [(&str, &str); 7] = [
+    ("", ""),
+    ("哈囉，中文！", "哈囉，中文！"),
+    ("<b>bread</b> &amp; butter", "<b>bread</b> & butter"),
+    ("&lt;/title>", "</title>"),
+    ("a &lt;/TextArea b", "a </TextArea b"),
+    ("&lt;/title", "</title"),
+    ("</titles></text>", "</titles></text>"),
+];
+
+#[test]
+fn encode_rcdata() {
+    for (expect, text) in RCDATA_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_rcdata(text));
+    }
+}
+
+#[test]
+fn encode_rcdata_to_string() {
+    for (expect, text) in RCDATA_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_rcdata_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_rcdata_to_writer() {
+    for (expect, text) in RCDATA_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_rcdata_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_rcdata() {
+    for (text, expect) in RCDATA_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+    }
+}
+
+const JSON_FOR_SCRIPT_CASES: [(&str, &str); 7] = [
+    ("", ""),
+    ("{\"a\":\"哈囉，中文！\"}", "{\"a\":\"哈囉，中文！\"}"),
+    (r#"{"a":"\u003C/script>"}"#, r#"{"a":"</script>"}"#),
+    (r#"{"a":"\u003C/ScRiPt x"}"#, r#"{"a":"</ScRiPt x"}"#),
+    (r#"{"a":"\u003C!-- <script>"}"#, r#"{"a":"<!-- <script>"}"#),
+    (r#"["\u2028","\u2029"]"#, "[\"\u{2028}\",\"\u{2029}\"]"),
+    (r#"{"a":"</scripts> 1 < 2"}"#, r#"{"a":"</scripts> 1 < 2"}"#),
+];
+
+#[test]
+fn encode_json_for_script() {
+    for (expect, text) in JSON_FOR_SCRIPT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_json_for_script(text));
+    }
+}
+
+#[test]
+fn encode_json_for_script_to_string() {
+    for (expect, text) in JSON_FOR_SCRIPT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_json_for_script_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_json_for_script_to_writer() {
+    for (expect, text) in JSON_FOR_SCRIPT_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_json_for_script_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn encode_rcdata_with_line_endings() {
+    let text = "a\r\nb\rc\n&</textarea>";
+
+    assert_eq!(
+        "a\r\nb\rc\n&amp;&lt;/textarea>",
+        html_escape::encode_rcdata_with_line_endings(text, html_escape::LineEndingPolicy::Keep)
+    );
+    assert_eq!(
+        "a\nb\nc\n&amp;&lt;/textarea>",
+        html_escape::encode_rcdata_with_line_endings(
+            text,
+            html_escape::LineEndingPolicy::Normalize
+        )
+    );
+    assert_eq!(
+        "a&#x0D;\nb&#x0D;c\n&amp;&lt;/textarea>",
+        html_escape::encode_rcdata_with_line_endings(text, html_escape::LineEndingPolicy::Escape)
+    );
+    assert_eq!(
+        "abc\n",
+        html_escape::encode_rcdata_with_line_endings(
+            "abc\n",
+            html_escape::LineEndingPolicy::Escape
+        )
+    );
+
+    assert_eq!(
+        "\nx\n",
+        html_escape::encode_rcdata_with_line_endings_to_string(
+            "\r\nx\r",
+            html_escape::LineEndingPolicy::Normalize,
+            &mut String::new()
+        )
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_rcdata_with_line_endings_to_writer() {
+    let mut v = Vec::new();
+    html_escape::encode_rcdata_with_line_endings_to_writer(
+        "\r\n</title>\r",
+        html_escape::LineEndingPolicy::Normalize,
+        &mut v,
+    )
+    .unwrap();
+
+    assert_eq!(b"\n&lt;/title>\n", v.as_slice());
+}
+
+#[test]
+fn decode_double_quoted_text() {
+    let text = r#"<\/script> \" <\/style>"#;
+
+    assert_eq!(r#"</script> " <\/style>"#, html_escape::decode_script_double_quoted_text(text));
+    assert_eq!(r#"<\/script> " </style>"#, html_escape::decode_style_double_quoted_text(text));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_double_quoted_text_to_writer() {
+    let text = r#"<\/script> \" <\/style>"#;
+
+    let mut v = Vec::new();
+    html_escape::decode_script_double_quoted_text_to_writer(text, &mut v).unwrap();
+    assert_eq!(br#"</script> " <\/style>"#, v.as_slice());
+
+    let mut v = Vec::new();
+    html_escape::decode_style_double_quoted_text_to_writer(text, &mut v).unwrap();
+    assert_eq!(br#"<\/script> " </style>"#, v.as_slice());
+}
+
+#[test]
+fn encode_to_sink() {
+    let text = "a</script></style></title><!-- \u{E9}\u{2028}'\"`\\";
+
+    let mut s = String::new();
+    html_escape::encode_script_to_sink(text, &mut s).unwrap();
+    assert_eq!(html_escape::encode_script(text), s);
+
+    let mut s = String::new();
+    html_escape::encode_script_double_quoted_text_to_sink(text, &mut s).unwrap();
+    assert_eq!(html_escape::encode_script_double_quoted_text(text), s);
+
+    let mut s = String::new();
+    html_escape::encode_style_single_quoted_text_to_sink(text, &mut s).unwrap();
+    assert_eq!(html_escape::encode_style_single_quoted_text(text), s);
+
+    let mut v = Vec::new();
+    html_escape::encode_rcdata_to_sink(text, &mut v).unwrap();
+    assert_eq!(html_escape::encode_rcdata(text).as_bytes(), v.as_slice());
+}
@@ -0,0 +1,178 @@
+#![cfg(feature = "futures-io")]
+
+extern crate html_escape;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+use futures_io::AsyncWrite;
+
+use html_escape::{AsyncEscapingWriter, DecodeOptions, EscapeContext, LineEndingPolicy};
+
+/// Writing to a `Vec<u8>` is always ready, so the futures complete without a runtime.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let mut cx = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+/// A writer which accepts one byte at a time and is pending on every other call.
+#[derive(Default)]
+struct SlowWriter {
+    written: Vec<u8>,
+    ready: bool,
+}
+
+impl AsyncWrite for SlowWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.ready = !self.ready;
+
+        if !self.ready || buf.is_empty() {
+            cx.waker().wake_by_ref();
+
+            return Poll::Pending;
+        }
+
+        self.written.push(buf[0]);
+
+        Poll::Ready(Ok(1))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+async fn write_all<W: AsyncWrite + Unpin>(output: &mut W, mut buf: &[u8]) -> std::io::Result<()> {
+    while !buf.is_empty() {
+        buf = &buf[std::future::poll_fn(|cx| Pin::new(&mut *output).poll_write(cx, buf)).await?..];
+    }
+
+    Ok(())
+}
+
+async fn close<W: AsyncWrite + Unpin>(output: &mut W) -> std::io::Result<()> {
+    std::future::poll_fn(|cx| Pin::new(&mut *output).poll_close(cx)).await
+}
+
+#[test]
+fn to_futures_writer() {
+    let mut v = Vec::new();
+
+    block_on(async {
+        html_escape::encode_text_to_futures_writer("<a> & ", &mut v).await.unwrap();
+        html_escape::encode_double_quoted_attribute_to_futures_writer("\"", &mut v).await.unwrap();
+        html_escape::decode_html_entities_to_futures_writer(" &lt;&#x1F600;", &mut v)
+            .await
+            .unwrap();
+    });
+
+    assert_eq!("&lt;a&gt; &amp; &quot; <\u{1F600}".as_bytes(), v.as_slice());
+}
+
+#[test]
+fn to_futures_writer_with_arguments() {
+    let mut v = Vec::new();
+
+    block_on(async {
+        html_escape::encode_href_to_futures_writer("/a b?c=\"", &mut v).await.unwrap();
+        html_escape::encode_text_with_line_endings_to_futures_writer(
+            "\r\n<",
+            LineEndingPolicy::Normalize,
+            &mut v,
+        )
+        .await
+        .unwrap();
+        html_escape::decode_html_entities_only_to_futures_writer("&amp;&lt;", &["amp"], &mut v)
+            .await
+            .unwrap();
+    });
+
+    assert_eq!(
+        format!(
+            "{}{}{}",
+            html_escape::encode_href("/a b?c=\""),
+            html_escape::encode_text_with_line_endings("\r\n<", LineEndingPolicy::Normalize),
+            html_escape::decode_html_entities_only("&amp;&lt;", &["amp"])
+        )
+        .as_bytes(),
+        v.as_slice()
+    );
+}
+
+#[test]
+fn try_decode_to_futures_writer() {
+    let mut v = Vec::new();
+
+    block_on(async {
+        html_escape::try_decode_html_entities_to_futures_writer("&lt;", &mut v).await.unwrap();
+
+        assert_eq!(
+            std::io::ErrorKind::InvalidData,
+            html_escape::try_decode_html_entities_to_futures_writer("a &lt", &mut v)
+                .await
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            std::io::ErrorKind::InvalidData,
+            html_escape::decode_html_entities_with_to_futures_writer(
+                "&amp;&amp;",
+                &DecodeOptions::new().max_output_length(1),
+                &mut v,
+            )
+            .await
+            .unwrap_err()
+            .kind()
+        );
+    });
+
+    assert_eq!(b"<", v.as_slice());
+}
+
+#[test]
+fn async_escaping_writer() {
+    let text = "a b\u{20AC}\u{1F600}=\"";
+
+    let mut writer =
+        AsyncEscapingWriter::new(SlowWriter::default(), EscapeContext::UnquotedAttribute);
+
+    block_on(async {
+        for byte in text.as_bytes() {
+            write_all(&mut writer, &[*byte]).await.unwrap();
+        }
+
+        close(&mut writer).await.unwrap();
+    });
+
+    assert_eq!(
+        html_escape::encode_unquoted_attribute(text).as_bytes(),
+        writer.get_ref().written.as_slice()
+    );
+
+    let mut writer = AsyncEscapingWriter::new(Vec::new(), EscapeContext::Text);
+
+    block_on(async {
+        write_all(&mut writer, b"<\xE2\x82").await.unwrap();
+
+        assert_eq!(std::io::ErrorKind::InvalidData, close(&mut writer).await.unwrap_err().kind());
+        assert_eq!(
+            std::io::ErrorKind::InvalidData,
+            write_all(&mut writer, b"a\xFFb").await.unwrap_err().kind()
+        );
+    });
+}
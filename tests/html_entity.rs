@@ -122,6 +122,69 @@ fn decode_safe_to_writer() {
     }
 }
 
+const SINGLE_QUOTED_ATTRIBUTE_CASES: [(&str, &str); 7] = [
+    ("", ""),
+    ("哈囉，中文！", "哈囉，中文！"),
+    ("麵包 &amp; butter", "麵包 & butter"),
+    ("\"bread\" &amp; 奶油", "\"bread\" & 奶油"),
+    ("&#x27;bread&#x27; &amp; 奶油", "'bread' & 奶油"),
+    ("&lt; less than &gt;", "< less than >"),
+    ("https://magiclen.org", "https://magiclen.org"),
+];
+
+#[test]
+fn encode_single_quoted_attribute() {
+    for (expect, text) in SINGLE_QUOTED_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_single_quoted_attribute(text));
+    }
+}
+
+#[test]
+fn encode_single_quoted_attribute_to_string() {
+    for (expect, text) in SINGLE_QUOTED_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_single_quoted_attribute_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_single_quoted_attribute_to_writer() {
+    for (expect, text) in SINGLE_QUOTED_ATTRIBUTE_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_single_quoted_attribute_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_single_quoted_attribute() {
+    for (text, expect) in SINGLE_QUOTED_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+    }
+}
+
+#[test]
+fn decode_single_quoted_attribute_to_string() {
+    for (text, expect) in SINGLE_QUOTED_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_single_quoted_attribute_to_writer() {
+    for (text, expect) in SINGLE_QUOTED_ATTRIBUTE_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_html_entities_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
 const UNQUOTED_ATTRIBUTE_CASES: [(&str, &str); 7] = [
     ("", ""),
     ("哈囉，中文！", "哈囉，中文！"),
@@ -185,32 +248,4176 @@ fn decode_unquoted_attribute_to_writer() {
     }
 }
 
-// TODO ----------
+const JS_IN_ATTRIBUTE_CASES: [(&str, &str); 6] = [
+    ("", ""),
+    ("哈囉，中文！", "哈囉，中文！"),
+    ("Hello, world.", "Hello, world."),
+    (r"It\x27s \x22quoted\x22 \x26 \x3Cb\x3E", "It's \"quoted\" & <b>"),
+    (r"a\x5Cb\x0Ac", "a\\b\nc"),
+    (r"a\u2028b\u2029", "a\u{2028}b\u{2029}"),
+];
 
-const BIG_NAMES_COMPATIBILITY_CASES: [(&str, &str); 4] =
-    [("&", "&AMP;"), ("<", "&LT;"), (">", "&GT;"), ("\"", "&QUOT;")];
+#[test]
+fn encode_js_in_attribute() {
+    for (expect, text) in JS_IN_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_js_in_attribute(text));
+    }
+}
 
 #[test]
-fn decode_html_entities() {
-    for (expect, text) in BIG_NAMES_COMPATIBILITY_CASES.iter().copied() {
+fn encode_js_in_attribute_to_string() {
+    for (expect, text) in JS_IN_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_js_in_attribute_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_js_in_attribute_to_writer() {
+    for (expect, text) in JS_IN_ATTRIBUTE_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_js_in_attribute_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const CSS_IN_ATTRIBUTE_CASES: [(&str, &str); 6] = [
+    ("", ""),
+    ("哈囉，中文！", "哈囉，中文！"),
+    ("Open Sans, sans-serif", "Open Sans, sans-serif"),
+    (r"x\27 \3B  color: red\3B \7D ", "x'; color: red;}"),
+    (r"\22 \3C \2F style\3E \22 ", "\"</style>\""),
+    (r"a\5C b\0A c\28 \29 ", "a\\b\nc()"),
+];
+
+#[test]
+fn encode_css_in_attribute() {
+    for (expect, text) in CSS_IN_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_css_in_attribute(text));
+    }
+}
+
+#[test]
+fn encode_css_in_attribute_to_string() {
+    for (expect, text) in CSS_IN_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_css_in_attribute_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_css_in_attribute_to_writer() {
+    for (expect, text) in CSS_IN_ATTRIBUTE_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_css_in_attribute_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const SRCSET_CASES: [(&str, &str); 5] = [
+    ("", ""),
+    ("https://magiclen.org/哈囉.png", "https://magiclen.org/哈囉.png"),
+    ("a.png%20999w%2Cevil.png", "a.png 999w,evil.png"),
+    ("a.png?x=1&amp;y=%222%22", "a.png?x=1&y=%222%22"),
+    ("&quot;&gt;&lt;img%09src=x&#x27;", "\"><img\tsrc=x'"),
+];
+
+#[test]
+fn encode_srcset() {
+    for (expect, text) in SRCSET_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_srcset(text));
+    }
+}
+
+#[test]
+fn encode_srcset_to_string() {
+    for (expect, text) in SRCSET_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_srcset_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_srcset_to_writer() {
+    for (expect, text) in SRCSET_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_srcset_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const HREF_CASES: [(&str, &str); 6] = [
+    ("", ""),
+    ("https://magiclen.org/?q=1&amp;r=2#top", "https://magiclen.org/?q=1&r=2#top"),
+    ("https://magiclen.org/%E5%93%88%E5%9B%89", "https://magiclen.org/哈囉"),
+    ("/a%20b/%22c%22%3Cd%3E%27e%27", "/a b/\"c\"<d>'e'"),
+    ("/already%20encoded", "/already%20encoded"),
+    ("/%0A%09%7Bx%7D%7C%5C", "/\n\t{x}|\\"),
+];
+
+#[test]
+fn encode_href() {
+    for (expect, text) in HREF_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_href(text));
+    }
+}
+
+#[test]
+fn encode_href_to_string() {
+    for (expect, text) in HREF_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_href_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_href_to_writer() {
+    for (expect, text) in HREF_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_href_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const TEXT_ASCII_ONLY_CASES: [(&str, &str); 5] = [
+    ("", ""),
+    ("&#x54C8;&#x56C9;&#xFF0C;&#x4E2D;&#x6587;&#xFF01;", "哈囉，中文！"),
+    ("&#x9EB5;&#x5305; &amp; butter", "麵包 & butter"),
+    ("&lt;p&gt;caf&#xE9; \"&#xA0;\"&lt;/p&gt;", "<p>café \"\u{00A0}\"</p>"),
+    ("&#x1F600;!", "\u{1F600}!"),
+];
+
+#[test]
+fn encode_text_ascii_only() {
+    for (expect, text) in TEXT_ASCII_ONLY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_text_ascii_only(text));
+    }
+}
+
+#[test]
+fn encode_text_ascii_only_to_string() {
+    for (expect, text) in TEXT_ASCII_ONLY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_text_ascii_only_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_text_ascii_only_to_writer() {
+    for (expect, text) in TEXT_ASCII_ONLY_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_text_ascii_only_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_text_ascii_only() {
+    for (text, expect) in TEXT_ASCII_ONLY_CASES.iter().copied() {
         assert_eq!(expect, html_escape::decode_html_entities(text));
     }
 }
 
+const QUOTED_ATTRIBUTE_ASCII_ONLY_CASES: [(&str, &str); 4] = [
+    ("", ""),
+    ("&#x54C8;&#x56C9;&#xFF0C;&#x4E2D;&#x6587;&#xFF01;", "哈囉，中文！"),
+    ("&quot;caf&#xE9;&quot; &amp; &#x27;th&#xE9;&#x27;", "\"café\" & 'thé'"),
+    ("https://magiclen.org", "https://magiclen.org"),
+];
+
+#[test]
+fn encode_quoted_attribute_ascii_only() {
+    for (expect, text) in QUOTED_ATTRIBUTE_ASCII_ONLY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_quoted_attribute_ascii_only(text));
+    }
+}
+
 #[test]
-fn decode_html_entities_to_string() {
-    for (expect, text) in BIG_NAMES_COMPATIBILITY_CASES.iter().copied() {
-        assert_eq!(expect, html_escape::decode_html_entities_to_string(text, &mut String::new()));
+fn encode_quoted_attribute_ascii_only_to_string() {
+    for (expect, text) in QUOTED_ATTRIBUTE_ASCII_ONLY_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_quoted_attribute_ascii_only_to_string(text, &mut String::new())
+        );
     }
 }
 
 #[cfg(feature = "std")]
 #[test]
-fn decode_html_entities_to_writer() {
-    for (expect, text) in BIG_NAMES_COMPATIBILITY_CASES.iter().copied() {
+fn encode_quoted_attribute_ascii_only_to_writer() {
+    for (expect, text) in QUOTED_ATTRIBUTE_ASCII_ONLY_CASES.iter().copied() {
         let mut v = Vec::new();
-        html_escape::decode_html_entities_to_writer(text, &mut v).unwrap();
+        html_escape::encode_quoted_attribute_ascii_only_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const TEXT_NAMED_ENTITIES_CASES: [(&str, &str); 5] = [
+    ("", ""),
+    ("&#x54C8;&#x56C9;&#xFF0C;&#x4E2D;&#x6587;&#xFF01;", "哈囉，中文！"),
+    ("&copy; 2020 &mdash; caf&eacute;&hellip;", "© 2020 — café…"),
+    ("&lt;p&gt;a&nbsp;&amp;&nbsp;b&lt;/p&gt;", "<p>a\u{00A0}&\u{00A0}b</p>"),
+    ("&rarr; &#x1F600;", "→ \u{1F600}"),
+];
+
+#[test]
+fn encode_text_named_entities() {
+    for (expect, text) in TEXT_NAMED_ENTITIES_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_text_named_entities(text));
+    }
+}
+
+#[test]
+fn encode_text_named_entities_to_string() {
+    for (expect, text) in TEXT_NAMED_ENTITIES_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_text_named_entities_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_text_named_entities_to_writer() {
+    for (expect, text) in TEXT_NAMED_ENTITIES_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_text_named_entities_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_text_named_entities() {
+    for (text, expect) in TEXT_NAMED_ENTITIES_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+    }
+}
+
+#[test]
+fn encode_quoted_attribute_named_entities() {
+    assert_eq!(
+        "&quot;&copy;&quot; &#x27;&eacute;&#x27;",
+        html_escape::encode_quoted_attribute_named_entities("\"©\" 'é'")
+    );
+}
+
+const UNQUOTED_ATTRIBUTE_DECIMAL_CASES: [(&str, &str); 5] = [
+    ("", ""),
+    ("哈囉，中文！", "哈囉，中文！"),
+    ("&quot;bread&quot;&#32;&amp;&#32;奶油", "\"bread\" & 奶油"),
+    ("https&#58;&#47;&#47;magiclen&#46;org", "https://magiclen.org"),
+    ("&#9;&#127;", "\t\x7F"),
+];
+
+#[test]
+fn encode_unquoted_attribute_decimal() {
+    for (expect, text) in UNQUOTED_ATTRIBUTE_DECIMAL_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_unquoted_attribute_decimal(text));
+    }
+}
+
+#[test]
+fn encode_unquoted_attribute_decimal_to_string() {
+    for (expect, text) in UNQUOTED_ATTRIBUTE_DECIMAL_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_unquoted_attribute_decimal_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_unquoted_attribute_decimal_to_writer() {
+    for (expect, text) in UNQUOTED_ATTRIBUTE_DECIMAL_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_unquoted_attribute_decimal_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_unquoted_attribute_decimal() {
+    for (text, expect) in UNQUOTED_ATTRIBUTE_DECIMAL_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+    }
+}
+
+#[test]
+fn encode_safe_decimal() {
+    assert_eq!(
+        "&lt;a href=&quot;https:&#47;&#47;magiclen.org&quot;&gt;Len&#39;s&lt;&#47;a&gt;",
+        html_escape::encode_safe_decimal("<a href=\"https://magiclen.org\">Len's</a>")
+    );
+}
+
+const WITH_FORMAT_CASES: [(html_escape::NumericEntityFormat, &str); 5] = [
+    (html_escape::NumericEntityFormat::HEXADECIMAL, "&lt;&#x27;&#xE9;&#x1F600;"),
+    (html_escape::NumericEntityFormat::DECIMAL, "&lt;&#39;&#233;&#128512;"),
+    (html_escape::NumericEntityFormat::HEXADECIMAL.lowercase(true), "&lt;&#x27;&#xe9;&#x1f600;"),
+    (html_escape::NumericEntityFormat::HEXADECIMAL.min_digits(4), "&lt;&#x0027;&#x00E9;&#x1F600;"),
+    (html_escape::NumericEntityFormat::DECIMAL.min_digits(4), "&lt;&#0039;&#0233;&#128512;"),
+];
+
+#[test]
+fn encode_quoted_attribute_ascii_only_with_format() {
+    for (format, expect) in WITH_FORMAT_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_quoted_attribute_ascii_only_with_format("<'é😀", format)
+        );
+    }
+}
+
+#[test]
+fn encode_quoted_attribute_ascii_only_with_format_to_string() {
+    for (format, expect) in WITH_FORMAT_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_quoted_attribute_ascii_only_with_format_to_string(
+                "<'é😀",
+                format,
+                &mut String::new()
+            )
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_quoted_attribute_ascii_only_with_format_to_writer() {
+    for (format, expect) in WITH_FORMAT_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_quoted_attribute_ascii_only_with_format_to_writer(
+            "<'é😀", format, &mut v,
+        )
+        .unwrap();
 
         assert_eq!(expect.as_bytes(), v.as_slice());
     }
 }
+
+#[test]
+fn decode_quoted_attribute_ascii_only_with_format() {
+    for (format, text) in WITH_FORMAT_CASES.iter().copied() {
+        assert_eq!("<'é😀", html_escape::decode_html_entities(text), "{:?}", format);
+    }
+}
+
+#[test]
+fn encode_with_format_default() {
+    assert_eq!(html_escape::NumericEntityFormat::HEXADECIMAL, Default::default());
+
+    assert_eq!(
+        html_escape::encode_unquoted_attribute("a b/c"),
+        html_escape::encode_unquoted_attribute_with_format("a b/c", Default::default())
+    );
+    assert_eq!(
+        html_escape::encode_safe("<a href='/'>"),
+        html_escape::encode_safe_with_format("<a href='/'>", Default::default())
+    );
+    assert_eq!(
+        html_escape::encode_text_ascii_only("a < é"),
+        html_escape::encode_text_ascii_only_with_format("a < é", Default::default())
+    );
+    assert_eq!(
+        "a&#x2f;b&#x20;c",
+        html_escape::encode_unquoted_attribute_with_format(
+            "a/b c",
+            html_escape::NumericEntityFormat::HEXADECIMAL.lowercase(true)
+        )
+    );
+}
+
+const TEXT_PRESERVING_ENTITIES_CASES: [(&str, &str); 8] = [
+    ("abc", "abc"),
+    ("&lt;b&gt;", "<b>"),
+    ("&amp;", "&amp;"),
+    ("&amp; &amp;", "& &amp;"),
+    ("Len&#x27;s &quot;&quot;", "Len's &quot;\""),
+    ("&#39;&#x1F600;&AMP;&eacute;", "&#39;&#x1F600;&AMP;&eacute;"),
+    (
+        "&amp;foo; &amp;#; &amp;#x; &amp;#xD800; &amp;#99999999;",
+        "&foo; &#; &#x; &#xD800; &#99999999;",
+    ),
+    ("&amp;amp &amp;", "&amp &"),
+];
+
+#[test]
+fn encode_text_preserving_entities() {
+    for (expect, text) in TEXT_PRESERVING_ENTITIES_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_text_preserving_entities(text));
+    }
+}
+
+#[test]
+fn encode_text_preserving_entities_to_string() {
+    for (expect, text) in TEXT_PRESERVING_ENTITIES_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_text_preserving_entities_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_text_preserving_entities_to_writer() {
+    for (expect, text) in TEXT_PRESERVING_ENTITIES_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_text_preserving_entities_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const SAFE_IDEMPOTENT_CASES: [(&str, &str); 5] = [
+    ("abc", "abc"),
+    ("&lt;&#x2F;a&gt;", "</a>"),
+    ("&lt;&#x2F;a&gt;", "&lt;&#x2F;a&gt;"),
+    ("Len&#x27;s &amp;&amp; &amp;amp", "Len's && &amp"),
+    ("&#47;&#x2F;", "&#47;/"),
+];
+
+#[test]
+fn encode_safe_idempotent() {
+    for (expect, text) in SAFE_IDEMPOTENT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_safe_idempotent(text));
+    }
+}
+
+#[test]
+fn encode_safe_idempotent_to_string() {
+    for (expect, text) in SAFE_IDEMPOTENT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_safe_idempotent_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_safe_idempotent_to_writer() {
+    for (expect, text) in SAFE_IDEMPOTENT_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_safe_idempotent_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn encode_idempotent_twice() {
+    for (_, text) in TEXT_PRESERVING_ENTITIES_CASES.iter().chain(SAFE_IDEMPOTENT_CASES.iter()) {
+        let once = html_escape::encode_safe_idempotent(text);
+        assert_eq!(once, html_escape::encode_safe_idempotent(once.as_ref()));
+
+        let once = html_escape::encode_text_preserving_entities(text);
+        assert_eq!(once, html_escape::encode_text_preserving_entities(once.as_ref()));
+    }
+}
+
+const CONTROL_POLICY_CASES: [(html_escape::ControlCharacterPolicy, &str); 4] = [
+    (html_escape::ControlCharacterPolicy::Keep, "a\u{0}b\u{1B}c\t\r\n&lt;"),
+    (html_escape::ControlCharacterPolicy::Strip, "abc\t\r\n&lt;"),
+    (html_escape::ControlCharacterPolicy::Replace, "a\u{FFFD}b\u{FFFD}c\t\r\n&lt;"),
+    (html_escape::ControlCharacterPolicy::Escape, "a&#x00;b&#x1B;c\t\r\n&lt;"),
+];
+
+#[test]
+fn encode_text_with_control_policy() {
+    for (policy, expect) in CONTROL_POLICY_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_text_with_control_policy("a\u{0}b\u{1B}c\t\r\n<", policy)
+        );
+    }
+}
+
+#[test]
+fn encode_text_with_control_policy_to_string() {
+    for (policy, expect) in CONTROL_POLICY_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_text_with_control_policy_to_string(
+                "a\u{0}b\u{1B}c\t\r\n<",
+                policy,
+                &mut String::new()
+            )
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_text_with_control_policy_to_writer() {
+    for (policy, expect) in CONTROL_POLICY_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_text_with_control_policy_to_writer(
+            "a\u{0}b\u{1B}c\t\r\n<",
+            policy,
+            &mut v,
+        )
+        .unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn encode_quoted_attribute_with_control_policy() {
+    assert_eq!(
+        "&quot;&#x27;",
+        html_escape::encode_quoted_attribute_with_control_policy(
+            "\"\u{7}'",
+            html_escape::ControlCharacterPolicy::Strip
+        )
+    );
+    assert_eq!(
+        "\u{7}",
+        html_escape::encode_double_quoted_attribute_with_control_policy(
+            "\u{7}",
+            html_escape::ControlCharacterPolicy::Keep
+        )
+    );
+    assert_eq!(
+        "&#x07;",
+        html_escape::encode_single_quoted_attribute_with_control_policy(
+            "\u{7}",
+            html_escape::ControlCharacterPolicy::Escape
+        )
+    );
+}
+
+const UNQUOTED_ATTRIBUTE_MINIMAL_CASES: [(&str, &str); 5] = [
+    ("/path/to-file_1.png", "/path/to-file_1.png"),
+    ("a&#x20;b&#x09;c&#x0A;", "a b\tc\n"),
+    ("&quot;&#x27;&#x60;&#x3D;", "\"'`="),
+    ("&lt;&gt;&amp;", "<>&"),
+    ("é;:#", "é;:#"),
+];
+
+#[test]
+fn encode_unquoted_attribute_minimal() {
+    for (expect, text) in UNQUOTED_ATTRIBUTE_MINIMAL_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_unquoted_attribute_minimal(text));
+    }
+}
+
+#[test]
+fn encode_unquoted_attribute_minimal_to_string() {
+    for (expect, text) in UNQUOTED_ATTRIBUTE_MINIMAL_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_unquoted_attribute_minimal_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_unquoted_attribute_minimal_to_writer() {
+    for (expect, text) in UNQUOTED_ATTRIBUTE_MINIMAL_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_unquoted_attribute_minimal_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_unquoted_attribute_minimal() {
+    for (text, expect) in UNQUOTED_ATTRIBUTE_MINIMAL_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+    }
+}
+
+const QUOTED_ATTRIBUTE_LEGACY_CASES: [(&str, &str); 3] = [
+    ("abc", "abc"),
+    ("&#x60;onload=alert(1)", "`onload=alert(1)"),
+    ("&lt;&quot;&#x27;&amp;&gt;&#x60;", "<\"'&>`"),
+];
+
+#[test]
+fn encode_quoted_attribute_legacy() {
+    for (expect, text) in QUOTED_ATTRIBUTE_LEGACY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_quoted_attribute_legacy(text));
+    }
+}
+
+#[test]
+fn encode_quoted_attribute_legacy_to_string() {
+    for (expect, text) in QUOTED_ATTRIBUTE_LEGACY_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_quoted_attribute_legacy_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_quoted_attribute_legacy_to_writer() {
+    for (expect, text) in QUOTED_ATTRIBUTE_LEGACY_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_quoted_attribute_legacy_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn encode_double_and_single_quoted_attribute_legacy() {
+    assert_eq!("'&quot;&#x60;", html_escape::encode_double_quoted_attribute_legacy("'\"`"));
+    assert_eq!("&#x27;\"&#x60;", html_escape::encode_single_quoted_attribute_legacy("'\"`"));
+}
+
+const SRCDOC_CASES: [(&str, &str); 3] = [
+    ("&lt;p&gt;Hello&lt;/p&gt;", "<p>Hello</p>"),
+    ("&lt;a href=&quot;/?a=1&amp;amp;b=2&quot;&gt;", "<a href=\"/?a=1&amp;b=2\">"),
+    ("&lt;p class=&#x27;x&#x27;&gt;&amp;copy;&lt;/p&gt;", "<p class='x'>&copy;</p>"),
+];
+
+#[test]
+fn encode_srcdoc() {
+    for (expect, text) in SRCDOC_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_srcdoc(text));
+    }
+}
+
+#[test]
+fn encode_srcdoc_to_string() {
+    for (expect, text) in SRCDOC_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_srcdoc_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_srcdoc_to_writer() {
+    for (expect, text) in SRCDOC_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_srcdoc_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_srcdoc() {
+    for (text, expect) in SRCDOC_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+    }
+}
+
+#[test]
+fn encode_srcdoc_text() {
+    for text in ["a < b && \"c\" > 'd'", "&lt;"].iter().copied() {
+        assert_eq!(
+            html_escape::encode_srcdoc(&html_escape::encode_text(text)),
+            html_escape::encode_srcdoc_text(text)
+        );
+        assert_eq!(
+            text,
+            html_escape::decode_html_entities(&html_escape::decode_html_entities(
+                &html_escape::encode_srcdoc_text(text)
+            ))
+        );
+    }
+}
+
+const TEXT_EMAIL_CASES: [(&str, &str); 5] = [
+    ("abc", "abc"),
+    ("caf&eacute; &amp; cr&egrave;me", "café & crème"),
+    ("&euro;5 &ndash; &copy;", "€5 – ©"),
+    ("&#8469; &#128512;", "ℕ 😀"),
+    ("'\"&lt;&gt;", "'\"<>"),
+];
+
+#[test]
+fn encode_text_email() {
+    for (expect, text) in TEXT_EMAIL_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_text_email(text));
+    }
+}
+
+#[test]
+fn encode_text_email_to_string() {
+    for (expect, text) in TEXT_EMAIL_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_text_email_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_text_email_to_writer() {
+    for (expect, text) in TEXT_EMAIL_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_text_email_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_text_email() {
+    for (text, expect) in TEXT_EMAIL_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+    }
+}
+
+#[test]
+fn encode_quoted_attribute_email() {
+    assert_eq!(
+        "&#39;&quot;&lt;&gt;&amp; &nbsp;&#8469;",
+        html_escape::encode_quoted_attribute_email("'\"<>& \u{a0}ℕ")
+    );
+}
+
+const TEXT_APOSTROPHE_CASES: [(&str, &str); 3] = [
+    ("abc", "abc"),
+    ("Len&#39;s &lt;b&gt; &amp; \"c\"", "Len's <b> & \"c\""),
+    ("&#39;&#39;", "''"),
+];
+
+#[test]
+fn encode_text_apostrophe() {
+    for (expect, text) in TEXT_APOSTROPHE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_text_apostrophe(text));
+    }
+}
+
+#[test]
+fn encode_text_apostrophe_to_string() {
+    for (expect, text) in TEXT_APOSTROPHE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_text_apostrophe_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_text_apostrophe_to_writer() {
+    for (expect, text) in TEXT_APOSTROPHE_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_text_apostrophe_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn encode_quoted_attribute_apostrophe() {
+    assert_eq!(
+        "Len&#39;s &lt;b&gt; &amp; &quot;c&quot;",
+        html_escape::encode_quoted_attribute_apostrophe("Len's <b> & \"c\"")
+    );
+}
+
+const QUOTED_ATTRIBUTE_ANY_CASES: [(&str, &str); 3] = [
+    ("a > b", "a > b"),
+    ("&quot;&#x27;&lt;&amp;", "\"'<&"),
+    ("哈囉 &#x27;中文&#x27;", "哈囉 '中文'"),
+];
+
+#[test]
+fn encode_quoted_attribute_any() {
+    for (expect, text) in QUOTED_ATTRIBUTE_ANY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_quoted_attribute_any(text));
+    }
+}
+
+#[test]
+fn encode_quoted_attribute_any_to_string() {
+    for (expect, text) in QUOTED_ATTRIBUTE_ANY_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_quoted_attribute_any_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_quoted_attribute_any_to_writer() {
+    for (expect, text) in QUOTED_ATTRIBUTE_ANY_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_quoted_attribute_any_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_quoted_attribute_any() {
+    for (text, expect) in QUOTED_ATTRIBUTE_ANY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+    }
+}
+
+const CSS_URL_CASES: [(&str, &str); 5] = [
+    ("", ""),
+    ("/images/a.png?x=1;y={2}", "/images/a.png?x=1;y={2}"),
+    (r"a\20 b\28 1\29 .png", "a b(1).png"),
+    (r"\22 \27 \3C /style\3E \5C \26 ", "\"'</style>\\&"),
+    (r"x\0A \09 \7F ", "x\n\t\u{7F}"),
+];
+
+#[test]
+fn encode_css_url() {
+    for (expect, text) in CSS_URL_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_css_url(text));
+    }
+}
+
+#[test]
+fn encode_css_url_to_string() {
+    for (expect, text) in CSS_URL_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_css_url_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_css_url_to_writer() {
+    for (expect, text) in CSS_URL_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_css_url_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const TEXT_TO_HTML_CASES: [(&str, &str); 6] = [
+    ("", ""),
+    ("a b c", "a b c"),
+    ("a &lt; b<br>\nc", "a < b\nc"),
+    ("a<br>\r\nb<br>\rc", "a\r\nb\rc"),
+    ("a&nbsp;&nbsp;b&nbsp;<br>\n&nbsp;c", "a  b \n c"),
+    ("&nbsp;&nbsp;&nbsp;&nbsp;x&nbsp;&nbsp;&nbsp;&nbsp;&nbsp;y", "\tx\t y"),
+];
+
+#[test]
+fn text_to_html() {
+    for (expect, text) in TEXT_TO_HTML_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::text_to_html(text));
+    }
+}
+
+#[test]
+fn text_to_html_to_string() {
+    for (expect, text) in TEXT_TO_HTML_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::text_to_html_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn text_to_html_to_writer() {
+    for (expect, text) in TEXT_TO_HTML_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::text_to_html_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const ATTRIBUTE_NAME_CASES: [(&str, &str); 6] = [
+    ("data-user-id", "data-user-id"),
+    ("data-名字", "data-名字"),
+    ("data-a_b", "data-a b"),
+    ("x__onclick_alert(1)", "x\"=onclick=alert(1)"),
+    ("a___b_", "a/>'b\u{7}"),
+    ("_x_", "\u{FDD0}x\u{10FFFF}"),
+];
+
+#[test]
+fn sanitize_attribute_name() {
+    for (expect, name) in ATTRIBUTE_NAME_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::sanitize_attribute_name(name));
+        assert!(html_escape::is_valid_attribute_name(expect));
+    }
+}
+
+#[test]
+fn sanitize_attribute_name_to_string() {
+    for (expect, name) in ATTRIBUTE_NAME_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::sanitize_attribute_name_to_string(name, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sanitize_attribute_name_to_writer() {
+    for (expect, name) in ATTRIBUTE_NAME_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::sanitize_attribute_name_to_writer(name, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn is_valid_attribute_name() {
+    assert!(html_escape::is_valid_attribute_name("class"));
+    assert!(html_escape::is_valid_attribute_name("@click.prevent"));
+    assert!(!html_escape::is_valid_attribute_name(""));
+    assert!(!html_escape::is_valid_attribute_name("a b"));
+    assert!(!html_escape::is_valid_attribute_name("a=b"));
+    assert!(!html_escape::is_valid_attribute_name("a\u{85}"));
+}
+
+const UNICODE_ESCAPES_CASES: [(html_escape::UnicodeEscapes, &str); 2] = [
+    (html_escape::UnicodeEscapes::NONE, "&lt;b&gt;\u{202E}gnp.exe\u{2066}é\u{2069}"),
+    (html_escape::UnicodeEscapes::BIDI_CONTROLS, "&lt;b&gt;&#x202E;gnp.exe&#x2066;é&#x2069;"),
+];
+
+#[test]
+fn encode_text_with_unicode_escapes() {
+    for (escapes, expect) in UNICODE_ESCAPES_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_text_with_unicode_escapes(
+                "<b>\u{202E}gnp.exe\u{2066}é\u{2069}",
+                escapes
+            )
+        );
+    }
+}
+
+#[test]
+fn encode_text_with_unicode_escapes_to_string() {
+    for (escapes, expect) in UNICODE_ESCAPES_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_text_with_unicode_escapes_to_string(
+                "<b>\u{202E}gnp.exe\u{2066}é\u{2069}",
+                escapes,
+                &mut String::new()
+            )
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_text_with_unicode_escapes_to_writer() {
+    for (escapes, expect) in UNICODE_ESCAPES_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_text_with_unicode_escapes_to_writer(
+            "<b>\u{202E}gnp.exe\u{2066}é\u{2069}",
+            escapes,
+            &mut v,
+        )
+        .unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn encode_quoted_attribute_with_unicode_escapes() {
+    assert_eq!(
+        "&quot;&#x202A;&#x27;",
+        html_escape::encode_quoted_attribute_with_unicode_escapes(
+            "\"\u{202A}'",
+            html_escape::UnicodeEscapes::BIDI_CONTROLS
+        )
+    );
+    assert_eq!(
+        "\u{202A}",
+        html_escape::encode_double_quoted_attribute_with_unicode_escapes(
+            "\u{202A}",
+            html_escape::UnicodeEscapes::default()
+        )
+    );
+}
+
+#[test]
+fn encode_text_with_invisible_unicode_escapes() {
+    let text = "pay\u{200B}pal\u{FEFF}\u{2060}\u{200D}\u{202E}";
+
+    assert_eq!(
+        "pay&#x200B;pal&#xFEFF;&#x2060;&#x200D;\u{202E}",
+        html_escape::encode_text_with_unicode_escapes(text, html_escape::UnicodeEscapes::INVISIBLE)
+    );
+    assert_eq!(
+        "pay&#x200B;pal&#xFEFF;&#x2060;&#x200D;&#x202E;",
+        html_escape::encode_single_quoted_attribute_with_unicode_escapes(
+            text,
+            html_escape::UnicodeEscapes::INVISIBLE | html_escape::UnicodeEscapes::BIDI_CONTROLS
+        )
+    );
+    assert!((html_escape::UnicodeEscapes::INVISIBLE | html_escape::UnicodeEscapes::BIDI_CONTROLS)
+        .contains(html_escape::UnicodeEscapes::INVISIBLE));
+}
+
+const UNQUOTED_ATTRIBUTE_UNICODE_ESCAPES_CASES: [(html_escape::UnicodeEscapes, &str); 2] = [
+    (html_escape::UnicodeEscapes::NONE, "a&#x20;b\u{A0}c\u{2028}d\u{3000}é"),
+    (html_escape::UnicodeEscapes::WHITESPACE, "a&#x20;b&#xA0;c&#x2028;d&#x3000;é"),
+];
+
+#[test]
+fn encode_unquoted_attribute_with_unicode_escapes() {
+    for (escapes, expect) in UNQUOTED_ATTRIBUTE_UNICODE_ESCAPES_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_unquoted_attribute_with_unicode_escapes(
+                "a b\u{A0}c\u{2028}d\u{3000}é",
+                escapes
+            )
+        );
+    }
+
+    assert_eq!(
+        "\u{A0}\u{1680}",
+        html_escape::encode_unquoted_attribute_with_unicode_escapes(
+            "\u{A0}\u{1680}",
+            html_escape::UnicodeEscapes::BIDI_CONTROLS
+        )
+    );
+}
+
+#[test]
+fn encode_unquoted_attribute_with_unicode_escapes_to_string() {
+    for (escapes, expect) in UNQUOTED_ATTRIBUTE_UNICODE_ESCAPES_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_unquoted_attribute_with_unicode_escapes_to_string(
+                "a b\u{A0}c\u{2028}d\u{3000}é",
+                escapes,
+                &mut String::new()
+            )
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_unquoted_attribute_with_unicode_escapes_to_writer() {
+    for (escapes, expect) in UNQUOTED_ATTRIBUTE_UNICODE_ESCAPES_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_unquoted_attribute_with_unicode_escapes_to_writer(
+            "a b\u{A0}c\u{2028}d\u{3000}é",
+            escapes,
+            &mut v,
+        )
+        .unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const LINE_ENDING_CASES: [(html_escape::LineEndingPolicy, &str); 3] = [
+    (html_escape::LineEndingPolicy::Keep, "a &lt; b\r\nc\rd\n"),
+    (html_escape::LineEndingPolicy::Normalize, "a &lt; b\nc\nd\n"),
+    (html_escape::LineEndingPolicy::Escape, "a &lt; b&#x0D;\nc&#x0D;d\n"),
+];
+
+#[test]
+fn encode_text_with_line_endings() {
+    for (policy, expect) in LINE_ENDING_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_text_with_line_endings("a < b\r\nc\rd\n", policy));
+    }
+
+    assert_eq!(
+        "a\nb",
+        html_escape::encode_text_with_line_endings(
+            "a\nb",
+            html_escape::LineEndingPolicy::Normalize
+        )
+    );
+}
+
+#[test]
+fn encode_text_with_line_endings_to_string() {
+    for (policy, expect) in LINE_ENDING_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_text_with_line_endings_to_string(
+                "a < b\r\nc\rd\n",
+                policy,
+                &mut String::new()
+            )
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_text_with_line_endings_to_writer() {
+    for (policy, expect) in LINE_ENDING_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_text_with_line_endings_to_writer("a < b\r\nc\rd\n", policy, &mut v)
+            .unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const BOM_POLICY_CASES: [(html_escape::BomPolicy, &str); 3] = [
+    (html_escape::BomPolicy::Keep, "\u{FEFF}\u{FEFF}a &amp; b\u{FEFF}"),
+    (html_escape::BomPolicy::Strip, "\u{FEFF}a &amp; b\u{FEFF}"),
+    (html_escape::BomPolicy::StripAndEscape, "&#xFEFF;a &amp; b&#xFEFF;"),
+];
+
+#[test]
+fn encode_text_with_bom_policy() {
+    for (policy, expect) in BOM_POLICY_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_text_with_bom_policy("\u{FEFF}\u{FEFF}a & b\u{FEFF}", policy)
+        );
+    }
+
+    assert_eq!(
+        "abc",
+        html_escape::encode_text_with_bom_policy("\u{FEFF}abc", html_escape::BomPolicy::Strip)
+    );
+    assert_eq!(
+        "&quot;a&quot;",
+        html_escape::encode_quoted_attribute_with_bom_policy(
+            "\u{FEFF}\"a\"",
+            html_escape::BomPolicy::StripAndEscape
+        )
+    );
+}
+
+#[test]
+fn encode_text_with_bom_policy_to_string() {
+    for (policy, expect) in BOM_POLICY_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_text_with_bom_policy_to_string(
+                "\u{FEFF}\u{FEFF}a & b\u{FEFF}",
+                policy,
+                &mut String::new()
+            )
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_text_with_bom_policy_to_writer() {
+    for (policy, expect) in BOM_POLICY_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_text_with_bom_policy_to_writer(
+            "\u{FEFF}\u{FEFF}a & b\u{FEFF}",
+            policy,
+            &mut v,
+        )
+        .unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn named_entity_for() {
+    assert_eq!(Some("amp"), html_escape::named_entity_for('&'));
+    assert_eq!(Some("nbsp"), html_escape::named_entity_for('\u{A0}'));
+    assert_eq!(Some("Tab"), html_escape::named_entity_for('\t'));
+    assert_eq!(None, html_escape::named_entity_for('中'));
+
+    assert_eq!(Some("hearts"), html_escape::html4_named_entity_for('♥'));
+    assert_eq!(None, html_escape::html4_named_entity_for('\t'));
+
+    for c in ['©', '→', '∮', '𝔸'].iter().copied() {
+        let name = html_escape::named_entity_for(c).unwrap();
+        let entity = format!("&{};", name);
+
+        assert_eq!(c.to_string(), html_escape::decode_html_entities(&entity));
+    }
+}
+
+// TODO ----------
+
+#[cfg(not(feature = "html4-entities"))]
+const BIG_NAMES_COMPATIBILITY_CASES: [(&str, &str); 4] =
+    [("&", "&AMP;"), ("<", "&LT;"), (">", "&GT;"), ("\"", "&QUOT;")];
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_html_entities() {
+    for (expect, text) in BIG_NAMES_COMPATIBILITY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+    }
+}
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_html_entities_to_string() {
+    for (expect, text) in BIG_NAMES_COMPATIBILITY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "html4-entities")))]
+#[test]
+fn decode_html_entities_to_writer() {
+    for (expect, text) in BIG_NAMES_COMPATIBILITY_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_html_entities_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_html_entities_with_bom_policy() {
+    assert_eq!(
+        "\u{FEFF}a & b\u{FEFF}",
+        html_escape::decode_html_entities_with_bom_policy(
+            "\u{FEFF}a &amp; b\u{FEFF}",
+            html_escape::BomPolicy::Keep
+        )
+    );
+    assert_eq!(
+        "a & b\u{FEFF}",
+        html_escape::decode_html_entities_with_bom_policy(
+            "\u{FEFF}a &amp; b\u{FEFF}",
+            html_escape::BomPolicy::StripAndEscape
+        )
+    );
+    assert_eq!(
+        "a",
+        html_escape::decode_html_entities_with_bom_policy_to_string(
+            "\u{FEFF}a",
+            html_escape::BomPolicy::Strip,
+            &mut String::new()
+        )
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_with_bom_policy_to_writer() {
+    let mut v = Vec::new();
+    html_escape::decode_html_entities_with_bom_policy_to_writer(
+        "\u{FEFF}&lt;",
+        html_escape::BomPolicy::Strip,
+        &mut v,
+    )
+    .unwrap();
+
+    assert_eq!(b"<", v.as_slice());
+}
+
+#[cfg(not(feature = "html4-entities"))]
+const HTML5_NAMED_ENTITIES_CASES: [(&str, &str); 6] = [
+    ("\u{A0}\u{A9}\u{2026}\u{2192}", "&nbsp;&copy;&hellip;&rarr;"),
+    ("a \u{2014} b", "a &mdash; b"),
+    ("\u{2242}\u{338}", "&NotEqualTilde;"),
+    ("\n", "&NewLine;"),
+    ("\u{1D504}\u{200D}", "&Afr;&zwj;"),
+    ("&unknownentity;", "&unknownentity;"),
+];
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_html5_named_entities() {
+    for (expect, text) in HTML5_NAMED_ENTITIES_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+        assert_eq!(expect, html_escape::decode_html_entities_to_string(text, &mut String::new()));
+    }
+
+    assert_eq!(2125, html_escape::NAMED_ENTITIES.len());
+}
+
+#[cfg(not(feature = "html4-entities"))]
+const LEGACY_CASES: [(&str, &str); 12] = [
+    ("a & b < c", "a &amp b &lt c"),
+    ("\u{A9} 2024\u{A0}x", "&copy 2024&nbspx"),
+    ("\u{AC}it;", "&notit;"),
+    ("\u{2209}", "&notin;"),
+    ("\u{2209} \u{AC}inva", "&notinva; &notinva"),
+    ("&amp;", "&ampamp;"),
+    ("\u{BE}4;", "&frac344;"),
+    ("\u{AC}CounterClockwiseContourIntegralX;", "&notCounterClockwiseContourIntegralX;"),
+    ("\u{BD}", "&frac12"),
+    ("&hellip", "&hellip"),
+    ("AB", "&#65&#x42"),
+    ("&& &#; &#x;", "&& &#; &#x;"),
+];
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_html_entities_legacy() {
+    for (expect, text) in LEGACY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities_legacy(text));
+    }
+
+    assert_eq!("&amp b", html_escape::decode_html_entities("&amp b"));
+    assert_eq!("&&", html_escape::decode_html_entities("&&amp;"));
+}
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_html_entities_legacy_to_string() {
+    for (expect, text) in LEGACY_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::decode_html_entities_legacy_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "html4-entities")))]
+#[test]
+fn decode_html_entities_legacy_to_writer() {
+    for (expect, text) in LEGACY_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_html_entities_legacy_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const NUMERIC_REFERENCE_CASES: [(&str, &str); 7] = [
+    ("\u{20AC}\u{2122}\u{178}", "&#x80;&#153;&#x9F;"),
+    ("\u{81}\u{9D}", "&#x81;&#157;"),
+    ("\u{FFFD}\u{FFFD}", "&#0;&#x0000;"),
+    ("\u{FFFD}\u{FFFD}", "&#xD800;&#57343;"),
+    ("\u{FFFD}\u{FFFD}", "&#x110000;&#99999999999999999999;"),
+    ("\u{10FFFF}", "&#x10FFFF;"),
+    ("&#xG;", "&#xG;"),
+];
+
+#[test]
+fn decode_numeric_references() {
+    for (expect, text) in NUMERIC_REFERENCE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+        assert_eq!(expect, html_escape::decode_html_entities_legacy(text));
+    }
+
+    assert_eq!("\u{2014}x", html_escape::decode_html_entities_legacy("&#151x"));
+}
+
+const IN_ATTRIBUTE_CASES: [(&str, &str); 6] = [
+    ("?a=1&copy=2", "?a=1&copy=2"),
+    ("?a=1&ampx", "?a=1&ampx"),
+    ("\u{A9} 2024", "&copy 2024"),
+    ("\u{A9}=2", "&copy;=2"),
+    ("&notit", "&notit"),
+    ("a&b", "a&amp;b"),
+];
+
+#[test]
+fn decode_html_entities_in_attribute() {
+    for (expect, text) in IN_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities_in_attribute(text));
+    }
+
+    assert_eq!("?a=1\u{A9}=2", html_escape::decode_html_entities_legacy("?a=1&copy=2"));
+}
+
+#[test]
+fn decode_html_entities_in_attribute_to_string() {
+    for (expect, text) in IN_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::decode_html_entities_in_attribute_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_in_attribute_to_writer() {
+    for (expect, text) in IN_ATTRIBUTE_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_html_entities_in_attribute_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const DECODER_CASES: [&str; 6] = [
+    "a &amp; b &lt;&gt; c",
+    "&#x1F600;&#128512; &#65 &amp",
+    "&notit; &notin; &CounterClockwiseContourIntegral;",
+    "?a=1&copy=2&copy;&&&",
+    "哈囉&eacute;&",
+    "&#999999999999999999999;&#x",
+];
+
+#[test]
+fn decoder() {
+    for text in DECODER_CASES.iter().copied() {
+        for chunk_size in 1..=text.len() {
+            let mut decoders = [
+                (html_escape::Decoder::new(), html_escape::decode_html_entities(text)),
+                (
+                    html_escape::Decoder::new_legacy(),
+                    html_escape::decode_html_entities_legacy(text),
+                ),
+                (
+                    html_escape::Decoder::new_in_attribute(),
+                    html_escape::decode_html_entities_in_attribute(text),
+                ),
+            ];
+
+            for (decoder, expect) in decoders.iter_mut() {
+                let mut s = String::new();
+                let mut chunk = String::new();
+
+                for c in text.chars() {
+                    chunk.push(c);
+
+                    if chunk.len() >= chunk_size {
+                        s.extend(decoder.feed(&chunk));
+                        chunk.clear();
+                    }
+                }
+
+                s.extend(decoder.feed(&chunk));
+                s.push_str(&decoder.finish());
+
+                assert_eq!(expect, &s, "{:?} in chunks of {}", text, chunk_size);
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_html_entities_in_place() {
+    for (expect, text) in HTML5_NAMED_ENTITIES_CASES
+        .iter()
+        .chain(NUMERIC_REFERENCE_CASES.iter())
+        .chain(BIG_NAMES_COMPATIBILITY_CASES.iter())
+        .copied()
+    {
+        let mut s = String::from(text);
+        html_escape::decode_html_entities_in_place(&mut s);
+
+        assert_eq!(expect, s);
+    }
+
+    let mut s = String::from("&nGt;&nLt;x&amp;&nGt;");
+    html_escape::decode_html_entities_in_place(&mut s);
+    assert_eq!("\u{226B}\u{20D2}\u{226A}\u{20D2}x&\u{226B}\u{20D2}", s);
+
+    let text = "&lt;&nGt;".repeat(100_000);
+    let mut s = text.clone();
+    html_escape::decode_html_entities_in_place(&mut s);
+    assert_eq!(html_escape::decode_html_entities(&text), s);
+
+    let mut s = String::from("no references");
+    html_escape::decode_html_entities_in_place(&mut s);
+    assert_eq!("no references", s);
+}
+
+#[test]
+fn decode_html_entities_bytes() {
+    use html_escape::InvalidUtf8Policy;
+
+    assert_eq!(
+        b"a & b\xFF<\xE6\x97".as_ref(),
+        html_escape::decode_html_entities_bytes(
+            b"a &amp; b\xFF&lt;\xE6\x97",
+            InvalidUtf8Policy::Keep
+        )
+        .as_ref()
+    );
+    assert_eq!(
+        "a & b\u{FFFD}<\u{FFFD}".as_bytes(),
+        html_escape::decode_html_entities_bytes(
+            b"a &amp; b\xFF&lt;\xE6\x97",
+            InvalidUtf8Policy::Replace
+        )
+        .as_ref()
+    );
+    assert_eq!(
+        "\u{FFFD}\u{FFFD}&amp".as_bytes(),
+        html_escape::decode_html_entities_bytes(b"\xC0\x80&amp", InvalidUtf8Policy::Replace)
+            .as_ref()
+    );
+    assert_eq!(
+        b"abc".as_ref(),
+        html_escape::decode_html_entities_bytes(b"abc", InvalidUtf8Policy::Replace).as_ref()
+    );
+
+    let mut v = Vec::new();
+    html_escape::decode_html_entities_bytes_to_vec(
+        b"&#x41;\xFF",
+        InvalidUtf8Policy::Replace,
+        &mut v,
+    );
+    assert_eq!("A\u{FFFD}".as_bytes(), v.as_slice());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_bytes_to_writer() {
+    use html_escape::InvalidUtf8Policy;
+
+    let mut v = Vec::new();
+    html_escape::decode_html_entities_bytes_to_writer(b"\xFF&gt;", InvalidUtf8Policy::Keep, &mut v)
+        .unwrap();
+    assert_eq!(b"\xFF>", v.as_slice());
+
+    let mut v = Vec::new();
+    html_escape::decode_html_entities_bytes_to_writer(
+        b"\xFF&gt;",
+        InvalidUtf8Policy::Replace,
+        &mut v,
+    )
+    .unwrap();
+    assert_eq!("\u{FFFD}>".as_bytes(), v.as_slice());
+}
+
+const TRY_DECODE_ERROR_CASES: [(&str, html_escape::DecodeErrorKind, usize, &str); 8] = [
+    ("a &foo; b", html_escape::DecodeErrorKind::UnknownNamedReference, 2, "&foo;"),
+    ("&#;", html_escape::DecodeErrorKind::MissingDigits, 0, "&#"),
+    ("x&#xG;", html_escape::DecodeErrorKind::MissingDigits, 1, "&#x"),
+    ("&amp;&#x2", html_escape::DecodeErrorKind::MissingSemicolon, 5, "&#x2"),
+    ("&copy 2024", html_escape::DecodeErrorKind::MissingSemicolon, 0, "&copy"),
+    ("&notit;", html_escape::DecodeErrorKind::UnknownNamedReference, 0, "&notit;"),
+    ("&#xD800;", html_escape::DecodeErrorKind::InvalidCodePoint, 0, "&#xD800;"),
+    ("&#0;", html_escape::DecodeErrorKind::InvalidCodePoint, 0, "&#0;"),
+];
+
+#[test]
+fn try_decode_html_entities() {
+    assert_eq!("AT&T & co <", html_escape::try_decode_html_entities("AT&T & co &lt;").unwrap());
+    assert_eq!("a&b", html_escape::try_decode_html_entities("a&b").unwrap());
+
+    assert!(matches!(
+        html_escape::try_decode_html_entities(""),
+        Ok(std::borrow::Cow::Borrowed(""))
+    ));
+    assert!(matches!(
+        html_escape::try_decode_html_entities("a & b"),
+        Ok(std::borrow::Cow::Borrowed("a & b"))
+    ));
+    assert!(matches!(
+        html_escape::try_decode_html_entities("&amp;"),
+        Ok(std::borrow::Cow::Owned(_))
+    ));
+
+    for (text, kind, offset, reference) in TRY_DECODE_ERROR_CASES.iter().copied() {
+        let error = html_escape::try_decode_html_entities(text).unwrap_err();
+
+        assert_eq!(kind, error.kind(), "{:?}", text);
+        assert_eq!(offset, error.offset(), "{:?}", text);
+        assert_eq!(reference, error.reference(), "{:?}", text);
+    }
+
+    assert_eq!(
+        "unknown named reference \"&foo;\" at byte 2",
+        html_escape::try_decode_html_entities("a &foo; b").unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn try_decode_html_entities_to_string() {
+    let mut s = String::from("x");
+
+    assert_eq!("<", html_escape::try_decode_html_entities_to_string("&lt;", &mut s).unwrap());
+    assert!(html_escape::try_decode_html_entities_to_string("&lt;&#;", &mut s).is_err());
+    assert_eq!("x<", s);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn try_decode_html_entities_to_writer() {
+    let mut v = Vec::new();
+    html_escape::try_decode_html_entities_to_writer("&lt;", &mut v).unwrap();
+    assert_eq!(b"<", v.as_slice());
+
+    let mut v = Vec::new();
+    let error = html_escape::try_decode_html_entities_to_writer("&lt; &foo;", &mut v).unwrap_err();
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+    assert_eq!(
+        Some(5),
+        error
+            .get_ref()
+            .and_then(|error| error.downcast_ref::<html_escape::DecodeError>())
+            .map(|error| error.offset())
+    );
+    assert_eq!(b"< ", v.as_slice());
+}
+
+#[test]
+fn decode_html_entities_with() {
+    use html_escape::{DecodeErrorKind, DecodeOptions, NamedEntitySet, NumericErrorPolicy};
+
+    let options = DecodeOptions::new();
+    assert_eq!(DecodeOptions::default(), options);
+    assert_eq!(
+        html_escape::decode_html_entities("&amp &lt; &#xD800; &nbsp;"),
+        html_escape::decode_html_entities_with("&amp &lt; &#xD800; &nbsp;", &options).unwrap()
+    );
+
+    let options = DecodeOptions::new().require_semicolon(false);
+    assert_eq!("& <", html_escape::decode_html_entities_with("&amp &lt", &options).unwrap());
+
+    let options = options.attribute(true);
+    assert_eq!(
+        "?a=1&copy=2 ©",
+        html_escape::decode_html_entities_with("?a=1&copy=2 &copy", &options).unwrap()
+    );
+
+    let options = DecodeOptions::new().named_entities(NamedEntitySet::Xml);
+    assert_eq!(
+        "<'> &nbsp; A",
+        html_escape::decode_html_entities_with("&lt;&apos;&gt; &nbsp; &#65;", &options).unwrap()
+    );
+
+    let options = DecodeOptions::new().named_entities(NamedEntitySet::None);
+    assert_eq!("&lt; A", html_escape::decode_html_entities_with("&lt; &#65;", &options).unwrap());
+
+    let options = DecodeOptions::new().numeric_error_policy(NumericErrorPolicy::Keep);
+    assert_eq!(
+        "&#xD800; &#0; \u{80}",
+        html_escape::decode_html_entities_with("&#xD800; &#0; &#x80;", &options).unwrap()
+    );
+
+    let options = DecodeOptions::new().require_semicolon(false).strict(true);
+    assert!(options.is_strict());
+    assert!(!options.is_semicolon_required());
+    assert_eq!(
+        DecodeErrorKind::MissingSemicolon,
+        html_escape::decode_html_entities_with("&lt; &amp", &options).unwrap_err().kind()
+    );
+
+    let options = DecodeOptions::new().named_entities(NamedEntitySet::Xml).strict(true);
+    let error = html_escape::decode_html_entities_with("&amp; &nbsp;", &options).unwrap_err();
+    assert_eq!(DecodeErrorKind::UnknownNamedReference, error.kind());
+    assert_eq!(6, error.offset());
+}
+
+#[test]
+fn decode_html_entities_with_to_string() {
+    use html_escape::DecodeOptions;
+
+    let mut s = String::from("x");
+
+    let options = DecodeOptions::new().require_semicolon(false);
+    assert_eq!(
+        "<",
+        html_escape::decode_html_entities_with_to_string("&lt", &options, &mut s).unwrap()
+    );
+
+    let options = options.strict(true);
+    assert!(html_escape::decode_html_entities_with_to_string("&lt", &options, &mut s).is_err());
+    assert_eq!("x<", s);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_with_to_writer() {
+    use html_escape::DecodeOptions;
+
+    let options = DecodeOptions::new().require_semicolon(false);
+
+    let mut v = Vec::new();
+    html_escape::decode_html_entities_with_to_writer("&lt &gt;", &options, &mut v).unwrap();
+    assert_eq!(b"< >", v.as_slice());
+
+    let mut v = Vec::new();
+    let error =
+        html_escape::decode_html_entities_with_to_writer("&lt &gt;", &options.strict(true), &mut v)
+            .unwrap_err();
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+    assert!(v.is_empty());
+}
+
+#[test]
+fn decode_html_entities_with_fallback() {
+    use html_escape::{DecodeErrorKind, DecodeOptions};
+
+    let options = DecodeOptions::new();
+
+    let mut unknown = Vec::new();
+    let decoded = html_escape::decode_html_entities_with_fallback(
+        "&foo; &lt; &my.app_icon; &bar;",
+        &options,
+        |name| {
+            unknown.push(name.to_string());
+
+            if name == "my.app_icon" {
+                Some(String::from("[icon]"))
+            } else {
+                None
+            }
+        },
+    )
+    .unwrap();
+    assert_eq!("&foo; < [icon] &bar;", decoded);
+    assert_eq!(vec!["foo", "my.app_icon", "bar"], unknown);
+
+    assert_eq!(
+        "a &b",
+        html_escape::decode_html_entities_with_fallback("a &b", &options, |_| Some("x")).unwrap()
+    );
+
+    let options = DecodeOptions::new().strict(true);
+    assert_eq!(
+        "x",
+        html_escape::decode_html_entities_with_fallback("&foo;", &options, |_| Some("x")).unwrap()
+    );
+    assert_eq!(
+        DecodeErrorKind::UnknownNamedReference,
+        html_escape::decode_html_entities_with_fallback("&foo;", &options, |_| None::<&str>)
+            .unwrap_err()
+            .kind()
+    );
+}
+
+#[test]
+fn decode_html_entities_with_fallback_to_string() {
+    use html_escape::DecodeOptions;
+
+    let mut s = String::from("x");
+
+    assert_eq!(
+        "<y",
+        html_escape::decode_html_entities_with_fallback_to_string(
+            "&lt;&y;",
+            &DecodeOptions::new(),
+            |name| Some(name.to_string()),
+            &mut s
+        )
+        .unwrap()
+    );
+    assert!(html_escape::decode_html_entities_with_fallback_to_string(
+        "&lt;&foo;",
+        &DecodeOptions::new().strict(true),
+        |_| None::<&str>,
+        &mut s
+    )
+    .is_err());
+    assert_eq!("x<y", s);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_with_fallback_to_writer() {
+    use html_escape::DecodeOptions;
+
+    let mut v = Vec::new();
+    html_escape::decode_html_entities_with_fallback_to_writer(
+        "&lt;&myapp-icon;",
+        &DecodeOptions::new(),
+        |_| Some("★"),
+        &mut v,
+    )
+    .unwrap();
+    assert_eq!("<★".as_bytes(), v.as_slice());
+}
+
+#[test]
+fn find_html_entities() {
+    let text = "AT&T &lt;b&gt; &#x1F600; &foo; &amp";
+
+    let entities: Vec<_> = html_escape::find_html_entities(text)
+        .map(|entity| (entity.range(), entity.raw(), entity.decoded().to_string()))
+        .collect();
+
+    assert_eq!(
+        vec![
+            (5..9, "&lt;", String::from("<")),
+            (10..14, "&gt;", String::from(">")),
+            (15..24, "&#x1F600;", String::from("😀")),
+        ],
+        entities
+    );
+
+    for entity in html_escape::find_html_entities(text) {
+        assert_eq!(entity.raw(), &text[entity.range()]);
+    }
+
+    let options = html_escape::DecodeOptions::new().require_semicolon(false);
+    let entity = html_escape::find_html_entities_with(text, &options).last().unwrap();
+    assert_eq!((31..35, "&"), (entity.range(), entity.decoded()));
+}
+
+const NUMERIC_ONLY_CASES: [(&str, &str); 3] =
+    [("&#x26; &amp; &#60;", "& &amp; <"), ("&nbsp;&#xA0;", "&nbsp;\u{A0}"), ("&&#;", "&&#;")];
+
+const NAMED_ONLY_CASES: [(&str, &str); 3] =
+    [("&#x26; &amp; &#60;", "&#x26; & &#60;"), ("&nbsp;&#xA0;", "\u{A0}&#xA0;"), ("&&#;", "&&#;")];
+
+#[test]
+fn decode_numeric_entities() {
+    for (text, expect) in NUMERIC_ONLY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_numeric_entities(text));
+        assert_eq!(
+            expect,
+            html_escape::decode_numeric_entities_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[test]
+fn decode_named_entities() {
+    for (text, expect) in NAMED_ONLY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_named_entities(text));
+        assert_eq!(expect, html_escape::decode_named_entities_to_string(text, &mut String::new()));
+    }
+
+    let options = html_escape::DecodeOptions::new().numeric_references(false).strict(true);
+    assert!(!options.is_numeric_references_enabled());
+    assert_eq!(
+        "&#; &#xD800; &",
+        html_escape::decode_html_entities_with("&#; &#xD800; &amp;", &options).unwrap()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_numeric_and_named_entities_to_writer() {
+    for (text, expect) in NUMERIC_ONLY_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_numeric_entities_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+
+    for (text, expect) in NAMED_ONLY_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_named_entities_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_html_entities_with_max_output_length() {
+    use html_escape::{DecodeErrorKind, DecodeOptions};
+
+    let options = DecodeOptions::new().max_output_length(5);
+    assert_eq!(Some(5), options.get_max_output_length());
+
+    assert_eq!("a < b", html_escape::decode_html_entities_with("a &lt; b", &options).unwrap());
+    assert_eq!("abcde", html_escape::decode_html_entities_with("abcde", &options).unwrap());
+
+    let error = html_escape::decode_html_entities_with("abcdef", &options).unwrap_err();
+    assert_eq!(DecodeErrorKind::OutputLimitExceeded, error.kind());
+    assert_eq!(5, error.offset());
+    assert_eq!("", error.reference());
+    assert_eq!("decoded text longer than the limit at byte 5", error.to_string());
+
+    let error = html_escape::decode_html_entities_with("a &lt; &nGt;", &options).unwrap_err();
+    assert_eq!(DecodeErrorKind::OutputLimitExceeded, error.kind());
+    assert_eq!(7, error.offset());
+
+    let mut s = String::from("x");
+    assert!(html_escape::decode_html_entities_with_to_string(
+        "&lt;&lt;&lt;&lt;&lt;&lt;",
+        &options,
+        &mut s
+    )
+    .is_err());
+    assert_eq!("x", s);
+
+    let error =
+        html_escape::decode_html_entities_with_fallback("&foo;", &options, |_| Some("[foo!]"))
+            .unwrap_err();
+    assert_eq!((DecodeErrorKind::OutputLimitExceeded, 0), (error.kind(), error.offset()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_with_max_output_length_to_writer() {
+    let options = html_escape::DecodeOptions::new().max_output_length(3);
+
+    let mut v = Vec::new();
+    let error =
+        html_escape::decode_html_entities_with_to_writer("&lt;&lt;&lt;&lt;", &options, &mut v)
+            .unwrap_err();
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+    assert_eq!(b"<<<", v.as_slice());
+}
+
+#[test]
+fn decoder_with_options() {
+    use html_escape::{DecodeErrorKind, DecodeOptions, Decoder};
+
+    let options = DecodeOptions::new().require_semicolon(false).max_output_length(7);
+
+    let mut decoder = Decoder::with_options(&options);
+    let mut s = String::new();
+
+    for chunk in ["a &l", "t b", " &amp"].iter() {
+        s.extend(decoder.try_feed(chunk).unwrap());
+    }
+
+    s.push_str(&decoder.try_finish().unwrap());
+    assert_eq!("a < b &", s);
+
+    let mut decoder = Decoder::with_options(&options);
+    let mut s = String::new();
+
+    s.extend(decoder.try_feed("ab&lt;").unwrap());
+    s.extend(decoder.try_feed("cd").unwrap());
+
+    let error = decoder.try_feed("efg").map(|_| ()).unwrap_err();
+    assert_eq!((DecodeErrorKind::OutputLimitExceeded, 10), (error.kind(), error.offset()));
+    assert_eq!("ab<cd", s);
+
+    let mut decoder = Decoder::with_options(&DecodeOptions::new().strict(true));
+    decoder.try_feed("ok &fo").unwrap().for_each(drop);
+
+    let error = decoder.try_feed("o; x").map(|_| ()).unwrap_err();
+    assert_eq!((DecodeErrorKind::UnknownNamedReference, 3), (error.kind(), error.offset()));
+
+    let mut decoder = Decoder::with_options(&options);
+    assert_eq!("abcdefgh &", decoder.feed("abcdefgh &amp").collect::<String>() + &decoder.finish());
+}
+
+const ONLY_CASES: [(&str, &str); 4] = [
+    ("&amp; &quot; &lt;", "& \" &lt;"),
+    ("&#38; &amp", "&#38; &amp"),
+    ("&ampx; &amp;&amp;", "&ampx; &&"),
+    ("no references", "no references"),
+];
+
+#[test]
+fn decode_html_entities_only() {
+    for (text, expect) in ONLY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities_only(text, &["amp", "quot"]));
+        assert_eq!(
+            expect,
+            html_escape::decode_html_entities_only_to_string(
+                text,
+                &["amp", "quot"],
+                &mut String::new()
+            )
+        );
+    }
+
+    assert_eq!("&lt;", html_escape::decode_html_entities_only("&lt;", &[]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_only_to_writer() {
+    for (text, expect) in ONLY_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_html_entities_only_to_writer(text, &["amp", "quot"], &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const EXCEPT_CASES: [(&str, &str); 4] = [
+    ("&lt;b&gt; &amp; &copy;", "&lt;b&gt; & ©"),
+    ("&#60;&#x3E;&LT;&GT;", "&#60;&#x3E;&LT;&GT;"),
+    ("&#38; &nbsp;", "& \u{A0}"),
+    ("no references", "no references"),
+];
+
+#[test]
+fn decode_html_entities_except() {
+    for (text, expect) in EXCEPT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities_except(text, &["lt", "gt"]));
+        assert_eq!(
+            expect,
+            html_escape::decode_html_entities_except_to_string(
+                text,
+                &["lt", "gt"],
+                &mut String::new()
+            )
+        );
+    }
+
+    assert_eq!("<", html_escape::decode_html_entities_except("&lt;", &[]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_except_to_writer() {
+    for (text, expect) in EXCEPT_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_html_entities_except_to_writer(text, &["lt", "gt"], &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_html_entities_with_stats() {
+    use html_escape::{DecodeErrorKind, DecodeOptions, ReplacementPolicy};
+
+    let options = DecodeOptions::new().require_semicolon(false);
+
+    let (decoded, stats) =
+        html_escape::decode_html_entities_with_stats("&copy &#169; &#xA9; &bad; &#;", &options);
+    assert_eq!("© © © &bad; &#;", decoded);
+    assert_eq!(1, stats.named_references());
+    assert_eq!(2, stats.numeric_references());
+    assert_eq!(
+        vec![
+            (DecodeErrorKind::MissingSemicolon, 0),
+            (DecodeErrorKind::UnknownNamedReference, 20),
+            (DecodeErrorKind::MissingDigits, 26),
+        ],
+        stats.malformed_references().iter().map(|e| (e.kind(), e.offset())).collect::<Vec<_>>()
+    );
+
+    let (decoded, stats) = html_escape::decode_html_entities_with_stats("AT&T", &options);
+    assert!(matches!(decoded, std::borrow::Cow::Borrowed("AT&T")));
+    assert_eq!(html_escape::DecodeStats::default(), stats);
+
+    let mut s = String::from("x");
+    let (decoded, stats) =
+        html_escape::decode_html_entities_with_stats_to_string("&lt;", &options, &mut s);
+    assert_eq!(("<", 1), (decoded, stats.named_references()));
+    assert_eq!("x<", s);
+
+    let options = DecodeOptions::new().replacement_policy(ReplacementPolicy::Strip);
+
+    let (decoded, stats) =
+        html_escape::decode_html_entities_with_stats("a&bad;b&#xD800;c", &options);
+    assert_eq!(
+        html_escape::decode_html_entities_with("a&bad;b&#xD800;c", &options).unwrap(),
+        decoded
+    );
+    assert_eq!("abc", decoded);
+    assert_eq!(
+        (0, 0, 0, 2),
+        (
+            stats.named_references(),
+            stats.numeric_references(),
+            stats.replaced_references(),
+            stats.removed_references()
+        )
+    );
+
+    let options = DecodeOptions::new().replacement_policy(ReplacementPolicy::ReplaceWithFFFD);
+
+    let (decoded, stats) = html_escape::decode_html_entities_with_stats("&bad;&#65;", &options);
+    assert_eq!("\u{FFFD}A", decoded);
+    assert_eq!((1, 1), (stats.replaced_references(), stats.numeric_references()));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_with_stats_to_writer() {
+    let mut v = Vec::new();
+    let stats = html_escape::decode_html_entities_with_stats_to_writer(
+        "&#65;&foo;",
+        &html_escape::DecodeOptions::new(),
+        &mut v,
+    )
+    .unwrap();
+    assert_eq!(b"A&foo;", v.as_slice());
+    assert_eq!(1, stats.numeric_references());
+    assert_eq!(1, stats.malformed_references().len());
+}
+
+const CASE_INSENSITIVE_CASES: [(&str, &str); 5] = [
+    ("&NBSP;&Amp;&lT;", "\u{A0}&<"),
+    ("&DAGGER; &Dagger; &dagger;", "† ‡ †"),
+    ("&COPY; &Copy;", "© ©"),
+    ("&NOTANENTITY;", "&NOTANENTITY;"),
+    ("&NBSP", "&NBSP"),
+];
+
+#[test]
+fn decode_html_entities_case_insensitive() {
+    let options = html_escape::DecodeOptions::new().case_insensitive(true);
+    assert!(options.is_case_insensitive());
+
+    for (text, expect) in CASE_INSENSITIVE_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::decode_html_entities_with(text, &options).unwrap(),
+            "{:?}",
+            text
+        );
+    }
+
+    assert_eq!("&NBSP;", html_escape::decode_html_entities("&NBSP;"));
+    assert!(html_escape::decode_html_entities_with("&NBSP;", &options.strict(true)).is_ok());
+}
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_html_entities_case_insensitive_all() {
+    let options = html_escape::DecodeOptions::new().case_insensitive(true);
+
+    let find = |name: &[u8]| {
+        let table = &html_escape::NAMED_ENTITIES;
+        let lowercase = name.to_ascii_lowercase();
+
+        table
+            .iter()
+            .find(|(t_name, _)| *t_name == name)
+            .or_else(|| table.iter().find(|(t_name, _)| *t_name == lowercase.as_slice()))
+            .or_else(|| table.iter().find(|(t_name, _)| t_name.eq_ignore_ascii_case(name)))
+            .map(|(_, s)| *s)
+            .unwrap()
+    };
+
+    for (name, _) in html_escape::NAMED_ENTITIES.iter() {
+        let swapped: Vec<u8> = name
+            .iter()
+            .map(|e| {
+                if e.is_ascii_uppercase() {
+                    e.to_ascii_lowercase()
+                } else {
+                    e.to_ascii_uppercase()
+                }
+            })
+            .collect();
+
+        for name in [name.to_ascii_uppercase(), swapped] {
+            let text = format!("&{};", std::str::from_utf8(&name).unwrap());
+
+            assert_eq!(
+                find(&name),
+                html_escape::decode_html_entities_with(&text, &options).unwrap(),
+                "{:?}",
+                text
+            );
+        }
+    }
+
+    assert_eq!(
+        "\u{2061} \u{2AEB}",
+        html_escape::decode_html_entities_with("&APPLYFUNCTION; &VBAR;", &options).unwrap()
+    );
+}
+
+const PATHOLOGICAL_NUMERIC_CASES: [(&str, &str, &str); 5] = [
+    ("&#0000000000000000000000000065;", "A", "A"),
+    ("&#x000000000000000000000000041;", "A", "A"),
+    ("&#99999999999999999999999999;", "\u{FFFD}", "&#99999999999999999999999999;"),
+    ("&#xFFFFFFFFFFFFFFFFFFFFFFFFFF;", "\u{FFFD}", "&#xFFFFFFFFFFFFFFFFFFFFFFFFFF;"),
+    ("&#4294967361;", "\u{FFFD}", "&#4294967361;"),
+];
+
+#[test]
+fn decode_pathological_numeric_references() {
+    use html_escape::{DecodeErrorKind, DecodeOptions, NumericErrorPolicy};
+
+    let keep = DecodeOptions::new().numeric_error_policy(NumericErrorPolicy::Keep);
+    let strict = DecodeOptions::new().strict(true);
+
+    for (text, replaced, kept) in PATHOLOGICAL_NUMERIC_CASES.iter().copied() {
+        assert_eq!(replaced, html_escape::decode_html_entities(text), "{:?}", text);
+        assert_eq!(
+            kept,
+            html_escape::decode_html_entities_with(text, &keep).unwrap(),
+            "{:?}",
+            text
+        );
+
+        if replaced == "\u{FFFD}" {
+            assert_eq!(
+                DecodeErrorKind::InvalidCodePoint,
+                html_escape::decode_html_entities_with(text, &strict).unwrap_err().kind()
+            );
+        }
+    }
+
+    let unlimited = DecodeOptions::new().max_numeric_length(usize::MAX);
+
+    let mut text = String::from("&#");
+    text.push_str(&"0".repeat(100_000));
+    text.push_str("38;");
+    assert_eq!("&", html_escape::decode_html_entities(&text));
+    assert_eq!("&", html_escape::decode_html_entities_with(&text, &unlimited).unwrap());
+    assert_eq!("&", html_escape::decode_html_entities_with(&text, &strict).unwrap());
+
+    let mut text = String::from("&#x");
+    text.push_str(&"1".repeat(33));
+    text.push(';');
+    assert_eq!(text, html_escape::decode_html_entities(&text));
+    assert_eq!(
+        DecodeErrorKind::TooManyDigits,
+        html_escape::decode_html_entities_with(&text, &strict).unwrap_err().kind()
+    );
+
+    let mut decoder = html_escape::Decoder::with_options(&unlimited);
+    let mut s = String::new();
+
+    s.extend(decoder.feed("&#"));
+
+    for _ in 0..100_000 {
+        s.extend(decoder.feed("0"));
+    }
+
+    s.extend(decoder.feed("38;"));
+    s.push_str(&decoder.finish());
+    assert_eq!("&", s);
+}
+
+#[test]
+fn decode_html_entities_with_max_numeric_length() {
+    use html_escape::DecodeOptions;
+
+    let options = DecodeOptions::new();
+
+    assert_eq!(32, options.get_max_numeric_length());
+
+    let mut text = String::from("&#x");
+    text.push_str(&"0".repeat(30));
+    text.push_str("26;");
+    assert_eq!("&", html_escape::decode_html_entities_with(&text, &options).unwrap());
+
+    let short = options.max_numeric_length(2);
+
+    assert_eq!(2, short.get_max_numeric_length());
+    assert_eq!(
+        "& & &#100;",
+        html_escape::decode_html_entities_with("&#38; &#00038; &#100;", &short).unwrap()
+    );
+    assert_eq!(
+        "&",
+        html_escape::decode_html_entities_with("&#x026;", &short.strict(true)).unwrap()
+    );
+    assert_eq!(
+        (html_escape::DecodeErrorKind::TooManyDigits, 2),
+        html_escape::decode_html_entities_with("a &#x126;", &short.strict(true))
+            .map_err(|error| (error.kind(), error.offset()))
+            .unwrap_err()
+    );
+
+    // the held back text stays bounded, however many digits are fed
+    for prefix in ["&#", "&#x"].iter() {
+        let mut decoder = html_escape::Decoder::new();
+        let mut s = String::new();
+
+        s.extend(decoder.feed(prefix));
+
+        for i in 0..1_000_000 {
+            s.extend(decoder.feed("0"));
+            assert!(prefix.len() + i + 1 - s.len() <= prefix.len() + 32, "{}", i);
+        }
+
+        s.extend(decoder.feed("38;"));
+        s.push_str(&decoder.finish());
+
+        let mut text = String::from(*prefix);
+        text.push_str(&"0".repeat(1_000_000));
+        text.push_str("38;");
+        assert_eq!(text, s);
+    }
+}
+
+#[test]
+fn content_decoder() {
+    use html_escape::{ContentDecoder, ContentKind};
+
+    let chunks = [
+        (ContentKind::Data, "<p>a &am"),
+        (ContentKind::Data, "p; b &l"),
+        (ContentKind::RawText, "t; &lt;/script&gt;"),
+        (ContentKind::RcData, "&lt;title&gt"),
+        (ContentKind::Attribute, "?x=1&not=2&amp;y=&copy;"),
+        (ContentKind::Data, "&copy &gt;"),
+    ];
+
+    let mut decoder = ContentDecoder::new();
+    let mut s = String::new();
+
+    for (kind, chunk) in chunks.iter().copied() {
+        s.extend(decoder.feed(kind, chunk));
+    }
+
+    s.push_str(&decoder.finish());
+
+    assert_eq!("<p>a & b &lt; &lt;/script&gt;<title>?x=1&not=2&y=©© >", s);
+
+    let mut decoder =
+        ContentDecoder::with_options(&html_escape::DecodeOptions::new().require_semicolon(false));
+    let mut s = String::new();
+
+    s.extend(decoder.feed(ContentKind::Data, "&copy &not=1"));
+    s.extend(decoder.feed(ContentKind::Attribute, "&copy &not=1"));
+    s.extend(decoder.feed(ContentKind::RawText, "&copy"));
+    s.push_str(&decoder.finish());
+
+    assert_eq!("© ¬=1© &not=1&copy", s);
+}
+
+#[test]
+fn content_decoder_legacy_references() {
+    use html_escape::{ContentDecoder, ContentKind};
+
+    let chunks = [
+        (ContentKind::Data, "&copy 2024 &co"),
+        (ContentKind::Data, "py; a &amp b "),
+        (ContentKind::RcData, "&copy 2024 &amp b &not=1 "),
+        (ContentKind::Attribute, "&copy 2024 &not=1"),
+        (ContentKind::RcData, " &amp"),
+    ];
+
+    let mut decoder = ContentDecoder::new();
+    let mut s = String::new();
+
+    for (kind, chunk) in chunks.iter().copied() {
+        s.extend(decoder.feed(kind, chunk));
+    }
+
+    s.push_str(&decoder.finish());
+
+    assert_eq!("© 2024 © a & b © 2024 & b ¬=1 © 2024 &not=1 &", s);
+}
+
+#[test]
+fn content_decoder_try_feed() {
+    use html_escape::{ContentDecoder, ContentKind, DecodeErrorKind, DecodeOptions};
+
+    let mut decoder = ContentDecoder::with_options(&DecodeOptions::new().strict(true));
+    let mut s = String::new();
+
+    s.extend(decoder.try_feed(ContentKind::Data, "a &l").unwrap());
+    s.extend(decoder.try_feed(ContentKind::RawText, "t; &foo;").unwrap());
+    s.extend(decoder.try_feed(ContentKind::Attribute, "?x=1&amp;y=2").unwrap());
+
+    let error = decoder.try_feed(ContentKind::Data, " &foo;").err().unwrap();
+
+    assert_eq!("a &lt; &foo;?x=1&y=2", s);
+    assert_eq!(DecodeErrorKind::UnknownNamedReference, error.kind());
+    assert_eq!(25, error.offset());
+
+    let mut decoder = ContentDecoder::with_options(&DecodeOptions::new().strict(true));
+
+    decoder.try_feed(ContentKind::Data, "a &amp").unwrap().for_each(drop);
+
+    assert_eq!(DecodeErrorKind::MissingSemicolon, decoder.try_finish().unwrap_err().kind());
+
+    let mut decoder = ContentDecoder::with_options(&DecodeOptions::new().max_output_length(8));
+
+    decoder.try_feed(ContentKind::Data, "&lt;a&gt;").unwrap().for_each(drop);
+
+    let error = decoder.try_feed(ContentKind::RawText, "bcdefg").err().unwrap();
+
+    assert_eq!(DecodeErrorKind::OutputLimitExceeded, error.kind());
+    assert_eq!(14, error.offset());
+}
+
+#[test]
+fn decode_html_entities_chars() {
+    for text in [
+        "",
+        "abc",
+        "a &lt; b &#x26; c",
+        "&amp&amp;&#128;&#0;",
+        "&lt;&gt;&fjlig;&NotEqualTilde;",
+        "中文 &foo; &",
+    ]
+    .iter()
+    {
+        assert_eq!(
+            html_escape::decode_html_entities(text),
+            html_escape::decode_html_entities_chars(text).collect::<String>()
+        );
+    }
+
+    let options = html_escape::DecodeOptions::new().require_semicolon(false);
+
+    assert_eq!(
+        "& &©",
+        html_escape::decode_html_entities_chars_with("&amp &amp;&copy", &options)
+            .collect::<String>()
+    );
+}
+
+#[cfg(not(feature = "html4-entities"))]
+const MULTI_CODEPOINT_CASES: [(&str, &str); 4] = [
+    ("&fjlig;", "fj"),
+    ("a&NotEqualTilde;b", "a\u{2242}\u{338}b"),
+    ("&nGt;&nLt;", "\u{226B}\u{20D2}\u{226A}\u{20D2}"),
+    ("&ThickSpace;.&bne;", "\u{205F}\u{200A}.=\u{20E5}"),
+];
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_multi_codepoint_entities() {
+    for (text, expect) in MULTI_CODEPOINT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+        assert_eq!(expect, html_escape::decode_html_entities_to_string(text, &mut String::new()));
+        assert_eq!(expect, html_escape::decode_html_entities_chars(text).collect::<String>());
+        assert_eq!(
+            expect.as_bytes(),
+            html_escape::decode_html_entities_bytes(
+                text.as_bytes(),
+                html_escape::InvalidUtf8Policy::Keep
+            )
+            .as_ref()
+        );
+
+        let mut s = String::from(text);
+        html_escape::decode_html_entities_in_place(&mut s);
+        assert_eq!(expect, s);
+
+        let mut decoder = html_escape::Decoder::new();
+        let (a, b) = text.split_at(text.len() / 2);
+        let s = decoder.feed(a).chain(decoder.feed(b)).collect::<String>() + &decoder.finish();
+        assert_eq!(expect, s);
+
+        let decoded: String =
+            html_escape::find_html_entities(text).map(|m| m.decoded().to_string()).collect();
+        assert_eq!(expect.chars().filter(|c| !"ab.".contains(*c)).collect::<String>(), decoded);
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "html4-entities")))]
+#[test]
+fn decode_multi_codepoint_entities_to_writer() {
+    for (text, expect) in MULTI_CODEPOINT_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_html_entities_to_writer(text, &mut v).unwrap();
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const REPEATEDLY_CASES: [(&str, usize, &str); 6] = [
+    ("abc", 8, "abc"),
+    ("&amp;amp;eacute;", 0, "&amp;amp;eacute;"),
+    ("&amp;amp;eacute;", 1, "&amp;eacute;"),
+    ("&amp;amp;eacute;", 8, "\u{E9}"),
+    ("&amp;lt;b&amp;gt; &amp;#x27;", 8, "<b> '"),
+    ("&amp;amp;amp;amp;", 8, "&"),
+];
+
+#[test]
+fn decode_html_entities_repeatedly() {
+    for (text, max_iterations, expect) in REPEATEDLY_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities_repeatedly(text, max_iterations));
+        assert_eq!(
+            expect,
+            html_escape::decode_html_entities_repeatedly_to_string(
+                text,
+                max_iterations,
+                &mut String::new()
+            )
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_repeatedly_to_writer() {
+    for (text, max_iterations, expect) in REPEATEDLY_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_html_entities_repeatedly_to_writer(text, max_iterations, &mut v)
+            .unwrap();
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn find_double_encoded_entities() {
+    let text = "&amp;&amp; &amp;lt;&amp;amp;eacute;&AMP;#65; &amp;foo; &amp;amp";
+
+    let ranges: Vec<_> = html_escape::find_double_encoded_entities(text).collect();
+
+    assert_eq!(vec![11..19, 19..35, 35..44], ranges);
+    assert_eq!("&amp;lt;", &text[ranges[0].clone()]);
+    assert_eq!("&amp;amp;eacute;", &text[ranges[1].clone()]);
+
+    assert_eq!(0, html_escape::find_double_encoded_entities("a &amp; b &lt; c").count());
+}
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_html_entities_to_edits() {
+    use html_escape::DecodeOptions;
+
+    for options in [DecodeOptions::new(), DecodeOptions::new().require_semicolon(false)].iter() {
+        for text in
+            ["", "abc", "a &lt; b &#x26; c", "&amp&amp;&fjlig;&#0; &foo;", "中文&copy"].iter()
+        {
+            let mut s = String::from(*text);
+
+            for (range, replacement) in
+                html_escape::decode_html_entities_to_edits(text, options).into_iter().rev()
+            {
+                s.replace_range(range, &replacement);
+            }
+
+            assert_eq!(html_escape::decode_html_entities_with(text, options).unwrap(), s);
+        }
+    }
+
+    let edits = html_escape::decode_html_entities_to_edits("x&fjlig;", &DecodeOptions::new());
+
+    assert_eq!(1..8, edits[0].0);
+    assert_eq!("fj", edits[0].1);
+}
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_html_entities_with_offsets() {
+    use html_escape::DecodeOptions;
+
+    let text = "a&lt;b &fjlig;c&#x4E2D;d &foo; e";
+
+    let (decoded, map) =
+        html_escape::decode_html_entities_with_offsets(text, &DecodeOptions::new());
+
+    assert_eq!("a<b fjc中d &foo; e", decoded);
+    assert_eq!(3, map.references());
+
+    let expect = [0, 1, 5, 6, 7, 7, 14, 15, 15, 15, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32];
+
+    for (output_offset, &input_offset) in expect.iter().enumerate() {
+        assert_eq!(input_offset, map.input_offset(output_offset), "{}", output_offset);
+    }
+
+    let (decoded, map) =
+        html_escape::decode_html_entities_with_offsets("abc", &DecodeOptions::new());
+
+    assert_eq!("abc", decoded);
+    assert_eq!(2, map.input_offset(2));
+
+    let mut s = String::from("x");
+    let (decoded, map) = html_escape::decode_html_entities_with_offsets_to_string(
+        "&amp y",
+        &DecodeOptions::new().require_semicolon(false),
+        &mut s,
+    );
+
+    assert_eq!("& y", decoded);
+    assert_eq!(4, map.input_offset(1));
+
+    let text = "a&foo;&bar;&lt;b&x;&y;&z;";
+
+    let (decoded, map) = html_escape::decode_html_entities_with_offsets(
+        text,
+        &DecodeOptions::new().replacement_policy(html_escape::ReplacementPolicy::Strip),
+    );
+
+    assert_eq!("a<b", decoded);
+    assert_eq!(6, map.references());
+    assert_eq!([0, 11, 15, 25], [0, 1, 2, 3].map(|output_offset| map.input_offset(output_offset)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_with_offsets_to_writer() {
+    let mut v = Vec::new();
+
+    let map = html_escape::decode_html_entities_with_offsets_to_writer(
+        "&lt;&gt;",
+        &html_escape::DecodeOptions::new(),
+        &mut v,
+    )
+    .unwrap();
+
+    assert_eq!(b"<>", v.as_slice());
+    assert_eq!(4, map.input_offset(1));
+    assert_eq!(8, map.input_offset(2));
+}
+
+#[test]
+fn decode_html_entities_in_ranges() {
+    use html_escape::DecodeOptions;
+
+    let text = "<p title=\"a&amp;b\">&lt;c&gt;</p> &amp;";
+
+    let mut output = String::from("x");
+
+    let ranges = html_escape::decode_html_entities_in_ranges_to_string(
+        text,
+        vec![10..17, 19..28, 0..0],
+        &DecodeOptions::new(),
+        &mut output,
+    );
+
+    assert_eq!(vec![1..4, 4..7, 7..7], ranges);
+    assert_eq!("xa&b<c>", output);
+
+    let mut v = Vec::new();
+
+    let ranges = html_escape::decode_html_entities_in_ranges_to_vec(
+        "a=1&copy=2 &copy",
+        vec![0..10, 10..16],
+        &DecodeOptions::new().require_semicolon(false).attribute(true),
+        &mut v,
+    );
+
+    assert_eq!(vec![0..10, 10..13], ranges);
+    assert_eq!("a=1&copy=2 \u{A9}".as_bytes(), v.as_slice());
+}
+
+#[test]
+#[should_panic]
+fn decode_html_entities_in_ranges_not_on_char_boundary() {
+    html_escape::decode_html_entities_in_ranges_to_string(
+        "中文",
+        Some(0..1),
+        &html_escape::DecodeOptions::new(),
+        &mut String::new(),
+    );
+}
+
+const EXCEPT_MARKUP_CASES: [(&str, &str); 4] = [
+    ("&lt;b&gt; &amp; &copy; &quot;&apos;", "&lt;b&gt; &amp; © &quot;&apos;"),
+    ("&#60;&#x3E;&#38;&#34;&#x27;&AMP;&QUOT;", "&#60;&#x3E;&#38;&#34;&#x27;&AMP;&QUOT;"),
+    ("&eacute;&nbsp;&#x4E2D;", "\u{E9}\u{A0}中"),
+    ("no references", "no references"),
+];
+
+#[test]
+fn decode_html_entities_except_markup() {
+    for (text, expect) in EXCEPT_MARKUP_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities_except_markup(text));
+        assert_eq!(
+            expect,
+            html_escape::decode_html_entities_except_markup_to_string(text, &mut String::new())
+        );
+        assert!(!expect.contains(|c| "<>\"'".contains(c)));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_except_markup_to_writer() {
+    for (text, expect) in EXCEPT_MARKUP_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_html_entities_except_markup_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const REPLACEMENT_POLICY_CASES: [(&str, &str, &str, &str); 5] = [
+    ("a &foo; b", "a \u{FFFD} b", "a &foo; b", "a  b"),
+    ("&#0;&#xD800;&#x110000;", "\u{FFFD}\u{FFFD}\u{FFFD}", "&#0;&#xD800;&#x110000;", ""),
+    ("&lt;&#x26;&#128;&copy;", "<&\u{20AC}\u{A9}", "<&\u{20AC}\u{A9}", "<&\u{20AC}\u{A9}"),
+    (
+        "&my-icon; &#; & &amp",
+        "&my-icon; &#; & &amp",
+        "&my-icon; &#; & &amp",
+        "&my-icon; &#; & &amp",
+    ),
+    ("&notit; &#65", "\u{FFFD} &#65", "&notit; &#65", " &#65"),
+];
+
+#[test]
+fn decode_html_entities_with_replacement_policy() {
+    use html_escape::{DecodeErrorKind, DecodeOptions, ReplacementPolicy};
+
+    for (text, replaced, kept, stripped) in REPLACEMENT_POLICY_CASES.iter().copied() {
+        for (policy, expect) in [
+            (ReplacementPolicy::ReplaceWithFFFD, replaced),
+            (ReplacementPolicy::KeepRaw, kept),
+            (ReplacementPolicy::Strip, stripped),
+        ]
+        .iter()
+        .copied()
+        {
+            let options = DecodeOptions::new().replacement_policy(policy);
+
+            assert_eq!(Some(policy), options.get_replacement_policy());
+            assert_eq!(
+                expect,
+                html_escape::decode_html_entities_with(text, &options).unwrap(),
+                "{:?}",
+                text
+            );
+            assert_eq!(
+                expect,
+                html_escape::decode_html_entities_chars_with(text, &options).collect::<String>()
+            );
+
+            let mut decoder = html_escape::Decoder::with_options(&options);
+            let s = decoder.feed(text).collect::<String>() + &decoder.finish();
+            assert_eq!(expect, s);
+        }
+    }
+
+    let options = DecodeOptions::new().replacement_policy(ReplacementPolicy::Error);
+
+    assert_eq!(
+        "<& &#; &amp",
+        html_escape::decode_html_entities_with("&lt;&amp; &#; &amp", &options).unwrap()
+    );
+
+    let error = html_escape::decode_html_entities_with("a &lt; &foo; b", &options).unwrap_err();
+    assert_eq!(DecodeErrorKind::UnknownNamedReference, error.kind());
+    assert_eq!(7, error.offset());
+
+    let error = html_escape::decode_html_entities_with("&#xD800;", &options).unwrap_err();
+    assert_eq!(DecodeErrorKind::InvalidCodePoint, error.kind());
+    assert_eq!("&#xD800;", error.reference());
+
+    let legacy = options.require_semicolon(false);
+    assert_eq!("\u{AC}it;", html_escape::decode_html_entities_with("&notit;", &legacy).unwrap());
+
+    assert_eq!(
+        "&foo;",
+        html_escape::decode_html_entities_chars_with("&foo;", &options).collect::<String>()
+    );
+    assert_eq!(None, DecodeOptions::new().get_replacement_policy());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_with_replacement_policy_to_writer() {
+    use html_escape::{DecodeOptions, ReplacementPolicy};
+
+    let options = DecodeOptions::new().replacement_policy(ReplacementPolicy::Error);
+
+    let mut v = Vec::new();
+    let error = html_escape::decode_html_entities_with_to_writer("a&lt;&#0;b", &options, &mut v)
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+    assert_eq!(b"a<", v.as_slice());
+
+    let mut v = Vec::new();
+    html_escape::decode_html_entities_with_to_writer(
+        "a&foo;b",
+        &options.replacement_policy(ReplacementPolicy::Strip),
+        &mut v,
+    )
+    .unwrap();
+
+    assert_eq!(b"ab", v.as_slice());
+}
+
+const NUMERIC_POLICY_CASES: [(&str, &str, &str, &str); 7] = [
+    ("&#0;", "\u{FFFD}", "&#0;", "\0"),
+    ("&#xD800;&#x110000;", "\u{FFFD}\u{FFFD}", "&#xD800;&#x110000;", "&#xD800;&#x110000;"),
+    ("&#x80;&#x81;", "\u{20AC}\u{81}", "\u{80}\u{81}", "\u{80}\u{81}"),
+    ("&#1;&#x1F;&#x7F;", "\u{1}\u{1F}\u{7F}", "&#1;&#x1F;\u{7F}", "\u{1}\u{1F}\u{7F}"),
+    ("&#9;&#xA;&#xD;", "\t\n\r", "\t\n\r", "\t\n\r"),
+    (
+        "&#xFFFE;&#xFFFF;&#xFFFD;",
+        "\u{FFFE}\u{FFFF}\u{FFFD}",
+        "&#xFFFE;&#xFFFF;\u{FFFD}",
+        "\u{FFFE}\u{FFFF}\u{FFFD}",
+    ),
+    ("&#x1F600;", "\u{1F600}", "\u{1F600}", "\u{1F600}"),
+];
+
+#[test]
+fn decode_html_entities_with_numeric_policy() {
+    use html_escape::{DecodeErrorKind, DecodeOptions, NumericErrorPolicy, NumericPolicy};
+
+    for (text, html, xml, passthrough) in NUMERIC_POLICY_CASES.iter().copied() {
+        for (policy, expect) in [
+            (NumericPolicy::Html, html),
+            (NumericPolicy::XmlStrict, xml),
+            (NumericPolicy::Passthrough, passthrough),
+        ]
+        .iter()
+        .copied()
+        {
+            let options = DecodeOptions::new().numeric_policy(policy);
+
+            assert_eq!(Some(policy), options.get_numeric_policy());
+            assert_eq!(
+                expect,
+                html_escape::decode_html_entities_with(text, &options).unwrap(),
+                "{:?}",
+                text
+            );
+        }
+    }
+
+    assert_eq!(
+        html_escape::decode_html_entities("&#x80;&#0;"),
+        html_escape::decode_html_entities_with(
+            "&#x80;&#0;",
+            &DecodeOptions::new().numeric_policy(NumericPolicy::Html)
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        None,
+        DecodeOptions::new().numeric_error_policy(NumericErrorPolicy::Keep).get_numeric_policy()
+    );
+    assert_eq!(Some(NumericPolicy::XmlStrict), DecodeOptions::XML.get_numeric_policy());
+    assert_eq!(NumericErrorPolicy::Keep, DecodeOptions::XML.get_numeric_error_policy());
+
+    let strict = DecodeOptions::new().numeric_policy(NumericPolicy::XmlStrict).strict(true);
+    let error = html_escape::decode_html_entities_with("a&#1;", &strict).unwrap_err();
+    assert_eq!(DecodeErrorKind::InvalidCodePoint, error.kind());
+    assert_eq!(1, error.offset());
+
+    let strict = DecodeOptions::new().numeric_policy(NumericPolicy::Passthrough).strict(true);
+    assert_eq!("\0", html_escape::decode_html_entities_with("&#0;", &strict).unwrap());
+
+    let strip = DecodeOptions::new()
+        .numeric_policy(NumericPolicy::XmlStrict)
+        .replacement_policy(html_escape::ReplacementPolicy::Strip);
+    assert_eq!("ab", html_escape::decode_html_entities_with("a&#1;b", &strip).unwrap());
+}
+
+const HTML4_NAMED_ENTITIES_CASES: [(&str, &str); 4] = [
+    ("&nbsp;&copy;&hearts;&Omega;", "\u{A0}\u{A9}\u{2665}\u{3A9}"),
+    ("&lang;&rang;", "\u{2329}\u{232A}"),
+    ("&NewLine;&fjlig;&apos;", "&NewLine;&fjlig;&apos;"),
+    ("&amp;&lt;&gt;&quot;", "&<>\""),
+];
+
+#[test]
+fn decode_html4_named_entities() {
+    use html_escape::{DecodeOptions, NamedEntitySet};
+
+    let options = DecodeOptions::new().named_entities(NamedEntitySet::Html4);
+
+    for (text, expect) in HTML4_NAMED_ENTITIES_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::decode_html_entities_with(text, &options).unwrap(),
+            "{:?}",
+            text
+        );
+    }
+
+    assert_eq!(
+        "\u{A9} &COPY",
+        html_escape::decode_html_entities_with("&copy &COPY", &options.require_semicolon(false))
+            .unwrap()
+    );
+    assert_eq!(
+        "\u{2665}",
+        html_escape::decode_html_entities_with("&HEARTS;", &options.case_insensitive(true))
+            .unwrap()
+    );
+    assert!(html_escape::HTML4_NAMED_ENTITIES.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[cfg(feature = "html4-entities")]
+const HTML4_FEATURE_CASES: [(&str, &str); 5] = [
+    ("&nbsp;&copy;&hearts;&Omega;", "\u{A0}\u{A9}\u{2665}\u{3A9}"),
+    ("&amp;&lt;&gt;&quot;", "&<>\""),
+    ("&AMP;&LT;&QUOT;", "&AMP;&LT;&QUOT;"),
+    ("&fjlig;&NotEqualTilde;&conint;", "&fjlig;&NotEqualTilde;&conint;"),
+    ("a &#60; &#x1F600; b", "a < \u{1F600} b"),
+];
+
+#[cfg(feature = "html4-entities")]
+#[test]
+fn decode_html_entities_html4_feature() {
+    for (text, expect) in HTML4_FEATURE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text), "{:?}", text);
+        assert_eq!(expect, html_escape::decode_html_entities_to_string(text, &mut String::new()));
+
+        let mut s = String::from(text);
+
+        html_escape::decode_html_entities_in_place(&mut s);
+        assert_eq!(expect, s);
+    }
+
+    assert_eq!(
+        "a & b < c \u{A9} 2024 \u{2209} \u{AC}inva &fjlig",
+        html_escape::decode_html_entities_legacy(
+            "a &amp b &lt c &copy 2024 &notin; &notinva &fjlig"
+        )
+    );
+
+    for c in ['\u{A9}', '\u{2192}', '\u{3A9}'].iter().copied() {
+        let entity = format!("&{};", html_escape::html4_named_entity_for(c).unwrap());
+
+        assert_eq!(c.to_string(), html_escape::decode_html_entities(&entity));
+    }
+}
+
+#[cfg(all(feature = "std", feature = "html4-entities"))]
+#[test]
+fn decode_html_entities_html4_feature_to_writer() {
+    for (text, expect) in HTML4_FEATURE_CASES.iter().copied() {
+        let mut v = Vec::new();
+
+        html_escape::decode_html_entities_to_writer(text, &mut v).unwrap();
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+#[test]
+fn decode_html_entities_with_nfc() {
+    use html_escape::DecodeOptions;
+
+    let options = DecodeOptions::new().normalize_nfc(true);
+
+    assert!(options.is_nfc_normalized());
+    assert_eq!(
+        "caf\u{E9}",
+        html_escape::decode_html_entities_with("cafe&#x301;", &options).unwrap()
+    );
+    assert_eq!(
+        "caf\u{E9}",
+        html_escape::decode_html_entities_with("cafe\u{301}", &options).unwrap()
+    );
+    assert_eq!(
+        "\u{E9}&foo;",
+        html_escape::decode_html_entities_with("&#101;&#769;&foo;", &options).unwrap()
+    );
+    assert_eq!(
+        "cafe\u{301}",
+        html_escape::decode_html_entities_with("cafe&#x301;", &DecodeOptions::new()).unwrap()
+    );
+
+    let mut s = String::from("x\u{301}");
+    assert_eq!(
+        "\u{E9}",
+        html_escape::decode_html_entities_with_to_string("e&#x301;", &options, &mut s).unwrap()
+    );
+    assert_eq!("x\u{301}\u{E9}", s);
+
+    let mut v = Vec::new();
+    html_escape::decode_html_entities_with_to_writer("A&#x30A;", &options, &mut v).unwrap();
+    assert_eq!("\u{C5}".as_bytes(), v.as_slice());
+}
+
+#[cfg(feature = "unicode-normalization")]
+#[test]
+fn decoder_with_nfc() {
+    use html_escape::{DecodeOptions, Decoder};
+
+    let options = DecodeOptions::new().normalize_nfc(true);
+
+    let chunks = ["a cafe", "&#x301; A", "\u{30A}", "&#x323; x&#x3", "01;e\u{323}", "&#x302;"];
+
+    for checked in [false, true].iter().copied() {
+        let mut decoder = Decoder::with_options(&options);
+        let mut s = String::new();
+
+        for chunk in chunks.iter() {
+            if checked {
+                s.extend(decoder.try_feed(chunk).unwrap());
+            } else {
+                s.extend(decoder.feed(chunk));
+            }
+        }
+
+        s.push_str(&decoder.finish());
+
+        assert_eq!(html_escape::decode_html_entities_with(&chunks.concat(), &options).unwrap(), s);
+        assert_eq!("a caf\u{E9} \u{1EA0}\u{30A} x\u{301}\u{1EC7}", s);
+    }
+}
+
+#[test]
+fn decode_html_entities_with_max_entity_name_length() {
+    use html_escape::{DecodeErrorKind, DecodeOptions, ReplacementPolicy};
+
+    let options = DecodeOptions::new();
+
+    assert_eq!(32, options.get_max_entity_name_length());
+
+    let long = format!("&{};", "a".repeat(40));
+
+    assert_eq!(long, html_escape::decode_html_entities_with(&long, &options.strict(true)).unwrap());
+    assert_eq!(
+        long,
+        html_escape::decode_html_entities_with(
+            &long,
+            &options.replacement_policy(ReplacementPolicy::Strip)
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        "",
+        html_escape::decode_html_entities_with(
+            &long,
+            &options.replacement_policy(ReplacementPolicy::Strip).max_entity_name_length(40)
+        )
+        .unwrap()
+    );
+
+    let unlimited = options.max_entity_name_length(usize::MAX);
+
+    assert_eq!("a & b", html_escape::decode_html_entities_with("a &amp; b", &unlimited).unwrap());
+    assert_eq!(
+        "\u{A9} &zzz;",
+        html_escape::decode_html_entities_with("&copy &zzz;", &unlimited.require_semicolon(false))
+            .unwrap()
+    );
+    assert_eq!(
+        " &",
+        html_escape::decode_html_entities_with(
+            "&zzz; &amp;",
+            &unlimited.replacement_policy(ReplacementPolicy::Strip)
+        )
+        .unwrap()
+    );
+
+    let short = options.max_entity_name_length(4);
+
+    assert_eq!(4, short.get_max_entity_name_length());
+    assert_eq!(
+        "& &hearts;",
+        html_escape::decode_html_entities_with("&amp; &hearts;", &short).unwrap()
+    );
+    assert_eq!(
+        DecodeErrorKind::UnknownNamedReference,
+        html_escape::decode_html_entities_with("&food;", &short.strict(true)).unwrap_err().kind()
+    );
+    assert_eq!(
+        "&foods;",
+        html_escape::decode_html_entities_with("&foods;", &short.strict(true)).unwrap()
+    );
+    assert_eq!(
+        "\u{A9}rights",
+        html_escape::decode_html_entities_with("&copyrights", &short.require_semicolon(false))
+            .unwrap()
+    );
+
+    let mut decoder = html_escape::Decoder::with_options(&short);
+    let mut s = String::new();
+
+    s.extend(decoder.feed("&hea"));
+    s.extend(decoder.feed("rts; &l"));
+    s.extend(decoder.feed("t;"));
+    s.push_str(&decoder.finish());
+    assert_eq!("&hearts; <", s);
+
+    let mut text = String::new();
+
+    for _ in 0..10_000 {
+        text.push('&');
+        text.push_str(&"a".repeat(100));
+    }
+
+    assert_eq!(text, html_escape::decode_html_entities(&text));
+}
+
+#[cfg(not(feature = "html4-entities"))]
+#[test]
+fn decode_html_entities_with_audit() {
+    use html_escape::{DecodeOptions, ReplacementPolicy};
+
+    let text = "&amp;lt; &#169; &nGt; &bogus; &#x110000;";
+    let options = DecodeOptions::new().replacement_policy(ReplacementPolicy::Strip);
+
+    let mut audited = Vec::new();
+
+    assert_eq!(
+        "&lt; \u{A9} \u{226B}\u{20D2}  ",
+        html_escape::decode_html_entities_with_audit(text, &options, |reference| {
+            audited.push((
+                reference.name().map(String::from),
+                reference.number(),
+                reference.span(),
+                reference.replacement().to_string(),
+            ));
+
+            assert_eq!(&text[reference.span()], reference.source());
+        })
+    );
+    assert_eq!(
+        vec![
+            (Some(String::from("amp")), None, 0..5, String::from("&")),
+            (None, Some(169), 9..15, String::from("\u{A9}")),
+            (Some(String::from("nGt")), None, 16..21, String::from("\u{226B}\u{20D2}")),
+            (Some(String::from("bogus")), None, 22..29, String::new()),
+            (None, Some(0x11_0000), 30..40, String::new()),
+        ],
+        audited
+    );
+
+    let mut count = 0;
+
+    assert_eq!(
+        "a & b",
+        html_escape::decode_html_entities_with_audit("a & b", &options, |_| count += 1)
+    );
+
+    let mut s = String::from("x");
+
+    assert_eq!(
+        "<",
+        html_escape::decode_html_entities_with_audit_to_string(
+            "&lt",
+            &options.require_semicolon(false),
+            |reference| {
+                assert_eq!(Some("lt"), reference.name());
+                count += 1;
+            },
+            &mut s
+        )
+    );
+    assert_eq!("x<", s);
+    assert_eq!(1, count);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_with_audit_to_writer() {
+    let mut spans = Vec::new();
+    let mut v = Vec::new();
+
+    html_escape::decode_html_entities_with_audit_to_writer(
+        "&lt;a&gt;",
+        &html_escape::DecodeOptions::new(),
+        |reference| spans.push(reference.span()),
+        &mut v,
+    )
+    .unwrap();
+
+    assert_eq!(b"<a>", v.as_slice());
+    assert_eq!(vec![0..4, 5..9], spans);
+}
+
+const DECODE_HREF_CASES: [(&str, &str); 6] = [
+    ("", ""),
+    ("https://magiclen.org/?q=1&amp;r=2#top", "https://magiclen.org/?q=1&r=2#top"),
+    ("/a%20b?c=%26&amp;d=%3D", "/a%20b?c=%26&d=%3D"),
+    ("?a=1&copy=2&not;&amp", "?a=1&copy=2\u{AC}&"),
+    ("/x?y=&#x25;41&amp;z", "/x?y=%41&z"),
+    ("https://magiclen.org/%E5%93%88%E5%9B%89", "https://magiclen.org/%E5%93%88%E5%9B%89"),
+];
+
+#[test]
+fn decode_href() {
+    for (text, expect) in DECODE_HREF_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_href(text));
+    }
+
+    for (expect, text) in HREF_CASES.iter().copied() {
+        if !expect.contains('%') {
+            assert_eq!(text, html_escape::decode_href(expect));
+        }
+    }
+}
+
+#[test]
+fn decode_href_to_string() {
+    for (text, expect) in DECODE_HREF_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_href_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_href_to_writer() {
+    for (text, expect) in DECODE_HREF_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_href_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn splice_decoded_region() {
+    use html_escape::DecodeOptions;
+
+    let options = DecodeOptions::new();
+
+    let text = "a &lt;b&gt; &amp; c";
+
+    assert_eq!(
+        "a &lt;B&gt; &amp; c",
+        html_escape::splice_decoded_region(text, 2..11, &options, |s| s.to_uppercase())
+    );
+    assert_eq!(
+        "a &lt;b&gt; &quot;&amp;&quot; c",
+        html_escape::splice_decoded_region(text, 12..17, &options, |s| format!("\"{}\"", s))
+    );
+
+    // a reference across a boundary is included in the region
+    assert_eq!(
+        "a &lt;b&gt; + c",
+        html_escape::splice_decoded_region(text, 14..18, &options, |s| {
+            assert_eq!("& ", s);
+
+            "+ "
+        })
+    );
+    assert_eq!(
+        "a x c",
+        html_escape::splice_decoded_region(text, 4..14, &options, |s| {
+            assert_eq!("<b> &", s);
+
+            "x"
+        })
+    );
+
+    // no reference is created at the boundaries
+    let text = "&amx;";
+
+    assert_eq!("&am&#x70;;", html_escape::splice_decoded_region(text, 3..4, &options, |_| "p"));
+    assert_eq!("&am&#x3B;", html_escape::splice_decoded_region(text, 3..4, &options, |_| ""));
+    assert_eq!("&am-;", html_escape::splice_decoded_region(text, 3..4, &options, |_| "-"));
+    assert_eq!(
+        "&amp;",
+        html_escape::decode_html_entities(&html_escape::splice_decoded_region(
+            text,
+            3..4,
+            &options,
+            |_| "p"
+        ))
+    );
+
+    let mut s = String::from("<");
+
+    assert_eq!(
+        "x &amp;amp; y",
+        html_escape::splice_decoded_region_to_string(
+            "x &amp;lt; y",
+            2..10,
+            &options,
+            |s| s.replace("lt", "amp"),
+            &mut s
+        )
+    );
+    assert_eq!("<x &amp;amp; y", s);
+}
+
+#[test]
+#[should_panic]
+fn splice_decoded_region_out_of_bounds() {
+    html_escape::splice_decoded_region("abc", 2..4, &html_escape::DecodeOptions::new(), |s| {
+        s.to_string()
+    });
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn splice_decoded_region_to_writer() {
+    let mut v = Vec::new();
+
+    html_escape::splice_decoded_region_to_writer(
+        "<b>&quot;x&quot;</b>",
+        3..16,
+        &html_escape::DecodeOptions::new(),
+        |s| s.replace('x', "<y>"),
+        &mut v,
+    )
+    .unwrap();
+
+    assert_eq!(b"<b>&quot;&lt;y&gt;&quot;</b>", v.as_slice());
+}
+
+#[test]
+fn visit_html_entities() {
+    use html_escape::{DecodeOptions, DecodeVisitor, EntityMatch};
+
+    #[derive(Default)]
+    struct Events(Vec<String>);
+
+    impl DecodeVisitor for Events {
+        fn on_text(&mut self, text: &str) {
+            self.0.push(format!("text {}", text));
+        }
+
+        fn on_entity(&mut self, entity: EntityMatch<'_>) {
+            self.0.push(format!(
+                "entity {} {:?} {}",
+                entity.raw(),
+                entity.range(),
+                entity.decoded()
+            ));
+        }
+    }
+
+    let mut events = Events::default();
+
+    html_escape::visit_html_entities("&lt;a &foo; b&#x26;&amp", &mut events);
+
+    assert_eq!(
+        vec!["entity &lt; 0..4 <", "text a &foo; b", "entity &#x26; 13..19 &", "text &amp",],
+        events.0
+    );
+
+    let mut events = Events::default();
+
+    html_escape::visit_html_entities_with(
+        "a&amp",
+        &DecodeOptions::new().require_semicolon(false),
+        &mut events,
+    );
+
+    assert_eq!(vec!["text a", "entity &amp 1..5 &"], events.0);
+
+    let mut s = String::from("x");
+
+    html_escape::visit_html_entities("&lt;&gt; &amp;", &mut s);
+    assert_eq!("x<> &", s);
+
+    let mut events = Events::default();
+
+    html_escape::visit_html_entities("", &mut events);
+    assert!(events.0.is_empty());
+}
+
+#[test]
+fn decode_html_entities_chunks() {
+    use std::borrow::Cow;
+
+    use html_escape::{DecodeOptions, ReplacementPolicy};
+
+    let chunks: Vec<Cow<str>> =
+        html_escape::decode_html_entities_chunks("&lt;&gt;a&foo;&#169;&amp").collect();
+
+    assert_eq!(vec!["<", ">", "a&foo;", "\u{A9}", "&amp"], chunks);
+    assert!(matches!(chunks[0], Cow::Borrowed(_)));
+    assert!(matches!(chunks[2], Cow::Borrowed(_)));
+    assert!(matches!(chunks[3], Cow::Owned(_)));
+
+    assert_eq!(0, html_escape::decode_html_entities_chunks("").count());
+    assert_eq!(vec!["abc"], html_escape::decode_html_entities_chunks("abc").collect::<Vec<_>>());
+
+    let options = DecodeOptions::new().replacement_policy(ReplacementPolicy::Strip);
+
+    assert_eq!(
+        vec!["a", "b"],
+        html_escape::decode_html_entities_chunks_with("a&foo;&bar;b&baz;", &options)
+            .collect::<Vec<_>>()
+    );
+
+    for text in ["", "&", "a &lt; b", "&amp;&amp;x&#x1F600;&nGt;"].iter() {
+        assert_eq!(
+            html_escape::decode_html_entities(text),
+            html_escape::decode_html_entities_chunks(text).collect::<String>()
+        );
+    }
+}
+
+#[test]
+fn encode_to_fmt_writer() {
+    use std::fmt::{self, Display, Formatter};
+
+    struct Escaped<'a>(&'a str);
+
+    impl<'a> Display for Escaped<'a> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            html_escape::encode_text_to_fmt_writer(self.0, f)
+        }
+    }
+
+    assert_eq!("<p>a &amp; &lt;b&gt;</p>", format!("<p>{}</p>", Escaped("a & <b>")));
+
+    let mut s = String::from("x");
+
+    html_escape::encode_double_quoted_attribute_to_fmt_writer("\"a\"", &mut s).unwrap();
+    html_escape::encode_safe_decimal_to_fmt_writer("<", &mut s).unwrap();
+    html_escape::encode_quoted_attribute_with_control_policy_to_fmt_writer(
+        "a\u{1}",
+        html_escape::ControlCharacterPolicy::Strip,
+        &mut s,
+    )
+    .unwrap();
+    html_escape::encode_script_to_fmt_writer("</script>", &mut s).unwrap();
+    assert_eq!("x&quot;a&quot;&lt;a<\\/script>", s);
+}
+
+#[test]
+fn encode_to_slice() {
+    let mut buffer = [0u8; 64];
+
+    let length = html_escape::encode_text_to_slice("a < b && \u{E9}", &mut buffer).unwrap();
+
+    assert_eq!("a &lt; b &amp;&amp; \u{E9}".as_bytes(), &buffer[..length]);
+
+    let length = html_escape::encode_unquoted_attribute_to_slice("a b=\"", &mut buffer).unwrap();
+
+    assert_eq!(b"a&#x20;b&#x3D;&quot;", &buffer[..length]);
+
+    let error = html_escape::encode_text_to_slice("ab<c", &mut buffer[..4]).unwrap_err();
+
+    assert_eq!(2, error.consumed());
+    assert_eq!(2, error.written());
+    assert_eq!(b"ab", &buffer[..2]);
+
+    let error = html_escape::encode_double_quoted_attribute_to_slice("a\u{E9}", &mut buffer[..2])
+        .unwrap_err();
+
+    assert_eq!(1, error.consumed());
+    assert_eq!(1, error.written());
+
+    let text = "<p class=\"\u{1F600}\">caf\u{E9} & co</p>";
+
+    for size in 6..20 {
+        let mut encoded = Vec::new();
+        let mut rest = text;
+
+        loop {
+            let mut buffer = vec![0u8; size];
+
+            match html_escape::encode_quoted_attribute_to_slice(rest, &mut buffer) {
+                Ok(length) => {
+                    encoded.extend_from_slice(&buffer[..length]);
+
+                    break;
+                }
+                Err(error) => {
+                    encoded.extend_from_slice(&buffer[..error.written()]);
+                    rest = &rest[error.consumed()..];
+                }
+            }
+        }
+
+        assert_eq!(html_escape::encode_quoted_attribute(text).as_bytes(), encoded.as_slice());
+    }
+}
+
+#[test]
+fn encode_families_to_slice() {
+    use html_escape::{BufferTooSmall, ControlCharacterPolicy};
+
+    type Encode = fn(&str, &mut [u8]) -> Result<usize, BufferTooSmall>;
+
+    let text = "<p title='\u{1F600}'>caf\u{E9} &amp; \u{1B}co &\u{A9}</p>";
+
+    let cases: [(Encode, String); 6] = [
+        (
+            |text, output| html_escape::encode_text_ascii_only_to_slice(text, output),
+            html_escape::encode_text_ascii_only(text).into(),
+        ),
+        (
+            |text, output| {
+                html_escape::encode_quoted_attribute_named_entities_to_slice(text, output)
+            },
+            html_escape::encode_quoted_attribute_named_entities(text).into(),
+        ),
+        (
+            |text, output| html_escape::encode_text_email_to_slice(text, output),
+            html_escape::encode_text_email(text).into(),
+        ),
+        (
+            |text, output| {
+                html_escape::encode_text_with_control_policy_to_slice(
+                    text,
+                    ControlCharacterPolicy::Escape,
+                    output,
+                )
+            },
+            html_escape::encode_text_with_control_policy(text, ControlCharacterPolicy::Escape)
+                .into(),
+        ),
+        (
+            |text, output| {
+                html_escape::encode_quoted_attribute_with_control_policy_to_slice(
+                    text,
+                    ControlCharacterPolicy::Replace,
+                    output,
+                )
+            },
+            html_escape::encode_quoted_attribute_with_control_policy(
+                text,
+                ControlCharacterPolicy::Replace,
+            )
+            .into(),
+        ),
+        (
+            |text, output| html_escape::encode_text_preserving_entities_to_slice(text, output),
+            html_escape::encode_text_preserving_entities(text).into(),
+        ),
+    ];
+
+    for (encode, expect) in cases.iter() {
+        for size in 10..24 {
+            let mut encoded = Vec::new();
+            let mut rest = text;
+
+            loop {
+                let mut buffer = vec![0u8; size];
+
+                match encode(rest, &mut buffer) {
+                    Ok(length) => {
+                        encoded.extend_from_slice(&buffer[..length]);
+
+                        break;
+                    }
+                    Err(error) => {
+                        assert!(error.written() <= size);
+
+                        encoded.extend_from_slice(&buffer[..error.written()]);
+                        rest = &rest[error.consumed()..];
+                    }
+                }
+            }
+
+            assert_eq!(expect.as_bytes(), encoded.as_slice());
+        }
+    }
+
+    let mut buffer = [0u8; 8];
+
+    let error =
+        html_escape::encode_text_ascii_only_to_slice("ab\u{1F600}", &mut buffer).unwrap_err();
+
+    assert_eq!(2, error.consumed());
+    assert_eq!(2, error.written());
+}
+
+#[test]
+fn encode_to_sink() {
+    use std::fmt;
+
+    use html_escape::FmtSink;
+
+    let mut s = String::from("> ");
+
+    html_escape::encode_text_to_sink("a < b && \u{E9}", &mut s).unwrap();
+
+    assert_eq!("> a &lt; b &amp;&amp; \u{E9}", s);
+
+    let mut v = Vec::new();
+
+    html_escape::encode_double_quoted_attribute_to_sink("\"a\"", &mut v).unwrap();
+
+    assert_eq!(b"&quot;a&quot;", v.as_slice());
+
+    struct Limited(String, usize);
+
+    impl fmt::Write for Limited {
+        fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+            if self.0.len() + s.len() > self.1 {
+                return Err(fmt::Error);
+            }
+
+            self.0.push_str(s);
+
+            Ok(())
+        }
+    }
+
+    let mut sink = FmtSink(Limited(String::new(), 8));
+
+    html_escape::encode_xml_text_to_sink("a<b", &mut sink).unwrap();
+
+    assert_eq!("a&lt;b", sink.0 .0);
+
+    assert!(html_escape::encode_xml_text_to_sink("&", &mut sink).is_err());
+
+    assert_eq!("a&lt;b", sink.0 .0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_to_io_sink() {
+    use html_escape::IoSink;
+
+    let mut sink = IoSink(Vec::new());
+
+    html_escape::encode_safe_to_sink("<'a'>", &mut sink).unwrap();
+
+    assert_eq!(html_escape::encode_safe("<'a'>").as_bytes(), sink.0.as_slice());
+}
+
+#[test]
+fn encode_families_to_sink() {
+    let text = "a <b> & \u{0}\u{E9}\u{1F600} 'c' \"d\" &amp; e=f\n";
+
+    let mut s = String::new();
+    html_escape::encode_text_ascii_only_to_sink(text, &mut s).unwrap();
+    assert_eq!(html_escape::encode_text_ascii_only(text), s);
+
+    for (policy, _) in CONTROL_POLICY_CASES.iter().copied() {
+        let mut s = String::new();
+        html_escape::encode_text_with_control_policy_to_sink(text, policy, &mut s).unwrap();
+        assert_eq!(html_escape::encode_text_with_control_policy(text, policy), s);
+    }
+
+    let mut s = String::new();
+    html_escape::encode_text_preserving_entities_to_sink(text, &mut s).unwrap();
+    assert_eq!(html_escape::encode_text_preserving_entities(text), s);
+
+    let mut s = String::new();
+    html_escape::encode_unquoted_attribute_to_sink(text, &mut s).unwrap();
+    assert_eq!(html_escape::encode_unquoted_attribute(text), s);
+
+    let mut s = String::new();
+    html_escape::encode_href_to_sink(text, &mut s).unwrap();
+    assert_eq!(html_escape::encode_href(text), s);
+
+    let mut s = String::new();
+    html_escape::text_to_html_to_sink(text, &mut s).unwrap();
+    assert_eq!(html_escape::text_to_html(text), s);
+
+    let mut v = Vec::new();
+    html_escape::encode_text_lossy_to_sink(b"a<\xFF", &mut v).unwrap();
+    assert_eq!(b"a&lt;\xEF\xBF\xBD", v.as_slice());
+}
+
+#[test]
+fn encode_text_lossy() {
+    use std::borrow::Cow;
+
+    assert!(matches!(html_escape::encode_text_lossy("caf\u{E9}".as_bytes()), Cow::Borrowed(_)));
+    assert_eq!(b"a &lt; b", html_escape::encode_text_lossy(b"a < b").as_ref());
+
+    let text_bytes = b"<\xFF>\xE2\x82 & \xC3";
+    let expect = "&lt;\u{FFFD}&gt;\u{FFFD} &amp; \u{FFFD}";
+
+    assert_eq!(expect.as_bytes(), html_escape::encode_text_lossy(text_bytes).as_ref());
+
+    let mut s = String::from("> ");
+
+    assert_eq!(expect, html_escape::encode_text_lossy_to_string(text_bytes, &mut s));
+    assert_eq!(format!("> {}", expect), s);
+
+    let mut s = String::new();
+
+    html_escape::encode_text_lossy_to_fmt_writer(text_bytes, &mut s).unwrap();
+    assert_eq!(expect, s);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_text_lossy_to_writer() {
+    let mut v = Vec::new();
+
+    html_escape::encode_text_lossy_to_writer(b"<\xFF>", &mut v).unwrap();
+    assert_eq!("&lt;\u{FFFD}&gt;".as_bytes(), v.as_slice());
+}
+
+#[test]
+fn encode_text_char() {
+    let text = "a < \u{1F600} & \u{E9}>\0";
+
+    let encoded: String =
+        text.chars().map(|c| html_escape::encode_text_char(c).to_string()).collect();
+
+    assert_eq!(html_escape::encode_text(text), encoded);
+
+    let mut s = String::new();
+
+    for c in text.chars() {
+        s.push_str(html_escape::encode_text_char(c).as_ref());
+    }
+
+    assert_eq!(html_escape::encode_text(text), s);
+
+    assert_eq!(html_escape::encode_text_char('&'), html_escape::encode_text_char('&'));
+    assert_ne!(html_escape::encode_text_char('<'), html_escape::encode_text_char('>'));
+}
+
+#[test]
+fn encode_text_utf16() {
+    use html_escape::LoneSurrogatePolicy;
+
+    let utf16 = |text: &str| text.encode_utf16().collect::<Vec<u16>>();
+
+    let text = utf16("a < \u{1F600} & \u{E9}>");
+
+    assert_eq!(
+        utf16("a &lt; \u{1F600} &amp; \u{E9}&gt;"),
+        html_escape::encode_text_utf16(&text, LoneSurrogatePolicy::Replace)
+    );
+
+    let text = [0x3C, 0xDC00, 0x26, 0xD83D];
+
+    let mut keep: Vec<u16> = utf16("&lt;");
+    keep.push(0xDC00);
+    keep.extend(utf16("&amp;"));
+    keep.push(0xD83D);
+
+    assert_eq!(keep, html_escape::encode_text_utf16(&text, LoneSurrogatePolicy::Keep));
+    assert_eq!(
+        utf16("&lt;\u{FFFD}&amp;\u{FFFD}"),
+        html_escape::encode_text_utf16(&text, LoneSurrogatePolicy::Replace)
+    );
+
+    let mut v = utf16("> ");
+
+    assert_eq!(
+        utf16("&lt;\u{FFFD}&amp;\u{FFFD}").as_slice(),
+        html_escape::encode_text_utf16_to_vec(&text, LoneSurrogatePolicy::Replace, &mut v)
+    );
+    assert_eq!(utf16("> &lt;\u{FFFD}&amp;\u{FFFD}"), v);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_os_str() {
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    use html_escape::InvalidUtf8Policy;
+
+    assert!(matches!(
+        html_escape::encode_os_str(OsStr::new("caf\u{E9}"), InvalidUtf8Policy::Keep),
+        Cow::Borrowed(_)
+    ));
+
+    let path = Path::new("src/<a> & b.rs");
+
+    assert_eq!(
+        b"src/&lt;a&gt; &amp; b.rs",
+        html_escape::encode_path(path, InvalidUtf8Policy::Replace).as_ref()
+    );
+
+    let mut v = Vec::new();
+
+    html_escape::encode_path_to_writer(path, InvalidUtf8Policy::Keep, &mut v).unwrap();
+    assert_eq!(b"src/&lt;a&gt; &amp; b.rs", v.as_slice());
+}
+
+#[cfg(all(feature = "std", unix))]
+#[test]
+fn encode_os_str_unix() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    use html_escape::InvalidUtf8Policy;
+
+    let text = OsStr::from_bytes(b"<\xFF>.txt");
+
+    assert_eq!(
+        b"&lt;\xFF&gt;.txt",
+        html_escape::encode_os_str(text, InvalidUtf8Policy::Keep).as_ref()
+    );
+    assert_eq!(
+        "&lt;\u{FFFD}&gt;.txt".as_bytes(),
+        html_escape::encode_os_str(text, InvalidUtf8Policy::Replace).as_ref()
+    );
+
+    let mut v = b"> ".to_vec();
+
+    assert_eq!(
+        b"&lt;\xFF&gt;.txt",
+        html_escape::encode_os_str_to_vec(text, InvalidUtf8Policy::Keep, &mut v)
+    );
+    assert_eq!(b"> &lt;\xFF&gt;.txt", v.as_slice());
+
+    let mut v = Vec::new();
+
+    html_escape::encode_os_str_to_writer(text, InvalidUtf8Policy::Replace, &mut v).unwrap();
+    assert_eq!("&lt;\u{FFFD}&gt;.txt".as_bytes(), v.as_slice());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn escaping_writer() {
+    use std::io::{self, Write};
+
+    use html_escape::{EscapeContext, EscapingWriter};
+
+    let mut writer = EscapingWriter::new(Vec::new(), EscapeContext::UnquotedAttribute);
+
+    for byte in "a b\u{20AC}\u{1F600}=".as_bytes() {
+        writer.write_all(&[*byte]).unwrap();
+    }
+
+    assert_eq!("a&#x20;b\u{20AC}\u{1F600}&#x3D;".as_bytes(), writer.finish().unwrap().as_slice());
+
+    let mut writer = EscapingWriter::new(Vec::new(), EscapeContext::DoubleQuotedAttribute);
+
+    io::copy(&mut "\"caf\u{E9}\" & <b>".as_bytes(), &mut writer).unwrap();
+    assert_eq!(b"&quot;caf\xC3\xA9&quot; &amp; &lt;b&gt;", writer.get_ref().as_slice());
+
+    let mut writer = EscapingWriter::new(Vec::new(), EscapeContext::Text);
+
+    writer.write_all(b"<\xE2\x82").unwrap();
+    assert_eq!(b"&lt;", writer.get_ref().as_slice());
+    assert_eq!(io::ErrorKind::InvalidData, writer.finish().unwrap_err().kind());
+
+    let mut writer = EscapingWriter::new(Vec::new(), EscapeContext::Text);
+
+    assert_eq!(io::ErrorKind::InvalidData, writer.write_all(b"a\xFFb").unwrap_err().kind());
+    assert_eq!(io::ErrorKind::InvalidData, writer.write_all(b"\xE2a").unwrap_err().kind());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_text_from_reader() {
+    use std::io::{self, BufReader};
+
+    use html_escape::EscapeContext;
+
+    let text = "<p class=\"\u{1F600}\">caf\u{E9} & co</p>".repeat(100);
+
+    for capacity in 1..8 {
+        let mut output = Vec::new();
+
+        let length = html_escape::encode_text_from_reader(
+            BufReader::with_capacity(capacity, text.as_bytes()),
+            &mut output,
+            EscapeContext::QuotedAttribute,
+        )
+        .unwrap();
+
+        assert_eq!(text.len() as u64, length);
+        assert_eq!(html_escape::encode_quoted_attribute(&text).as_bytes(), output.as_slice());
+    }
+
+    let mut output = Vec::new();
+
+    let error = html_escape::encode_text_from_reader(
+        BufReader::with_capacity(2, &b"<a\xE2\x82"[..]),
+        &mut output,
+        EscapeContext::Text,
+    )
+    .unwrap_err();
+
+    assert_eq!(io::ErrorKind::InvalidData, error.kind());
+    assert_eq!(b"&lt;a", output.as_slice());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn escaping_reader() {
+    use std::io::{self, Read};
+
+    use html_escape::{EscapeContext, EscapingReader};
+
+    struct ByteReader<'a>(&'a [u8]);
+
+    impl<'a> Read for ByteReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.split_first() {
+                Some((&byte, rest)) if !buf.is_empty() => {
+                    buf[0] = byte;
+                    self.0 = rest;
+
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    let text = "a b\u{20AC}\u{1F600}=\"";
+
+    let mut reader =
+        EscapingReader::new(ByteReader(text.as_bytes()), EscapeContext::UnquotedAttribute);
+    let mut s = String::new();
+
+    reader.read_to_string(&mut s).unwrap();
+    assert_eq!("a&#x20;b\u{20AC}\u{1F600}&#x3D;&quot;", s);
+
+    let mut reader = EscapingReader::new(text.as_bytes(), EscapeContext::UnquotedAttribute);
+    let mut v = Vec::new();
+    let mut buffer = [0u8; 1];
+
+    while reader.read(&mut buffer).unwrap() == 1 {
+        v.push(buffer[0]);
+    }
+
+    assert_eq!(s.as_bytes(), v.as_slice());
+
+    let long = "<&>".repeat(5000);
+    let mut s = String::new();
+
+    EscapingReader::new(long.as_bytes(), EscapeContext::Text).read_to_string(&mut s).unwrap();
+    assert_eq!("&lt;&amp;&gt;".repeat(5000), s);
+
+    let mut v = Vec::new();
+
+    assert_eq!(
+        io::ErrorKind::InvalidData,
+        EscapingReader::new(&b"<\xE2\x82"[..], EscapeContext::Text)
+            .read_to_end(&mut v)
+            .unwrap_err()
+            .kind()
+    );
+    assert_eq!(b"&lt;", v.as_slice());
+
+    assert_eq!(
+        io::ErrorKind::InvalidData,
+        EscapingReader::new(&b"a\xFFb"[..], EscapeContext::Text)
+            .read_to_end(&mut Vec::new())
+            .unwrap_err()
+            .kind()
+    );
+}
+
+#[test]
+fn decode_to_fmt_writer() {
+    use html_escape::{DecodeErrorKind, DecodeOptions};
+
+    let mut s = String::new();
+
+    html_escape::decode_html_entities_to_fmt_writer("&lt;a&gt; ", &mut s).unwrap();
+    html_escape::decode_script_to_fmt_writer("<\\/script>", &mut s).unwrap();
+    assert_eq!("<a> </script>", s);
+
+    let mut s = String::from("x");
+
+    assert_eq!(
+        DecodeErrorKind::UnknownNamedReference,
+        html_escape::try_decode_html_entities_to_fmt_writer("a &foo;", &mut s).unwrap_err().kind()
+    );
+    assert_eq!("x", s);
+    assert!(html_escape::try_decode_html_entities_to_fmt_writer("&amp;", &mut s).unwrap().is_ok());
+    assert_eq!("x&", s);
+
+    let stats = html_escape::decode_html_entities_with_stats_to_fmt_writer(
+        "&#60;&gt;",
+        &DecodeOptions::new(),
+        &mut s,
+    )
+    .unwrap();
+
+    assert_eq!(1, stats.named_references());
+    assert_eq!("x&<>", s);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decoding_writer() {
+    use std::io::{self, Write};
+
+    use html_escape::{DecodeErrorKind, DecodeOptions, DecodingWriter};
+
+    let text = "&lt;a&gt; &#x1F600;\u{20AC} &amp;copy &notit; &amp";
+
+    let mut writer = DecodingWriter::new(Vec::new());
+
+    for byte in text.as_bytes() {
+        writer.write_all(&[*byte]).unwrap();
+    }
+
+    assert_eq!(
+        html_escape::decode_html_entities(text).as_bytes(),
+        writer.finish().unwrap().as_slice()
+    );
+
+    let mut writer = DecodingWriter::new(Vec::new());
+
+    io::copy(&mut text.as_bytes(), &mut writer).unwrap();
+    assert_eq!(html_escape::decode_html_entities(text).as_bytes(), writer.finish().unwrap());
+
+    let mut writer = DecodingWriter::with_options(Vec::new(), &DecodeOptions::new().strict(true));
+
+    writer.write_all(b"a &lt").unwrap();
+
+    let error = writer.write_all(b" b").unwrap_err();
+
+    assert_eq!(io::ErrorKind::InvalidData, error.kind());
+    assert_eq!(
+        DecodeErrorKind::MissingSemicolon,
+        error.get_ref().unwrap().downcast_ref::<html_escape::DecodeError>().unwrap().kind()
+    );
+
+    let mut writer = DecodingWriter::new(Vec::new());
+
+    writer.write_all(b"&lt;\xE2\x82").unwrap();
+    assert_eq!(io::ErrorKind::InvalidData, writer.finish().unwrap_err().kind());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_html_entities_from_reader() {
+    use std::io::{self, BufReader};
+
+    use html_escape::{DecodeErrorKind, DecodeOptions};
+
+    let text = "&lt;a&gt; &#x1F600;\u{20AC} &amp;copy &notit; &amp".repeat(100);
+
+    for capacity in 1..8 {
+        let mut output = Vec::new();
+
+        let length = html_escape::decode_html_entities_from_reader(
+            BufReader::with_capacity(capacity, text.as_bytes()),
+            &mut output,
+        )
+        .unwrap();
+
+        assert_eq!(text.len() as u64, length);
+        assert_eq!(html_escape::decode_html_entities(&text).as_bytes(), output.as_slice());
+    }
+
+    let options = DecodeOptions::new().require_semicolon(false);
+
+    let mut output = Vec::new();
+
+    html_escape::decode_html_entities_from_reader_with(
+        BufReader::with_capacity(2, &b"&copy &amp"[..]),
+        &mut output,
+        &options,
+    )
+    .unwrap();
+
+    assert_eq!("\u{A9} &".as_bytes(), output.as_slice());
+
+    let error = html_escape::decode_html_entities_from_reader_with(
+        BufReader::with_capacity(2, &b"a &lt b"[..]),
+        Vec::new(),
+        &DecodeOptions::new().strict(true),
+    )
+    .unwrap_err();
+
+    assert_eq!(io::ErrorKind::InvalidData, error.kind());
+    assert_eq!(
+        DecodeErrorKind::MissingSemicolon,
+        error.get_ref().unwrap().downcast_ref::<html_escape::DecodeError>().unwrap().kind()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decoding_reader() {
+    use std::io::{self, Read};
+
+    use html_escape::{DecodeOptions, DecodingReader};
+
+    struct ByteReader<'a>(&'a [u8]);
+
+    impl<'a> Read for ByteReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.split_first() {
+                Some((&byte, rest)) if !buf.is_empty() => {
+                    buf[0] = byte;
+                    self.0 = rest;
+
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    let text = "&lt;a&gt; &#x1F600;\u{20AC} &amp;copy &notit; &amp";
+
+    let mut reader = DecodingReader::new(ByteReader(text.as_bytes()));
+    let mut s = String::new();
+
+    reader.read_to_string(&mut s).unwrap();
+    assert_eq!(html_escape::decode_html_entities(text), s);
+
+    let mut reader = DecodingReader::new(text.as_bytes());
+    let mut v = Vec::new();
+    let mut buffer = [0u8; 1];
+
+    while reader.read(&mut buffer).unwrap() == 1 {
+        v.push(buffer[0]);
+    }
+
+    assert_eq!(s.as_bytes(), v.as_slice());
+
+    let long = "&lt;&amp;&gt;".repeat(5000);
+    let mut s = String::new();
+
+    DecodingReader::new(long.as_bytes()).read_to_string(&mut s).unwrap();
+    assert_eq!("<&>".repeat(5000), s);
+
+    assert_eq!(
+        io::ErrorKind::InvalidData,
+        DecodingReader::with_options(&b"a &foo;"[..], &DecodeOptions::new().strict(true))
+            .read_to_end(&mut Vec::new())
+            .unwrap_err()
+            .kind()
+    );
+
+    let mut v = Vec::new();
+
+    assert_eq!(
+        io::ErrorKind::InvalidData,
+        DecodingReader::new(&b"&lt;\xFFb"[..]).read_to_end(&mut v).unwrap_err().kind()
+    );
+    assert_eq!(b"<", v.as_slice());
+}
+
+#[test]
+fn decode_to_slice() {
+    let mut buffer = [0u8; 64];
+
+    let length =
+        html_escape::decode_html_entities_to_slice("&lt;a&gt; &#x1F600; &amp", &mut buffer)
+            .unwrap();
+
+    assert_eq!("<a> \u{1F600} &amp".as_bytes(), &buffer[..length]);
+
+    let length =
+        html_escape::decode_html_entities_legacy_to_slice("&copy &amp", &mut buffer).unwrap();
+
+    assert_eq!("\u{A9} &".as_bytes(), &buffer[..length]);
+
+    let length =
+        html_escape::decode_html_entities_in_attribute_to_slice("?a=1&copy=2&lt", &mut buffer)
+            .unwrap();
+
+    assert_eq!(b"?a=1&copy=2<", &buffer[..length]);
+
+    let error =
+        html_escape::decode_html_entities_to_slice("ab&#x1F600;", &mut buffer[..5]).unwrap_err();
+
+    assert_eq!(2, error.consumed());
+    assert_eq!(2, error.written());
+
+    let text = "&lt;p class=&quot;\u{1F600}&quot;&gt;caf&eacute; &amp; co";
+
+    for size in 4..20 {
+        let mut decoded = Vec::new();
+        let mut rest = text;
+
+        loop {
+            let mut buffer = vec![0u8; size];
+
+            match html_escape::decode_html_entities_to_slice(rest, &mut buffer) {
+                Ok(length) => {
+                    decoded.extend_from_slice(&buffer[..length]);
+
+                    break;
+                }
+                Err(error) => {
+                    decoded.extend_from_slice(&buffer[..error.written()]);
+                    rest = &rest[error.consumed()..];
+                }
+            }
+        }
+
+        assert_eq!(html_escape::decode_html_entities(text).as_bytes(), decoded.as_slice());
+    }
+}
+
+#[test]
+fn decode_html_entities_utf16() {
+    use html_escape::LoneSurrogatePolicy;
+
+    let utf16 = |text: &str| text.encode_utf16().collect::<Vec<u16>>();
+
+    let text = "&lt;a&gt; &#x1F600; &amp;copy \u{E9} &amp";
+
+    assert_eq!(
+        utf16(&html_escape::decode_html_entities(text)),
+        html_escape::decode_html_entities_utf16(&utf16(text), LoneSurrogatePolicy::Replace)
+    );
+
+    let mut text = utf16("&lt;");
+    text.push(0xDC00);
+    text.extend(utf16("&amp;"));
+
+    let mut keep = utf16("<");
+    keep.push(0xDC00);
+    keep.extend(utf16("&"));
+
+    assert_eq!(keep, html_escape::decode_html_entities_utf16(&text, LoneSurrogatePolicy::Keep));
+    assert_eq!(
+        utf16("<\u{FFFD}&"),
+        html_escape::decode_html_entities_utf16(&text, LoneSurrogatePolicy::Replace)
+    );
+
+    let mut v = utf16("> ");
+
+    html_escape::decode_html_entities_utf16_to_vec(&text, LoneSurrogatePolicy::Replace, &mut v);
+    assert_eq!(utf16("> <\u{FFFD}&"), v);
+}
+
+#[test]
+fn decode_to_sink() {
+    let mut s = String::new();
+
+    html_escape::decode_html_entities_to_sink("&lt;a&gt; &#x1F600; &amp", &mut s).unwrap();
+
+    assert_eq!("<a> \u{1F600} &amp", s);
+
+    let mut v = Vec::new();
+
+    html_escape::decode_html_entities_in_attribute_to_sink("?a=1&copy=2&lt", &mut v).unwrap();
+
+    assert_eq!(b"?a=1&copy=2<", v.as_slice());
+
+    let mut sink = html_escape::FmtSink(String::new());
+
+    html_escape::decode_numeric_entities_to_sink("&#38; &amp;", &mut sink).unwrap();
+
+    assert_eq!("& &amp;", sink.0);
+}
@@ -0,0 +1,161 @@
+extern crate html_escape;
+
+const COMMENT_CASES: [(&str, &str); 10] = [
+    ("", ""),
+    ("哈囉，中文！", "哈囉，中文！"),
+    (" a - b ", " a - b "),
+    ("a-&#x2D;b", "a--b"),
+    ("a-&#x2D;-b", "a---b"),
+    ("&gt; a", "> a"),
+    ("&#x2D;> a", "-> a"),
+    ("a &#x2D;", "a -"),
+    ("<!-&#x2D; -&#x2D;-&#x2D;!>", "<!-- ----!>"),
+    ("&amp;lt;", "&lt;"),
+];
+
+#[test]
+fn encode_comment() {
+    for (expect, text) in COMMENT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_comment(text));
+    }
+}
+
+#[test]
+fn encode_comment_to_string() {
+    for (expect, text) in COMMENT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_comment_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_comment_to_writer() {
+    for (expect, text) in COMMENT_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_comment_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_comment() {
+    for (text, expect) in COMMENT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_comment(text));
+    }
+
+    assert_eq!("a &copy; &#x2d; b", html_escape::decode_comment("a &copy; &#x2d; b"));
+}
+
+#[test]
+fn decode_comment_to_string() {
+    for (text, expect) in COMMENT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_comment_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_comment_to_writer() {
+    for (text, expect) in COMMENT_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_comment_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const CDATA_CASES: [(&str, &str); 6] = [
+    ("", ""),
+    ("哈囉，中文！", "哈囉，中文！"),
+    ("<p>a &amp; b</p>", "<p>a &amp; b</p>"),
+    ("a]]]]><![CDATA[>b", "a]]>b"),
+    ("]]]]]><![CDATA[>]]]]><![CDATA[>", "]]]>]]>"),
+    ("]] > ]>", "]] > ]>"),
+];
+
+#[test]
+fn encode_cdata() {
+    for (expect, text) in CDATA_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_cdata(text));
+    }
+}
+
+#[test]
+fn encode_cdata_to_string() {
+    for (expect, text) in CDATA_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_cdata_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_cdata_to_writer() {
+    for (expect, text) in CDATA_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_cdata_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const PROCESSING_INSTRUCTION_CASES: [(&str, &str); 6] = [
+    ("", ""),
+    ("哈囉，中文！", "哈囉，中文！"),
+    ("href=\"a.css\" type=\"text/css\"", "href=\"a.css\" type=\"text/css\""),
+    ("a ?&gt; b", "a ?> b"),
+    ("??&gt;?&gt;", "??>?>"),
+    ("? > >", "? > >"),
+];
+
+#[test]
+fn encode_processing_instruction() {
+    for (expect, text) in PROCESSING_INSTRUCTION_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_processing_instruction(text));
+    }
+}
+
+#[test]
+fn encode_processing_instruction_to_string() {
+    for (expect, text) in PROCESSING_INSTRUCTION_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_processing_instruction_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_processing_instruction_to_writer() {
+    for (expect, text) in PROCESSING_INSTRUCTION_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_processing_instruction_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn encode_to_sink() {
+    for (expect, text) in COMMENT_CASES.iter().copied() {
+        let mut s = String::new();
+        html_escape::encode_comment_to_sink(text, &mut s).unwrap();
+
+        assert_eq!(expect, s);
+    }
+
+    for (expect, text) in CDATA_CASES.iter().copied() {
+        let mut s = String::new();
+        html_escape::encode_cdata_to_sink(text, &mut s).unwrap();
+
+        assert_eq!(expect, s);
+    }
+
+    for (expect, text) in PROCESSING_INSTRUCTION_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_processing_instruction_to_sink(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
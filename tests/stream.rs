@@ -0,0 +1,130 @@
+#![cfg(feature = "futures-core")]
+
+extern crate html_escape;
+
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+
+use html_escape::{
+    DecodeErrorKind, DecodeOptions, DecodeStream, EscapeContext, EscapeStream, TryDecodeStream,
+};
+
+/// A stream of chunks which is pending before every chunk.
+struct Chunks {
+    chunks: Vec<String>,
+    ready: bool,
+}
+
+impl Chunks {
+    fn new(chunks: &[&str]) -> Chunks {
+        Chunks {
+            chunks: chunks.iter().map(|chunk| chunk.to_string()).collect(),
+            ready: false,
+        }
+    }
+}
+
+impl Stream for Chunks {
+    type Item = String;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<String>> {
+        self.ready = !self.ready;
+
+        if !self.ready {
+            cx.waker().wake_by_ref();
+
+            return Poll::Pending;
+        }
+
+        Poll::Ready(if self.chunks.is_empty() {
+            None
+        } else {
+            Some(self.chunks.remove(0))
+        })
+    }
+}
+
+fn collect<T, St: Stream<Item = T> + Unpin>(mut stream: St) -> Vec<T> {
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut chunks = Vec::new();
+
+    loop {
+        match Pin::new(&mut stream).poll_next(&mut cx) {
+            Poll::Ready(Some(chunk)) => chunks.push(chunk),
+            Poll::Ready(None) => return chunks,
+            Poll::Pending => (),
+        }
+    }
+}
+
+#[test]
+fn escape_stream() {
+    assert_eq!(
+        vec!["&lt;b&gt;", "", "a&#x20;&amp;&#x20;b"],
+        collect(EscapeStream::new(
+            Chunks::new(&["<b>", "", "a & b"]),
+            EscapeContext::UnquotedAttribute
+        ))
+    );
+}
+
+#[test]
+fn decode_stream() {
+    let chunks = ["a &l", "t", "; &#x1F6", "00; &not", "it; &amp"];
+
+    let decoded = collect(DecodeStream::new(Chunks::new(&chunks)));
+
+    assert_eq!(vec!["a ", "< ", "\u{1F600} ", "&notit; ", "&amp"], decoded);
+    assert_eq!(html_escape::decode_html_entities(&chunks.concat()), decoded.concat());
+
+    assert_eq!(
+        vec!["?a=1", "&copy=2"],
+        collect(DecodeStream::with_options(
+            Chunks::new(&["?a=1&co", "py=2"]),
+            &DecodeOptions::new().attribute(true)
+        ))
+    );
+}
+
+#[test]
+fn try_decode_stream() {
+    let chunks = ["a &l", "t", "; &#x1F6", "00; &not", "it;"];
+
+    let decoded: Result<Vec<String>, _> =
+        collect(TryDecodeStream::new(Chunks::new(&chunks), &DecodeOptions::new()))
+            .into_iter()
+            .collect();
+
+    assert_eq!(vec!["a ", "< ", "\u{1F600} ", "&notit;"], decoded.unwrap());
+
+    let decoded = collect(TryDecodeStream::new(
+        Chunks::new(&["a &l", "t; &foo;", " b"]),
+        &DecodeOptions::new().strict(true),
+    ));
+
+    assert_eq!(2, decoded.len());
+    assert_eq!(Ok("a "), decoded[0].as_deref());
+
+    let error = decoded[1].as_ref().unwrap_err();
+
+    assert_eq!(DecodeErrorKind::UnknownNamedReference, error.kind());
+    assert_eq!(7, error.offset());
+
+    let decoded = collect(TryDecodeStream::new(
+        Chunks::new(&["a ", "&amp"]),
+        &DecodeOptions::new().strict(true),
+    ));
+
+    assert_eq!(DecodeErrorKind::MissingSemicolon, decoded[1].as_ref().unwrap_err().kind());
+
+    let decoded = collect(TryDecodeStream::new(
+        Chunks::new(&["abc", "&lt;de", "f"]),
+        &DecodeOptions::new().max_output_length(5),
+    ));
+
+    assert_eq!(Ok("abc"), decoded[0].as_deref());
+    assert_eq!(DecodeErrorKind::OutputLimitExceeded, decoded[1].as_ref().unwrap_err().kind());
+    assert_eq!(2, decoded.len());
+}
@@ -0,0 +1,206 @@
+extern crate html_escape;
+
+const XML_TEXT_CASES: [(&str, &str); 6] = [
+    ("", ""),
+    ("哈囉，中文！", "哈囉，中文！"),
+    ("麵包 &amp; butter", "麵包 & butter"),
+    ("\"bread\" 'and' 奶油", "\"bread\" 'and' 奶油"),
+    ("&lt;item&gt;]]&gt;", "<item>]]>"),
+    ("\u{00A0}©", "\u{00A0}©"),
+];
+
+#[test]
+fn encode_xml_text() {
+    for (expect, text) in XML_TEXT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_xml_text(text));
+    }
+}
+
+#[test]
+fn encode_xml_text_to_string() {
+    for (expect, text) in XML_TEXT_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_xml_text_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_xml_text_to_writer() {
+    for (expect, text) in XML_TEXT_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_xml_text_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const XML_ATTRIBUTE_CASES: [(&str, &str); 6] = [
+    ("", ""),
+    ("哈囉，中文！", "哈囉，中文！"),
+    ("麵包 &amp; butter", "麵包 & butter"),
+    ("&quot;bread&quot; &apos;and&apos; 奶油", "\"bread\" 'and' 奶油"),
+    ("&lt;item&gt;", "<item>"),
+    ("a&#x9;b&#xD;&#xA;c", "a\tb\r\nc"),
+];
+
+#[test]
+fn encode_xml_attribute() {
+    for (expect, text) in XML_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_xml_attribute(text));
+    }
+}
+
+#[test]
+fn encode_xml_attribute_to_string() {
+    for (expect, text) in XML_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_xml_attribute_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_xml_attribute_to_writer() {
+    for (expect, text) in XML_ATTRIBUTE_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_xml_attribute_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+const HTML_IN_XML_CASES: [(&str, &str); 3] = [
+    ("&lt;p&gt;Hello&lt;/p&gt;", "<p>Hello</p>"),
+    ("&lt;p&gt;Tom &amp;amp; Jerry&lt;/p&gt;", "<p>Tom &amp; Jerry</p>"),
+    ("&lt;a href=\"/?a=1&amp;amp;b=2\"&gt;link&lt;/a&gt;", "<a href=\"/?a=1&amp;b=2\">link</a>"),
+];
+
+#[test]
+fn encode_html_in_xml() {
+    for (expect, text) in HTML_IN_XML_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_html_in_xml(text));
+    }
+}
+
+#[test]
+fn encode_html_in_xml_to_string() {
+    for (expect, text) in HTML_IN_XML_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_html_in_xml_to_string(text, &mut String::new()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_html_in_xml_to_writer() {
+    for (expect, text) in HTML_IN_XML_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_html_in_xml_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_html_in_xml() {
+    for (text, expect) in HTML_IN_XML_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_html_entities(text));
+    }
+}
+
+const FOREIGN_ATTRIBUTE_CASES: [(&str, &str); 3] = [
+    ("translate(10, 20)", "translate(10, 20)"),
+    ("&lt;tspan&gt; &amp; &quot;x&quot; &apos;y&apos;", "<tspan> & \"x\" 'y'"),
+    ("\u{00A0}©", "\u{00A0}©"),
+];
+
+#[test]
+fn encode_foreign_attribute() {
+    for (expect, text) in FOREIGN_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::encode_foreign_attribute(text));
+    }
+}
+
+#[test]
+fn encode_foreign_attribute_to_string() {
+    for (expect, text) in FOREIGN_ATTRIBUTE_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::encode_foreign_attribute_to_string(text, &mut String::new())
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_foreign_attribute_to_writer() {
+    for (expect, text) in FOREIGN_ATTRIBUTE_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::encode_foreign_attribute_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn encode_foreign_text() {
+    assert_eq!("x &lt; y &amp;&amp; 'z'", html_escape::encode_foreign_text("x < y && 'z'"));
+}
+
+const DECODE_XML_CASES: [(&str, &str); 4] = [
+    ("", ""),
+    ("&lt;a&gt; &amp; &quot;b&quot; &apos;c&apos;", "<a> & \"b\" 'c'"),
+    ("&#x41;&#66; AT&T", "AB AT&T"),
+    ("&#x80;", "\u{80}"),
+];
+
+const DECODE_XML_ERROR_CASES: [(&str, html_escape::DecodeErrorKind); 6] = [
+    ("&nbsp;", html_escape::DecodeErrorKind::UnknownNamedReference),
+    ("&copy;", html_escape::DecodeErrorKind::UnknownNamedReference),
+    ("&amp", html_escape::DecodeErrorKind::MissingSemicolon),
+    ("&#xD800;", html_escape::DecodeErrorKind::InvalidCodePoint),
+    ("&#1;", html_escape::DecodeErrorKind::InvalidCodePoint),
+    ("&#xFFFF;", html_escape::DecodeErrorKind::InvalidCodePoint),
+];
+
+#[test]
+fn decode_xml_entities() {
+    for (text, expect) in DECODE_XML_CASES.iter().copied() {
+        assert_eq!(expect, html_escape::decode_xml_entities(text).unwrap());
+    }
+
+    for (text, kind) in DECODE_XML_ERROR_CASES.iter().copied() {
+        assert_eq!(kind, html_escape::decode_xml_entities(text).unwrap_err().kind(), "{:?}", text);
+    }
+}
+
+#[test]
+fn decode_xml_entities_to_string() {
+    for (text, expect) in DECODE_XML_CASES.iter().copied() {
+        assert_eq!(
+            expect,
+            html_escape::decode_xml_entities_to_string(text, &mut String::new()).unwrap()
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decode_xml_entities_to_writer() {
+    for (text, expect) in DECODE_XML_CASES.iter().copied() {
+        let mut v = Vec::new();
+        html_escape::decode_xml_entities_to_writer(text, &mut v).unwrap();
+
+        assert_eq!(expect.as_bytes(), v.as_slice());
+    }
+}
+
+#[test]
+fn decode_xml_entities_keeping_html_names() {
+    assert_eq!(
+        "< &nbsp; &#xD800;",
+        html_escape::decode_html_entities_with(
+            "&lt; &nbsp; &#xD800;",
+            &html_escape::DecodeOptions::XML
+        )
+        .unwrap()
+    );
+}